@@ -23,11 +23,19 @@
 //!
 //! # Convert with specific encoding
 //! subx convert input.srt --format ass --encoding utf-8 --keep-original
+//!
+//! # Drop hearing-impaired annotations while converting
+//! subx convert input.srt --format vtt --strip-sdh=en
+//!
+//! # Write Big5 with CRLF line endings for a hardware player
+//! subx convert input.srt --format srt --encoding big5 --line-ending crlf
 //! ```
 
 #![allow(clippy::needless_borrows_for_generic_args)]
 // src/cli/convert_args.rs
-use crate::cli::InputPathHandler;
+use crate::cli::output_encoding;
+use crate::cli::{InputPathHandler, LineEndingArg, SdhLanguageArg, UnmappableArg};
+use crate::core::formats::encoding::OutputEncoding;
 use crate::error::SubXError;
 use clap::{Args, ValueEnum};
 use std::path::PathBuf;
@@ -61,9 +69,43 @@ pub struct ConvertArgs {
     /// Character encoding for input and output files
     #[arg(long, default_value = "utf-8")]
     pub encoding: String,
+
+    /// Write a byte order mark (UTF-8/16/32 output only)
+    #[arg(long)]
+    pub bom: bool,
+
+    /// Line ending style of the output files
+    #[arg(long, value_enum, value_name = "STYLE")]
+    pub line_ending: Option<LineEndingArg>,
+
+    /// What to do with characters the output encoding cannot represent
+    #[arg(long, value_enum, default_value = "fail")]
+    pub on_unmappable: UnmappableArg,
+
+    /// Remove hearing-impaired (SDH) annotations using the given rule set
+    #[arg(
+        long,
+        value_enum,
+        value_name = "LANGUAGE",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "auto"
+    )]
+    pub strip_sdh: Option<SdhLanguageArg>,
 }
 
 impl ConvertArgs {
+    /// Output encoding selected by `--encoding`, `--bom`, `--line-ending`
+    /// and `--on-unmappable`.
+    pub fn output_encoding(&self) -> crate::Result<OutputEncoding> {
+        output_encoding::output_encoding(
+            &self.encoding,
+            self.bom,
+            self.line_ending,
+            self.on_unmappable,
+        )
+    }
+
     /// Get all input paths
     /// Get all input paths, combining input and input_paths parameters
     pub fn get_input_handler(&self) -> Result<InputPathHandler, SubXError> {
@@ -252,6 +294,17 @@ impl OutputSubtitleFormat {
     }
 }
 
+impl From<OutputSubtitleFormat> for crate::core::formats::SubtitleFormatType {
+    fn from(format: OutputSubtitleFormat) -> Self {
+        match format {
+            OutputSubtitleFormat::Srt => Self::Srt,
+            OutputSubtitleFormat::Ass => Self::Ass,
+            OutputSubtitleFormat::Vtt => Self::Vtt,
+            OutputSubtitleFormat::Sub => Self::Sub,
+        }
+    }
+}
+
 impl std::fmt::Display for OutputSubtitleFormat {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.as_str())
@@ -262,6 +315,7 @@ impl std::fmt::Display for OutputSubtitleFormat {
 mod tests_parse {
     use super::*;
     use crate::cli::{Cli, Commands};
+    use crate::core::formats::encoding::{Charset, LineEnding, UnmappablePolicy};
     use clap::Parser;
     use std::path::PathBuf;
 
@@ -307,5 +361,59 @@ mod tests_parse {
         assert_eq!(args.output, Some(PathBuf::from("out")));
         assert!(args.keep_original);
         assert_eq!(args.encoding, "gbk");
+        assert_eq!(args.strip_sdh, None);
+    }
+
+    #[test]
+    fn test_convert_args_strip_sdh() {
+        let cli = Cli::try_parse_from(&["subx-cli", "convert", "--strip-sdh", "in.srt"]).unwrap();
+        let args = match cli.command {
+            Commands::Convert(c) => c,
+            _ => panic!("Expected Convert command"),
+        };
+        assert_eq!(args.input, Some(PathBuf::from("in.srt")));
+        assert_eq!(args.strip_sdh, Some(SdhLanguageArg::Auto));
+
+        let cli =
+            Cli::try_parse_from(&["subx-cli", "convert", "in.srt", "--strip-sdh=cjk"]).unwrap();
+        let args = match cli.command {
+            Commands::Convert(c) => c,
+            _ => panic!("Expected Convert command"),
+        };
+        assert_eq!(args.strip_sdh, Some(SdhLanguageArg::Cjk));
+    }
+
+    #[test]
+    fn test_convert_args_output_encoding() {
+        let cli = Cli::try_parse_from(&[
+            "subx-cli",
+            "convert",
+            "in.srt",
+            "--encoding",
+            "utf-16",
+            "--bom",
+            "--line-ending",
+            "crlf",
+            "--on-unmappable",
+            "report",
+        ])
+        .unwrap();
+        let args = match cli.command {
+            Commands::Convert(c) => c,
+            _ => panic!("Expected Convert command"),
+        };
+        let output = args.output_encoding().unwrap();
+        assert_eq!(output.charset, Charset::Utf16Le);
+        assert!(output.bom);
+        assert_eq!(output.line_ending, Some(LineEnding::Crlf));
+        assert_eq!(output.unmappable, UnmappablePolicy::Report);
+
+        let cli = Cli::try_parse_from(&["subx-cli", "convert", "in.srt", "--encoding", "klingon"])
+            .unwrap();
+        let args = match cli.command {
+            Commands::Convert(c) => c,
+            _ => panic!("Expected Convert command"),
+        };
+        assert!(args.output_encoding().is_err());
     }
 }
@@ -26,6 +26,15 @@
 //!
 //! # Batch detect all subtitle files in current directory
 //! subx detect-encoding *.srt *.ass *.vtt *.sub
+//!
+//! # Repair files that were decoded with the wrong code page and re-saved
+//! subx detect-encoding --fix-mojibake broken.srt
+//!
+//! # Convert every non-UTF-8 subtitle in a folder, keeping .bak backups
+//! subx detect-encoding --convert -i ./subs --recursive
+//!
+//! # Convert to GBK, including files detected with low confidence
+//! subx detect-encoding --convert --to gbk --force *.srt
 //! ```
 
 use crate::cli::InputPathHandler;
@@ -53,6 +62,22 @@ pub struct DetectEncodingArgs {
     #[arg(short, long)]
     pub recursive: bool,
 
+    /// Rewrite files in place as UTF-8 with detected mojibake (e.g. `Ã©` for `é`) reversed
+    #[arg(long, conflicts_with = "convert")]
+    pub fix_mojibake: bool,
+
+    /// Convert files not in the target encoding, keeping `.bak` backups
+    #[arg(long)]
+    pub convert: bool,
+
+    /// Target encoding for --convert [default: utf-8]
+    #[arg(long, value_name = "ENCODING", requires = "convert")]
+    pub to: Option<String>,
+
+    /// Convert files even when detection confidence is below the threshold
+    #[arg(long, requires = "convert")]
+    pub force: bool,
+
     /// File paths to analyze for encoding detection
     #[arg(required = true, conflicts_with = "input_paths")]
     pub file_paths: Vec<String>,
@@ -105,6 +130,58 @@ mod tests {
         assert!(args.verbose);
     }
 
+    #[test]
+    fn test_detect_encoding_args_fix_mojibake() {
+        let cli = Cli::try_parse_from(["subx-cli", "detect-encoding", "a.srt"]).unwrap();
+        let args = match cli.command {
+            Commands::DetectEncoding(a) => a,
+            _ => panic!("Expected DetectEncoding command"),
+        };
+        assert!(!args.fix_mojibake);
+
+        let cli = Cli::try_parse_from(["subx-cli", "detect-encoding", "--fix-mojibake", "a.srt"])
+            .unwrap();
+        let args = match cli.command {
+            Commands::DetectEncoding(a) => a,
+            _ => panic!("Expected DetectEncoding command"),
+        };
+        assert!(args.fix_mojibake);
+    }
+
+    #[test]
+    fn test_detect_encoding_args_convert() {
+        let cli = Cli::try_parse_from([
+            "subx-cli",
+            "detect-encoding",
+            "--convert",
+            "--to",
+            "gbk",
+            "--force",
+            "a.srt",
+        ])
+        .unwrap();
+        let args = match cli.command {
+            Commands::DetectEncoding(a) => a,
+            _ => panic!("Expected DetectEncoding command"),
+        };
+        assert!(args.convert);
+        assert!(args.force);
+        assert_eq!(args.target_encoding(), "gbk");
+
+        let cli =
+            Cli::try_parse_from(["subx-cli", "detect-encoding", "--convert", "a.srt"]).unwrap();
+        let args = match cli.command {
+            Commands::DetectEncoding(a) => a,
+            _ => panic!("Expected DetectEncoding command"),
+        };
+        assert_eq!(args.target_encoding(), "utf-8");
+
+        assert!(
+            Cli::try_parse_from(["subx-cli", "detect-encoding", "--to", "gbk", "a.srt"]).is_err()
+        );
+        assert!(Cli::try_parse_from(["subx-cli", "detect-encoding", "--force", "a.srt"]).is_err());
+    }
+
     #[test]
     fn test_detect_encoding_args_conflict_file_and_input() {
         let res = Cli::try_parse_from(["subx-cli", "detect-encoding", "file.srt", "-i", "dir"]);
@@ -113,6 +190,11 @@ mod tests {
 }
 
 impl DetectEncodingArgs {
+    /// Target encoding of `--convert`, UTF-8 unless `--to` is given.
+    pub fn target_encoding(&self) -> &str {
+        self.to.as_deref().unwrap_or("utf-8")
+    }
+
     /// Get all input paths, combining file_paths and input_paths parameters
     pub fn get_input_handler(&self) -> Result<InputPathHandler, SubXError> {
         let merged_paths = InputPathHandler::merge_paths_from_multiple_sources(
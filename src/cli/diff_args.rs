@@ -0,0 +1,62 @@
+//! Subtitle comparison command-line arguments.
+//!
+//! This module defines the command-line interface for the `diff` subcommand,
+//! which compares two versions of a subtitle cue by cue instead of line by
+//! line. Cues are aligned by time and text similarity, so re-numbered or
+//! globally shifted files produce a short, meaningful report.
+//!
+//! # Examples
+//!
+//! ```bash
+//! # Compare a vendor re-delivery with the previous version
+//! subx diff movie.v1.srt movie.v2.srt
+//!
+//! # Machine-readable report, ignoring timing changes below 100 ms
+//! subx diff old.ass new.ass --tolerance 100 --json
+//! ```
+
+use clap::Args;
+use std::path::PathBuf;
+
+/// Command-line arguments for comparing two subtitle files.
+#[derive(Args, Debug)]
+pub struct DiffArgs {
+    /// Original subtitle file
+    pub old: PathBuf,
+
+    /// Revised subtitle file
+    pub new: PathBuf,
+
+    /// Timing difference in milliseconds below which cues count as unchanged
+    #[arg(long, default_value = "20")]
+    pub tolerance: u64,
+
+    /// Print the report as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::{Cli, Commands};
+    use clap::Parser;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_diff_args_defaults() {
+        let cli = Cli::try_parse_from(["subx-cli", "diff", "a.srt", "b.srt"]).unwrap();
+        let args = match cli.command {
+            Commands::Diff(a) => a,
+            _ => panic!("Expected Diff command"),
+        };
+        assert_eq!(args.old, PathBuf::from("a.srt"));
+        assert_eq!(args.new, PathBuf::from("b.srt"));
+        assert_eq!(args.tolerance, 20);
+        assert!(!args.json);
+    }
+
+    #[test]
+    fn test_diff_args_requires_two_files() {
+        assert!(Cli::try_parse_from(["subx-cli", "diff", "a.srt"]).is_err());
+    }
+}
@@ -0,0 +1,75 @@
+//! Subtitle inspection command-line arguments.
+//!
+//! This module defines the command-line interface for the `info` subcommand,
+//! which reports what SubX makes of a subtitle file: the detected format and
+//! encoding, cue count and timing, overlaps, reading speed and line length
+//! distributions, styling and language.
+//!
+//! # Examples
+//!
+//! ```bash
+//! # Inspect a single file
+//! subx info movie.srt
+//!
+//! # JSON report for every subtitle in a directory tree
+//! subx info -i ./subs --recursive --json
+//! ```
+
+use crate::cli::InputPathHandler;
+use crate::error::SubXError;
+use clap::Args;
+use std::path::PathBuf;
+
+/// Command-line arguments for inspecting subtitle files.
+#[derive(Args, Debug)]
+pub struct InfoArgs {
+    /// Input subtitle file or directory
+    pub input: Option<PathBuf>,
+
+    /// Specify file or directory paths to inspect, can be used multiple times
+    #[arg(short = 'i', long = "input", value_name = "PATH")]
+    pub input_paths: Vec<PathBuf>,
+
+    /// Recursively process subdirectories
+    #[arg(short, long)]
+    pub recursive: bool,
+
+    /// Print the report as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl InfoArgs {
+    /// Get all input paths, combining input and input_paths parameters
+    pub fn get_input_handler(&self) -> Result<InputPathHandler, SubXError> {
+        let optional_paths = vec![self.input.clone()];
+        let merged_paths = InputPathHandler::merge_paths_from_multiple_sources(
+            &optional_paths,
+            &self.input_paths,
+            &[],
+        )?;
+
+        Ok(InputPathHandler::from_args(&merged_paths, self.recursive)?
+            .with_extensions(&["srt", "ass", "vtt", "sub", "ssa"]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Cli, Commands};
+    use clap::Parser;
+
+    #[test]
+    fn test_info_args_parsing() {
+        let cli = Cli::try_parse_from(["subx-cli", "info", "movie.srt", "--json"]).unwrap();
+        let args = match cli.command {
+            Commands::Info(a) => a,
+            _ => panic!("Expected Info command"),
+        };
+        assert_eq!(args.input, Some(PathBuf::from("movie.srt")));
+        assert!(args.input_paths.is_empty());
+        assert!(!args.recursive);
+        assert!(args.json);
+    }
+}
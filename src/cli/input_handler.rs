@@ -88,13 +88,7 @@ use crate::error::SubXError;
 /// // Example of how commands use InputPathHandler
 /// # let args = MatchArgs {
 /// #     path: Some(PathBuf::from("test")),
-/// #     input_paths: vec![],
-/// #     recursive: false,
-/// #     dry_run: false,
-/// #     confidence: 80,
-/// #     backup: false,
-/// #     copy: false,
-/// #     move_files: false,
+/// #     ..Default::default()
 /// # };
 /// let handler = args.get_input_handler()?;
 /// let files = handler.collect_files()?;
@@ -0,0 +1,129 @@
+//! Subtitle join command-line arguments.
+//!
+//! This module defines the command-line interface for the `join` subcommand,
+//! which appends multi-part subtitles (such as `CD1`/`CD2` releases) into a
+//! single subtitle for a single-file video.
+//!
+//! Each later part is shifted by the length of the parts before it. The
+//! lengths come either from explicit `--offset` values or from the duration
+//! of the matching `--video` files.
+//!
+//! # Examples
+//!
+//! ```bash
+//! # Join using the duration of the first CD's video
+//! subx join movie.cd1.srt movie.cd2.srt --video movie.cd1.avi
+//!
+//! # Join with an explicit offset
+//! subx join movie.cd1.srt movie.cd2.srt --offset 52:13.040 -o movie.srt
+//! ```
+
+use crate::cli::parse_time_value;
+use clap::Args;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Command-line arguments for joining subtitle parts.
+#[derive(Args, Debug)]
+pub struct JoinArgs {
+    /// Subtitle parts in playback order
+    #[arg(required = true, num_args = 2..)]
+    pub parts: Vec<PathBuf>,
+
+    /// Length of each part except the last, in seconds or [HH:]MM:SS[.mmm]
+    #[arg(long, value_parser = parse_time_value, conflicts_with = "videos")]
+    pub offset: Vec<Duration>,
+
+    /// Video file of each part except the last; its duration is used as the offset
+    #[arg(long = "video", value_name = "VIDEO")]
+    pub videos: Vec<PathBuf>,
+
+    /// Output file path (default: first part name without its CD/part marker)
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Overwrite the output file if it already exists
+    #[arg(long)]
+    pub force: bool,
+}
+
+impl JoinArgs {
+    /// Validate that exactly one offset source is given for every part boundary.
+    pub fn validate(&self) -> Result<(), String> {
+        let boundaries = self.parts.len().saturating_sub(1);
+        let given = self.offset.len().max(self.videos.len());
+        if given != boundaries {
+            return Err(format!(
+                "Joining {} parts requires {} --offset or --video values, got {}",
+                self.parts.len(),
+                boundaries,
+                given
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::{Cli, Commands};
+    use clap::Parser;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    #[test]
+    fn test_join_args_with_offset() {
+        let cli = Cli::try_parse_from([
+            "subx-cli",
+            "join",
+            "a.cd1.srt",
+            "a.cd2.srt",
+            "--offset",
+            "52:13.5",
+        ])
+        .unwrap();
+        let args = match cli.command {
+            Commands::Join(a) => a,
+            _ => panic!("Expected Join command"),
+        };
+        assert_eq!(
+            args.parts,
+            vec![PathBuf::from("a.cd1.srt"), PathBuf::from("a.cd2.srt")]
+        );
+        assert_eq!(args.offset, vec![Duration::from_millis(3_133_500)]);
+        assert!(args.videos.is_empty());
+        assert!(args.validate().is_ok());
+    }
+
+    #[test]
+    fn test_join_args_with_videos() {
+        let cli = Cli::try_parse_from([
+            "subx-cli", "join", "a.srt", "b.srt", "c.srt", "--video", "a.mkv", "--video", "b.mkv",
+        ])
+        .unwrap();
+        let args = match cli.command {
+            Commands::Join(a) => a,
+            _ => panic!("Expected Join command"),
+        };
+        assert_eq!(args.videos.len(), 2);
+        assert!(args.validate().is_ok());
+    }
+
+    #[test]
+    fn test_join_args_validation() {
+        let res = Cli::try_parse_from(["subx-cli", "join", "a.srt"]);
+        assert!(res.is_err());
+
+        let res = Cli::try_parse_from([
+            "subx-cli", "join", "a.srt", "b.srt", "--offset", "10", "--video", "a.mkv",
+        ]);
+        assert!(res.is_err());
+
+        let cli = Cli::try_parse_from(["subx-cli", "join", "a.srt", "b.srt"]).unwrap();
+        let args = match cli.command {
+            Commands::Join(a) => a,
+            _ => panic!("Expected Join command"),
+        };
+        assert!(args.validate().is_err());
+    }
+}
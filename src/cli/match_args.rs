@@ -1,9 +1,13 @@
 #![allow(clippy::needless_borrows_for_generic_args)]
 //! Command-line arguments for the AI-powered subtitle matching command.
 
-use crate::cli::InputPathHandler;
+use crate::cli::output_encoding;
+use crate::cli::{InputPathHandler, LineEndingArg, UnmappableArg};
+use crate::core::formats::encoding::OutputEncoding;
+use crate::core::matcher::engine::{ConflictResolution, FileRelocationMode};
+use crate::core::matcher::{CandidateSelection, NamingTemplate};
 use crate::error::SubXError;
-use clap::Args;
+use clap::{Args, ValueEnum};
 use std::path::PathBuf;
 
 /// Arguments for AI-powered subtitle file matching and renaming.
@@ -39,10 +43,170 @@ pub struct MatchArgs {
     /// Move matched subtitle files to the same folder as their corresponding video files
     #[arg(long = "move", short = 'm')]
     pub move_files: bool,
+
+    /// Link the video folders to the matched subtitle files with symbolic
+    /// links, leaving the originals in place
+    #[arg(long)]
+    pub symlink: bool,
+
+    /// Hard link matched subtitle files into the video folders, copying them
+    /// when a folder is on another filesystem
+    #[arg(long)]
+    pub hardlink: bool,
+
+    /// Store symbolic link targets relative to the video folder
+    #[arg(long, requires = "symlink")]
+    pub relative: bool,
+
+    /// Re-encode matched subtitle files to this character encoding
+    #[arg(long, value_name = "ENCODING")]
+    pub encoding: Option<String>,
+
+    /// Write a byte order mark when re-encoding (UTF-8/16/32 only)
+    #[arg(long)]
+    pub bom: bool,
+
+    /// Convert line endings of matched subtitle files
+    #[arg(long, value_enum, value_name = "STYLE")]
+    pub line_ending: Option<LineEndingArg>,
+
+    /// What to do with characters the output encoding cannot represent
+    #[arg(long, value_enum, default_value = "fail")]
+    pub on_unmappable: UnmappableArg,
+
+    /// Match offline by filename heuristics instead of querying the AI provider
+    #[arg(long)]
+    pub no_ai: bool,
+
+    /// Settle confident filename-heuristic matches locally and send only the
+    /// remaining subtitles to the AI provider
+    #[arg(long, conflicts_with = "no_ai")]
+    pub local_prefilter: bool,
+
+    /// Naming preset (subx, plex, jellyfin, emby, kodi) or template such as
+    /// "{video_stem}{.lang_bcp47}{.forced}.{ext}"; overrides general.naming_template
+    #[arg(long, value_name = "PRESET|TEMPLATE")]
+    pub naming: Option<String>,
+
+    /// Check matches against the speech detected in each video (VAD) and
+    /// move subtitles whose cues follow another video's dialogue better
+    #[arg(long)]
+    pub verify_audio: bool,
+
+    /// Review each proposed match before it is applied: accept, reject,
+    /// choose another video or edit the target name. Decisions are saved to
+    /// the match cache, so `--interactive --dry-run` followed by a plain run
+    /// applies them
+    #[arg(long)]
+    pub interactive: bool,
+
+    /// Rank subtitles competing for the same target name (timing, release
+    /// group, encoding, cue count, SDH/forced, lint) and name them by rank
+    /// or keep only the best
+    #[arg(long, value_enum, value_name = "MODE")]
+    pub candidates: Option<CandidatesArg>,
+
+    /// Keep matches whose cues end well after the video, as read from its
+    /// container metadata, instead of rejecting them
+    #[arg(long)]
+    pub no_duration_check: bool,
+
+    /// What to do when a subtitle's target name is taken by another file;
+    /// defaults to `prompt` with `--interactive` and `rename` otherwise
+    #[arg(long, value_enum, value_name = "POLICY")]
+    pub on_conflict: Option<ConflictArg>,
+
+    /// Remove subtitles next to each matched video that are byte-identical
+    /// or have the same cues as another one, keeping a single copy
+    #[arg(long)]
+    pub dedupe: bool,
+}
+
+impl Default for MatchArgs {
+    /// The arguments of a plain `subx-cli match` run without paths.
+    fn default() -> Self {
+        Self {
+            path: None,
+            input_paths: Vec::new(),
+            dry_run: false,
+            confidence: 80,
+            recursive: false,
+            backup: false,
+            copy: false,
+            move_files: false,
+            symlink: false,
+            hardlink: false,
+            relative: false,
+            encoding: None,
+            bom: false,
+            line_ending: None,
+            on_unmappable: UnmappableArg::Fail,
+            no_ai: false,
+            local_prefilter: false,
+            naming: None,
+            verify_audio: false,
+            interactive: false,
+            candidates: None,
+            no_duration_check: false,
+            on_conflict: None,
+            dedupe: false,
+        }
+    }
+}
+
+/// Handling of a subtitle whose target name is taken by another file.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConflictArg {
+    /// Add a numeric suffix to the new name
+    Rename,
+    /// Leave the subtitle where it is
+    Skip,
+    /// Ask what to do
+    Prompt,
+    /// Replace the file if its content is identical, otherwise rename
+    OverwriteIdentical,
+    /// Replace the file if the subtitle was modified more recently, otherwise skip
+    KeepNewer,
+    /// Replace the file if the subtitle is larger, otherwise skip
+    KeepLarger,
+    /// Move the file to a `.backup` file, then replace it
+    BackupReplace,
+}
+
+impl From<ConflictArg> for ConflictResolution {
+    fn from(arg: ConflictArg) -> Self {
+        match arg {
+            ConflictArg::Rename => ConflictResolution::AutoRename,
+            ConflictArg::Skip => ConflictResolution::Skip,
+            ConflictArg::Prompt => ConflictResolution::Prompt,
+            ConflictArg::OverwriteIdentical => ConflictResolution::OverwriteIfIdentical,
+            ConflictArg::KeepNewer => ConflictResolution::KeepNewer,
+            ConflictArg::KeepLarger => ConflictResolution::KeepLarger,
+            ConflictArg::BackupReplace => ConflictResolution::BackupAndReplace,
+        }
+    }
+}
+
+/// Handling of several subtitles matched to the same video and target name.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CandidatesArg {
+    /// Keep all; the best takes the plain name, the others are numbered by rank
+    Ranked,
+    /// Keep only the best candidate
+    Best,
+}
+
+impl From<CandidatesArg> for CandidateSelection {
+    fn from(arg: CandidatesArg) -> Self {
+        match arg {
+            CandidatesArg::Ranked => CandidateSelection::Ranked,
+            CandidatesArg::Best => CandidateSelection::Best,
+        }
+    }
 }
 
 impl MatchArgs {
-    /// Validate that copy and move arguments are not used together
+    /// Validate that at most one relocation mode is chosen
     pub fn validate(&self) -> Result<(), String> {
         if self.copy && self.move_files {
             return Err(
@@ -50,9 +214,63 @@ impl MatchArgs {
                     .to_string(),
             );
         }
+        let modes = [self.copy, self.move_files, self.symlink, self.hardlink];
+        if modes.iter().filter(|&&m| m).count() > 1 {
+            return Err("Choose only one of --copy, --move, --symlink and --hardlink.".to_string());
+        }
         Ok(())
     }
 
+    /// Relocation mode selected by `--copy`, `--move`, `--symlink` or
+    /// `--hardlink`.
+    pub fn relocation_mode(&self) -> FileRelocationMode {
+        if self.copy {
+            FileRelocationMode::Copy
+        } else if self.move_files {
+            FileRelocationMode::Move
+        } else if self.symlink {
+            FileRelocationMode::Symlink {
+                relative: self.relative,
+            }
+        } else if self.hardlink {
+            FileRelocationMode::Hardlink
+        } else {
+            FileRelocationMode::None
+        }
+    }
+
+    /// Conflict policy selected by `--on-conflict`, falling back to
+    /// prompting with `--interactive` and auto-renaming otherwise.
+    pub fn conflict_resolution(&self) -> ConflictResolution {
+        match self.on_conflict {
+            Some(policy) => policy.into(),
+            None if self.interactive => ConflictResolution::Prompt,
+            None => ConflictResolution::AutoRename,
+        }
+    }
+
+    /// Output encoding for matched subtitles, or `None` when no
+    /// `--encoding`, `--bom` or `--line-ending` option was given and the
+    /// files are relocated unchanged.
+    pub fn output_encoding(&self) -> crate::Result<Option<OutputEncoding>> {
+        if self.encoding.is_none() && !self.bom && self.line_ending.is_none() {
+            return Ok(None);
+        }
+        output_encoding::output_encoding(
+            self.encoding.as_deref().unwrap_or("utf-8"),
+            self.bom,
+            self.line_ending,
+            self.on_unmappable,
+        )
+        .map(Some)
+    }
+
+    /// Naming template from `--naming`, falling back to the configured
+    /// `general.naming_template`.
+    pub fn naming_template(&self, configured: &str) -> crate::Result<NamingTemplate> {
+        self.naming.as_deref().unwrap_or(configured).parse()
+    }
+
     /// Get all input paths, combining path and input_paths parameters
     pub fn get_input_handler(&self) -> Result<InputPathHandler, SubXError> {
         let optional_paths = vec![self.path.clone()];
@@ -70,7 +288,8 @@ impl MatchArgs {
 // Test parameter parsing behavior
 #[cfg(test)]
 mod tests {
-    use crate::cli::{Cli, Commands};
+    use crate::cli::{CandidatesArg, Cli, Commands, ConflictArg, MatchArgs};
+    use crate::core::matcher::engine::{ConflictResolution, FileRelocationMode};
     use clap::Parser;
     use std::path::PathBuf;
 
@@ -89,6 +308,15 @@ mod tests {
         assert_eq!(args.confidence, 80);
     }
 
+    #[test]
+    fn test_match_args_default_matches_parsed_defaults() {
+        let cli = Cli::try_parse_from(&["subx-cli", "match"]).unwrap();
+        let Commands::Match(args) = cli.command else {
+            panic!("Expected Match command");
+        };
+        assert_eq!(format!("{:?}", args), format!("{:?}", MatchArgs::default()));
+    }
+
     #[test]
     fn test_match_args_parsing() {
         let cli = Cli::try_parse_from(&[
@@ -114,6 +342,110 @@ mod tests {
         assert_eq!(args.confidence, 50);
     }
 
+    #[test]
+    fn test_match_args_offline_flags() {
+        let cli = Cli::try_parse_from(&["subx-cli", "match", "path", "--no-ai"]).unwrap();
+        let args = match cli.command {
+            Commands::Match(m) => m,
+            _ => panic!("Expected Match command"),
+        };
+        assert!(args.no_ai);
+        assert!(!args.local_prefilter);
+
+        let cli = Cli::try_parse_from(&["subx-cli", "match", "path", "--local-prefilter"]).unwrap();
+        let args = match cli.command {
+            Commands::Match(m) => m,
+            _ => panic!("Expected Match command"),
+        };
+        assert!(args.local_prefilter);
+
+        let result =
+            Cli::try_parse_from(&["subx-cli", "match", "path", "--no-ai", "--local-prefilter"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_match_args_naming_overrides_config() {
+        let cli = Cli::try_parse_from(&["subx-cli", "match", "path", "--naming", "plex"]).unwrap();
+        let args = match cli.command {
+            Commands::Match(m) => m,
+            _ => panic!("Expected Match command"),
+        };
+        assert_eq!(args.naming_template("jellyfin").unwrap().as_str(), "plex");
+
+        let cli = Cli::try_parse_from(&["subx-cli", "match", "path"]).unwrap();
+        let args = match cli.command {
+            Commands::Match(m) => m,
+            _ => panic!("Expected Match command"),
+        };
+        assert_eq!(
+            args.naming_template("jellyfin").unwrap().as_str(),
+            "jellyfin"
+        );
+        assert!(args.naming_template("{video_stem}.srt").is_err());
+        assert!(!args.verify_audio);
+
+        let cli = Cli::try_parse_from(&["subx-cli", "match", "path", "--verify-audio"]).unwrap();
+        let args = match cli.command {
+            Commands::Match(m) => m,
+            _ => panic!("Expected Match command"),
+        };
+        assert!(args.verify_audio);
+        assert!(!args.interactive);
+
+        let cli = Cli::try_parse_from(&["subx-cli", "match", "path", "--interactive"]).unwrap();
+        let args = match cli.command {
+            Commands::Match(m) => m,
+            _ => panic!("Expected Match command"),
+        };
+        assert!(args.interactive);
+        assert_eq!(args.candidates, None);
+
+        let cli =
+            Cli::try_parse_from(&["subx-cli", "match", "path", "--candidates", "best"]).unwrap();
+        let args = match cli.command {
+            Commands::Match(m) => m,
+            _ => panic!("Expected Match command"),
+        };
+        assert_eq!(args.candidates, Some(CandidatesArg::Best));
+        assert!(!args.no_duration_check);
+        assert!(
+            Cli::try_parse_from(&["subx-cli", "match", "path", "--candidates", "all"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_match_args_conflict_policy() {
+        let parse = |extra: &[&str]| {
+            let cli =
+                Cli::try_parse_from(["subx-cli", "match", "path"].iter().chain(extra)).unwrap();
+            match cli.command {
+                Commands::Match(m) => m,
+                _ => panic!("Expected Match command"),
+            }
+        };
+        let args = parse(&[]);
+        assert!(matches!(
+            args.conflict_resolution(),
+            ConflictResolution::AutoRename
+        ));
+        assert!(!args.dedupe);
+        assert!(matches!(
+            parse(&["--interactive"]).conflict_resolution(),
+            ConflictResolution::Prompt
+        ));
+        let args = parse(&["--on-conflict", "backup-replace", "--dedupe"]);
+        assert_eq!(args.on_conflict, Some(ConflictArg::BackupReplace));
+        assert!(matches!(
+            args.conflict_resolution(),
+            ConflictResolution::BackupAndReplace
+        ));
+        assert!(args.dedupe);
+        assert!(
+            Cli::try_parse_from(&["subx-cli", "match", "path", "--on-conflict", "merge"]).is_err()
+        );
+    }
+
     #[test]
     fn test_match_args_invalid_confidence() {
         let res = Cli::try_parse_from(&["subx-cli", "match", "path", "--confidence", "150"]);
@@ -136,6 +468,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_match_args_link_modes() {
+        let cli =
+            Cli::try_parse_from(&["subx-cli", "match", "path", "--symlink", "--relative"]).unwrap();
+        let args = match cli.command {
+            Commands::Match(m) => m,
+            _ => panic!("Expected Match command"),
+        };
+        assert!(args.validate().is_ok());
+        assert_eq!(
+            args.relocation_mode(),
+            FileRelocationMode::Symlink { relative: true }
+        );
+
+        let cli =
+            Cli::try_parse_from(&["subx-cli", "match", "path", "--hardlink", "--copy"]).unwrap();
+        let args = match cli.command {
+            Commands::Match(m) => m,
+            _ => panic!("Expected Match command"),
+        };
+        assert!(args.validate().is_err());
+
+        assert!(Cli::try_parse_from(&["subx-cli", "match", "path", "--relative"]).is_err());
+    }
+
     #[test]
     fn test_match_args_copy_parameter() {
         let cli = Cli::try_parse_from(&["subx-cli", "match", "path", "--copy"]).unwrap();
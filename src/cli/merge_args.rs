@@ -0,0 +1,142 @@
+//! Bilingual subtitle merge command-line arguments.
+//!
+//! This module defines the command-line interface for the `merge` subcommand,
+//! which combines two subtitle tracks for the same video into one bilingual
+//! subtitle. Cues are paired by time overlap and unpaired cues are kept.
+//!
+//! # Output Layouts
+//!
+//! - **SRT / VTT / SUB**: Primary and secondary text are stacked in one cue
+//! - **ASS**: Each track is written with its own style (top and bottom)
+//!
+//! # Examples
+//!
+//! ```bash
+//! # Stack Traditional Chinese over English in an SRT file
+//! subx merge movie.tc.srt movie.en.srt
+//!
+//! # Produce an ASS file with separate top/bottom styles
+//! subx merge movie.tc.srt movie.en.srt --format ass -o movie.bilingual.ass
+//! ```
+
+use crate::cli::OutputSubtitleFormat;
+use clap::Args;
+use std::path::PathBuf;
+
+/// Command-line arguments for merging two subtitles into a bilingual subtitle.
+#[derive(Args, Debug)]
+pub struct MergeArgs {
+    /// Primary subtitle file, shown on top
+    pub primary: PathBuf,
+
+    /// Secondary subtitle file, shown below the primary text
+    pub secondary: PathBuf,
+
+    /// Output file path (default: <primary>.<lang>-<lang>.<ext> next to the primary file)
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Output format (default: inferred from --output, otherwise the primary file's format)
+    #[arg(long, value_enum)]
+    pub format: Option<OutputSubtitleFormat>,
+
+    /// Minimum overlap ratio (0.0-1.0) required to pair two cues
+    #[arg(long, default_value = "0.5")]
+    pub min_overlap: f32,
+
+    /// Font for the primary (top) ASS style, overriding the language default
+    #[arg(long)]
+    pub top_font: Option<String>,
+
+    /// Font for the secondary (bottom) ASS style, overriding the language default
+    #[arg(long)]
+    pub bottom_font: Option<String>,
+
+    /// Overwrite the output file if it already exists
+    #[arg(long)]
+    pub force: bool,
+}
+
+impl MergeArgs {
+    /// Validate argument values that clap cannot check on its own.
+    pub fn validate(&self) -> Result<(), String> {
+        if !(0.0..=1.0).contains(&self.min_overlap) {
+            return Err(format!(
+                "--min-overlap must be between 0.0 and 1.0, got {}",
+                self.min_overlap
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::{Cli, Commands, OutputSubtitleFormat};
+    use clap::Parser;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_merge_args_defaults() {
+        let cli = Cli::try_parse_from(["subx-cli", "merge", "a.tc.srt", "a.en.srt"]).unwrap();
+        let args = match cli.command {
+            Commands::Merge(a) => a,
+            _ => panic!("Expected Merge command"),
+        };
+        assert_eq!(args.primary, PathBuf::from("a.tc.srt"));
+        assert_eq!(args.secondary, PathBuf::from("a.en.srt"));
+        assert_eq!(args.output, None);
+        assert_eq!(args.format, None);
+        assert_eq!(args.min_overlap, 0.5);
+        assert!(!args.force);
+        assert!(args.validate().is_ok());
+    }
+
+    #[test]
+    fn test_merge_args_parsing() {
+        let cli = Cli::try_parse_from([
+            "subx-cli",
+            "merge",
+            "a.tc.srt",
+            "a.en.srt",
+            "--format",
+            "ass",
+            "-o",
+            "out.ass",
+            "--min-overlap",
+            "0.3",
+            "--top-font",
+            "Noto Sans TC",
+            "--force",
+        ])
+        .unwrap();
+        let args = match cli.command {
+            Commands::Merge(a) => a,
+            _ => panic!("Expected Merge command"),
+        };
+        assert_eq!(args.format, Some(OutputSubtitleFormat::Ass));
+        assert_eq!(args.output, Some(PathBuf::from("out.ass")));
+        assert_eq!(args.min_overlap, 0.3);
+        assert_eq!(args.top_font.as_deref(), Some("Noto Sans TC"));
+        assert!(args.bottom_font.is_none());
+        assert!(args.force);
+    }
+
+    #[test]
+    fn test_merge_args_invalid_overlap() {
+        let cli = Cli::try_parse_from([
+            "subx-cli",
+            "merge",
+            "a.srt",
+            "b.srt",
+            "--min-overlap",
+            "1.5",
+        ])
+        .unwrap();
+        let args = match cli.command {
+            Commands::Merge(a) => a,
+            _ => panic!("Expected Merge command"),
+        };
+        assert!(args.validate().is_err());
+    }
+}
@@ -11,6 +11,14 @@
 //! - `match` - AI-powered subtitle file matching and renaming
 //! - `convert` - Subtitle format conversion between standards
 //! - `sync` - Audio-subtitle synchronization and timing adjustment
+//! - `merge` - Bilingual subtitle merging of two language tracks
+//! - `join` / `split` - Joining multi-part subtitles and splitting one in two
+//! - `strip-sdh` - Removal of hearing-impaired (SDH) annotations
+//! - `zh-convert` - Traditional/Simplified Chinese text conversion
+//! - `diff` - Semantic cue-by-cue comparison of two subtitle files
+//! - `info` - Inspection of a subtitle's format, encoding, timing and statistics
+//! - `undo` - Reverting the file changes of a recorded run
+//! - `watch` - Processing new downloads in watched directories
 //! - `detect-encoding` - Character encoding detection and conversion
 //! - `config` - Configuration management and inspection
 //! - `cache` - Cache inspection and dry-run management
@@ -33,26 +41,48 @@ mod cache_args;
 mod config_args;
 mod convert_args;
 mod detect_encoding_args;
+mod diff_args;
 mod generate_completion_args;
+mod info_args;
 mod input_handler;
+mod join_args;
 mod match_args;
+mod merge_args;
+mod output_encoding;
+mod split_args;
+mod strip_sdh_args;
 mod sync_args;
 pub mod table;
+mod time_value;
 pub mod ui;
+mod undo_args;
+mod watch_args;
+mod zh_convert_args;
 
 pub use cache_args::{CacheAction, CacheArgs};
 use clap::{Parser, Subcommand};
 pub use config_args::{ConfigAction, ConfigArgs};
 pub use convert_args::{ConvertArgs, OutputSubtitleFormat};
 pub use detect_encoding_args::DetectEncodingArgs;
+pub use diff_args::DiffArgs;
 pub use generate_completion_args::GenerateCompletionArgs;
+pub use info_args::InfoArgs;
 pub use input_handler::InputPathHandler;
-pub use match_args::MatchArgs;
-pub use sync_args::{SyncArgs, SyncMethod, SyncMethodArg, SyncMode};
+pub use join_args::JoinArgs;
+pub use match_args::{CandidatesArg, ConflictArg, MatchArgs};
+pub use merge_args::MergeArgs;
+pub use output_encoding::{LineEndingArg, UnmappableArg};
+pub use split_args::SplitArgs;
+pub use strip_sdh_args::{SdhLanguageArg, StripSdhArgs};
+pub use sync_args::{SyncArgs, SyncMethod, SyncMethodArg, SyncMode, UnderflowPolicyArg};
+pub use time_value::{parse_offset_value, parse_time_value};
 pub use ui::{
     create_progress_bar, display_ai_usage, display_match_results, print_error, print_success,
     print_warning,
 };
+pub use undo_args::UndoArgs;
+pub use watch_args::WatchArgs;
+pub use zh_convert_args::{ChineseScriptArg, ZhConvertArgs};
 
 /// Main CLI application structure defining the top-level interface.
 #[derive(Parser, Debug)]
@@ -88,6 +118,33 @@ pub enum Commands {
 
     /// Manage cache and inspect dry-run results
     Cache(CacheArgs),
+
+    /// Merge two subtitle tracks into a bilingual subtitle
+    Merge(MergeArgs),
+
+    /// Join multi-part subtitles (e.g. CD1/CD2) into one file
+    Join(JoinArgs),
+
+    /// Split a subtitle into two parts at a timestamp or cue index
+    Split(SplitArgs),
+
+    /// Remove hearing-impaired (SDH) annotations from subtitle text
+    StripSdh(StripSdhArgs),
+
+    /// Convert subtitle text between Simplified and Traditional Chinese
+    ZhConvert(ZhConvertArgs),
+
+    /// Compare two subtitle files cue by cue (timing, text, added/removed cues)
+    Diff(DiffArgs),
+
+    /// Inspect a subtitle file's format, encoding, timing and statistics
+    Info(InfoArgs),
+
+    /// Revert the file changes of a match, convert or sync run
+    Undo(UndoArgs),
+
+    /// Watch directories and process new videos and subtitles as they arrive
+    Watch(WatchArgs),
 }
 
 /// Executes the SubX CLI application with parsed arguments.
@@ -0,0 +1,64 @@
+//! Output encoding options shared by commands that write subtitle files.
+//!
+//! `convert`, `sync` and `match` accept the same `--bom`, `--line-ending`
+//! and `--on-unmappable` flags next to their `--encoding` option; this
+//! module holds the value types for them and builds the resulting
+//! [`OutputEncoding`].
+
+use clap::ValueEnum;
+
+use crate::core::formats::encoding::{LineEnding, OutputEncoding, UnmappablePolicy};
+
+/// Line ending style of written subtitle files.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineEndingArg {
+    /// Unix style line endings (`\n`)
+    Lf,
+    /// Windows style line endings (`\r\n`)
+    Crlf,
+}
+
+impl From<LineEndingArg> for LineEnding {
+    fn from(arg: LineEndingArg) -> Self {
+        match arg {
+            LineEndingArg::Lf => LineEnding::Lf,
+            LineEndingArg::Crlf => LineEnding::Crlf,
+        }
+    }
+}
+
+/// Handling of characters the output encoding cannot represent.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum UnmappableArg {
+    /// Replace them with `?` and print a warning
+    Report,
+    /// Abort without writing the file
+    #[default]
+    Fail,
+}
+
+impl From<UnmappableArg> for UnmappablePolicy {
+    fn from(arg: UnmappableArg) -> Self {
+        match arg {
+            UnmappableArg::Report => UnmappablePolicy::Report,
+            UnmappableArg::Fail => UnmappablePolicy::Fail,
+        }
+    }
+}
+
+/// Build the output encoding selected by the command-line options.
+///
+/// # Errors
+///
+/// Returns a configuration error if `encoding` is not a known charset name.
+pub(crate) fn output_encoding(
+    encoding: &str,
+    bom: bool,
+    line_ending: Option<LineEndingArg>,
+    on_unmappable: UnmappableArg,
+) -> crate::Result<OutputEncoding> {
+    Ok(OutputEncoding::from_name(encoding)?
+        .with_bom(bom)
+        .with_line_ending(line_ending.map(Into::into))
+        .with_unmappable_policy(on_unmappable.into()))
+}
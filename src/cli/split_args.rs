@@ -0,0 +1,121 @@
+//! Subtitle split command-line arguments.
+//!
+//! This module defines the command-line interface for the `split` subcommand,
+//! which cuts one subtitle into two parts, for example to separate a
+//! double-episode subtitle. The second part is rebased to start at zero.
+//!
+//! # Examples
+//!
+//! ```bash
+//! # Split at 22 minutes
+//! subx split double.srt --at 22:00
+//!
+//! # Split before cue 412 and choose output names
+//! subx split double.ass --at-index 412 -o ep01.ass -o ep02.ass
+//! ```
+
+use crate::cli::parse_time_value;
+use crate::core::formats::split_join::SplitPoint;
+use clap::Args;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Command-line arguments for splitting a subtitle in two.
+#[derive(Args, Debug)]
+pub struct SplitArgs {
+    /// Subtitle file to split
+    pub input: PathBuf,
+
+    /// Split timestamp in seconds or [HH:]MM:SS[.mmm]
+    #[arg(long, value_parser = parse_time_value, required_unless_present = "at_index", conflicts_with = "at_index")]
+    pub at: Option<Duration>,
+
+    /// 1-based index of the cue that starts the second part
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+    pub at_index: Option<u64>,
+
+    /// Output paths for the two parts (default: <input>.part1.<ext> and <input>.part2.<ext>)
+    #[arg(short, long, num_args = 2, value_names = ["FIRST", "SECOND"])]
+    pub output: Vec<PathBuf>,
+
+    /// Overwrite output files if they already exist
+    #[arg(long)]
+    pub force: bool,
+}
+
+impl SplitArgs {
+    /// Returns the requested split point.
+    pub fn split_point(&self) -> SplitPoint {
+        match (self.at, self.at_index) {
+            (Some(time), _) => SplitPoint::Time(time),
+            (None, Some(index)) => SplitPoint::Index(index as usize),
+            (None, None) => SplitPoint::Time(Duration::ZERO),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::{Cli, Commands};
+    use crate::core::formats::split_join::SplitPoint;
+    use clap::Parser;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    #[test]
+    fn test_split_args_at_time() {
+        let cli = Cli::try_parse_from(["subx-cli", "split", "ep.srt", "--at", "22:00"]).unwrap();
+        let args = match cli.command {
+            Commands::Split(a) => a,
+            _ => panic!("Expected Split command"),
+        };
+        assert_eq!(args.input, PathBuf::from("ep.srt"));
+        assert_eq!(
+            args.split_point(),
+            SplitPoint::Time(Duration::from_secs(1320))
+        );
+        assert!(args.output.is_empty());
+    }
+
+    #[test]
+    fn test_split_args_at_index_with_outputs() {
+        let cli = Cli::try_parse_from([
+            "subx-cli",
+            "split",
+            "ep.ass",
+            "--at-index",
+            "412",
+            "-o",
+            "a.ass",
+            "b.ass",
+        ])
+        .unwrap();
+        let args = match cli.command {
+            Commands::Split(a) => a,
+            _ => panic!("Expected Split command"),
+        };
+        assert_eq!(args.split_point(), SplitPoint::Index(412));
+        assert_eq!(
+            args.output,
+            vec![PathBuf::from("a.ass"), PathBuf::from("b.ass")]
+        );
+    }
+
+    #[test]
+    fn test_split_args_require_exactly_one_point() {
+        assert!(Cli::try_parse_from(["subx-cli", "split", "ep.srt"]).is_err());
+        assert!(
+            Cli::try_parse_from([
+                "subx-cli",
+                "split",
+                "ep.srt",
+                "--at",
+                "10",
+                "--at-index",
+                "3"
+            ])
+            .is_err()
+        );
+        assert!(Cli::try_parse_from(["subx-cli", "split", "ep.srt", "--at-index", "0"]).is_err());
+    }
+}
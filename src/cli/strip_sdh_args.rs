@@ -0,0 +1,144 @@
+//! SDH annotation removal command-line arguments.
+//!
+//! This module defines the command-line interface for the `strip-sdh`
+//! subcommand, which removes hearing-impaired annotations such as `[MUSIC]`,
+//! `(laughs)`, `♪` lyric lines, `JOHN:` speaker labels and `（笑）` from
+//! subtitle text. Cues left without text are dropped and a report of every
+//! removal is printed.
+//!
+//! The same cleanup is available during conversion with
+//! `subx convert --strip-sdh`.
+//!
+//! # Examples
+//!
+//! ```bash
+//! # Clean a file in place using every built-in rule set
+//! subx strip-sdh movie.en.srt
+//!
+//! # Preview what would be removed from a directory of CJK subtitles
+//! subx strip-sdh ./subs --recursive --language cjk --dry-run
+//!
+//! # Add a custom pattern and write to a new file
+//! subx strip-sdh movie.srt --pattern '<<[^>]*>>' -o movie.clean.srt
+//! ```
+
+use crate::cli::InputPathHandler;
+use crate::core::formats::sdh::SdhLanguage;
+use crate::error::SubXError;
+use clap::{Args, ValueEnum};
+use std::path::PathBuf;
+
+/// Rule set selection for SDH annotation removal.
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
+pub enum SdhLanguageArg {
+    /// Apply the English and CJK rule sets
+    Auto,
+    /// English rules: brackets, parentheses, lyrics and speaker labels
+    En,
+    /// CJK rules: full-width parentheses and brackets
+    Cjk,
+}
+
+impl From<SdhLanguageArg> for SdhLanguage {
+    fn from(arg: SdhLanguageArg) -> Self {
+        match arg {
+            SdhLanguageArg::Auto => Self::Auto,
+            SdhLanguageArg::En => Self::English,
+            SdhLanguageArg::Cjk => Self::Cjk,
+        }
+    }
+}
+
+/// Command-line arguments for removing SDH annotations from subtitles.
+#[derive(Args, Debug)]
+pub struct StripSdhArgs {
+    /// Input subtitle file or directory
+    pub input: Option<PathBuf>,
+
+    /// Specify file or directory paths to process, can be used multiple times
+    #[arg(short = 'i', long = "input", value_name = "PATH")]
+    pub input_paths: Vec<PathBuf>,
+
+    /// Recursively process subdirectories
+    #[arg(short, long)]
+    pub recursive: bool,
+
+    /// Language rule set to apply
+    #[arg(long, value_enum, default_value = "auto")]
+    pub language: SdhLanguageArg,
+
+    /// Additional regular expression to remove, can be used multiple times
+    #[arg(long, value_name = "REGEX")]
+    pub pattern: Vec<String>,
+
+    /// Output file path (single input only; default: overwrite the input)
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Print the removal report without writing any file
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+impl StripSdhArgs {
+    /// Get all input paths, combining input and input_paths parameters
+    pub fn get_input_handler(&self) -> Result<InputPathHandler, SubXError> {
+        let optional_paths = vec![self.input.clone()];
+        let merged_paths = InputPathHandler::merge_paths_from_multiple_sources(
+            &optional_paths,
+            &self.input_paths,
+            &[],
+        )?;
+
+        Ok(InputPathHandler::from_args(&merged_paths, self.recursive)?
+            .with_extensions(&["srt", "ass", "vtt", "sub", "ssa"]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Cli, Commands};
+    use clap::Parser;
+
+    #[test]
+    fn test_strip_sdh_args_defaults() {
+        let cli = Cli::try_parse_from(["subx-cli", "strip-sdh", "movie.srt"]).unwrap();
+        let args = match cli.command {
+            Commands::StripSdh(a) => a,
+            _ => panic!("Expected StripSdh command"),
+        };
+        assert_eq!(args.input, Some(PathBuf::from("movie.srt")));
+        assert_eq!(args.language, SdhLanguageArg::Auto);
+        assert!(args.pattern.is_empty());
+        assert!(args.output.is_none());
+        assert!(!args.dry_run);
+    }
+
+    #[test]
+    fn test_strip_sdh_args_parsing() {
+        let cli = Cli::try_parse_from([
+            "subx-cli",
+            "strip-sdh",
+            "-i",
+            "subs",
+            "-r",
+            "--language",
+            "cjk",
+            "--pattern",
+            "<<.*>>",
+            "--dry-run",
+        ])
+        .unwrap();
+        let args = match cli.command {
+            Commands::StripSdh(a) => a,
+            _ => panic!("Expected StripSdh command"),
+        };
+        assert_eq!(args.input_paths, vec![PathBuf::from("subs")]);
+        assert!(args.recursive);
+        assert_eq!(args.language, SdhLanguageArg::Cjk);
+        assert_eq!(args.pattern, vec!["<<.*>>".to_string()]);
+        assert!(args.dry_run);
+        assert_eq!(SdhLanguage::from(args.language), SdhLanguage::Cjk);
+    }
+}
@@ -33,10 +33,34 @@ impl From<SyncMethodArg> for crate::core::sync::SyncMethod {
     }
 }
 
-use crate::cli::InputPathHandler;
+/// Handling of cues pushed before zero by a negative manual offset.
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
+pub enum UnderflowPolicyArg {
+    /// Clamp the start time to zero, dropping cues that would end before zero
+    Clamp,
+    /// Drop every cue whose start time would fall before zero
+    Drop,
+}
+
+impl From<UnderflowPolicyArg> for crate::core::sync::UnderflowPolicy {
+    fn from(arg: UnderflowPolicyArg) -> Self {
+        match arg {
+            UnderflowPolicyArg::Clamp => Self::Clamp,
+            UnderflowPolicyArg::Drop => Self::Drop,
+        }
+    }
+}
+
+use crate::cli::output_encoding;
+use crate::cli::{
+    InputPathHandler, LineEndingArg, UnmappableArg, parse_offset_value, parse_time_value,
+};
+use crate::core::formats::encoding::OutputEncoding;
+use crate::core::sync::TimeAdjustment;
 use crate::error::{SubXError, SubXResult};
 use clap::{Args, ValueEnum};
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 /// Refactored sync command arguments supporting multiple sync methods.
 #[derive(Args, Debug, Clone)]
@@ -71,13 +95,54 @@ pub struct SyncArgs {
     pub recursive: bool,
 
     /// Manual time offset in seconds (positive delays subtitles, negative advances them).
+    ///
+    /// Also accepts signed timestamps (`-00:00:01.500`) and frame counts (`+12f@23.976`).
     #[arg(
         long,
-        value_name = "SECONDS",
-        help = "Manual offset in seconds (positive delays subtitles, negative advances them)"
+        value_name = "OFFSET",
+        value_parser = parse_offset_value,
+        allow_hyphen_values = true,
+        help = "Manual offset: seconds, [+-]HH:MM:SS.mmm or frames like +12f@23.976 (positive delays subtitles, negative advances them)"
     )]
     pub offset: Option<f32>,
 
+    // === Range Options ===
+    /// Only adjust cues starting at or after this time.
+    #[arg(
+        long,
+        value_name = "TIME",
+        value_parser = parse_time_value,
+        help = "Only adjust cues starting at or after this time (seconds or HH:MM:SS.mmm)"
+    )]
+    pub from: Option<Duration>,
+
+    /// Only adjust cues starting before this time.
+    #[arg(
+        long,
+        value_name = "TIME",
+        value_parser = parse_time_value,
+        help = "Only adjust cues starting before this time (seconds or HH:MM:SS.mmm)"
+    )]
+    pub to: Option<Duration>,
+
+    /// Stretch factor applied around the range start before the offset.
+    #[arg(
+        long,
+        value_name = "FACTOR",
+        value_parser = parse_stretch_factor,
+        help = "Stretch cue times by a factor or frame-rate ratio such as 25/23.976"
+    )]
+    pub stretch: Option<f64>,
+
+    /// Handling of cues pushed before zero by a negative offset.
+    #[arg(
+        long,
+        value_enum,
+        default_value = "clamp",
+        help = "Handling of cues pushed before zero by a negative offset"
+    )]
+    pub on_underflow: UnderflowPolicyArg,
+
     /// Sync method selection.
     #[arg(short, long, value_enum, help = "Synchronization method")]
     pub method: Option<SyncMethodArg>,
@@ -111,6 +176,36 @@ pub struct SyncArgs {
     )]
     pub output: Option<PathBuf>,
 
+    /// Output character encoding.
+    #[arg(
+        long,
+        value_name = "ENCODING",
+        help = "Character encoding of the output file (default: utf-8)"
+    )]
+    pub encoding: Option<String>,
+
+    /// Write a byte order mark.
+    #[arg(long, help = "Write a byte order mark (UTF-8/16/32 output only)")]
+    pub bom: bool,
+
+    /// Output line ending style.
+    #[arg(
+        long,
+        value_enum,
+        value_name = "STYLE",
+        help = "Line ending style of the output file"
+    )]
+    pub line_ending: Option<LineEndingArg>,
+
+    /// Handling of unrepresentable characters.
+    #[arg(
+        long,
+        value_enum,
+        default_value = "fail",
+        help = "What to do with characters the output encoding cannot represent"
+    )]
+    pub on_unmappable: UnmappableArg,
+
     /// Verbose output.
     #[arg(
         long,
@@ -153,11 +248,18 @@ impl SyncArgs {
     pub fn validate(&self) -> Result<(), String> {
         // Check manual mode parameter combination
         if let Some(SyncMethodArg::Manual) = &self.method {
-            if self.offset.is_none() {
+            if self.offset.is_none() && self.stretch.is_none() {
                 return Err("Manual method requires --offset parameter.".to_string());
             }
         }
 
+        // Check time range
+        if let (Some(from), Some(to)) = (self.from, self.to) {
+            if from >= to {
+                return Err("--from must be earlier than --to.".to_string());
+            }
+        }
+
         // In batch mode, check if we have some input source
         if self.batch.is_some() {
             let has_input_paths = !self.input_paths.is_empty();
@@ -183,7 +285,7 @@ Need help? Run: subx sync --help"
         let has_video = self.video.is_some();
         let has_subtitle = self.subtitle.is_some();
         let has_positional = !self.positional_paths.is_empty();
-        let is_manual = self.offset.is_some();
+        let is_manual = self.offset.is_some() || self.stretch.is_some();
 
         // Manual mode only requires subtitle (can be provided via positional args)
         if is_manual {
@@ -232,12 +334,34 @@ Need help? Run: subx sync --help"
 
     /// Check if in manual mode.
     pub fn is_manual_mode(&self) -> bool {
-        self.offset.is_some() || matches!(self.method, Some(SyncMethodArg::Manual))
+        self.offset.is_some()
+            || self.stretch.is_some()
+            || matches!(self.method, Some(SyncMethodArg::Manual))
+    }
+
+    /// Build the timing adjustment for `offset_seconds`, applying the
+    /// `--from`/`--to` range, `--stretch` factor and underflow handling.
+    pub fn time_adjustment(&self, offset_seconds: f32) -> TimeAdjustment {
+        TimeAdjustment::offset(offset_seconds)
+            .with_range(self.from, self.to)
+            .with_stretch(self.stretch.unwrap_or(1.0))
+            .with_underflow(self.on_underflow.into())
+    }
+
+    /// Output encoding selected by `--encoding`, `--bom`, `--line-ending`
+    /// and `--on-unmappable`.
+    pub fn output_encoding(&self) -> crate::Result<OutputEncoding> {
+        output_encoding::output_encoding(
+            self.encoding.as_deref().unwrap_or("utf-8"),
+            self.bom,
+            self.line_ending,
+            self.on_unmappable,
+        )
     }
 
     /// Determine sync method (backward compatible).
     pub fn sync_method(&self) -> SyncMethod {
-        if self.offset.is_some() {
+        if self.offset.is_some() || self.stretch.is_some() {
             SyncMethod::Manual
         } else {
             SyncMethod::Auto
@@ -441,6 +565,24 @@ pub enum SyncMode {
 
 // Helper functions
 
+/// Parse a stretch factor given as a number or a ratio such as `25/23.976`.
+fn parse_stretch_factor(value: &str) -> Result<f64, String> {
+    let invalid = || format!("Invalid stretch factor: {}", value);
+    let factor = match value.split_once('/') {
+        Some((num, den)) => {
+            let num: f64 = num.trim().parse().map_err(|_| invalid())?;
+            let den: f64 = den.trim().parse().map_err(|_| invalid())?;
+            num / den
+        }
+        None => value.trim().parse().map_err(|_| invalid())?,
+    };
+    if factor.is_finite() && factor > 0.0 {
+        Ok(factor)
+    } else {
+        Err(invalid())
+    }
+}
+
 fn create_default_output_path(input: &Path) -> PathBuf {
     let mut output = input.to_path_buf();
 
@@ -470,6 +612,14 @@ mod tests {
             input_paths: Vec::new(),
             recursive: false,
             offset: Some(2.5),
+            from: None,
+            to: None,
+            stretch: None,
+            on_underflow: UnderflowPolicyArg::Clamp,
+            encoding: None,
+            bom: false,
+            line_ending: None,
+            on_unmappable: UnmappableArg::Fail,
             method: None,
             window: 30,
             vad_sensitivity: None,
@@ -482,6 +632,65 @@ mod tests {
         assert_eq!(args.sync_method(), SyncMethod::Manual);
     }
 
+    #[test]
+    fn test_sync_args_range_offset_parsing() {
+        let cli = Cli::try_parse_from([
+            "subx-cli",
+            "sync",
+            "--offset",
+            "-00:00:01.500",
+            "--from",
+            "42:10",
+            "--stretch",
+            "25/25",
+            "--on-underflow",
+            "drop",
+            "movie.srt",
+        ])
+        .unwrap();
+        let args = match cli.command {
+            Commands::Sync(a) => a,
+            _ => panic!("Expected Sync command"),
+        };
+        assert_eq!(args.offset, Some(-1.5));
+        assert_eq!(args.from, Some(Duration::from_secs(2530)));
+        assert_eq!(args.to, None);
+        assert_eq!(args.stretch, Some(1.0));
+        assert_eq!(args.on_underflow, UnderflowPolicyArg::Drop);
+        assert!(args.validate().is_ok());
+
+        let adjustment = args.time_adjustment(-1.5);
+        assert_eq!(adjustment.range_start, Some(Duration::from_secs(2530)));
+        assert_eq!(
+            adjustment.underflow,
+            crate::core::sync::UnderflowPolicy::Drop
+        );
+    }
+
+    #[test]
+    fn test_sync_args_frame_offset_and_invalid_range() {
+        let cli = Cli::try_parse_from([
+            "subx-cli",
+            "sync",
+            "--offset",
+            "+12f@24",
+            "--from",
+            "20",
+            "--to",
+            "10",
+            "movie.srt",
+        ])
+        .unwrap();
+        let args = match cli.command {
+            Commands::Sync(a) => a,
+            _ => panic!("Expected Sync command"),
+        };
+        assert_eq!(args.offset, Some(0.5));
+        assert!(args.validate().is_err());
+
+        assert!(Cli::try_parse_from(["subx-cli", "sync", "--stretch", "0", "movie.srt"]).is_err());
+    }
+
     #[test]
     fn test_sync_args_batch_input() {
         let cli = Cli::try_parse_from([
@@ -525,6 +734,14 @@ mod tests {
             input_paths: Vec::new(),
             recursive: false,
             offset: None,
+            from: None,
+            to: None,
+            stretch: None,
+            on_underflow: UnderflowPolicyArg::Clamp,
+            encoding: None,
+            bom: false,
+            line_ending: None,
+            on_unmappable: UnmappableArg::Fail,
             method: None,
             window: 30,
             vad_sensitivity: None,
@@ -547,6 +764,14 @@ mod tests {
             input_paths: Vec::new(),
             recursive: false,
             offset: None,
+            from: None,
+            to: None,
+            stretch: None,
+            on_underflow: UnderflowPolicyArg::Clamp,
+            encoding: None,
+            bom: false,
+            line_ending: None,
+            on_unmappable: UnmappableArg::Fail,
             method: None,
             window: 30,
             vad_sensitivity: None,
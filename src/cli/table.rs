@@ -163,3 +163,36 @@ pub fn create_match_table(rows: Vec<MatchDisplayRow>) -> String {
         .with(Modify::new(Rows::new(1..)).with(Alignment::left()));
     table.to_string()
 }
+
+/// Row of the summary table printed by `detect-encoding --convert`.
+#[derive(Tabled)]
+pub struct EncodingConversionRow {
+    /// File name
+    #[tabled(rename = "File")]
+    pub file: String,
+
+    /// Detected encoding and confidence
+    #[tabled(rename = "Before")]
+    pub before: String,
+
+    /// Encoding after conversion
+    #[tabled(rename = "After")]
+    pub after: String,
+
+    /// Replacement characters produced while decoding
+    #[tabled(rename = "Replacements")]
+    pub replacements: String,
+
+    /// Converted, skipped or failed, with the reason
+    #[tabled(rename = "Status")]
+    pub status: String,
+}
+
+/// Create the summary table of an encoding conversion run.
+pub fn create_encoding_conversion_table(rows: Vec<EncodingConversionRow>) -> String {
+    let mut table = Table::new(rows);
+    table
+        .with(Style::rounded())
+        .with(Modify::new(Rows::new(1..)).with(Alignment::left()));
+    table.to_string()
+}
@@ -0,0 +1,181 @@
+//! Parsing of time values given on the command line.
+//!
+//! Subcommands that accept a point in time or a duration (for example
+//! `split --at` and `join --offset`) share this parser so that users can
+//! write either plain seconds or a clock-style timestamp.
+//!
+//! # Accepted Forms
+//!
+//! - `90`, `90.5` - seconds
+//! - `1:30`, `01:30.500` - minutes and seconds
+//! - `1:02:03`, `01:02:03,250`, `01:02:03.250` - hours, minutes and seconds
+//!
+//! Signed offsets (`sync --offset`) additionally accept a leading `+` or `-`
+//! and frame counts such as `+12f@23.976` or `-3 frames @25`.
+
+use std::time::Duration;
+
+/// Parse a time value in seconds or `[HH:]MM:SS[.mmm]` notation.
+///
+/// Both `.` and `,` are accepted as the fractional separator so that SRT
+/// timestamps can be pasted directly.
+///
+/// # Errors
+///
+/// Returns a human-readable message if the value is malformed, negative, or
+/// has out-of-range minute/second fields.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use subx_cli::cli::parse_time_value;
+///
+/// assert_eq!(parse_time_value("90").unwrap(), Duration::from_secs(90));
+/// assert_eq!(parse_time_value("01:02:03,250").unwrap(), Duration::from_millis(3_723_250));
+/// ```
+pub fn parse_time_value(value: &str) -> Result<Duration, String> {
+    let invalid = || format!("Invalid time value: {}", value);
+    let normalized = value.trim().replace(',', ".");
+    let parts: Vec<&str> = normalized.split(':').collect();
+    if parts.len() > 3 {
+        return Err(invalid());
+    }
+    let (last, units) = parts.split_last().ok_or_else(invalid)?;
+    let (whole, frac) = last.split_once('.').unwrap_or((last, ""));
+    let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    if !is_digits(whole)
+        || !(frac.is_empty() || is_digits(frac))
+        || frac.len() > 9
+        || !units.iter().all(|u| is_digits(u))
+    {
+        return Err(invalid());
+    }
+
+    let seconds: u64 = whole.parse().map_err(|_| invalid())?;
+    if !units.is_empty() && seconds >= 60 {
+        return Err(invalid());
+    }
+    let mut total = seconds;
+    for (i, unit) in units.iter().rev().enumerate() {
+        let n: u64 = unit.parse().map_err(|_| invalid())?;
+        if i == 0 && units.len() == 2 && n >= 60 {
+            return Err(invalid());
+        }
+        total += n * 60u64.pow(i as u32 + 1);
+    }
+    let nanos: u32 = if frac.is_empty() {
+        0
+    } else {
+        format!("{:0<9}", frac).parse().map_err(|_| invalid())?
+    };
+    Ok(Duration::new(total, nanos))
+}
+
+/// Parse a signed offset in seconds, timestamp notation or frames.
+///
+/// The value may start with `+` or `-` and is either a time value accepted by
+/// [`parse_time_value`] or a frame count followed by the frame rate, written
+/// `<N>f@<FPS>` or `<N> frames @ <FPS>`.
+///
+/// # Errors
+///
+/// Returns a human-readable message if the value is malformed or the frame
+/// rate is not positive.
+///
+/// # Examples
+///
+/// ```rust
+/// use subx_cli::cli::parse_offset_value;
+///
+/// assert_eq!(parse_offset_value("-00:00:01.500").unwrap(), -1.5);
+/// assert_eq!(parse_offset_value("+12f@24").unwrap(), 0.5);
+/// ```
+pub fn parse_offset_value(value: &str) -> Result<f32, String> {
+    let invalid = || format!("Invalid offset value: {}", value);
+    let trimmed = value.trim();
+    let (sign, magnitude) = match trimmed.strip_prefix('-') {
+        Some(rest) => (-1.0, rest),
+        None => (1.0, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+    };
+    let seconds = match magnitude.split_once('@') {
+        Some((frames, fps)) => {
+            let frames = frames.trim();
+            let count = ["frames", "frame", "f"]
+                .iter()
+                .find_map(|suffix| frames.strip_suffix(suffix))
+                .ok_or_else(invalid)?
+                .trim();
+            let count: u64 = count.parse().map_err(|_| invalid())?;
+            let fps: f64 = fps.trim().parse().map_err(|_| invalid())?;
+            if !(fps.is_finite() && fps > 0.0) {
+                return Err(invalid());
+            }
+            count as f64 / fps
+        }
+        None => parse_time_value(magnitude)
+            .map_err(|_| invalid())?
+            .as_secs_f64(),
+    };
+    Ok((sign * seconds) as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_seconds() {
+        assert_eq!(parse_time_value("90").unwrap(), Duration::from_secs(90));
+        assert_eq!(
+            parse_time_value("1.5").unwrap(),
+            Duration::from_millis(1500)
+        );
+    }
+
+    #[test]
+    fn test_parse_clock_notation() {
+        assert_eq!(parse_time_value("1:30").unwrap(), Duration::from_secs(90));
+        assert_eq!(
+            parse_time_value("01:02:03.250").unwrap(),
+            Duration::from_millis(3_723_250)
+        );
+        assert_eq!(
+            parse_time_value("00:52:13,040").unwrap(),
+            Duration::from_millis(3_133_040)
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_values() {
+        assert!(parse_time_value("").is_err());
+        assert!(parse_time_value("-5").is_err());
+        assert!(parse_time_value("1:2:3:4").is_err());
+        assert!(parse_time_value("1:75").is_err());
+        assert!(parse_time_value("abc").is_err());
+        assert!(parse_time_value("1::2").is_err());
+        assert!(parse_time_value("1:60:00").is_err());
+        assert!(parse_time_value("1.2.3").is_err());
+    }
+
+    #[test]
+    fn test_parse_offset_values() {
+        assert_eq!(parse_offset_value("2.5").unwrap(), 2.5);
+        assert_eq!(parse_offset_value("-2.5").unwrap(), -2.5);
+        assert_eq!(parse_offset_value("+1:00").unwrap(), 60.0);
+        assert_eq!(parse_offset_value("-00:00:01,500").unwrap(), -1.5);
+        assert_eq!(parse_offset_value("+12f@24").unwrap(), 0.5);
+        assert_eq!(parse_offset_value("-3 frames @ 25").unwrap(), -0.12);
+        let ntsc = parse_offset_value("12 frames@23.976").unwrap();
+        assert!((ntsc - 12.0 / 23.976).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_parse_invalid_offset_values() {
+        assert!(parse_offset_value("").is_err());
+        assert!(parse_offset_value("--1").is_err());
+        assert!(parse_offset_value("12@24").is_err());
+        assert!(parse_offset_value("12f@0").is_err());
+        assert!(parse_offset_value("xf@24").is_err());
+    }
+}
@@ -324,14 +324,12 @@ pub fn display_match_results(results: &[MatchOperation], is_dry_run: bool) {
                 let operation_icon = match op.relocation_mode {
                     crate::core::matcher::engine::FileRelocationMode::Copy => "📄",
                     crate::core::matcher::engine::FileRelocationMode::Move => "📁",
+                    crate::core::matcher::engine::FileRelocationMode::Symlink { .. }
+                    | crate::core::matcher::engine::FileRelocationMode::Hardlink => "🔗",
                     _ => "",
                 };
 
-                let operation_verb = match op.relocation_mode {
-                    crate::core::matcher::engine::FileRelocationMode::Copy => "Copy to",
-                    crate::core::matcher::engine::FileRelocationMode::Move => "Move to",
-                    _ => "",
-                };
+                let operation_verb = format!("{} to", op.relocation_mode.verb());
 
                 if let Some(target_path) = &op.relocation_target_path {
                     rows.push(MatchDisplayRow {
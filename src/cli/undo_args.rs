@@ -0,0 +1,56 @@
+//! Undo command-line arguments.
+//!
+//! This module defines the command-line interface for the `undo` subcommand,
+//! which reverts the file changes a `match`, `convert` or `sync` run recorded
+//! in the journal.
+//!
+//! # Examples
+//!
+//! ```bash
+//! # Revert the most recent run
+//! subx undo
+//!
+//! # List recorded runs, then revert one of them
+//! subx undo --list
+//! subx undo 1760790896-3fa2b7c1
+//!
+//! # Show what would be reverted
+//! subx undo --dry-run
+//! ```
+
+use clap::Args;
+
+/// Command-line arguments for reverting a recorded run.
+#[derive(Args, Debug)]
+pub struct UndoArgs {
+    /// Id of the run to revert (default: the most recent run not yet undone)
+    pub run_id: Option<String>,
+
+    /// List the recorded runs instead of reverting one
+    #[arg(long, conflicts_with = "run_id")]
+    pub list: bool,
+
+    /// Show the steps without changing any file
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::{Cli, Commands};
+    use clap::Parser;
+
+    #[test]
+    fn test_undo_args_parsing() {
+        let cli = Cli::try_parse_from(["subx-cli", "undo", "123-abc", "--dry-run"]).unwrap();
+        let args = match cli.command {
+            Commands::Undo(a) => a,
+            _ => panic!("Expected Undo command"),
+        };
+        assert_eq!(args.run_id.as_deref(), Some("123-abc"));
+        assert!(args.dry_run);
+        assert!(!args.list);
+
+        assert!(Cli::try_parse_from(["subx-cli", "undo", "123-abc", "--list"]).is_err());
+    }
+}
@@ -0,0 +1,72 @@
+//! Watch command-line arguments.
+//!
+//! This module defines the command-line interface for the `watch`
+//! subcommand, which monitors directories and runs the configured pipeline
+//! (`watch.pipeline`: encoding fix, convert, match and relocate, sync) on
+//! video and subtitle files as they appear.
+//!
+//! # Examples
+//!
+//! ```bash
+//! # Watch two download folders and their subdirectories
+//! subx watch -r ~/Downloads/tv ~/Downloads/subs
+//!
+//! # Process whatever is new once and exit, e.g. from a scheduled job
+//! subx watch -r --once ~/Media
+//! ```
+
+use clap::Args;
+use std::path::PathBuf;
+
+/// Command-line arguments for watching directories for new downloads.
+#[derive(Args, Debug)]
+pub struct WatchArgs {
+    /// Directories to watch
+    #[arg(required = true, value_name = "DIR")]
+    pub paths: Vec<PathBuf>,
+
+    /// Watch subdirectories as well
+    #[arg(short, long)]
+    pub recursive: bool,
+
+    /// Process the files not processed yet and exit instead of watching
+    #[arg(long)]
+    pub once: bool,
+
+    /// Quiet period in milliseconds before new files are processed
+    /// (default: watch.debounce_ms)
+    #[arg(long, value_name = "MS")]
+    pub debounce: Option<u64>,
+
+    /// State file remembering processed files (default: watch_state.json in
+    /// the config directory)
+    #[arg(long, value_name = "FILE")]
+    pub state_file: Option<PathBuf>,
+
+    /// JSON-lines log file (default: watch.log in the config directory)
+    #[arg(long, value_name = "FILE")]
+    pub log_file: Option<PathBuf>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::{Cli, Commands};
+    use clap::Parser;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_watch_args_parsing() {
+        let cli = Cli::try_parse_from(["subx-cli", "watch", "-r", "a", "b", "--debounce", "500"])
+            .unwrap();
+        let args = match cli.command {
+            Commands::Watch(a) => a,
+            _ => panic!("Expected Watch command"),
+        };
+        assert_eq!(args.paths, vec![PathBuf::from("a"), PathBuf::from("b")]);
+        assert!(args.recursive);
+        assert!(!args.once);
+        assert_eq!(args.debounce, Some(500));
+
+        assert!(Cli::try_parse_from(["subx-cli", "watch"]).is_err());
+    }
+}
@@ -0,0 +1,126 @@
+//! Traditional ↔ Simplified Chinese conversion command-line arguments.
+//!
+//! This module defines the command-line interface for the `zh-convert`
+//! subcommand, which converts subtitle text between Simplified and
+//! Traditional Chinese using the built-in offline dictionaries and re-tags
+//! the output file name (`movie.sc.srt` → `movie.tc.srt`).
+//!
+//! # Examples
+//!
+//! ```bash
+//! # Simplified to Traditional, writing movie.tc.srt
+//! subx zh-convert movie.sc.srt --to tc
+//!
+//! # Include Taiwan vocabulary (软件 → 軟體, 视频 → 影片)
+//! subx zh-convert movie.sc.srt --to tc --taiwan
+//!
+//! # Convert a directory of Traditional subtitles to Simplified
+//! subx zh-convert -i ./subs --recursive --to sc
+//! ```
+
+use crate::cli::InputPathHandler;
+use crate::core::chinese::ChineseConversion;
+use crate::error::SubXError;
+use clap::{Args, ValueEnum};
+use std::path::PathBuf;
+
+/// Target Chinese script for conversion.
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
+pub enum ChineseScriptArg {
+    /// Traditional Chinese
+    Tc,
+    /// Simplified Chinese
+    Sc,
+}
+
+impl From<ChineseScriptArg> for ChineseConversion {
+    fn from(arg: ChineseScriptArg) -> Self {
+        match arg {
+            ChineseScriptArg::Tc => Self::SimplifiedToTraditional,
+            ChineseScriptArg::Sc => Self::TraditionalToSimplified,
+        }
+    }
+}
+
+/// Command-line arguments for converting between Simplified and Traditional Chinese.
+#[derive(Args, Debug)]
+pub struct ZhConvertArgs {
+    /// Input subtitle file or directory
+    pub input: Option<PathBuf>,
+
+    /// Specify file or directory paths to process, can be used multiple times
+    #[arg(short = 'i', long = "input", value_name = "PATH")]
+    pub input_paths: Vec<PathBuf>,
+
+    /// Recursively process subdirectories
+    #[arg(short, long)]
+    pub recursive: bool,
+
+    /// Target script
+    #[arg(long, value_enum)]
+    pub to: ChineseScriptArg,
+
+    /// Also convert Mainland and Taiwan vocabulary (e.g. 软件 ↔ 軟體)
+    #[arg(long)]
+    pub taiwan: bool,
+
+    /// Output file path (single input only; default: input re-tagged with the target language)
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Overwrite output files that already exist
+    #[arg(long)]
+    pub force: bool,
+}
+
+impl ZhConvertArgs {
+    /// Get all input paths, combining input and input_paths parameters
+    pub fn get_input_handler(&self) -> Result<InputPathHandler, SubXError> {
+        let optional_paths = vec![self.input.clone()];
+        let merged_paths = InputPathHandler::merge_paths_from_multiple_sources(
+            &optional_paths,
+            &self.input_paths,
+            &[],
+        )?;
+
+        Ok(InputPathHandler::from_args(&merged_paths, self.recursive)?
+            .with_extensions(&["srt", "ass", "vtt", "sub", "ssa"]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Cli, Commands};
+    use clap::Parser;
+
+    #[test]
+    fn test_zh_convert_args_parsing() {
+        let cli = Cli::try_parse_from([
+            "subx-cli",
+            "zh-convert",
+            "a.sc.srt",
+            "--to",
+            "tc",
+            "--taiwan",
+        ])
+        .unwrap();
+        let args = match cli.command {
+            Commands::ZhConvert(a) => a,
+            _ => panic!("Expected ZhConvert command"),
+        };
+        assert_eq!(args.input, Some(PathBuf::from("a.sc.srt")));
+        assert_eq!(args.to, ChineseScriptArg::Tc);
+        assert!(args.taiwan);
+        assert!(!args.force);
+        assert_eq!(
+            ChineseConversion::from(args.to),
+            ChineseConversion::SimplifiedToTraditional
+        );
+    }
+
+    #[test]
+    fn test_zh_convert_args_requires_target() {
+        assert!(Cli::try_parse_from(["subx-cli", "zh-convert", "a.srt"]).is_err());
+    }
+}
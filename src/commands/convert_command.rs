@@ -55,6 +55,7 @@
 //!     output: Some(PathBuf::from("output.ass")),
 //!     keep_original: true,
 //!     encoding: "utf-8".to_string(),
+//!     strip_sdh: None,
 //! };
 //!
 //! convert_command::execute(args).await?;
@@ -66,6 +67,7 @@
 //!     output: None, // Use default naming
 //!     keep_original: true,
 //!     encoding: "utf-8".to_string(),
+//!     strip_sdh: None,
 //! };
 //!
 //! convert_command::execute(batch_args).await?;
@@ -73,8 +75,10 @@
 
 use crate::cli::{ConvertArgs, OutputSubtitleFormat};
 use crate::config::ConfigService;
-use crate::core::file_manager::FileManager;
 use crate::core::formats::converter::{ConversionConfig, FormatConverter};
+use crate::core::formats::sdh::SdhCleaner;
+use crate::core::fs_util::staging_path;
+use crate::core::journal::Journal;
 use crate::error::SubXError;
 
 /// Execute subtitle format conversion with comprehensive validation and error handling.
@@ -170,6 +174,7 @@ use crate::error::SubXError;
 ///     output: Some(PathBuf::from("movie_styled.ass")),
 ///     keep_original: true,
 ///     encoding: "utf-8".to_string(),
+///     strip_sdh: None,
 /// };
 /// convert_command::execute(explicit_args).await?;
 ///
@@ -180,6 +185,7 @@ use crate::error::SubXError;
 ///     output: None, // Will become "episode.vtt"
 ///     keep_original: false,
 ///     encoding: "utf-8".to_string(),
+///     strip_sdh: None,
 /// };
 /// convert_command::execute(auto_args).await?;
 ///
@@ -190,6 +196,7 @@ use crate::error::SubXError;
 ///     output: None,
 ///     keep_original: true,
 ///     encoding: "utf-8".to_string(),
+///     strip_sdh: None,
 /// };
 /// convert_command::execute(batch_args).await?;
 /// ```
@@ -205,11 +212,18 @@ pub async fn execute(args: ConvertArgs, config_service: &dyn ConfigService) -> c
     let app_config = config_service.get_config()?;
 
     // Configure conversion engine with user preferences and application defaults
+    let output_encoding = args.output_encoding()?;
     let config = ConversionConfig {
         preserve_styling: app_config.formats.preserve_styling,
         target_encoding: args.encoding.clone(),
+        bom: output_encoding.bom,
+        line_ending: output_encoding.line_ending,
+        unmappable: output_encoding.unmappable,
         keep_original: args.keep_original,
         validate_output: true,
+        sdh_cleaner: args
+            .strip_sdh
+            .map(|language| SdhCleaner::new(language.into())),
     };
     let converter = FormatConverter::new(config);
 
@@ -237,7 +251,8 @@ pub async fn execute(args: ConvertArgs, config_service: &dyn ConfigService) -> c
     if files.is_empty() {
         return Ok(());
     }
-    // Process each file
+    // Process each file, journaling outputs and removed originals for `subx undo`
+    let journal = Journal::start("convert")?;
     for input_path in files {
         let fmt = output_format.to_string();
         let output_path = if let Some(ref o) = args.output {
@@ -252,21 +267,31 @@ pub async fn execute(args: ConvertArgs, config_service: &dyn ConfigService) -> c
         } else {
             input_path.with_extension(fmt.clone())
         };
-        match converter
-            .convert_file(&input_path, &output_path, &fmt)
-            .await
-        {
+        // Convert next to the output and only replace it once the
+        // conversion succeeded, so failures leave nothing to journal
+        let staging = staging_path(&output_path);
+        match converter.convert_file(&input_path, &staging, &fmt).await {
             Ok(result) => {
                 if result.success {
+                    let previous = journal.preserve(&output_path)?;
+                    std::fs::rename(&staging, &output_path)?;
+                    journal.record_written(&output_path, previous)?;
                     println!(
                         "✓ Conversion completed: {} -> {}",
                         input_path.display(),
                         output_path.display()
                     );
+                    for warning in &result.warnings {
+                        eprintln!("  Warning: {warning}");
+                    }
+                    if let Some(report) = result.sdh_report.filter(|r| !r.is_empty()) {
+                        println!("{report}");
+                    }
                     if !args.keep_original {
-                        let _ = FileManager::new().remove_file(&input_path);
+                        let _ = journal.remove_file(&input_path);
                     }
                 } else {
+                    let _ = std::fs::remove_file(&staging);
                     eprintln!("✗ Conversion failed for {}", input_path.display());
                     for err in result.errors {
                         eprintln!("  Error: {err}");
@@ -274,6 +299,7 @@ pub async fn execute(args: ConvertArgs, config_service: &dyn ConfigService) -> c
                 }
             }
             Err(e) => {
+                let _ = std::fs::remove_file(&staging);
                 eprintln!("✗ Conversion error for {}: {}", input_path.display(), e);
             }
         }
@@ -304,6 +330,7 @@ pub async fn execute_with_config(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::UnmappableArg;
     use crate::config::{TestConfigBuilder, TestConfigService};
     use std::fs;
     use std::sync::Arc;
@@ -332,6 +359,10 @@ mod tests {
             output: Some(output_file.clone()),
             keep_original: false,
             encoding: String::from("utf-8"),
+            bom: false,
+            line_ending: None,
+            on_unmappable: UnmappableArg::Fail,
+            strip_sdh: None,
         };
 
         execute_with_config(args, config_service).await?;
@@ -343,6 +374,75 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_convert_with_strip_sdh() -> crate::Result<()> {
+        let config_service = Arc::new(TestConfigService::with_defaults());
+
+        let temp_dir = TempDir::new().unwrap();
+        let input_file = temp_dir.path().join("test.srt");
+        let output_file = temp_dir.path().join("test.vtt");
+        fs::write(
+            &input_file,
+            "1\n00:00:01,000 --> 00:00:02,000\n[MUSIC]\n\n2\n00:00:03,000 --> 00:00:04,000\n(sighs) Fine.\n\n",
+        )
+        .unwrap();
+
+        let args = ConvertArgs {
+            input: Some(input_file.clone()),
+            input_paths: Vec::new(),
+            recursive: false,
+            format: Some(OutputSubtitleFormat::Vtt),
+            output: Some(output_file.clone()),
+            keep_original: true,
+            encoding: String::from("utf-8"),
+            bom: false,
+            line_ending: None,
+            on_unmappable: UnmappableArg::Fail,
+            strip_sdh: Some(crate::cli::SdhLanguageArg::En),
+        };
+
+        execute_with_config(args, config_service).await?;
+
+        let content = fs::read_to_string(&output_file).unwrap();
+        assert!(!content.contains("MUSIC"));
+        assert!(content.contains("00:00:03.000 --> 00:00:04.000\nFine."));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_convert_to_legacy_encoding_with_crlf() -> crate::Result<()> {
+        let config_service = Arc::new(TestConfigService::with_defaults());
+
+        let temp_dir = TempDir::new().unwrap();
+        let input_file = temp_dir.path().join("test.srt");
+        let output_file = temp_dir.path().join("test.vtt");
+        fs::write(&input_file, "1\n00:00:01,000 --> 00:00:02,000\nCafé\n\n").unwrap();
+
+        let args = ConvertArgs {
+            input: Some(input_file.clone()),
+            input_paths: Vec::new(),
+            recursive: false,
+            format: Some(OutputSubtitleFormat::Vtt),
+            output: Some(output_file.clone()),
+            keep_original: true,
+            encoding: String::from("windows-1252"),
+            bom: false,
+            line_ending: Some(crate::cli::LineEndingArg::Crlf),
+            on_unmappable: UnmappableArg::Fail,
+            strip_sdh: None,
+        };
+
+        execute_with_config(args, config_service).await?;
+
+        let bytes = fs::read(&output_file).unwrap();
+        assert!(bytes.starts_with(b"WEBVTT\r\n"));
+        assert!(bytes.windows(6).any(|w| w == b"Caf\xE9\r\n"));
+        assert!(!bytes.windows(2).any(|w| w[1] == b'\n' && w[0] != b'\r'));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_convert_batch_processing() -> crate::Result<()> {
         // Create test configuration
@@ -371,6 +471,10 @@ mod tests {
             output: Some(temp_dir.path().join("output")),
             keep_original: false,
             encoding: String::from("utf-8"),
+            bom: false,
+            line_ending: None,
+            on_unmappable: UnmappableArg::Fail,
+            strip_sdh: None,
         };
 
         // Only check execution result, do not verify actual file generation,
@@ -397,6 +501,10 @@ mod tests {
             output: None,
             keep_original: false,
             encoding: String::from("utf-8"),
+            bom: false,
+            line_ending: None,
+            on_unmappable: UnmappableArg::Fail,
+            strip_sdh: None,
         };
 
         let result = execute_with_config(args, config_service).await;
@@ -405,6 +513,35 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_failed_conversion_keeps_existing_output() {
+        let config_service = Arc::new(TestConfigService::with_defaults());
+
+        let temp_dir = TempDir::new().unwrap();
+        let input_file = temp_dir.path().join("test.unknown");
+        let output_file = temp_dir.path().join("test.srt");
+        fs::write(&input_file, "not a subtitle").unwrap();
+        fs::write(&output_file, "existing").unwrap();
+
+        let args = ConvertArgs {
+            input: Some(input_file),
+            input_paths: Vec::new(),
+            recursive: false,
+            format: Some(OutputSubtitleFormat::Srt),
+            output: Some(output_file.clone()),
+            keep_original: true,
+            encoding: String::from("utf-8"),
+            bom: false,
+            line_ending: None,
+            on_unmappable: UnmappableArg::Fail,
+            strip_sdh: None,
+        };
+
+        execute_with_config(args, config_service).await.unwrap();
+        assert_eq!(fs::read_to_string(&output_file).unwrap(), "existing");
+        assert!(!staging_path(&output_file).exists());
+    }
+
     #[tokio::test]
     async fn test_convert_with_different_config() {
         // Create test configuration with custom settings
@@ -432,6 +569,10 @@ mod tests {
             output: Some(output_file.clone()),
             keep_original: true,
             encoding: String::from("utf-8"),
+            bom: false,
+            line_ending: None,
+            on_unmappable: UnmappableArg::Fail,
+            strip_sdh: None,
         };
 
         let result = execute_with_config(args, config_service).await;
@@ -76,8 +76,10 @@
 //! ```
 
 use crate::Result;
-use crate::config::ConfigService;
-use crate::core::formats::encoding::EncodingDetector;
+use crate::config::{ConfigService, ProductionConfigService};
+use crate::core::formats::encoding::{
+    Charset, EncodingConverter, EncodingDetector, EncodingInfo, MojibakeAnalyzer, OutputEncoding,
+};
 use log::error;
 
 /// Execute character encoding detection for subtitle files with comprehensive analysis.
@@ -191,12 +193,30 @@ use log::error;
 /// - **Migration**: Assess encoding diversity when migrating subtitle libraries
 /// - **Automation**: Integrate encoding detection into batch processing workflows
 use crate::cli::DetectEncodingArgs;
+use crate::cli::table::{EncodingConversionRow, create_encoding_conversion_table};
+use crate::config::Config;
 use crate::error::SubXError;
+use std::path::{Path, PathBuf};
 
 /// Execute character encoding detection for subtitle files based on input arguments.
 pub fn detect_encoding_command(args: &DetectEncodingArgs) -> Result<()> {
+    let config = ProductionConfigService::new()?.get_config()?;
+    run(args, &config)
+}
+
+/// Detect, and with `--convert` transcode, the input files. Conversion
+/// skips files detected below the configured encoding detection confidence
+/// unless forced.
+fn run(args: &DetectEncodingArgs, config: &Config) -> Result<()> {
     // Initialize the encoding detection engine
-    let detector = EncodingDetector::with_defaults();
+    let detector = EncodingDetector::with_config(config);
+    let min_confidence = config.formats.encoding_detection_confidence;
+    let mojibake = MojibakeAnalyzer::new();
+    let target = args
+        .convert
+        .then(|| OutputEncoding::from_name(args.target_encoding()))
+        .transpose()?;
+    let mut conversions = Vec::new();
 
     // Collect target files using InputPathHandler logic
     let paths = args
@@ -225,15 +245,138 @@ pub fn detect_encoding_command(args: &DetectEncodingArgs) -> Result<()> {
                 );
                 let sample = if args.verbose {
                     info.sample_text.clone()
-                } else if info.sample_text.len() > 50 {
-                    format!("{}...", &info.sample_text[..47])
+                } else if info.sample_text.chars().count() > 50 {
+                    let head: String = info.sample_text.chars().take(47).collect();
+                    format!("{head}...")
                 } else {
                     info.sample_text.clone()
                 };
-                println!("  Sample text: {sample}\n");
+                println!("  Sample text: {sample}");
+                if let Err(e) = report_mojibake(&mojibake, &path, &info, args.fix_mojibake) {
+                    error!("Unable to repair mojibake in {}: {}", path.display(), e);
+                }
+                println!();
             }
             Err(e) => error!("Unable to detect encoding for {}: {}", path.display(), e),
         }
+        if let Some(target) = &target {
+            conversions.push(convert_file(&path, target, min_confidence, args.force));
+        }
+    }
+    if target.is_some() {
+        println!("{}", create_encoding_conversion_table(conversions));
+    }
+    Ok(())
+}
+
+/// Convert one file to `target` in place, keeping a `.bak` backup, and
+/// describe the outcome as a summary table row.
+fn convert_file(
+    path: &Path,
+    target: &OutputEncoding,
+    min_confidence: f32,
+    force: bool,
+) -> EncodingConversionRow {
+    let mut row = EncodingConversionRow {
+        file: path.display().to_string(),
+        before: "-".to_string(),
+        after: "-".to_string(),
+        replacements: "-".to_string(),
+        status: String::new(),
+    };
+    row.status = match try_convert_file(path, target, min_confidence, force, &mut row) {
+        Ok(status) => status,
+        Err(e) => format!("Failed: {e}"),
+    };
+    row
+}
+
+fn try_convert_file(
+    path: &Path,
+    target: &OutputEncoding,
+    min_confidence: f32,
+    force: bool,
+    row: &mut EncodingConversionRow,
+) -> Result<String> {
+    // Take the best candidate even when it is uncertain; the threshold is
+    // applied here so that --force can override it.
+    let detector = EncodingDetector::with_defaults().with_confidence_threshold(0.0);
+    let file_str = path.to_string_lossy();
+    let info = detector.detect_file_encoding(&file_str)?;
+    row.before = format!("{:?} ({:.1}%)", info.charset, info.confidence * 100.0);
+    if info.charset == target.charset {
+        return Ok(format!("Skipped: already {:?}", target.charset));
+    }
+    if info.confidence < min_confidence && !force {
+        return Ok(format!(
+            "Skipped: confidence below {:.0}% (use --force)",
+            min_confidence * 100.0
+        ));
+    }
+
+    let converter = EncodingConverter::new();
+    let result = converter.convert_file_to_utf8(&file_str, &info)?;
+    let validation = converter.validate_conversion(&result);
+    row.replacements = result.error_count.to_string();
+    let encoded = target.encode(&result.converted_text)?;
+
+    std::fs::copy(path, backup_path(path))?;
+    std::fs::write(path, &encoded.bytes)?;
+    row.after = format!("{:?}", target.charset);
+    Ok(if validation.warnings.is_empty() {
+        "Converted".to_string()
+    } else {
+        format!("Converted: {}", validation.warnings.join("; "))
+    })
+}
+
+/// `movie.srt` is backed up as `movie.srt.bak`, or as `movie.srt.bak.1`,
+/// `movie.srt.bak.2`, ... when earlier backups already exist.
+fn backup_path(path: &Path) -> PathBuf {
+    let mut base = path.as_os_str().to_owned();
+    base.push(".bak");
+    let base = PathBuf::from(base);
+    if !base.exists() {
+        return base;
+    }
+    (1..)
+        .map(|n| {
+            let mut numbered = base.as_os_str().to_owned();
+            numbered.push(format!(".{n}"));
+            PathBuf::from(numbered)
+        })
+        .find(|candidate| !candidate.exists())
+        .expect("unbounded backup numbering")
+}
+
+/// Report text decoded with the wrong encoding and optionally repair it.
+///
+/// The repaired text is written back as UTF-8.
+fn report_mojibake(
+    analyzer: &MojibakeAnalyzer,
+    path: &std::path::Path,
+    info: &EncodingInfo,
+    fix: bool,
+) -> Result<()> {
+    let text = EncodingConverter::new()
+        .convert_file_to_utf8(&path.to_string_lossy(), info)?
+        .converted_text;
+    let Some(repair) = analyzer.analyze(&text) else {
+        return Ok(());
+    };
+    println!(
+        "  Mojibake: {:?} text misread as {:?} (Confidence: {:.1}%, {} characters recoverable)",
+        repair.original,
+        repair.misread_as,
+        repair.confidence * 100.0,
+        repair.repaired_characters
+    );
+    if fix {
+        // The misread encoding usually cannot represent the repaired script
+        OutputEncoding::new(Charset::Utf8)
+            .with_bom(info.bom_detected)
+            .write(path, &repair.repaired_text)?;
+        println!("  Repaired in place");
     }
     Ok(())
 }
@@ -254,8 +397,8 @@ pub fn detect_encoding_command(args: &DetectEncodingArgs) -> Result<()> {
 /// Returns `Ok(())` on successful completion, or an error if detection fails.
 pub fn detect_encoding_command_with_config(
     args: DetectEncodingArgs,
-    _config_service: &dyn ConfigService,
+    config_service: &dyn ConfigService,
 ) -> Result<()> {
-    // Delegate to new implementation based on input argument struct
-    detect_encoding_command(&args)
+    let config = config_service.get_config()?;
+    run(&args, &config)
 }
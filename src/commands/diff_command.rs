@@ -0,0 +1,103 @@
+//! Subtitle comparison command implementation.
+//!
+//! This module implements the `diff` subcommand. Both subtitles are loaded
+//! with [`FormatManager`], compared with
+//! [`SubtitleDiffer`](crate::core::formats::diff::SubtitleDiffer), and the
+//! resulting report is printed as text or JSON.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use subx_cli::cli::DiffArgs;
+//! use subx_cli::commands::diff_command;
+//!
+//! diff_command::execute(args, &config_service).await?;
+//! ```
+
+use std::time::Duration;
+
+use crate::cli::DiffArgs;
+use crate::config::ConfigService;
+use crate::core::formats::diff::{SubtitleDiff, SubtitleDiffer};
+use crate::core::formats::manager::FormatManager;
+use crate::error::SubXError;
+
+/// Execute the subtitle comparison command.
+///
+/// # Arguments
+///
+/// * `args` - The two subtitle files and report options
+/// * `config_service` - Configuration service (reserved for future settings)
+///
+/// # Errors
+///
+/// Returns an error if either subtitle cannot be loaded or the JSON report
+/// cannot be serialized.
+pub async fn execute(args: DiffArgs, _config_service: &dyn ConfigService) -> crate::Result<()> {
+    let diff = compare(&args)?;
+    if args.json {
+        let json = serde_json::to_string_pretty(&diff)
+            .map_err(|e| SubXError::CommandExecution(format!("Failed to serialize diff: {}", e)))?;
+        println!("{}", json);
+    } else {
+        println!("--- {}", args.old.display());
+        println!("+++ {}", args.new.display());
+        println!("{}", diff);
+    }
+    Ok(())
+}
+
+/// Execute the subtitle comparison command with an injected configuration service.
+pub async fn execute_with_config(
+    args: DiffArgs,
+    config_service: std::sync::Arc<dyn ConfigService>,
+) -> crate::Result<()> {
+    execute(args, config_service.as_ref()).await
+}
+
+fn compare(args: &DiffArgs) -> crate::Result<SubtitleDiff> {
+    let format_manager = FormatManager::new();
+    let old = format_manager.load_subtitle(&args.old)?;
+    let new = format_manager.load_subtitle(&args.new)?;
+    Ok(SubtitleDiffer::new()
+        .with_tolerance(Duration::from_millis(args.tolerance))
+        .diff(&old, &new))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TestConfigService;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_diff_detects_offset_and_edit() -> crate::Result<()> {
+        let temp = TempDir::new().unwrap();
+        let old = temp.path().join("old.srt");
+        let new = temp.path().join("new.srt");
+        fs::write(
+            &old,
+            "1\n00:00:01,000 --> 00:00:02,000\nHello\n\n2\n00:00:03,000 --> 00:00:04,000\nHow are you?\n\n3\n00:00:05,000 --> 00:00:06,000\nFine.\n\n",
+        )
+        .unwrap();
+        fs::write(
+            &new,
+            "1\n00:00:02,000 --> 00:00:03,000\nHello\n\n2\n00:00:04,000 --> 00:00:05,000\nHow are you doing?\n\n3\n00:00:06,000 --> 00:00:07,000\nFine.\n\n",
+        )
+        .unwrap();
+
+        let args = DiffArgs {
+            old: old.clone(),
+            new: new.clone(),
+            tolerance: 20,
+            json: false,
+        };
+        let diff = compare(&args)?;
+        assert_eq!(diff.constant_offset_ms, Some(1000));
+        assert_eq!(diff.summary.retimed, 2);
+        assert_eq!(diff.summary.edited, 1);
+
+        execute(args, &TestConfigService::with_defaults()).await
+    }
+}
@@ -35,13 +35,8 @@ use std::sync::Arc;
 /// let config_service = Arc::new(TestConfigService::with_defaults());
 /// let match_args = MatchArgs {
 ///     path: Some("/path/to/files".into()),
-///     input_paths: vec![],
 ///     dry_run: true,
-///     confidence: 80,
-///     recursive: false,
-///     backup: false,
-///     copy: false,
-///     move_files: false,
+///     ..Default::default()
 /// };
 ///
 /// dispatch_command(Commands::Match(match_args), config_service).await?;
@@ -82,6 +77,33 @@ pub async fn dispatch_command(
             )?;
             Ok(())
         }
+        Commands::Merge(args) => {
+            crate::commands::merge_command::execute_with_config(args, config_service).await
+        }
+        Commands::Join(args) => {
+            crate::commands::join_command::execute_with_config(args, config_service).await
+        }
+        Commands::Split(args) => {
+            crate::commands::split_command::execute_with_config(args, config_service).await
+        }
+        Commands::StripSdh(args) => {
+            crate::commands::strip_sdh_command::execute_with_config(args, config_service).await
+        }
+        Commands::ZhConvert(args) => {
+            crate::commands::zh_convert_command::execute_with_config(args, config_service).await
+        }
+        Commands::Diff(args) => {
+            crate::commands::diff_command::execute_with_config(args, config_service).await
+        }
+        Commands::Info(args) => {
+            crate::commands::info_command::execute_with_config(args, config_service).await
+        }
+        Commands::Undo(args) => {
+            crate::commands::undo_command::execute_with_config(args, config_service).await
+        }
+        Commands::Watch(args) => {
+            crate::commands::watch_command::execute_with_config(args, config_service).await
+        }
     }
 }
 
@@ -121,13 +143,32 @@ pub async fn dispatch_command_with_ref(
             )?;
             Ok(())
         }
+        Commands::Merge(args) => {
+            crate::commands::merge_command::execute(args, config_service).await
+        }
+        Commands::Join(args) => crate::commands::join_command::execute(args, config_service).await,
+        Commands::Split(args) => {
+            crate::commands::split_command::execute(args, config_service).await
+        }
+        Commands::StripSdh(args) => {
+            crate::commands::strip_sdh_command::execute(args, config_service).await
+        }
+        Commands::ZhConvert(args) => {
+            crate::commands::zh_convert_command::execute(args, config_service).await
+        }
+        Commands::Diff(args) => crate::commands::diff_command::execute(args, config_service).await,
+        Commands::Info(args) => crate::commands::info_command::execute(args, config_service).await,
+        Commands::Undo(args) => crate::commands::undo_command::execute(args, config_service).await,
+        Commands::Watch(args) => {
+            crate::commands::watch_command::execute(args, config_service).await
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::cli::{ConvertArgs, MatchArgs, OutputSubtitleFormat};
+    use crate::cli::{ConvertArgs, MatchArgs, OutputSubtitleFormat, UnmappableArg};
     use crate::config::TestConfigService;
 
     #[tokio::test]
@@ -138,13 +179,8 @@ mod tests {
         ));
         let args = MatchArgs {
             path: Some("/tmp/test".into()),
-            input_paths: vec![],
             dry_run: true,
-            confidence: 80,
-            recursive: false,
-            backup: false,
-            copy: false,
-            move_files: false,
+            ..Default::default()
         };
 
         // Should not panic and should handle the command
@@ -180,6 +216,10 @@ mod tests {
             output: None,
             keep_original: false,
             encoding: "utf-8".to_string(),
+            bom: false,
+            line_ending: None,
+            on_unmappable: UnmappableArg::Fail,
+            strip_sdh: None,
         };
 
         // Should handle the command (even if it fails due to missing files)
@@ -192,13 +232,8 @@ mod tests {
         let config_service = TestConfigService::with_ai_settings("test_provider", "test_model");
         let args = MatchArgs {
             path: Some("/tmp/test".into()),
-            input_paths: vec![],
             dry_run: true,
-            confidence: 80,
-            recursive: false,
-            backup: false,
-            copy: false,
-            move_files: false,
+            ..Default::default()
         };
 
         // Test the reference version
@@ -0,0 +1,283 @@
+//! Subtitle inspection command implementation.
+//!
+//! This module implements the `info` subcommand. For each input subtitle it
+//! reports the detected encoding, the format detector that accepted the
+//! content in [`FormatManager::parse_auto`], the subtitle language, and the
+//! timing and text statistics computed by
+//! [`SubtitleStats`](crate::core::formats::stats::SubtitleStats).
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use subx_cli::cli::InfoArgs;
+//! use subx_cli::commands::info_command;
+//!
+//! info_command::execute(args, &config_service).await?;
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::cli::InfoArgs;
+use crate::config::ConfigService;
+use crate::core::formats::manager::FormatManager;
+use crate::core::formats::stats::{
+    Distribution, MAX_COMFORTABLE_CPS, MAX_COMFORTABLE_LINE_LENGTH, SubtitleStats,
+};
+use crate::core::language::{LanguageDetector, TextLanguageIdentifier};
+use crate::error::SubXError;
+
+/// Detected encoding of a subtitle file.
+#[derive(Debug, Clone, Serialize)]
+pub struct EncodingReport {
+    /// Detected character set
+    pub charset: String,
+    /// Detection confidence (0.0-1.0)
+    pub confidence: f32,
+    /// Whether the file starts with a byte order mark
+    pub bom: bool,
+}
+
+/// Everything `subx info` reports about one subtitle file.
+#[derive(Debug, Clone, Serialize)]
+pub struct SubtitleInfo {
+    /// Path of the inspected file
+    pub path: PathBuf,
+    /// Parsed subtitle format (e.g. "srt")
+    pub format: String,
+    /// Name of the format detector that accepted the content
+    pub detector: String,
+    /// Format implied by the file extension, if it is a known one
+    pub extension_format: Option<String>,
+    /// Detected encoding
+    pub encoding: EncodingReport,
+    /// Subtitle language code, if known
+    pub language: Option<String>,
+    /// Where the language was found ("metadata", "directory", "filename" or
+    /// "content")
+    pub language_source: Option<String>,
+    /// Timing and text statistics
+    pub stats: SubtitleStats,
+}
+
+/// Execute the subtitle inspection command.
+///
+/// # Arguments
+///
+/// * `args` - Input paths and output options
+/// * `config_service` - Configuration service (reserved for future settings)
+///
+/// # Errors
+///
+/// Returns an error if an input cannot be read or is not a recognized
+/// subtitle format.
+pub async fn execute(args: InfoArgs, _config_service: &dyn ConfigService) -> crate::Result<()> {
+    let handler = args
+        .get_input_handler()
+        .map_err(|e| SubXError::CommandExecution(e.to_string()))?;
+    let files = handler
+        .collect_files()
+        .map_err(|e| SubXError::CommandExecution(e.to_string()))?;
+
+    let format_manager = FormatManager::new();
+    let reports = files
+        .iter()
+        .map(|path| inspect(&format_manager, path))
+        .collect::<crate::Result<Vec<_>>>()?;
+
+    if args.json {
+        let json = serde_json::to_string_pretty(&reports)
+            .map_err(|e| SubXError::CommandExecution(format!("Failed to serialize info: {}", e)))?;
+        println!("{}", json);
+    } else {
+        for report in &reports {
+            print_report(report);
+        }
+    }
+    Ok(())
+}
+
+/// Execute the subtitle inspection command with an injected configuration service.
+pub async fn execute_with_config(
+    args: InfoArgs,
+    config_service: std::sync::Arc<dyn ConfigService>,
+) -> crate::Result<()> {
+    execute(args, config_service.as_ref()).await
+}
+
+/// Inspect a single subtitle file.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or no format detector accepts
+/// its content.
+pub fn inspect(format_manager: &FormatManager, path: &Path) -> crate::Result<SubtitleInfo> {
+    let path_str = path
+        .to_str()
+        .ok_or_else(|| SubXError::subtitle_format("", "Invalid file path encoding"))?;
+    let encoding = format_manager.get_encoding_info(path_str)?;
+    let content = format_manager.read_subtitle_with_encoding_detection(path_str)?;
+    let detector = format_manager.detect_format(&content).ok_or_else(|| {
+        SubXError::subtitle_format("Unknown", format!("{}: unknown subtitle format", path_str))
+    })?;
+    let subtitle = detector.parse(&content)?;
+
+    let extension_format = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(|ext| format_manager.get_format_by_extension(ext))
+        .map(|fmt| fmt.format_name().to_string());
+    let (language, language_source) = match subtitle.metadata.language.clone() {
+        Some(code) => (Some(code), Some("metadata".to_string())),
+        None => match LanguageDetector::new()
+            .detect_from_path(path)
+            .or_else(|| TextLanguageIdentifier::new().identify_subtitle(&subtitle))
+        {
+            Some(info) => (
+                Some(info.code),
+                Some(format!("{:?}", info.source).to_lowercase()),
+            ),
+            None => (None, None),
+        },
+    };
+
+    Ok(SubtitleInfo {
+        path: path.to_path_buf(),
+        format: subtitle.format.as_str().to_string(),
+        detector: detector.format_name().to_string(),
+        extension_format,
+        encoding: EncodingReport {
+            charset: format!("{:?}", encoding.charset),
+            confidence: encoding.confidence,
+            bom: encoding.bom_detected,
+        },
+        language,
+        language_source,
+        stats: SubtitleStats::from_subtitle(&subtitle),
+    })
+}
+
+fn print_report(info: &SubtitleInfo) {
+    println!("File: {}", info.path.display());
+    print!(
+        "  Format: {} (detected by {} detector",
+        info.format, info.detector
+    );
+    match &info.extension_format {
+        Some(ext) if *ext != info.detector => print!("; extension suggests {}", ext),
+        _ => {}
+    }
+    println!(")");
+    println!(
+        "  Encoding: {} (confidence {:.0}%{})",
+        info.encoding.charset,
+        info.encoding.confidence * 100.0,
+        if info.encoding.bom { ", BOM" } else { "" }
+    );
+    match (&info.language, &info.language_source) {
+        (Some(code), Some(source)) => println!("  Language: {} (from {})", code, source),
+        _ => println!("  Language: unknown"),
+    }
+
+    let stats = &info.stats;
+    println!("  Entries: {}", stats.entry_count);
+    if let Some(first) = &stats.first_cue {
+        println!(
+            "  First cue: #{} {} {:?}",
+            first.index,
+            format_ms(first.start_ms),
+            first.text
+        );
+    }
+    if let Some(last) = &stats.last_cue {
+        println!(
+            "  Last cue: #{} {} {:?}",
+            last.index,
+            format_ms(last.start_ms),
+            last.text
+        );
+    }
+    println!("  Total duration: {}", format_ms(stats.total_duration_ms));
+    println!("  Overlapping cues: {}", stats.overlap_count);
+    if let Some(cps) = &stats.cps {
+        println!(
+            "  CPS: {} ({} cues above {})",
+            format_distribution(cps),
+            stats.fast_cues,
+            MAX_COMFORTABLE_CPS
+        );
+    }
+    if let Some(lines) = &stats.line_length {
+        println!(
+            "  Line length: {} ({} lines above {})",
+            format_distribution(lines),
+            stats.long_lines,
+            MAX_COMFORTABLE_LINE_LENGTH
+        );
+    }
+    println!("  Styled cues: {}", stats.styled_cues);
+    println!();
+}
+
+fn format_distribution(d: &Distribution) -> String {
+    format!(
+        "min {:.1}, median {:.1}, mean {:.1}, p95 {:.1}, max {:.1}",
+        d.min, d.median, d.mean, d.p95, d.max
+    )
+}
+
+fn format_ms(ms: u64) -> String {
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        ms / 3_600_000,
+        (ms % 3_600_000) / 60_000,
+        (ms % 60_000) / 1000,
+        ms % 1000
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TestConfigService;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_inspect_reports_format_language_and_stats() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("movie.en.txt");
+        fs::write(
+            &path,
+            "1\n00:00:01,000 --> 00:00:03,000\n<i>Hello</i>\n\n2\n00:00:02,000 --> 00:00:04,000\nWorld\n\n",
+        )
+        .unwrap();
+
+        let info = inspect(&FormatManager::new(), &path).unwrap();
+        assert_eq!(info.format, "srt");
+        assert_eq!(info.detector, "SRT");
+        assert_eq!(info.extension_format, None);
+        assert_eq!(info.encoding.charset, "Utf8");
+        assert_eq!(info.language.as_deref(), Some("en"));
+        assert_eq!(info.stats.entry_count, 2);
+        assert_eq!(info.stats.overlap_count, 1);
+        assert_eq!(info.stats.styled_cues, 1);
+        assert_eq!(info.stats.total_duration_ms, 3000);
+    }
+
+    #[tokio::test]
+    async fn test_info_json_output() -> crate::Result<()> {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("movie.srt");
+        fs::write(&path, "1\n00:00:01,000 --> 00:00:02,000\nHi\n\n").unwrap();
+
+        let args = InfoArgs {
+            input: Some(path),
+            input_paths: Vec::new(),
+            recursive: false,
+            json: true,
+        };
+        execute(args, &TestConfigService::with_defaults()).await
+    }
+}
@@ -0,0 +1,162 @@
+//! Subtitle join command implementation.
+//!
+//! This module implements the `join` subcommand, which appends multi-part
+//! subtitles into one track using [`Subtitle::join`]. The offset for each
+//! part boundary comes from an explicit value or from the duration of the
+//! preceding part's video, probed with
+//! [`DirectAudioLoader`](crate::services::vad::audio_loader::DirectAudioLoader).
+//!
+//! The output is written in the format implied by its file extension, so any
+//! supported format can be joined.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use subx_cli::cli::JoinArgs;
+//! use subx_cli::commands::join_command;
+//!
+//! join_command::execute(args, &config_service).await?;
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::cli::JoinArgs;
+use crate::config::ConfigService;
+use crate::core::formats::Subtitle;
+use crate::core::formats::manager::FormatManager;
+use crate::error::SubXError;
+use crate::services::vad::audio_loader::DirectAudioLoader;
+
+/// Trailing first-part marker such as `.cd1`, ` Disc 01` or `-part1`.
+static FIRST_PART_MARKER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)[ ._-]*(cd|disc|part)[ ._-]?0*1$").unwrap());
+
+/// Execute the subtitle join command.
+///
+/// # Arguments
+///
+/// * `args` - Join arguments with the parts and their offsets or videos
+/// * `config_service` - Configuration service (reserved for future settings)
+///
+/// # Errors
+///
+/// Returns an error if the arguments are inconsistent, a part or video cannot
+/// be read, or the output file exists and `--force` was not given.
+pub async fn execute(args: JoinArgs, _config_service: &dyn ConfigService) -> crate::Result<()> {
+    args.validate().map_err(SubXError::CommandExecution)?;
+
+    let format_manager = FormatManager::new();
+    let parts = args
+        .parts
+        .iter()
+        .map(|p| format_manager.load_subtitle(p))
+        .collect::<crate::Result<Vec<_>>>()?;
+
+    let offsets: Vec<Duration> = if args.videos.is_empty() {
+        args.offset.clone()
+    } else {
+        let loader = DirectAudioLoader::new()?;
+        args.videos
+            .iter()
+            .map(|v| loader.probe_duration(v))
+            .collect::<crate::Result<Vec<_>>>()?
+    };
+
+    let joined = Subtitle::join(&parts, &offsets)?;
+    let output = args
+        .output
+        .clone()
+        .unwrap_or_else(|| default_output_path(&args.parts[0]));
+    if output.exists() && !args.force {
+        return Err(SubXError::CommandExecution(format!(
+            "Output file already exists: {}. Use --force to overwrite.",
+            output.display()
+        )));
+    }
+    format_manager.save_subtitle(&joined, &output)?;
+
+    println!(
+        "✓ Joined {} parts ({} entries) -> {}",
+        parts.len(),
+        joined.entries.len(),
+        output.display()
+    );
+    Ok(())
+}
+
+/// Execute the subtitle join command with an injected configuration service.
+pub async fn execute_with_config(
+    args: JoinArgs,
+    config_service: std::sync::Arc<dyn ConfigService>,
+) -> crate::Result<()> {
+    execute(args, config_service.as_ref()).await
+}
+
+/// Derive the joined file name by removing a trailing `CD1`/`part1` marker.
+fn default_output_path(first_part: &Path) -> PathBuf {
+    let stem = first_part
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("joined");
+    let ext = first_part
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("srt");
+    let name = if FIRST_PART_MARKER.is_match(stem) {
+        format!("{}.{}", FIRST_PART_MARKER.replace(stem, ""), ext)
+    } else {
+        format!("{}.joined.{}", stem, ext)
+    };
+    first_part.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TestConfigService;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_default_output_path_strips_cd_marker() {
+        assert_eq!(
+            default_output_path(Path::new("/m/Movie.CD1.srt")),
+            PathBuf::from("/m/Movie.srt")
+        );
+        assert_eq!(
+            default_output_path(Path::new("/m/Movie - Part 1.ass")),
+            PathBuf::from("/m/Movie.ass")
+        );
+        assert_eq!(
+            default_output_path(Path::new("/m/Movie.srt")),
+            PathBuf::from("/m/Movie.joined.srt")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_join_with_explicit_offset() -> crate::Result<()> {
+        let temp = TempDir::new().unwrap();
+        let cd1 = temp.path().join("movie.cd1.srt");
+        let cd2 = temp.path().join("movie.cd2.srt");
+        fs::write(&cd1, "1\n00:00:01,000 --> 00:00:02,000\nFirst\n\n").unwrap();
+        fs::write(&cd2, "1\n00:00:03,000 --> 00:00:04,000\nSecond\n\n").unwrap();
+
+        let args = JoinArgs {
+            parts: vec![cd1, cd2],
+            offset: vec![Duration::from_secs(3600)],
+            videos: Vec::new(),
+            output: None,
+            force: false,
+        };
+        execute(args, &TestConfigService::with_defaults()).await?;
+
+        let content = fs::read_to_string(temp.path().join("movie.srt")).unwrap();
+        assert!(content.contains("1\n00:00:01,000 --> 00:00:02,000\nFirst"));
+        assert!(content.contains("2\n01:00:03,000 --> 01:00:04,000\nSecond"));
+        Ok(())
+    }
+}
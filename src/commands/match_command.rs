@@ -66,13 +66,19 @@ use crate::cli::MatchArgs;
 use crate::cli::display_match_results;
 use crate::config::ConfigService;
 use crate::core::ComponentFactory;
-use crate::core::matcher::{FileDiscovery, MatchConfig, MatchEngine, MediaFileType};
+use crate::core::journal::Journal;
+use crate::core::matcher::{
+    FileDiscovery, MatchConfig, MatchEngine, MatchOperation, MediaFileType, TerminalPrompter,
+    review_operations,
+};
 use crate::core::parallel::{
     FileProcessingTask, ProcessingOperation, Task, TaskResult, TaskScheduler,
 };
 use crate::error::SubXError;
-use crate::services::ai::AIProvider;
+use crate::services::ai::local::LOCAL_MODEL_NAME;
+use crate::services::ai::{AIProvider, LocalProvider};
 use indicatif::ProgressDrawTarget;
+use std::io::IsTerminal;
 
 /// Execute the AI-powered subtitle matching operation with full workflow.
 ///
@@ -174,7 +180,7 @@ pub async fn execute(args: MatchArgs, config_service: &dyn ConfigService) -> Res
 
     // Create AI client using the component factory
     let factory = ComponentFactory::new(config_service)?;
-    let ai_client = factory.create_ai_provider()?;
+    let ai_client = create_ai_client(&args, &factory)?;
 
     // Execute the matching workflow with dependency injection
     execute_with_client(args, ai_client, &config).await
@@ -210,12 +216,22 @@ pub async fn execute_with_config(
 
     // Create AI client using the component factory
     let factory = ComponentFactory::new(config_service.as_ref())?;
-    let ai_client = factory.create_ai_provider()?;
+    let ai_client = create_ai_client(&args, &factory)?;
 
     // Execute the matching workflow with dependency injection
     execute_with_client(args, ai_client, &config).await
 }
 
+/// Create the matching provider: the offline heuristics for `--no-ai`,
+/// otherwise the configured AI provider.
+fn create_ai_client(args: &MatchArgs, factory: &ComponentFactory) -> Result<Box<dyn AIProvider>> {
+    if args.no_ai {
+        Ok(Box::new(LocalProvider::new()))
+    } else {
+        factory.create_ai_provider()
+    }
+}
+
 /// Execute the matching workflow with dependency-injected AI client.
 ///
 /// This function implements the core matching logic while accepting an
@@ -303,14 +319,36 @@ pub async fn execute_with_client(
     ai_client: Box<dyn AIProvider>,
     config: &crate::config::Config,
 ) -> Result<()> {
+    run_with_client(args, ai_client, config).await.map(|_| ())
+}
+
+/// Run the match workflow like [`execute`] and return the operations that
+/// were applied, or proposed in dry-run mode.
+pub async fn run(
+    args: MatchArgs,
+    config_service: &dyn ConfigService,
+) -> Result<Vec<MatchOperation>> {
+    let config = config_service.get_config()?;
+    let factory = ComponentFactory::new(config_service)?;
+    let ai_client = create_ai_client(&args, &factory)?;
+    run_with_client(args, ai_client, &config).await
+}
+
+/// Run the match workflow like [`execute_with_client`] and return the
+/// operations that were applied, or proposed in dry-run mode.
+pub async fn run_with_client(
+    args: MatchArgs,
+    ai_client: Box<dyn AIProvider>,
+    config: &crate::config::Config,
+) -> Result<Vec<MatchOperation>> {
     // Determine file relocation mode from command line arguments
-    let relocation_mode = if args.copy {
-        crate::core::matcher::engine::FileRelocationMode::Copy
-    } else if args.move_files {
-        crate::core::matcher::engine::FileRelocationMode::Move
-    } else {
-        crate::core::matcher::engine::FileRelocationMode::None
-    };
+    let relocation_mode = args.relocation_mode();
+
+    if args.interactive && !std::io::stdin().is_terminal() {
+        return Err(SubXError::CommandExecution(
+            "--interactive needs a terminal to prompt on".to_string(),
+        ));
+    }
 
     // Create matching engine configuration from provided config
     let match_config = MatchConfig {
@@ -320,12 +358,28 @@ pub async fn execute_with_client(
         enable_content_analysis: true,
         backup_enabled: args.backup || config.general.backup_enabled,
         relocation_mode,
-        conflict_resolution: crate::core::matcher::engine::ConflictResolution::AutoRename,
-        ai_model: config.ai.model.clone(),
+        conflict_resolution: args.conflict_resolution(),
+        ai_model: if args.no_ai || config.ai.provider == "local" {
+            LOCAL_MODEL_NAME.to_string()
+        } else {
+            config.ai.model.clone()
+        },
+        output_encoding: args.output_encoding()?,
+        naming: args.naming_template(&config.general.naming_template)?,
+        verification_margin: config.ai.verification_margin,
     };
 
     // Initialize the matching engine with AI client and configuration
-    let engine = MatchEngine::new(ai_client, match_config);
+    let journal = Journal::start("match")?;
+    let run_id = journal.run_id().to_string();
+    let engine = MatchEngine::new(ai_client, match_config)
+        .with_local_prefilter(args.local_prefilter)
+        .with_audio_verification(args.verify_audio.then(|| config.sync.vad.clone()))
+        .with_candidate_selection(args.candidates.map(Into::into))
+        .with_duration_check(!args.no_duration_check)
+        .with_chunking(config.ai.max_prompt_tokens)
+        .with_scheduler_config(&config)
+        .with_journal(journal);
 
     // Use the get_input_handler method to get all input files
     let input_handler = args.get_input_handler()?;
@@ -340,7 +394,14 @@ pub async fn execute_with_client(
     }
 
     // Perform matching using unified file-list based approach
-    let operations = engine.match_file_list(&files).await?;
+    let mut operations = engine.match_file_list(&files).await?;
+
+    // Let the user review the proposals and remember the decisions
+    if args.interactive && !operations.is_empty() {
+        let videos = engine.scan_videos(&files)?;
+        operations = review_operations(&engine, operations, &videos, &mut TerminalPrompter)?;
+        engine.save_reviewed(&files, &operations).await?;
+    }
 
     // Display formatted results table to user
     display_match_results(&operations, args.dry_run);
@@ -349,8 +410,17 @@ pub async fn execute_with_client(
     if !args.dry_run {
         engine.execute_operations(&operations, args.dry_run).await?;
     }
+    // Clean up duplicate subtitles next to the matched videos
+    let removed = if args.dedupe {
+        engine.remove_duplicates(&operations, args.dry_run)?
+    } else {
+        0
+    };
+    if !args.dry_run && (!operations.is_empty() || removed > 0) {
+        println!("Run {run_id} recorded; revert it with `subx undo {run_id}`");
+    }
 
-    Ok(())
+    Ok(operations)
 }
 
 /// Execute parallel matching operations across multiple files and directories.
@@ -625,13 +695,8 @@ mod tests {
         // Execute dry-run
         let args = MatchArgs {
             path: Some(PathBuf::from(&media_path)),
-            input_paths: Vec::new(),
             dry_run: true,
-            recursive: false,
-            confidence: 80,
-            backup: false,
-            copy: false,
-            move_files: false,
+            ..Default::default()
         };
 
         // Note: Since we're testing in isolation, we might need to use execute_with_config
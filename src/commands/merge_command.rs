@@ -0,0 +1,206 @@
+//! Bilingual subtitle merge command implementation.
+//!
+//! This module implements the `merge` subcommand, which loads two subtitle
+//! tracks, pairs their cues by time overlap using
+//! [`BilingualMerger`](crate::core::formats::bilingual::BilingualMerger), and
+//! writes a single bilingual subtitle.
+//!
+//! Track languages are detected from the file names using the
+//! [`LanguageDetector`](crate::core::language::LanguageDetector) conventions
+//! (`tc`, `sc`, `en`). They select default ASS fonts and the default output
+//! file name.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use subx_cli::cli::MergeArgs;
+//! use subx_cli::commands::merge_command;
+//!
+//! merge_command::execute(args, &config_service).await?;
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use crate::cli::MergeArgs;
+use crate::config::ConfigService;
+use crate::core::formats::SubtitleFormatType;
+use crate::core::formats::bilingual::{BilingualMerger, default_style_for_language};
+use crate::core::formats::manager::FormatManager;
+use crate::error::SubXError;
+
+/// Execute the bilingual merge command.
+///
+/// # Arguments
+///
+/// * `args` - Merge arguments containing both input tracks and output options
+/// * `config_service` - Configuration service (reserved for future settings)
+///
+/// # Errors
+///
+/// Returns an error if an input cannot be loaded, the output format is not
+/// supported, or the output file exists and `--force` was not given.
+pub async fn execute(args: MergeArgs, _config_service: &dyn ConfigService) -> crate::Result<()> {
+    args.validate().map_err(SubXError::CommandExecution)?;
+
+    let format_manager = FormatManager::new();
+    let primary = format_manager.load_subtitle(&args.primary)?;
+    let secondary = format_manager.load_subtitle(&args.secondary)?;
+
+    let merged = BilingualMerger::new()
+        .with_min_overlap_ratio(args.min_overlap)
+        .merge_files(&primary, &args.primary, &secondary, &args.secondary);
+
+    let format = resolve_output_format(&args, &primary.format)?;
+    let output_path = args.output.clone().unwrap_or_else(|| {
+        default_output_path(
+            &args.primary,
+            merged.language_tag().as_deref(),
+            format.as_str(),
+        )
+    });
+    if output_path.exists() && !args.force {
+        return Err(SubXError::CommandExecution(format!(
+            "Output file already exists: {}. Use --force to overwrite.",
+            output_path.display()
+        )));
+    }
+
+    let content = if format == SubtitleFormatType::Ass {
+        let mut top = default_style_for_language("Top", merged.primary_language.as_deref(), 8);
+        let mut bottom =
+            default_style_for_language("Bottom", merged.secondary_language.as_deref(), 2);
+        if let Some(font) = &args.top_font {
+            top.font_name = font.clone();
+        }
+        if let Some(font) = &args.bottom_font {
+            bottom.font_name = font.clone();
+        }
+        merged.to_ass(&top, &bottom)
+    } else {
+        let formatter = format_manager.get_format(format.as_str()).ok_or_else(|| {
+            SubXError::subtitle_format(format.as_str(), "Unsupported output format for merge")
+        })?;
+        formatter.serialize(&merged.to_stacked(format))?
+    };
+    std::fs::write(&output_path, content)?;
+
+    println!(
+        "✓ Merged {} cues ({} paired) -> {}",
+        merged.cues.len(),
+        merged.paired_count(),
+        output_path.display()
+    );
+    Ok(())
+}
+
+/// Execute the bilingual merge command with an injected configuration service.
+pub async fn execute_with_config(
+    args: MergeArgs,
+    config_service: std::sync::Arc<dyn ConfigService>,
+) -> crate::Result<()> {
+    execute(args, config_service.as_ref()).await
+}
+
+fn resolve_output_format(
+    args: &MergeArgs,
+    primary_format: &SubtitleFormatType,
+) -> crate::Result<SubtitleFormatType> {
+    if let Some(format) = &args.format {
+        return Ok(format.clone().into());
+    }
+    if let Some(ext) = args
+        .output
+        .as_ref()
+        .and_then(|p| p.extension())
+        .and_then(|e| e.to_str())
+    {
+        return match ext.to_lowercase().as_str() {
+            "srt" => Ok(SubtitleFormatType::Srt),
+            "ass" | "ssa" => Ok(SubtitleFormatType::Ass),
+            "vtt" => Ok(SubtitleFormatType::Vtt),
+            "sub" => Ok(SubtitleFormatType::Sub),
+            other => Err(SubXError::subtitle_format(
+                other,
+                "Unsupported output format for merge",
+            )),
+        };
+    }
+    Ok(primary_format.clone())
+}
+
+fn default_output_path(primary: &Path, language_tag: Option<&str>, format: &str) -> PathBuf {
+    let detector = crate::core::language::LanguageDetector::new();
+    let stem = primary
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("merged");
+    // Drop a trailing language suffix such as ".tc" so it is not repeated
+    let base = match detector.get_primary_language(primary) {
+        Some(code) => stem.strip_suffix(&format!(".{}", code)).unwrap_or(stem),
+        None => stem,
+    };
+    let tag = language_tag.unwrap_or("bilingual");
+    primary.with_file_name(format!("{}.{}.{}", base, tag, format))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TestConfigService;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn args(primary: PathBuf, secondary: PathBuf) -> MergeArgs {
+        MergeArgs {
+            primary,
+            secondary,
+            output: None,
+            format: None,
+            min_overlap: 0.5,
+            top_font: None,
+            bottom_font: None,
+            force: false,
+        }
+    }
+
+    #[test]
+    fn test_default_output_path_uses_language_tag() {
+        let path = default_output_path(Path::new("/tmp/movie.tc.srt"), Some("tc-en"), "srt");
+        assert_eq!(path, PathBuf::from("/tmp/movie.tc-en.srt"));
+        let path = default_output_path(Path::new("/tmp/movie.srt"), None, "ass");
+        assert_eq!(path, PathBuf::from("/tmp/movie.bilingual.ass"));
+    }
+
+    #[tokio::test]
+    async fn test_merge_writes_stacked_srt() -> crate::Result<()> {
+        let temp = TempDir::new().unwrap();
+        let zh = temp.path().join("movie.tc.srt");
+        let en = temp.path().join("movie.en.srt");
+        fs::write(&zh, "1\n00:00:01,000 --> 00:00:03,000\n你好\n\n").unwrap();
+        fs::write(&en, "1\n00:00:01,100 --> 00:00:02,900\nHello\n\n").unwrap();
+
+        let config_service = TestConfigService::with_defaults();
+        execute(args(zh, en), &config_service).await?;
+
+        let content = fs::read_to_string(temp.path().join("movie.tc-en.srt")).unwrap();
+        assert!(content.contains("00:00:01,000 --> 00:00:03,000\n你好\nHello"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_merge_refuses_to_overwrite_without_force() {
+        let temp = TempDir::new().unwrap();
+        let zh = temp.path().join("a.srt");
+        let en = temp.path().join("b.srt");
+        let out = temp.path().join("out.srt");
+        fs::write(&zh, "1\n00:00:01,000 --> 00:00:03,000\n你好\n\n").unwrap();
+        fs::write(&en, "1\n00:00:01,000 --> 00:00:03,000\nHello\n\n").unwrap();
+        fs::write(&out, "existing").unwrap();
+
+        let mut merge_args = args(zh, en);
+        merge_args.output = Some(out.clone());
+        let config_service = TestConfigService::with_defaults();
+        assert!(execute(merge_args, &config_service).await.is_err());
+        assert_eq!(fs::read_to_string(&out).unwrap(), "existing");
+    }
+}
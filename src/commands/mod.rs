@@ -2,7 +2,11 @@
 //!
 //! This module contains implementations of each CLI subcommand's business logic,
 //! including AI matching, format conversion, synchronization, encoding detection,
-//! configuration management, and cache operations.
+//! configuration management, cache operations, bilingual merging,
+//! splitting or joining of subtitle parts, SDH annotation removal,
+//! Traditional/Simplified Chinese conversion, semantic subtitle comparison,
+//! subtitle file inspection, undoing recorded runs, and watching directories
+//! for new downloads.
 //!
 //! The `dispatcher` module provides centralized command routing to eliminate
 //! code duplication between CLI and library API interfaces.
@@ -23,7 +27,16 @@ pub mod cache_command;
 pub mod config_command;
 pub mod convert_command;
 pub mod detect_encoding_command;
+pub mod diff_command;
 /// Central command dispatcher for unified command execution across CLI and library interfaces.
 pub mod dispatcher;
+pub mod info_command;
+pub mod join_command;
 pub mod match_command;
+pub mod merge_command;
+pub mod split_command;
+pub mod strip_sdh_command;
 pub mod sync_command;
+pub mod undo_command;
+pub mod watch_command;
+pub mod zh_convert_command;
@@ -0,0 +1,117 @@
+//! Subtitle split command implementation.
+//!
+//! This module implements the `split` subcommand, which cuts a subtitle in
+//! two with [`Subtitle::split`](crate::core::formats::Subtitle::split) and
+//! writes both parts in the input's format. The second part is rebased so
+//! that it starts at zero.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use subx_cli::cli::SplitArgs;
+//! use subx_cli::commands::split_command;
+//!
+//! split_command::execute(args, &config_service).await?;
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use crate::cli::SplitArgs;
+use crate::config::ConfigService;
+use crate::core::formats::manager::FormatManager;
+use crate::error::SubXError;
+
+/// Execute the subtitle split command.
+///
+/// # Arguments
+///
+/// * `args` - Split arguments with the input file and split point
+/// * `config_service` - Configuration service (reserved for future settings)
+///
+/// # Errors
+///
+/// Returns an error if the input cannot be loaded, the split point leaves a
+/// part empty, or an output file exists and `--force` was not given.
+pub async fn execute(args: SplitArgs, _config_service: &dyn ConfigService) -> crate::Result<()> {
+    let format_manager = FormatManager::new();
+    let subtitle = format_manager.load_subtitle(&args.input)?;
+    let (first, second) = subtitle.split(args.split_point())?;
+
+    let outputs = match args.output.as_slice() {
+        [a, b] => [a.clone(), b.clone()],
+        _ => default_output_paths(&args.input),
+    };
+    for output in &outputs {
+        if output.exists() && !args.force {
+            return Err(SubXError::CommandExecution(format!(
+                "Output file already exists: {}. Use --force to overwrite.",
+                output.display()
+            )));
+        }
+    }
+    format_manager.save_subtitle(&first, &outputs[0])?;
+    format_manager.save_subtitle(&second, &outputs[1])?;
+
+    println!(
+        "✓ Split {} -> {} ({} entries), {} ({} entries)",
+        args.input.display(),
+        outputs[0].display(),
+        first.entries.len(),
+        outputs[1].display(),
+        second.entries.len()
+    );
+    Ok(())
+}
+
+/// Execute the subtitle split command with an injected configuration service.
+pub async fn execute_with_config(
+    args: SplitArgs,
+    config_service: std::sync::Arc<dyn ConfigService>,
+) -> crate::Result<()> {
+    execute(args, config_service.as_ref()).await
+}
+
+fn default_output_paths(input: &Path) -> [PathBuf; 2] {
+    let stem = input
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("split");
+    let ext = input.extension().and_then(|e| e.to_str()).unwrap_or("srt");
+    [1, 2].map(|n| input.with_file_name(format!("{}.part{}.{}", stem, n, ext)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TestConfigService;
+    use std::fs;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_split_vtt_at_time() -> crate::Result<()> {
+        let temp = TempDir::new().unwrap();
+        let input = temp.path().join("double.vtt");
+        fs::write(
+            &input,
+            "WEBVTT\n\n00:00:01.000 --> 00:00:02.000\nEpisode one\n\n00:22:05.000 --> 00:22:06.000\nEpisode two\n\n",
+        )
+        .unwrap();
+
+        let args = SplitArgs {
+            input,
+            at: Some(Duration::from_secs(22 * 60)),
+            at_index: None,
+            output: Vec::new(),
+            force: false,
+        };
+        execute(args, &TestConfigService::with_defaults()).await?;
+
+        let first = fs::read_to_string(temp.path().join("double.part1.vtt")).unwrap();
+        let second = fs::read_to_string(temp.path().join("double.part2.vtt")).unwrap();
+        assert!(first.contains("Episode one"));
+        assert!(!first.contains("Episode two"));
+        assert!(second.contains("00:00:05.000 --> 00:00:06.000\nEpisode two"));
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+//! SDH annotation removal command implementation.
+//!
+//! This module implements the `strip-sdh` subcommand. Each input subtitle is
+//! loaded, cleaned with [`SdhCleaner`], and written back in its own format
+//! (or to `--output` for a single input). A report listing every removed
+//! annotation is printed per file.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use subx_cli::cli::StripSdhArgs;
+//! use subx_cli::commands::strip_sdh_command;
+//!
+//! strip_sdh_command::execute(args, &config_service).await?;
+//! ```
+
+use crate::cli::StripSdhArgs;
+use crate::config::ConfigService;
+use crate::core::formats::manager::FormatManager;
+use crate::core::formats::sdh::SdhCleaner;
+use crate::error::SubXError;
+
+/// Execute the SDH annotation removal command.
+///
+/// # Arguments
+///
+/// * `args` - Input paths, rule set selection and output options
+/// * `config_service` - Configuration service (reserved for future settings)
+///
+/// # Errors
+///
+/// Returns an error if a custom pattern is invalid, `--output` is combined
+/// with several inputs, or a subtitle cannot be loaded or written.
+pub async fn execute(args: StripSdhArgs, _config_service: &dyn ConfigService) -> crate::Result<()> {
+    let mut cleaner = SdhCleaner::new(args.language.into());
+    for pattern in &args.pattern {
+        cleaner = cleaner.with_pattern(pattern)?;
+    }
+
+    let handler = args
+        .get_input_handler()
+        .map_err(|e| SubXError::CommandExecution(e.to_string()))?;
+    let files = handler
+        .collect_files()
+        .map_err(|e| SubXError::CommandExecution(e.to_string()))?;
+    if args.output.is_some() && files.len() > 1 {
+        return Err(SubXError::CommandExecution(
+            "--output can only be used with a single input file".to_string(),
+        ));
+    }
+
+    let format_manager = FormatManager::new();
+    for input_path in files {
+        let mut subtitle = format_manager.load_subtitle(&input_path)?;
+        let report = cleaner.clean(&mut subtitle);
+
+        println!("{}:", input_path.display());
+        println!("{}", report);
+        if args.dry_run {
+            continue;
+        }
+        let output_path = args.output.clone().unwrap_or_else(|| input_path.clone());
+        if report.is_empty() && output_path == input_path {
+            continue;
+        }
+        format_manager.save_subtitle(&subtitle, &output_path)?;
+        println!("✓ Saved: {}", output_path.display());
+    }
+    Ok(())
+}
+
+/// Execute the SDH annotation removal command with an injected configuration service.
+pub async fn execute_with_config(
+    args: StripSdhArgs,
+    config_service: std::sync::Arc<dyn ConfigService>,
+) -> crate::Result<()> {
+    execute(args, config_service.as_ref()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::SdhLanguageArg;
+    use crate::config::TestConfigService;
+    use std::fs;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn args(input: PathBuf) -> StripSdhArgs {
+        StripSdhArgs {
+            input: Some(input),
+            input_paths: Vec::new(),
+            recursive: false,
+            language: SdhLanguageArg::Auto,
+            pattern: Vec::new(),
+            output: None,
+            dry_run: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_strip_sdh_in_place() -> crate::Result<()> {
+        let temp = TempDir::new().unwrap();
+        let input = temp.path().join("movie.srt");
+        fs::write(
+            &input,
+            "1\n00:00:01,000 --> 00:00:02,000\n[DOOR CLOSES]\n\n2\n00:00:03,000 --> 00:00:04,000\nJOHN: Hello.\n\n",
+        )
+        .unwrap();
+
+        execute(args(input.clone()), &TestConfigService::with_defaults()).await?;
+
+        let content = fs::read_to_string(&input).unwrap();
+        assert!(content.starts_with("1\n00:00:03,000 --> 00:00:04,000\nHello."));
+        assert!(!content.contains("DOOR"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_strip_sdh_dry_run_leaves_file_untouched() -> crate::Result<()> {
+        let temp = TempDir::new().unwrap();
+        let input = temp.path().join("movie.srt");
+        let original = "1\n00:00:01,000 --> 00:00:02,000\n(laughs) Yes.\n\n";
+        fs::write(&input, original).unwrap();
+
+        let mut strip_args = args(input.clone());
+        strip_args.dry_run = true;
+        execute(strip_args, &TestConfigService::with_defaults()).await?;
+
+        assert_eq!(fs::read_to_string(&input).unwrap(), original);
+        Ok(())
+    }
+}
@@ -8,7 +8,10 @@ use crate::cli::SyncArgs;
 use crate::cli::SyncMode;
 use crate::config::Config;
 use crate::config::ConfigService;
+use crate::core::formats::Subtitle;
 use crate::core::formats::manager::FormatManager;
+use crate::core::journal::Journal;
+use crate::core::media_duration::{DurationFit, cues_end, format_hms, probe_media_duration};
 use crate::core::sync::{SyncEngine, SyncMethod, SyncResult};
 use crate::{Result, error::SubXError};
 
@@ -18,6 +21,7 @@ async fn run_single(
     config: &Config,
     sync_engine: &SyncEngine,
     format_manager: &FormatManager,
+    journal: &Journal,
 ) -> Result<()> {
     let subtitle_path = args.subtitle.as_ref().ok_or_else(|| {
         SubXError::CommandExecution(
@@ -39,12 +43,14 @@ async fn run_single(
         eprintln!("[DEBUG] Failed to load subtitle: {e}");
         e
     })?;
-    let sync_result = if let Some(offset) = args.offset {
+    // A stretch without an offset is still a manual adjustment
+    let manual_offset = args.offset.or(args.stretch.map(|_| 0.0));
+    let mut sync_result = if let Some(offset) = manual_offset {
         if args.verbose {
             println!("⚙️  Using manual offset: {offset:.3}s");
         }
-        sync_engine
-            .apply_manual_offset(&mut subtitle, offset)
+        let applied = sync_engine
+            .apply_time_adjustment(&mut subtitle, &args.time_adjustment(offset))
             .map_err(|e| {
                 eprintln!("[DEBUG] Failed to apply manual offset: {e}");
                 e
@@ -55,7 +61,7 @@ async fn run_single(
             method_used: crate::core::sync::SyncMethod::Manual,
             correlation_peak: 0.0,
             processing_duration: std::time::Duration::ZERO,
-            warnings: Vec::new(),
+            warnings: applied.warnings,
             additional_info: None,
         }
     } else {
@@ -82,7 +88,7 @@ async fn run_single(
         }
         let mut sync_cfg = config.sync.clone();
         apply_cli_overrides(&mut sync_cfg, args)?;
-        let result = sync_engine
+        let mut result = sync_engine
             .detect_sync_offset(video_path.as_path(), &subtitle, Some(method))
             .await
             .map_err(|e| {
@@ -96,16 +102,28 @@ async fn run_single(
             println!("   Processing time: {:?}", result.processing_duration);
         }
         if !args.dry_run {
-            sync_engine
-                .apply_manual_offset(&mut subtitle, result.offset_seconds)
+            let applied = sync_engine
+                .apply_time_adjustment(&mut subtitle, &args.time_adjustment(result.offset_seconds))
                 .map_err(|e| {
                     eprintln!("[DEBUG] Failed to apply detected offset: {e}");
                     e
                 })?;
+            result.warnings.extend(applied.warnings);
         }
         result
     };
+    // The cues are only shifted in dry runs when the offset was given
+    if manual_offset.is_some() || !args.dry_run {
+        if let Some(warning) = args
+            .video
+            .as_deref()
+            .and_then(|video| duration_warning(video, &subtitle))
+        {
+            sync_result.warnings.push(warning);
+        }
+    }
     display_sync_result(&sync_result, args.verbose);
+    let output_encoding = args.output_encoding()?;
     if !args.dry_run {
         if let Some(out) = args.get_output_path() {
             if out.exists() && !args.force {
@@ -118,10 +136,17 @@ async fn run_single(
                     out.display()
                 )));
             }
-            format_manager.save_subtitle(&subtitle, &out).map_err(|e| {
-                eprintln!("[DEBUG] Failed to save subtitle: {e}");
-                e
-            })?;
+            let previous = journal.preserve(&out)?;
+            let warnings = format_manager
+                .save_subtitle_with_encoding(&subtitle, &out, &output_encoding)
+                .map_err(|e| {
+                    eprintln!("[DEBUG] Failed to save subtitle: {e}");
+                    e
+                })?;
+            journal.record_written(&out, previous)?;
+            for warning in warnings {
+                eprintln!("⚠️  {warning}");
+            }
             if args.verbose {
                 println!("💾 Synchronized subtitle saved to: {}", out.display());
             } else {
@@ -139,6 +164,25 @@ async fn run_single(
     Ok(())
 }
 
+/// Warning for cues that end well after `video`, whose duration is read from
+/// its container metadata; `None` if they fit or the duration is unknown.
+fn duration_warning(video: &std::path::Path, subtitle: &Subtitle) -> Option<String> {
+    if video.as_os_str().is_empty() {
+        return None;
+    }
+    let duration = probe_media_duration(video)?;
+    let end = cues_end(subtitle)?;
+    match DurationFit::check(end, duration) {
+        DurationFit::Overruns { overrun } => Some(format!(
+            "Cues end at {}, {} after the end of the video ({}); check the offset",
+            format_hms(end),
+            format_hms(overrun),
+            format_hms(duration)
+        )),
+        _ => None,
+    }
+}
+
 /// Execute the sync command with the provided arguments.
 ///
 /// This function handles both manual offset synchronization and automatic
@@ -193,6 +237,7 @@ pub async fn execute(args: SyncArgs, config_service: &dyn ConfigService) -> Resu
 
     let sync_engine = SyncEngine::new(config.sync.clone())?;
     let format_manager = FormatManager::new();
+    let journal = Journal::start("sync")?;
 
     // Batch mode: multiple video-subtitle pairs
     if let Ok(SyncMode::Batch(handler)) = args.get_sync_mode() {
@@ -240,7 +285,14 @@ pub async fn execute(args: SyncArgs, config_service: &dyn ConfigService) -> Resu
             single_args.recursive = false;
             single_args.video = Some(video_files[0].clone());
             single_args.subtitle = Some(subtitle_files[0].clone());
-            run_single(&single_args, &config, &sync_engine, &format_manager).await?;
+            run_single(
+                &single_args,
+                &config,
+                &sync_engine,
+                &format_manager,
+                &journal,
+            )
+            .await?;
             return Ok(());
         }
 
@@ -291,7 +343,14 @@ pub async fn execute(args: SyncArgs, config_service: &dyn ConfigService) -> Resu
                 single_args.recursive = false;
                 single_args.video = Some((*video_path).clone());
                 single_args.subtitle = Some((*sub_path).clone());
-                run_single(&single_args, &config, &sync_engine, &format_manager).await?;
+                run_single(
+                    &single_args,
+                    &config,
+                    &sync_engine,
+                    &format_manager,
+                    &journal,
+                )
+                .await?;
 
                 processed_videos.insert(video_path.as_path());
                 processed_subtitles.insert(sub_path.as_path());
@@ -332,7 +391,14 @@ pub async fn execute(args: SyncArgs, config_service: &dyn ConfigService) -> Resu
                 resolved_args.offset = Some(0.0);
                 resolved_args.method = Some(crate::cli::SyncMethodArg::Manual);
             }
-            run_single(&resolved_args, &config, &sync_engine, &format_manager).await?;
+            run_single(
+                &resolved_args,
+                &config,
+                &sync_engine,
+                &format_manager,
+                &journal,
+            )
+            .await?;
             Ok(())
         }
         Err(err) => Err(err),
@@ -343,6 +409,7 @@ pub async fn execute(args: SyncArgs, config_service: &dyn ConfigService) -> Resu
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::{UnderflowPolicyArg, UnmappableArg};
     use crate::config::TestConfigService;
     use std::fs;
     use std::sync::Arc;
@@ -368,6 +435,14 @@ mod tests {
             input_paths: vec![],
             recursive: false,
             offset: Some(1.0), // Use manual offset to avoid audio processing
+            from: None,
+            to: None,
+            stretch: None,
+            on_underflow: UnderflowPolicyArg::Clamp,
+            encoding: None,
+            bom: false,
+            line_ending: None,
+            on_unmappable: UnmappableArg::Fail,
             method: Some(crate::cli::SyncMethodArg::Manual),
             window: 30,
             vad_sensitivity: None,
@@ -383,6 +458,74 @@ mod tests {
         // In dry run mode, files are not actually created, so we just verify the command executed successfully
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_sync_in_place_leaves_linked_original_alone() -> Result<()> {
+        let config_service = Arc::new(TestConfigService::with_sync_settings(0.5, 30.0));
+        let tmp = TempDir::new().unwrap();
+        let archive = tmp.path().join("archive.srt");
+        let video = tmp.path().join("movie.mp4");
+        let linked = tmp.path().join("movie.srt");
+        let content = "1\n00:00:01,000 --> 00:00:02,000\nTest1\n\n";
+        fs::write(&archive, content).unwrap();
+        fs::write(&video, b"").unwrap();
+        fs::hard_link(&archive, &linked).unwrap();
+
+        let args = SyncArgs {
+            positional_paths: Vec::new(),
+            video: Some(video),
+            subtitle: Some(linked.clone()),
+            input_paths: vec![],
+            recursive: false,
+            offset: Some(1.0),
+            from: None,
+            to: None,
+            stretch: None,
+            on_underflow: UnderflowPolicyArg::Clamp,
+            encoding: None,
+            bom: false,
+            line_ending: None,
+            on_unmappable: UnmappableArg::Fail,
+            method: Some(crate::cli::SyncMethodArg::Manual),
+            window: 30,
+            vad_sensitivity: None,
+            output: Some(linked.clone()),
+            verbose: false,
+            dry_run: false,
+            force: true,
+            batch: None,
+        };
+        execute(args, config_service.as_ref()).await?;
+
+        assert_eq!(fs::read_to_string(&archive).unwrap(), content);
+        assert!(
+            fs::read_to_string(&linked)
+                .unwrap()
+                .contains("00:00:02,000 -->")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_duration_warning_for_cues_past_video_end() {
+        let temp = TempDir::new().unwrap();
+        // Three seconds of silence stand in for the video
+        let video = temp.path().join("video.wav");
+        crate::services::vad::audio_loader::write_silent_wav(&video, 3);
+        let format_manager = FormatManager::new();
+        let subtitle = |end: &str| {
+            format_manager
+                .parse_auto(&format!("1\n00:00:01,000 --> {}\nHello\n\n", end))
+                .unwrap()
+        };
+
+        assert!(duration_warning(&video, &subtitle("00:00:02,000")).is_none());
+        let warning = duration_warning(&video, &subtitle("00:20:03,000")).unwrap();
+        assert!(warning.contains("00:20:00 after the end of the video"));
+        assert!(
+            duration_warning(&temp.path().join("missing.mp4"), &subtitle("00:20:03,000")).is_none()
+        );
+    }
 }
 
 /// Maintain consistency with other commands
@@ -468,5 +611,8 @@ fn display_sync_result(result: &SyncResult, verbose: bool) {
             result.offset_seconds,
             result.confidence * 100.0
         );
+        for warning in &result.warnings {
+            println!("  ⚠️  {warning}");
+        }
     }
 }
@@ -0,0 +1,79 @@
+//! Undo command implementation.
+//!
+//! This module implements the `undo` subcommand on top of the
+//! [`Journal`]: it lists the recorded runs or reverts one of them, by default
+//! the most recent run that has not been undone yet. A run is only reverted
+//! when none of its files changed since and no file would be overwritten.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use subx_cli::cli::UndoArgs;
+//! use subx_cli::commands::undo_command;
+//!
+//! undo_command::execute(args, &config_service).await?;
+//! ```
+
+use crate::cli::UndoArgs;
+use crate::config::ConfigService;
+use crate::core::journal::{Journal, format_timestamp};
+use crate::error::SubXError;
+
+/// Execute the undo command.
+///
+/// # Errors
+///
+/// Returns an error if the journal cannot be read, the run is unknown or
+/// already undone, or a file of the run changed since it was recorded.
+pub async fn execute(args: UndoArgs, _config_service: &dyn ConfigService) -> crate::Result<()> {
+    let journal = Journal::open_default()?;
+    let runs = journal.runs()?;
+
+    if args.list {
+        if runs.is_empty() {
+            println!("No recorded runs");
+        }
+        for run in &runs {
+            println!(
+                "{}  {}  {:<8} {} file change(s){}",
+                run.id,
+                format_timestamp(run.timestamp),
+                run.command,
+                run.entries.len(),
+                if run.undone { "  (undone)" } else { "" }
+            );
+        }
+        return Ok(());
+    }
+
+    let run_id = match args.run_id {
+        Some(run_id) => run_id,
+        None => runs
+            .iter()
+            .rev()
+            .find(|run| !run.undone)
+            .map(|run| run.id.clone())
+            .ok_or_else(|| SubXError::CommandExecution("No run to undo".to_string()))?,
+    };
+
+    let steps = journal.undo(&run_id, args.dry_run)?;
+    for step in &steps {
+        if args.dry_run {
+            println!("Preview: {}", step);
+        } else {
+            println!("✓ {}", step);
+        }
+    }
+    if !args.dry_run {
+        println!("Run {} undone", run_id);
+    }
+    Ok(())
+}
+
+/// Execute the undo command with injected configuration service.
+pub async fn execute_with_config(
+    args: UndoArgs,
+    config_service: std::sync::Arc<dyn ConfigService>,
+) -> crate::Result<()> {
+    execute(args, config_service.as_ref()).await
+}
@@ -0,0 +1,588 @@
+//! Watch command implementation.
+//!
+//! This module implements the `watch` subcommand: it monitors directories
+//! with `notify`, debounces the filesystem events and runs the steps listed
+//! in `watch.pipeline` on every batch of new videos and subtitles:
+//!
+//! 1. `encoding` – repair mojibake and transcode subtitles to UTF-8
+//! 2. `convert` – convert subtitles to `formats.default_output`, deleting
+//!    the originals only with `watch.delete_converted`
+//! 3. `match` – match subtitles with videos, relocating them as configured
+//!    by `watch.relocation`
+//! 4. `sync` – synchronize each matched subtitle with its video
+//!
+//! Processed files are remembered in a [`WatchState`] file so that restarts
+//! and the pipeline's own renames are not processed again. Subtitles no video
+//! matched yet stay pending and are retried when new videos arrive. Every
+//! step is recorded as a JSON line in the [`WatchLog`], and file changes go to
+//! the journal like those of the individual commands, so `subx undo` reverts
+//! them step by step.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use subx_cli::cli::WatchArgs;
+//! use subx_cli::commands::watch_command;
+//!
+//! watch_command::execute(args, &config_service).await?;
+//! ```
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use notify::{EventKind, RecursiveMode, Watcher};
+
+use crate::Result;
+use crate::cli::{ConvertArgs, MatchArgs, SyncArgs, UnderflowPolicyArg, UnmappableArg, WatchArgs};
+use crate::commands::{convert_command, match_command, sync_command};
+use crate::config::{Config, ConfigService};
+use crate::core::formats::encoding::{
+    Charset, EncodingConverter, EncodingDetector, MojibakeAnalyzer, OutputEncoding,
+};
+use crate::core::journal::Journal;
+use crate::core::matcher::{FileDiscovery, MediaFile, MediaFileType};
+use crate::core::watch::{Debouncer, WatchEvent, WatchLevel, WatchLog, WatchState};
+use crate::error::SubXError;
+
+/// Execute the watch command.
+///
+/// Files already in the watched directories that were never processed are
+/// handled first; with `--once` the command stops there, otherwise it keeps
+/// watching until interrupted with Ctrl-C.
+///
+/// # Errors
+///
+/// Returns an error if a path is not a directory, the state or log file
+/// cannot be opened, the files already there cannot be processed, or the
+/// directories cannot be watched. Once watching, failures of individual
+/// steps and batches are logged and do not stop the watcher.
+pub async fn execute(args: WatchArgs, config_service: &dyn ConfigService) -> Result<()> {
+    let config = config_service.get_config()?;
+    for path in &args.paths {
+        if !path.is_dir() {
+            return Err(SubXError::CommandExecution(format!(
+                "Not a directory: {}",
+                path.display()
+            )));
+        }
+    }
+    let log = WatchLog::open(match &args.log_file {
+        Some(path) => path.clone(),
+        None => WatchLog::default_path()?,
+    })?;
+    let state = WatchState::load(match &args.state_file {
+        Some(path) => path.clone(),
+        None => WatchState::default_path()?,
+    })?;
+    let mut pipeline = Pipeline {
+        config_service,
+        config: &config,
+        roots: &args.paths,
+        recursive: args.recursive,
+        state,
+        log,
+    };
+
+    // Catch up with files that arrived while nothing was watching
+    let mut existing = Vec::new();
+    for root in &args.paths {
+        let files = FileDiscovery::new().scan_directory(root, args.recursive)?;
+        existing.extend(files.into_iter().map(|f| f.path));
+    }
+    pipeline.process(existing).await?;
+    if args.once {
+        return Ok(());
+    }
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let mut watcher =
+        notify::recommended_watcher(move |res: notify::Result<notify::Event>| match res {
+            Ok(event) if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) => {
+                for path in event.paths {
+                    let _ = tx.send(path);
+                }
+            }
+            Ok(_) => {}
+            Err(e) => log::warn!("Filesystem watch error: {}", e),
+        })
+        .map_err(watch_error)?;
+    let mode = if args.recursive {
+        RecursiveMode::Recursive
+    } else {
+        RecursiveMode::NonRecursive
+    };
+    for root in &args.paths {
+        watcher.watch(root, mode).map_err(watch_error)?;
+    }
+
+    let debounce = args.debounce.unwrap_or(config.watch.debounce_ms);
+    let mut debouncer = Debouncer::new(Duration::from_millis(debounce));
+    pipeline.log.record(WatchEvent::new(
+        WatchLevel::Info,
+        "started",
+        format!(
+            "Watching {} (pipeline: {}, log: {})",
+            args.paths
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            config.watch.pipeline.join(", "),
+            pipeline.log.path().display()
+        ),
+    ));
+    println!("Watching for new files, press Ctrl-C to stop");
+
+    loop {
+        let deadline = debouncer.deadline();
+        tokio::select! {
+            received = rx.recv() => match received {
+                Some(path) => debouncer.push(path, Instant::now()),
+                None => break,
+            },
+            _ = async {
+                match deadline {
+                    Some(deadline) => tokio::time::sleep_until(deadline.into()).await,
+                    None => std::future::pending().await,
+                }
+            } => {
+                if let Some(batch) = debouncer.take_ready(Instant::now()) {
+                    if let Err(e) = pipeline.process(batch).await {
+                        pipeline.log.record(WatchEvent::new(
+                            WatchLevel::Error,
+                            "batch",
+                            format!("Processing batch failed: {}", e),
+                        ));
+                    }
+                }
+            }
+            _ = tokio::signal::ctrl_c() => break,
+        }
+    }
+
+    pipeline.log.record(WatchEvent::new(
+        WatchLevel::Info,
+        "stopped",
+        "Stopped watching",
+    ));
+    Ok(())
+}
+
+/// Execute the watch command with injected configuration service.
+pub async fn execute_with_config(
+    args: WatchArgs,
+    config_service: std::sync::Arc<dyn ConfigService>,
+) -> Result<()> {
+    execute(args, config_service.as_ref()).await
+}
+
+/// The configured steps and the state they share across batches.
+struct Pipeline<'a> {
+    config_service: &'a dyn ConfigService,
+    config: &'a Config,
+    roots: &'a [PathBuf],
+    recursive: bool,
+    state: WatchState,
+    log: WatchLog,
+}
+
+impl Pipeline<'_> {
+    fn has_step(&self, step: &str) -> bool {
+        self.config.watch.pipeline.iter().any(|s| s == step)
+    }
+
+    /// Run the pipeline on the media files among `paths` that were not
+    /// processed yet, then remember the resulting files.
+    async fn process(&mut self, paths: Vec<PathBuf>) -> Result<()> {
+        let files: Vec<MediaFile> = FileDiscovery::new()
+            .scan_file_list(&paths)?
+            .into_iter()
+            .filter(|f| !self.state.is_processed(&f.path))
+            .collect();
+        if files.is_empty() {
+            return Ok(());
+        }
+        let (videos, mut subtitles): (Vec<PathBuf>, Vec<PathBuf>) = {
+            let (videos, subtitles): (Vec<_>, Vec<_>) = files
+                .into_iter()
+                .partition(|f| matches!(f.file_type, MediaFileType::Video));
+            (
+                videos.into_iter().map(|f| f.path).collect(),
+                subtitles.into_iter().map(|f| f.path).collect(),
+            )
+        };
+        self.log.record(WatchEvent::new(
+            WatchLevel::Info,
+            "batch",
+            format!(
+                "{} new video(s), {} new subtitle(s)",
+                videos.len(),
+                subtitles.len()
+            ),
+        ));
+
+        // Videos only need matching and syncing; pending subtitles are
+        // retried whenever new videos arrive
+        if !videos.is_empty() && self.has_step("match") {
+            for subtitle in self.pending_subtitles(&videos)? {
+                if !subtitles.contains(&subtitle) {
+                    subtitles.push(subtitle);
+                }
+            }
+        }
+
+        let mut done: Vec<PathBuf> = videos.clone();
+        if self.has_step("encoding") {
+            let journal = Journal::start("watch")?;
+            for subtitle in &subtitles {
+                let result = self.fix_encoding(&journal, subtitle);
+                self.run_step("encoding", subtitle, result);
+            }
+        }
+        if self.has_step("convert") {
+            for subtitle in subtitles.iter_mut() {
+                let result = self.convert(subtitle).await;
+                if let Some(Some(output)) = self.run_step("convert", subtitle, result) {
+                    // A kept original must not be converted again
+                    done.push(std::mem::replace(subtitle, output));
+                }
+            }
+        }
+
+        // Pairs of video and subtitle for syncing
+        let mut pairs: Vec<(PathBuf, PathBuf)> = Vec::new();
+        if self.has_step("match") {
+            if !subtitles.is_empty() {
+                let result = self.match_files(&subtitles, &videos).await;
+                let first = subtitles[0].clone();
+                if let Some(matched) = self.run_step("match", &first, result) {
+                    for (video, source, target) in matched {
+                        done.push(source);
+                        done.push(target.clone());
+                        pairs.push((video, target));
+                    }
+                }
+            }
+            for subtitle in &subtitles {
+                if !done.contains(subtitle) {
+                    self.log.record(
+                        WatchEvent::new(
+                            WatchLevel::Warn,
+                            "step",
+                            "No matching video yet, will retry when videos arrive",
+                        )
+                        .with_step("match")
+                        .with_path(subtitle),
+                    );
+                }
+            }
+        } else {
+            pairs = pair_by_name(&subtitles, &self.scope_videos(&videos)?);
+            done.extend(subtitles.iter().cloned());
+        }
+
+        if self.has_step("sync") {
+            for (video, subtitle) in &pairs {
+                let result = self.sync(video, subtitle).await;
+                self.run_step("sync", subtitle, result);
+            }
+        }
+
+        for path in &done {
+            self.state.mark_processed(path);
+        }
+        self.state.save()?;
+        Ok(())
+    }
+
+    /// Log the outcome of `step` on `path` and return its value on success.
+    fn run_step<T>(&self, step: &str, path: &Path, result: Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(e) => {
+                self.log.record(
+                    WatchEvent::new(WatchLevel::Error, "step", format!("{} failed: {}", step, e))
+                        .with_step(step)
+                        .with_path(path),
+                );
+                None
+            }
+        }
+    }
+
+    fn record_done(&self, step: &str, path: &Path, message: String) {
+        self.log.record(
+            WatchEvent::new(WatchLevel::Info, "step", message)
+                .with_step(step)
+                .with_path(path),
+        );
+    }
+
+    /// Repair mojibake and transcode `path` to UTF-8 in place, recording
+    /// the change in the batch's `journal`.
+    fn fix_encoding(&self, journal: &Journal, path: &Path) -> Result<()> {
+        let file = path.to_string_lossy();
+        let info = EncodingDetector::with_defaults()
+            .with_confidence_threshold(0.0)
+            .detect_file_encoding(&file)?;
+        let text = EncodingConverter::new()
+            .convert_file_to_utf8(&file, &info)?
+            .converted_text;
+        let (text, message) = match MojibakeAnalyzer::new().analyze(&text) {
+            Some(repair) => (
+                repair.repaired_text,
+                format!(
+                    "Repaired {:?} text misread as {:?}",
+                    repair.original, repair.misread_as
+                ),
+            ),
+            None if info.charset == Charset::Utf8 => return Ok(()),
+            None if info.confidence < self.config.formats.encoding_detection_confidence => {
+                self.log.record(
+                    WatchEvent::new(
+                        WatchLevel::Warn,
+                        "step",
+                        format!(
+                            "Left as is: {:?} detected with low confidence ({:.0}%)",
+                            info.charset,
+                            info.confidence * 100.0
+                        ),
+                    )
+                    .with_step("encoding")
+                    .with_path(path),
+                );
+                return Ok(());
+            }
+            None => (text, format!("Transcoded from {:?} to UTF-8", info.charset)),
+        };
+        let previous = journal.preserve(path)?;
+        OutputEncoding::from_name("utf-8")?.write(path, &text)?;
+        journal.record_written(path, previous)?;
+        self.record_done("encoding", path, message);
+        Ok(())
+    }
+
+    /// Convert `path` to the default output format; returns the new file.
+    async fn convert(&self, path: &Path) -> Result<Option<PathBuf>> {
+        let format = &self.config.formats.default_output;
+        let extension = path
+            .extension()
+            .map(|e| e.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        if extension == *format || !["srt", "ass", "ssa", "vtt", "sub"].contains(&&*extension) {
+            return Ok(None);
+        }
+        let output = path.with_extension(format);
+        let args = ConvertArgs {
+            input: Some(path.to_path_buf()),
+            input_paths: vec![],
+            recursive: false,
+            format: None,
+            output: None,
+            keep_original: !self.config.watch.delete_converted,
+            encoding: "utf-8".to_string(),
+            bom: false,
+            line_ending: None,
+            on_unmappable: UnmappableArg::Fail,
+            strip_sdh: None,
+        };
+        convert_command::execute(args, self.config_service).await?;
+        if !output.exists() {
+            return Err(SubXError::CommandExecution(format!(
+                "Conversion to {} produced no output",
+                format
+            )));
+        }
+        self.record_done(
+            "convert",
+            path,
+            format!("Converted to {}", output.display()),
+        );
+        Ok(Some(output))
+    }
+
+    /// Match `subtitles` with the videos in scope; returns the video, the
+    /// original subtitle and the subtitle's new path of every match.
+    async fn match_files(
+        &self,
+        subtitles: &[PathBuf],
+        new_videos: &[PathBuf],
+    ) -> Result<Vec<(PathBuf, PathBuf, PathBuf)>> {
+        let videos = self.scope_videos(new_videos)?;
+        if videos.is_empty() {
+            return Ok(Vec::new());
+        }
+        let relocation = self.config.watch.relocation.as_str();
+        let args = MatchArgs {
+            input_paths: videos.iter().chain(subtitles).cloned().collect(),
+            confidence: self.config.watch.confidence,
+            copy: relocation == "copy",
+            move_files: relocation == "move",
+            ..Default::default()
+        };
+        let operations = match_command::run(args, self.config_service).await?;
+        Ok(operations
+            .into_iter()
+            .map(|op| {
+                let target = op.target_path();
+                self.record_done(
+                    "match",
+                    &op.subtitle_file.path,
+                    format!(
+                        "Matched with {} as {}",
+                        op.video_file.name,
+                        target.display()
+                    ),
+                );
+                (
+                    op.video_file.path.clone(),
+                    op.subtitle_file.path.clone(),
+                    target,
+                )
+            })
+            .collect())
+    }
+
+    /// Synchronize `subtitle` with `video` in place.
+    async fn sync(&self, video: &Path, subtitle: &Path) -> Result<()> {
+        let args = SyncArgs {
+            positional_paths: Vec::new(),
+            video: Some(video.to_path_buf()),
+            subtitle: Some(subtitle.to_path_buf()),
+            input_paths: vec![],
+            recursive: false,
+            offset: None,
+            from: None,
+            to: None,
+            stretch: None,
+            on_underflow: UnderflowPolicyArg::Clamp,
+            encoding: None,
+            bom: false,
+            line_ending: None,
+            on_unmappable: UnmappableArg::Fail,
+            method: None,
+            window: 30,
+            vad_sensitivity: None,
+            output: Some(subtitle.to_path_buf()),
+            verbose: false,
+            dry_run: false,
+            force: true,
+            batch: None,
+        };
+        sync_command::execute(args, self.config_service).await?;
+        self.record_done(
+            "sync",
+            subtitle,
+            format!("Synchronized with {}", video.display()),
+        );
+        Ok(())
+    }
+
+    /// Directories the match step looks at: those of the new files, or all
+    /// watched directories when subtitles may be relocated to their video.
+    fn scope(&self, new_files: &[PathBuf]) -> Vec<(PathBuf, bool)> {
+        if self.config.watch.relocation != "none" {
+            return self
+                .roots
+                .iter()
+                .map(|root| (root.clone(), self.recursive))
+                .collect();
+        }
+        let dirs: BTreeSet<PathBuf> = new_files
+            .iter()
+            .filter_map(|p| p.parent().map(Path::to_path_buf))
+            .collect();
+        dirs.into_iter().map(|dir| (dir, false)).collect()
+    }
+
+    /// Videos, or subtitles when `videos` is false, in the scope of
+    /// `new_files`.
+    fn scope_files(&self, new_files: &[PathBuf], videos: bool) -> Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+        for (dir, recursive) in self.scope(new_files) {
+            for file in FileDiscovery::new().scan_directory(&dir, recursive)? {
+                let is_video = matches!(file.file_type, MediaFileType::Video);
+                if is_video == videos && !files.contains(&file.path) {
+                    files.push(file.path);
+                }
+            }
+        }
+        Ok(files)
+    }
+
+    /// Videos new subtitles can be matched with.
+    fn scope_videos(&self, new_videos: &[PathBuf]) -> Result<Vec<PathBuf>> {
+        let mut videos = self.scope_files(new_videos, true)?;
+        for video in new_videos {
+            if !videos.contains(video) {
+                videos.push(video.clone());
+            }
+        }
+        Ok(videos)
+    }
+
+    /// Subtitles near `new_videos` still waiting for their video.
+    fn pending_subtitles(&self, new_videos: &[PathBuf]) -> Result<Vec<PathBuf>> {
+        Ok(self
+            .scope_files(new_videos, false)?
+            .into_iter()
+            .filter(|p| !self.state.is_processed(p))
+            .collect())
+    }
+}
+
+/// Pair subtitles with the video in their directory whose file stem starts
+/// their own, e.g. `Movie.mkv` with `Movie.en.srt`.
+fn pair_by_name(subtitles: &[PathBuf], videos: &[PathBuf]) -> Vec<(PathBuf, PathBuf)> {
+    subtitles
+        .iter()
+        .filter_map(|subtitle| {
+            let name = subtitle.file_name()?.to_string_lossy().to_string();
+            videos
+                .iter()
+                .filter(|video| video.parent() == subtitle.parent())
+                .filter_map(|video| Some((video, video.file_stem()?.to_string_lossy())))
+                .filter(|(_, stem)| name.starts_with(&format!("{}.", stem)))
+                .max_by_key(|(_, stem)| stem.len())
+                .map(|(video, _)| (video.clone(), subtitle.clone()))
+        })
+        .collect()
+}
+
+fn watch_error(e: notify::Error) -> SubXError {
+    SubXError::CommandExecution(format!("Unable to watch directories: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pair_by_name_prefers_longest_stem_in_same_directory() {
+        let videos = vec![
+            PathBuf::from("/tv/Show.mkv"),
+            PathBuf::from("/tv/Show.S01E01.mkv"),
+            PathBuf::from("/other/Show.S01E02.mkv"),
+        ];
+        let subtitles = vec![
+            PathBuf::from("/tv/Show.S01E01.en.srt"),
+            PathBuf::from("/tv/Show.S01E02.srt"),
+            PathBuf::from("/tv/Unrelated.srt"),
+        ];
+        let pairs = pair_by_name(&subtitles, &videos);
+        assert_eq!(
+            pairs,
+            vec![
+                (
+                    PathBuf::from("/tv/Show.S01E01.mkv"),
+                    PathBuf::from("/tv/Show.S01E01.en.srt")
+                ),
+                (
+                    PathBuf::from("/tv/Show.mkv"),
+                    PathBuf::from("/tv/Show.S01E02.srt")
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! Traditional ↔ Simplified Chinese conversion command implementation.
+//!
+//! This module implements the `zh-convert` subcommand. Each input subtitle is
+//! loaded, its text converted with [`ChineseConverter`], and the result is
+//! written in the same format to a file name re-tagged with the target
+//! language by [`retag_language`].
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use subx_cli::cli::ZhConvertArgs;
+//! use subx_cli::commands::zh_convert_command;
+//!
+//! zh_convert_command::execute(args, &config_service).await?;
+//! ```
+
+use crate::cli::ZhConvertArgs;
+use crate::config::ConfigService;
+use crate::core::chinese::{ChineseConversion, ChineseConverter, retag_language};
+use crate::core::formats::manager::FormatManager;
+use crate::error::SubXError;
+
+/// Execute the Chinese script conversion command.
+///
+/// # Arguments
+///
+/// * `args` - Input paths, target script and output options
+/// * `config_service` - Configuration service (reserved for future settings)
+///
+/// # Errors
+///
+/// Returns an error if `--output` is combined with several inputs, an output
+/// file exists and `--force` was not given, or a subtitle cannot be loaded or
+/// written.
+pub async fn execute(
+    args: ZhConvertArgs,
+    _config_service: &dyn ConfigService,
+) -> crate::Result<()> {
+    let conversion: ChineseConversion = args.to.into();
+    let converter = ChineseConverter::new(conversion, args.taiwan);
+
+    let handler = args
+        .get_input_handler()
+        .map_err(|e| SubXError::CommandExecution(e.to_string()))?;
+    let files = handler
+        .collect_files()
+        .map_err(|e| SubXError::CommandExecution(e.to_string()))?;
+    if args.output.is_some() && files.len() > 1 {
+        return Err(SubXError::CommandExecution(
+            "--output can only be used with a single input file".to_string(),
+        ));
+    }
+
+    let format_manager = FormatManager::new();
+    for input_path in files {
+        let output_path = args
+            .output
+            .clone()
+            .unwrap_or_else(|| retag_language(&input_path, conversion.target_language()));
+        if output_path.exists() && !args.force {
+            return Err(SubXError::CommandExecution(format!(
+                "Output file already exists: {}. Use --force to overwrite.",
+                output_path.display()
+            )));
+        }
+
+        let mut subtitle = format_manager.load_subtitle(&input_path)?;
+        let changed = converter.convert_subtitle(&mut subtitle);
+        format_manager.save_subtitle(&subtitle, &output_path)?;
+        println!(
+            "✓ Converted {} -> {} ({} of {} entries changed)",
+            input_path.display(),
+            output_path.display(),
+            changed,
+            subtitle.entries.len()
+        );
+    }
+    Ok(())
+}
+
+/// Execute the Chinese script conversion command with an injected configuration service.
+pub async fn execute_with_config(
+    args: ZhConvertArgs,
+    config_service: std::sync::Arc<dyn ConfigService>,
+) -> crate::Result<()> {
+    execute(args, config_service.as_ref()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::ChineseScriptArg;
+    use crate::config::TestConfigService;
+    use std::fs;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn args(input: PathBuf, to: ChineseScriptArg) -> ZhConvertArgs {
+        ZhConvertArgs {
+            input: Some(input),
+            input_paths: Vec::new(),
+            recursive: false,
+            to,
+            taiwan: false,
+            output: None,
+            force: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_zh_convert_writes_retagged_file() -> crate::Result<()> {
+        let temp = TempDir::new().unwrap();
+        let input = temp.path().join("movie.sc.srt");
+        fs::write(&input, "1\n00:00:01,000 --> 00:00:02,000\n谢谢你\n\n").unwrap();
+
+        execute(
+            args(input.clone(), ChineseScriptArg::Tc),
+            &TestConfigService::with_defaults(),
+        )
+        .await?;
+
+        let content = fs::read_to_string(temp.path().join("movie.tc.srt")).unwrap();
+        assert!(content.contains("謝謝你"));
+        assert!(fs::read_to_string(&input).unwrap().contains("谢谢你"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_zh_convert_refuses_to_overwrite_without_force() {
+        let temp = TempDir::new().unwrap();
+        let input = temp.path().join("movie.tc.srt");
+        fs::write(&input, "1\n00:00:01,000 --> 00:00:02,000\n謝謝\n\n").unwrap();
+        fs::write(temp.path().join("movie.sc.srt"), "existing").unwrap();
+
+        let result = execute(
+            args(input, ChineseScriptArg::Sc),
+            &TestConfigService::with_defaults(),
+        )
+        .await;
+        assert!(result.is_err());
+    }
+}
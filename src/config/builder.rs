@@ -303,6 +303,28 @@ impl TestConfigBuilder {
         self
     }
 
+    // Watch Configuration Methods
+
+    /// Set the steps of the watch pipeline.
+    ///
+    /// # Arguments
+    ///
+    /// * `steps` - Steps to run, e.g. `["convert", "match"]`
+    pub fn with_watch_pipeline(mut self, steps: &[&str]) -> Self {
+        self.config.watch.pipeline = steps.iter().map(|step| step.to_string()).collect();
+        self
+    }
+
+    /// Set how the watch pipeline relocates matched subtitles.
+    ///
+    /// # Arguments
+    ///
+    /// * `relocation` - `none`, `copy` or `move`
+    pub fn with_watch_relocation(mut self, relocation: &str) -> Self {
+        self.config.watch.relocation = relocation.to_string();
+        self
+    }
+
     // Builder Methods
 
     /// Build a test configuration service with the configured settings.
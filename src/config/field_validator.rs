@@ -47,6 +47,15 @@ pub fn validate_field(key: &str, value: &str) -> Result<()> {
                 .map_err(|_| SubXError::config("Temperature must be a number"))?;
             validate_temperature(temp)?;
         }
+        "ai.verification_margin" => {
+            let margin: f32 = value
+                .parse()
+                .map_err(|_| SubXError::config("Verification margin must be a number"))?;
+            validate_range(margin, 0.0, 1.0)?;
+        }
+        "ai.max_prompt_tokens" => {
+            validate_usize_range(value, 1_000, 1_000_000)?;
+        }
         "ai.max_tokens" => {
             let tokens: u32 = value
                 .parse()
@@ -157,6 +166,9 @@ pub fn validate_field(key: &str, value: &str) -> Result<()> {
                 .map_err(|_| SubXError::config("Worker idle timeout must be a positive integer"))?;
             validate_range(timeout, 10, 3600)?;
         }
+        "general.naming_template" => {
+            value.parse::<crate::core::matcher::NamingTemplate>()?;
+        }
 
         // Parallel configuration fields
         "parallel.max_workers" => {
@@ -181,6 +193,34 @@ pub fn validate_field(key: &str, value: &str) -> Result<()> {
             validate_enum(value, &["Block", "Drop", "Expand"])?;
         }
 
+        // Watch configuration fields
+        "watch.debounce_ms" => {
+            let debounce: u64 = value
+                .parse()
+                .map_err(|_| SubXError::config("Watch debounce must be a positive integer"))?;
+            validate_range(debounce, 100, 600_000)?;
+        }
+        "watch.pipeline" => {
+            let steps = parse_list(value);
+            if steps.is_empty() {
+                return Err(SubXError::config(
+                    "Watch pipeline must contain at least one step",
+                ));
+            }
+            for step in steps {
+                validate_enum(&step, super::validator::WATCH_STEPS)?;
+            }
+        }
+        "watch.relocation" => {
+            validate_enum(value, &["none", "copy", "move"])?;
+        }
+        "watch.confidence" => {
+            validate_uint_range(value, 0, 100)?;
+        }
+        "watch.delete_converted" => {
+            parse_bool(value)?;
+        }
+
         _ => {
             return Err(SubXError::config(format!(
                 "Unknown configuration key: {key}"
@@ -199,6 +239,10 @@ pub fn get_field_description(key: &str) -> &'static str {
         "ai.api_key" => "API key for the AI service",
         "ai.base_url" => "Custom API endpoint URL (optional)",
         "ai.temperature" => "AI response randomness (0.0-2.0)",
+        "ai.verification_margin" => {
+            "Confidence band below the match threshold that gets a second AI check (0.0-1.0)"
+        }
+        "ai.max_prompt_tokens" => "Estimated prompt size at which match requests are split",
         "ai.max_tokens" => "Maximum tokens in AI response",
         "ai.max_sample_length" => "Maximum sample length for AI processing",
         "ai.retry_attempts" => "Number of retry attempts for AI requests",
@@ -225,6 +269,9 @@ pub fn get_field_description(key: &str) -> &'static str {
         "general.task_timeout_seconds" => "Task timeout in seconds",
         "general.enable_progress_bar" => "Enable progress bar display",
         "general.worker_idle_timeout_seconds" => "Worker idle timeout in seconds",
+        "general.naming_template" => {
+            "Subtitle naming preset (subx, plex, jellyfin, emby, kodi) or template"
+        }
 
         "parallel.max_workers" => "Maximum number of worker threads",
         "parallel.task_queue_size" => "Size of the task queue",
@@ -232,6 +279,12 @@ pub fn get_field_description(key: &str) -> &'static str {
         "parallel.auto_balance_workers" => "Enable automatic worker load balancing",
         "parallel.overflow_strategy" => "Strategy for handling queue overflow",
 
+        "watch.debounce_ms" => "Quiet period before watch mode processes new files (ms)",
+        "watch.pipeline" => "Watch mode steps (comma-separated: encoding, convert, match, sync)",
+        "watch.relocation" => "Watch mode subtitle relocation ('none', 'copy' or 'move')",
+        "watch.confidence" => "Minimum match confidence of watch mode (0-100)",
+        "watch.delete_converted" => "Delete subtitles after watch mode converted them",
+
         _ => "Configuration field",
     }
 }
@@ -248,11 +301,15 @@ mod tests {
         assert!(validate_field("ai.provider", "azure-openai").is_ok());
         assert!(validate_field("ai.temperature", "0.8").is_ok());
         assert!(validate_field("ai.max_tokens", "4000").is_ok());
+        assert!(validate_field("ai.verification_margin", "0.15").is_ok());
+        assert!(validate_field("ai.max_prompt_tokens", "8000").is_ok());
 
         // Invalid cases
         assert!(validate_field("ai.provider", "invalid").is_err());
         assert!(validate_field("ai.temperature", "3.0").is_err());
         assert!(validate_field("ai.max_tokens", "0").is_err());
+        assert!(validate_field("ai.verification_margin", "1.5").is_err());
+        assert!(validate_field("ai.max_prompt_tokens", "100").is_err());
     }
 
     #[test]
@@ -279,6 +336,19 @@ mod tests {
         assert!(validate_field("formats.preserve_styling", "maybe").is_err());
     }
 
+    #[test]
+    fn test_validate_naming_template() {
+        // Valid cases
+        assert!(validate_field("general.naming_template", "jellyfin").is_ok());
+        assert!(
+            validate_field("general.naming_template", "{video_stem}{.lang_bcp47}.{ext}").is_ok()
+        );
+
+        // Invalid cases
+        assert!(validate_field("general.naming_template", "infuse").is_err());
+        assert!(validate_field("general.naming_template", "{video_stem}.srt").is_err());
+    }
+
     #[test]
     fn test_validate_unknown_field() {
         assert!(validate_field("unknown.field", "value").is_err());
@@ -293,4 +363,22 @@ mod tests {
             "Configuration field"
         );
     }
+
+    #[test]
+    fn test_validate_watch_fields() {
+        // Valid cases
+        assert!(validate_field("watch.debounce_ms", "500").is_ok());
+        assert!(validate_field("watch.pipeline", "encoding, match").is_ok());
+        assert!(validate_field("watch.relocation", "copy").is_ok());
+        assert!(validate_field("watch.confidence", "90").is_ok());
+        assert!(validate_field("watch.delete_converted", "true").is_ok());
+
+        // Invalid cases
+        assert!(validate_field("watch.debounce_ms", "10").is_err());
+        assert!(validate_field("watch.pipeline", "match,upload").is_err());
+        assert!(validate_field("watch.pipeline", " , ").is_err());
+        assert!(validate_field("watch.relocation", "link").is_err());
+        assert!(validate_field("watch.confidence", "150").is_err());
+        assert!(validate_field("watch.delete_converted", "maybe").is_err());
+    }
 }
@@ -118,6 +118,9 @@ pub struct Config {
     pub general: GeneralConfig,
     /// Parallel processing parameters.
     pub parallel: ParallelConfig,
+    /// Watch mode pipeline settings.
+    #[serde(default)]
+    pub watch: WatchConfig,
     /// Optional file path from which the configuration was loaded.
     pub loaded_from: Option<PathBuf>,
 }
@@ -166,6 +169,24 @@ pub struct AIConfig {
     /// Azure OpenAI API version (optional, defaults to latest)
     #[serde(default)]
     pub api_version: Option<String>,
+
+    /// Width of the band below the match confidence threshold whose pairs
+    /// get a second `verify_match` pass (0.0 disables verification).
+    #[serde(default = "default_verification_margin")]
+    pub verification_margin: f32,
+
+    /// Estimated prompt size above which `match` splits its files into
+    /// several analysis requests.
+    #[serde(default = "default_max_prompt_tokens")]
+    pub max_prompt_tokens: usize,
+}
+
+fn default_verification_margin() -> f32 {
+    0.1
+}
+
+fn default_max_prompt_tokens() -> usize {
+    32_000
 }
 
 impl Default for AIConfig {
@@ -184,6 +205,8 @@ impl Default for AIConfig {
             // This is especially important for users with high-latency connections
             request_timeout_seconds: 120,
             api_version: None,
+            verification_margin: default_verification_margin(),
+            max_prompt_tokens: default_max_prompt_tokens(),
         }
     }
 }
@@ -367,6 +390,14 @@ pub struct GeneralConfig {
     pub enable_progress_bar: bool,
     /// Worker idle timeout in seconds.
     pub worker_idle_timeout_seconds: u64,
+    /// Naming preset (`subx`, `plex`, `jellyfin`, `emby`, `kodi`) or
+    /// template for matched subtitle files.
+    #[serde(default = "default_naming_template")]
+    pub naming_template: String,
+}
+
+fn default_naming_template() -> String {
+    crate::core::matcher::naming::DEFAULT_NAMING.to_string()
 }
 
 impl Default for GeneralConfig {
@@ -379,6 +410,7 @@ impl Default for GeneralConfig {
             workspace: std::path::PathBuf::from("."),
             enable_progress_bar: true,
             worker_idle_timeout_seconds: 60,
+            naming_template: default_naming_template(),
         }
     }
 }
@@ -428,6 +460,53 @@ impl Default for ParallelConfig {
     }
 }
 
+/// Watch mode configuration.
+///
+/// Controls how `subx watch` batches filesystem events and which steps it
+/// runs on new files.
+///
+/// # Examples
+///
+/// ```rust
+/// use subx_cli::config::WatchConfig;
+///
+/// let watch = WatchConfig::default();
+/// assert_eq!(watch.pipeline, ["encoding", "convert", "match", "sync"]);
+/// assert_eq!(watch.relocation, "none");
+/// assert!(!watch.delete_converted);
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WatchConfig {
+    /// Quiet period in milliseconds after the last filesystem event before
+    /// the collected files are processed.
+    pub debounce_ms: u64,
+    /// Steps run on new files, any of `encoding`, `convert`, `match` and
+    /// `sync`; they always run in that order.
+    pub pipeline: Vec<String>,
+    /// What the match step does with subtitles in another folder than their
+    /// video: `none`, `copy` or `move`.
+    pub relocation: String,
+    /// Minimum confidence (0-100) the match step requires.
+    pub confidence: u8,
+    /// Whether the convert step deletes the subtitle it converted from.
+    pub delete_converted: bool,
+}
+
+impl Default for WatchConfig {
+    fn default() -> Self {
+        Self {
+            debounce_ms: 2000,
+            pipeline: ["encoding", "convert", "match", "sync"]
+                .iter()
+                .map(|step| step.to_string())
+                .collect(),
+            relocation: "none".to_string(),
+            confidence: 80,
+            delete_converted: false,
+        }
+    }
+}
+
 /// Strategy for handling overflow when all workers are busy.
 ///
 /// This enum defines different strategies for handling situations where
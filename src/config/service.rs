@@ -356,6 +356,14 @@ impl ProductionConfigService {
                 let v = value.parse().unwrap(); // Validation already done
                 config.ai.temperature = v;
             }
+            ["ai", "verification_margin"] => {
+                let v = value.parse().unwrap(); // Validation already done
+                config.ai.verification_margin = v;
+            }
+            ["ai", "max_prompt_tokens"] => {
+                let v = value.parse().unwrap(); // Validation already done
+                config.ai.max_prompt_tokens = v;
+            }
             ["ai", "max_tokens"] => {
                 let v = value.parse().unwrap(); // Validation already done
                 config.ai.max_tokens = v;
@@ -436,6 +444,9 @@ impl ProductionConfigService {
                 let v = value.parse().unwrap(); // Validation already done
                 config.general.worker_idle_timeout_seconds = v;
             }
+            ["general", "naming_template"] => {
+                config.general.naming_template = value.to_string();
+            }
             ["parallel", "max_workers"] => {
                 let v = value.parse().unwrap(); // Validation already done
                 config.parallel.max_workers = v;
@@ -460,6 +471,24 @@ impl ProductionConfigService {
                     _ => unreachable!(), // Validation already done
                 };
             }
+            ["watch", "debounce_ms"] => {
+                let v = value.parse().unwrap(); // Validation already done
+                config.watch.debounce_ms = v;
+            }
+            ["watch", "pipeline"] => {
+                config.watch.pipeline = parse_list(value);
+            }
+            ["watch", "relocation"] => {
+                config.watch.relocation = value.to_string();
+            }
+            ["watch", "confidence"] => {
+                let v = value.parse().unwrap(); // Validation already done
+                config.watch.confidence = v;
+            }
+            ["watch", "delete_converted"] => {
+                let v = parse_bool(value)?;
+                config.watch.delete_converted = v;
+            }
             _ => {
                 return Err(SubXError::config(format!(
                     "Unknown configuration key: {key}"
@@ -577,7 +606,9 @@ impl ConfigService for ProductionConfigService {
             ["ai", "base_url"] => Ok(config.ai.base_url.clone()),
             ["ai", "max_sample_length"] => Ok(config.ai.max_sample_length.to_string()),
             ["ai", "temperature"] => Ok(config.ai.temperature.to_string()),
+            ["ai", "verification_margin"] => Ok(config.ai.verification_margin.to_string()),
             ["ai", "max_tokens"] => Ok(config.ai.max_tokens.to_string()),
+            ["ai", "max_prompt_tokens"] => Ok(config.ai.max_prompt_tokens.to_string()),
             ["ai", "retry_attempts"] => Ok(config.ai.retry_attempts.to_string()),
             ["ai", "retry_delay_ms"] => Ok(config.ai.retry_delay_ms.to_string()),
             ["ai", "request_timeout_seconds"] => Ok(config.ai.request_timeout_seconds.to_string()),
@@ -611,6 +642,7 @@ impl ConfigService for ProductionConfigService {
             ["general", "worker_idle_timeout_seconds"] => {
                 Ok(config.general.worker_idle_timeout_seconds.to_string())
             }
+            ["general", "naming_template"] => Ok(config.general.naming_template.clone()),
 
             ["parallel", "max_workers"] => Ok(config.parallel.max_workers.to_string()),
             ["parallel", "task_queue_size"] => Ok(config.parallel.task_queue_size.to_string()),
@@ -624,6 +656,12 @@ impl ConfigService for ProductionConfigService {
                 Ok(format!("{:?}", config.parallel.overflow_strategy))
             }
 
+            ["watch", "debounce_ms"] => Ok(config.watch.debounce_ms.to_string()),
+            ["watch", "pipeline"] => Ok(config.watch.pipeline.join(",")),
+            ["watch", "relocation"] => Ok(config.watch.relocation.clone()),
+            ["watch", "confidence"] => Ok(config.watch.confidence.to_string()),
+            ["watch", "delete_converted"] => Ok(config.watch.delete_converted.to_string()),
+
             _ => Err(SubXError::config(format!(
                 "Unknown configuration key: {}",
                 key
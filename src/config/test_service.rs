@@ -167,8 +167,10 @@ impl ConfigService for TestConfigService {
             ["ai", "api_key"] => Ok(config.ai.api_key.clone().unwrap_or_default()),
             ["ai", "base_url"] => Ok(config.ai.base_url.clone()),
             ["ai", "temperature"] => Ok(config.ai.temperature.to_string()),
+            ["ai", "verification_margin"] => Ok(config.ai.verification_margin.to_string()),
             ["ai", "max_sample_length"] => Ok(config.ai.max_sample_length.to_string()),
             ["ai", "max_tokens"] => Ok(config.ai.max_tokens.to_string()),
+            ["ai", "max_prompt_tokens"] => Ok(config.ai.max_prompt_tokens.to_string()),
             ["ai", "retry_attempts"] => Ok(config.ai.retry_attempts.to_string()),
             ["ai", "retry_delay_ms"] => Ok(config.ai.retry_delay_ms.to_string()),
             ["ai", "request_timeout_seconds"] => Ok(config.ai.request_timeout_seconds.to_string()),
@@ -196,6 +198,7 @@ impl ConfigService for TestConfigService {
             ["general", "worker_idle_timeout_seconds"] => {
                 Ok(config.general.worker_idle_timeout_seconds.to_string())
             }
+            ["general", "naming_template"] => Ok(config.general.naming_template.clone()),
             ["general", "max_concurrent_jobs"] => {
                 Ok(config.general.max_concurrent_jobs.to_string())
             }
@@ -210,6 +213,11 @@ impl ConfigService for TestConfigService {
             ["parallel", "overflow_strategy"] => {
                 Ok(format!("{:?}", config.parallel.overflow_strategy))
             }
+            ["watch", "debounce_ms"] => Ok(config.watch.debounce_ms.to_string()),
+            ["watch", "pipeline"] => Ok(config.watch.pipeline.join(",")),
+            ["watch", "relocation"] => Ok(config.watch.relocation.clone()),
+            ["watch", "confidence"] => Ok(config.watch.confidence.to_string()),
+            ["watch", "delete_converted"] => Ok(config.watch.delete_converted.to_string()),
             _ => Err(SubXError::config(format!(
                 "Unknown configuration key: {key}"
             ))),
@@ -271,6 +279,14 @@ impl TestConfigService {
                 let v = validate_float_range(value, 0.0, 1.0)?;
                 config.ai.temperature = v;
             }
+            ["ai", "verification_margin"] => {
+                let v = validate_float_range(value, 0.0, 1.0)?;
+                config.ai.verification_margin = v;
+            }
+            ["ai", "max_prompt_tokens"] => {
+                let v = validate_usize_range(value, 1_000, 1_000_000)?;
+                config.ai.max_prompt_tokens = v;
+            }
             ["ai", "max_tokens"] => {
                 let v = validate_uint_range(value, 1, 100_000)?;
                 config.ai.max_tokens = v;
@@ -375,6 +391,10 @@ impl TestConfigService {
                 let v = validate_u64_range(value, 10, 3600)?;
                 config.general.worker_idle_timeout_seconds = v;
             }
+            ["general", "naming_template"] => {
+                value.parse::<crate::core::matcher::NamingTemplate>()?;
+                config.general.naming_template = value.to_string();
+            }
             ["parallel", "max_workers"] => {
                 let v = validate_usize_range(value, 1, 64)?;
                 config.parallel.max_workers = v;
@@ -400,6 +420,29 @@ impl TestConfigService {
                     _ => unreachable!(),
                 };
             }
+            ["watch", "debounce_ms"] => {
+                let v = validate_u64_range(value, 100, 600_000)?;
+                config.watch.debounce_ms = v;
+            }
+            ["watch", "pipeline"] => {
+                let steps = parse_list(value);
+                for step in &steps {
+                    validate_enum(step, crate::config::validator::WATCH_STEPS)?;
+                }
+                config.watch.pipeline = steps;
+            }
+            ["watch", "relocation"] => {
+                validate_enum(value, &["none", "copy", "move"])?;
+                config.watch.relocation = value.to_string();
+            }
+            ["watch", "confidence"] => {
+                let v = validate_uint_range(value, 0, 100)?;
+                config.watch.confidence = v as u8;
+            }
+            ["watch", "delete_converted"] => {
+                let v = parse_bool(value)?;
+                config.watch.delete_converted = v;
+            }
             _ => {
                 return Err(SubXError::config(format!(
                     "Unknown configuration key: {key}"
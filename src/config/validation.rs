@@ -111,6 +111,16 @@ pub fn parse_bool(value: &str) -> SubXResult<bool> {
     }
 }
 
+/// Parse a comma-separated list, trimming items and skipping empty ones.
+pub fn parse_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
 /// Validate that a string is a valid URL.
 ///
 /// # Arguments
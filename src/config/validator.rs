@@ -14,7 +14,7 @@ use super::validation::*;
 use crate::Result;
 use crate::config::Config;
 use crate::config::{
-    AIConfig, FormatsConfig, GeneralConfig, ParallelConfig, SyncConfig, VadConfig,
+    AIConfig, FormatsConfig, GeneralConfig, ParallelConfig, SyncConfig, VadConfig, WatchConfig,
 };
 use crate::error::SubXError;
 
@@ -34,6 +34,7 @@ pub fn validate_config(config: &Config) -> Result<()> {
     validate_general_config(&config.general)?;
     validate_formats_config(&config.formats)?;
     validate_parallel_config(&config.parallel)?;
+    validate_watch_config(&config.watch)?;
 
     // Cross-section validation
     validate_config_consistency(config)?;
@@ -108,9 +109,12 @@ pub fn validate_ai_config(ai_config: &AIConfig) -> Result<()> {
                 validate_url_format(&ai_config.base_url)?;
             }
         }
+        "local" => {
+            // Matching runs offline; no credentials or model settings apply
+        }
         _ => {
             return Err(SubXError::config(format!(
-                "Unsupported AI provider: {}. Supported providers: openai, openrouter, anthropic, azure-openai",
+                "Unsupported AI provider: {}. Supported providers: openai, openrouter, anthropic, azure-openai, local",
                 ai_config.provider
             )));
         }
@@ -126,6 +130,12 @@ pub fn validate_ai_config(ai_config: &AIConfig) -> Result<()> {
     validate_range(ai_config.request_timeout_seconds as f64, 10.0, 600.0)
         .map_err(|_| SubXError::config("Request timeout must be between 10 and 600 seconds"))?;
 
+    validate_range(ai_config.verification_margin, 0.0, 1.0)
+        .map_err(|_| SubXError::config("Verification margin must be between 0.0 and 1.0"))?;
+
+    validate_range(ai_config.max_prompt_tokens, 1_000, 1_000_000)
+        .map_err(|_| SubXError::config("Max prompt tokens must be between 1000 and 1000000"))?;
+
     Ok(())
 }
 
@@ -156,6 +166,11 @@ pub fn validate_general_config(general_config: &GeneralConfig) -> Result<()> {
     )
     .map_err(|_| SubXError::config("Worker idle timeout must be between 10 and 3600 seconds"))?;
 
+    // Validate naming preset or template
+    general_config
+        .naming_template
+        .parse::<crate::core::matcher::NamingTemplate>()?;
+
     Ok(())
 }
 
@@ -200,6 +215,31 @@ pub fn validate_parallel_config(parallel_config: &ParallelConfig) -> Result<()>
     Ok(())
 }
 
+/// Validate watch mode configuration.
+pub fn validate_watch_config(watch_config: &WatchConfig) -> Result<()> {
+    validate_range(watch_config.debounce_ms, 100, 600_000)
+        .map_err(|_| SubXError::config("Watch debounce must be between 100 and 600000 ms"))?;
+
+    if watch_config.pipeline.is_empty() {
+        return Err(SubXError::config(
+            "Watch pipeline must contain at least one step",
+        ));
+    }
+    for step in &watch_config.pipeline {
+        validate_enum(step, WATCH_STEPS)?;
+    }
+
+    validate_enum(&watch_config.relocation, &["none", "copy", "move"])?;
+
+    validate_range(watch_config.confidence, 0, 100)
+        .map_err(|_| SubXError::config("Watch confidence must be between 0 and 100"))?;
+
+    Ok(())
+}
+
+/// Steps a watch pipeline can contain, in the order they run.
+pub const WATCH_STEPS: &[&str] = &["encoding", "convert", "match", "sync"];
+
 /// Validate configuration consistency across sections.
 fn validate_config_consistency(config: &Config) -> Result<()> {
     // Example: Ensure AI is properly configured if using AI features
@@ -344,6 +384,13 @@ mod tests {
         ai_config.model = "dep123".to_string();
         ai_config.api_version = Some("2025-04-01-preview".to_string());
         assert!(validate_ai_config(&ai_config).is_ok());
+
+        // local provider needs no credentials
+        let ai_config = AIConfig {
+            provider: "local".to_string(),
+            ..AIConfig::default()
+        };
+        assert!(validate_ai_config(&ai_config).is_ok());
     }
 
     #[test]
@@ -352,7 +399,7 @@ mod tests {
         ai_config.provider = "invalid".to_string();
         let err = validate_ai_config(&ai_config).unwrap_err();
         assert!(err.to_string().contains(
-            "Unsupported AI provider: invalid. Supported providers: openai, openrouter, anthropic, azure-openai"
+            "Unsupported AI provider: invalid. Supported providers: openai, openrouter, anthropic, azure-openai, local"
         ));
     }
 
@@ -0,0 +1,411 @@
+//! Offline Traditional ↔ Simplified Chinese text conversion.
+//!
+//! This module provides [`ChineseConverter`], a dictionary-based converter in
+//! the style of OpenCC. Text is segmented by forward maximum matching against
+//! a phrase dictionary, and remaining characters fall back to a character
+//! table. Phrases pick the right traditional form for one-to-many characters
+//! (`头发` → `頭髮` but `发现` → `發現`).
+//!
+//! # Dictionaries
+//!
+//! The dictionaries are embedded at compile time from plain-text files next
+//! to this module:
+//!
+//! - `st_characters.txt`: Simplified → Traditional (Taiwan standard) characters
+//! - `st_phrases.txt`: Simplified → Traditional phrases
+//! - `tw_phrases.txt`: Mainland → Taiwan vocabulary (`软件` → `軟體`)
+//!
+//! Traditional → Simplified conversion uses the same files in reverse.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use subx_cli::core::chinese::{ChineseConverter, ChineseConversion};
+//!
+//! let converter = ChineseConverter::new(ChineseConversion::SimplifiedToTraditional, true);
+//! assert_eq!(converter.convert("我的头发"), "我的頭髮");
+//! assert_eq!(converter.convert("打开软件"), "打開軟體");
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use once_cell::sync::Lazy;
+
+use crate::core::formats::Subtitle;
+
+const ST_CHARACTERS: &str = include_str!("st_characters.txt");
+const ST_PHRASES: &str = include_str!("st_phrases.txt");
+const TW_PHRASES: &str = include_str!("tw_phrases.txt");
+
+/// Characters that occur only in Simplified and only in Traditional text.
+static SCRIPT_CHARACTERS: Lazy<(HashSet<char>, HashSet<char>)> = Lazy::new(|| {
+    let entries = parse_dictionary(ST_CHARACTERS);
+    let simplified: HashSet<char> = entries.iter().filter_map(|(s, _)| single_char(s)).collect();
+    let traditional: HashSet<char> = entries
+        .iter()
+        .flat_map(|(_, t)| t.iter().filter_map(|t| single_char(t)))
+        .collect();
+    (
+        simplified.difference(&traditional).copied().collect(),
+        traditional.difference(&simplified).copied().collect(),
+    )
+});
+
+/// Direction of a Chinese script conversion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChineseConversion {
+    /// Simplified Chinese to Traditional Chinese
+    SimplifiedToTraditional,
+    /// Traditional Chinese to Simplified Chinese
+    TraditionalToSimplified,
+}
+
+impl ChineseConversion {
+    /// Language code of the converted text, as used by
+    /// [`LanguageDetector`](crate::core::language::LanguageDetector).
+    pub fn target_language(&self) -> &'static str {
+        match self {
+            Self::SimplifiedToTraditional => "tc",
+            Self::TraditionalToSimplified => "sc",
+        }
+    }
+}
+
+/// Dictionary-based converter between Simplified and Traditional Chinese.
+#[derive(Debug, Clone)]
+pub struct ChineseConverter {
+    conversion: ChineseConversion,
+    characters: HashMap<char, char>,
+    phrases: HashMap<String, String>,
+    max_phrase_len: usize,
+}
+
+impl ChineseConverter {
+    /// Build a converter for `conversion`.
+    ///
+    /// With `taiwan_phrases`, Mainland vocabulary is additionally replaced by
+    /// the Taiwanese equivalent (or the reverse for Traditional → Simplified).
+    pub fn new(conversion: ChineseConversion, taiwan_phrases: bool) -> Self {
+        let mut phrase_entries = parse_dictionary(ST_PHRASES);
+        if taiwan_phrases {
+            phrase_entries.extend(parse_dictionary(TW_PHRASES));
+        }
+        let character_entries = parse_dictionary(ST_CHARACTERS);
+
+        let mut characters = HashMap::new();
+        let mut phrases = HashMap::new();
+        match conversion {
+            ChineseConversion::SimplifiedToTraditional => {
+                for (simplified, traditional) in character_entries {
+                    if let (Some(s), Some(t)) =
+                        (single_char(&simplified), single_char(traditional[0]))
+                    {
+                        characters.insert(s, t);
+                    }
+                }
+                for (simplified, traditional) in phrase_entries {
+                    phrases.insert(simplified, traditional[0].to_string());
+                }
+            }
+            ChineseConversion::TraditionalToSimplified => {
+                // Defaults first so that they win over alternative forms
+                for (simplified, traditional) in &character_entries {
+                    if let (Some(s), Some(t)) =
+                        (single_char(simplified), single_char(traditional[0]))
+                    {
+                        characters.entry(t).or_insert(s);
+                    }
+                }
+                for (simplified, traditional) in &character_entries {
+                    let Some(s) = single_char(simplified) else {
+                        continue;
+                    };
+                    for t in traditional.iter().skip(1).filter_map(|t| single_char(t)) {
+                        characters.entry(t).or_insert(s);
+                    }
+                }
+                // Later entries (Taiwan vocabulary) override generic phrases
+                for (simplified, traditional) in phrase_entries {
+                    phrases.insert(traditional[0].to_string(), simplified);
+                }
+            }
+        }
+        let max_phrase_len = phrases.keys().map(|k| k.chars().count()).max().unwrap_or(1);
+        Self {
+            conversion,
+            characters,
+            phrases,
+            max_phrase_len,
+        }
+    }
+
+    /// The conversion direction of this converter.
+    pub fn conversion(&self) -> ChineseConversion {
+        self.conversion
+    }
+
+    /// Convert a piece of text.
+    ///
+    /// Longest phrase matches take precedence; other characters are mapped
+    /// individually and characters without an entry are kept unchanged.
+    pub fn convert(&self, text: &str) -> String {
+        let chars: Vec<char> = text.chars().collect();
+        let mut output = String::with_capacity(text.len());
+        let mut i = 0;
+        'outer: while i < chars.len() {
+            let longest = self.max_phrase_len.min(chars.len() - i);
+            for len in (2..=longest).rev() {
+                let candidate: String = chars[i..i + len].iter().collect();
+                if let Some(replacement) = self.phrases.get(&candidate) {
+                    output.push_str(replacement);
+                    i += len;
+                    continue 'outer;
+                }
+            }
+            output.push(*self.characters.get(&chars[i]).unwrap_or(&chars[i]));
+            i += 1;
+        }
+        output
+    }
+
+    /// Convert the text of every subtitle entry in place.
+    ///
+    /// Returns the number of entries whose text changed.
+    pub fn convert_subtitle(&self, subtitle: &mut Subtitle) -> usize {
+        let mut changed = 0;
+        for entry in &mut subtitle.entries {
+            let converted = self.convert(&entry.text);
+            if converted != entry.text {
+                entry.text = converted;
+                changed += 1;
+            }
+        }
+        changed
+    }
+}
+
+/// Replace the Chinese language tag in a subtitle file name.
+///
+/// Tags such as `.sc.`, `_chs.` or `-zh-hant.` are swapped for their
+/// counterpart in the `target` script (`tc` or `sc`). When the file name has
+/// no Chinese tag, `.<target>` is inserted before the extension.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::path::{Path, PathBuf};
+/// use subx_cli::core::chinese::retag_language;
+///
+/// assert_eq!(retag_language(Path::new("movie.sc.srt"), "tc"), PathBuf::from("movie.tc.srt"));
+/// assert_eq!(retag_language(Path::new("movie.srt"), "tc"), PathBuf::from("movie.tc.srt"));
+/// ```
+pub fn retag_language(path: &Path, target: &str) -> PathBuf {
+    // Pairs of (simplified tag, traditional tag)
+    const TAGS: &[(&str, &str)] = &[
+        ("sc", "tc"),
+        ("chs", "cht"),
+        ("zh-hans", "zh-hant"),
+        ("zh-cn", "zh-tw"),
+        ("简中", "繁中"),
+        ("简体", "繁體"),
+    ];
+    let to_traditional = target == "tc";
+    let stem = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or_default();
+    let ext = path.extension().and_then(|e| e.to_str());
+
+    let new_stem = TAGS
+        .iter()
+        .flat_map(|pair| [(pair.0, pair), (pair.1, pair)])
+        .flat_map(|(tag, pair)| ['.', '_', '-'].map(|sep| (sep, tag, pair)))
+        .find_map(|(sep, tag, pair)| {
+            // Compare the stem's own trailing characters so the slice below
+            // indexes the same string that was matched
+            let start = stem.char_indices().rev().nth(tag.chars().count())?.0;
+            (stem[start..].to_lowercase() == format!("{}{}", sep, tag))
+                .then_some((start, sep, pair))
+        })
+        .map(|(start, sep, (simplified, traditional))| {
+            let replacement = if to_traditional {
+                traditional
+            } else {
+                simplified
+            };
+            format!("{}{}{}", &stem[..start], sep, replacement)
+        });
+    let new_stem = new_stem.unwrap_or_else(|| format!("{}.{}", stem, target));
+    let file_name = match ext {
+        Some(ext) => format!("{}.{}", new_stem, ext),
+        None => new_stem,
+    };
+    path.with_file_name(file_name)
+}
+
+/// Characters of a text that belong to only one Chinese script.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScriptCounts {
+    /// Characters written only in Simplified Chinese (`这`, `发`)
+    pub simplified: usize,
+    /// Characters written only in Traditional Chinese (`這`, `發`)
+    pub traditional: usize,
+}
+
+/// Count the characters of `text` that exist in only one of the two scripts.
+///
+/// Characters shared by both scripts (`的`, `我`) are not counted.
+///
+/// # Examples
+///
+/// ```rust
+/// use subx_cli::core::chinese::count_script_characters;
+///
+/// let counts = count_script_characters("这是我的頭髮");
+/// assert_eq!((counts.simplified, counts.traditional), (1, 2));
+/// ```
+pub fn count_script_characters(text: &str) -> ScriptCounts {
+    let (simplified, traditional) = &*SCRIPT_CHARACTERS;
+    let mut counts = ScriptCounts::default();
+    for c in text.chars() {
+        if simplified.contains(&c) {
+            counts.simplified += 1;
+        } else if traditional.contains(&c) {
+            counts.traditional += 1;
+        }
+    }
+    counts
+}
+
+/// Parse an OpenCC-style dictionary: `key<TAB>value [alternatives...]`.
+fn parse_dictionary(content: &str) -> Vec<(String, Vec<&str>)> {
+    content
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let (key, values) = line.split_once('\t')?;
+            let values: Vec<&str> = values.split(' ').filter(|v| !v.is_empty()).collect();
+            (!values.is_empty()).then(|| (key.to_string(), values))
+        })
+        .collect()
+}
+
+fn single_char(s: &str) -> Option<char> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::formats::{SubtitleEntry, SubtitleFormatType, SubtitleMetadata};
+    use std::time::Duration;
+
+    fn s2t() -> ChineseConverter {
+        ChineseConverter::new(ChineseConversion::SimplifiedToTraditional, false)
+    }
+
+    fn t2s() -> ChineseConverter {
+        ChineseConverter::new(ChineseConversion::TraditionalToSimplified, false)
+    }
+
+    #[test]
+    fn test_simplified_to_traditional_characters() {
+        assert_eq!(s2t().convert("这是一个简单的测试"), "這是一個簡單的測試");
+        assert_eq!(s2t().convert("Hello, 世界!"), "Hello, 世界!");
+    }
+
+    #[test]
+    fn test_phrases_resolve_one_to_many_characters() {
+        let c = s2t();
+        assert_eq!(c.convert("我发现你的头发很长"), "我發現你的頭髮很長");
+        assert_eq!(c.convert("以后再说"), "以後再說");
+        assert_eq!(c.convert("皇后来了"), "皇后來了");
+        assert_eq!(c.convert("吃一碗面条"), "吃一碗麵條");
+        assert_eq!(c.convert("把衣服晒干"), "把衣服曬乾");
+        assert_eq!(c.convert("没关系"), "沒關係");
+    }
+
+    #[test]
+    fn test_taiwan_phrases() {
+        let plain = s2t();
+        let taiwan = ChineseConverter::new(ChineseConversion::SimplifiedToTraditional, true);
+        assert_eq!(plain.convert("这个软件的信息"), "這個軟件的信息");
+        assert_eq!(taiwan.convert("这个软件的信息"), "這個軟體的資訊");
+        assert_eq!(taiwan.convert("坐出租车"), "坐計程車");
+    }
+
+    #[test]
+    fn test_traditional_to_simplified() {
+        let c = t2s();
+        assert_eq!(c.convert("我發現你的頭髮很長"), "我发现你的头发很长");
+        assert_eq!(c.convert("這本著名的小說"), "这本著名的小说");
+        assert_eq!(c.convert("他睡著了"), "他睡着了");
+        let taiwan = ChineseConverter::new(ChineseConversion::TraditionalToSimplified, true);
+        assert_eq!(taiwan.convert("下載軟體"), "下载软件");
+    }
+
+    #[test]
+    fn test_convert_subtitle_counts_changed_entries() {
+        let mut subtitle = Subtitle::new(
+            SubtitleFormatType::Srt,
+            SubtitleMetadata::new(SubtitleFormatType::Srt),
+        );
+        subtitle.entries = vec![
+            SubtitleEntry::new(1, Duration::ZERO, Duration::from_secs(1), "谢谢".into()),
+            SubtitleEntry::new(
+                2,
+                Duration::from_secs(2),
+                Duration::from_secs(3),
+                "OK".into(),
+            ),
+        ];
+        assert_eq!(s2t().convert_subtitle(&mut subtitle), 1);
+        assert_eq!(subtitle.entries[0].text, "謝謝");
+    }
+
+    #[test]
+    fn test_retag_language() {
+        assert_eq!(
+            retag_language(Path::new("/m/movie.sc.srt"), "tc"),
+            PathBuf::from("/m/movie.tc.srt")
+        );
+        assert_eq!(
+            retag_language(Path::new("/m/movie_CHT.ass"), "sc"),
+            PathBuf::from("/m/movie_chs.ass")
+        );
+        assert_eq!(
+            retag_language(Path::new("/m/movie.zh-hans.vtt"), "tc"),
+            PathBuf::from("/m/movie.zh-hant.vtt")
+        );
+        assert_eq!(
+            retag_language(Path::new("/m/movie.简中.srt"), "tc"),
+            PathBuf::from("/m/movie.繁中.srt")
+        );
+        assert_eq!(
+            retag_language(Path::new("/m/movie.en.srt"), "tc"),
+            PathBuf::from("/m/movie.en.tc.srt")
+        );
+        assert_eq!(
+            retag_language(Path::new("/m/İSTANBUL.CHS.srt"), "tc"),
+            PathBuf::from("/m/İSTANBUL.cht.srt")
+        );
+    }
+
+    #[test]
+    fn test_dictionaries_are_well_formed() {
+        for content in [ST_CHARACTERS, ST_PHRASES, TW_PHRASES] {
+            for line in content.lines().filter(|l| !l.starts_with('#')) {
+                assert!(line.contains('\t'), "malformed line: {line}");
+            }
+        }
+        for (key, values) in parse_dictionary(ST_CHARACTERS) {
+            assert!(single_char(&key).is_some(), "{key}");
+            assert!(values.iter().all(|v| single_char(v).is_some()), "{key}");
+        }
+    }
+}
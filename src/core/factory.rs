@@ -76,8 +76,13 @@ impl ComponentFactory {
             relocation_mode: crate::core::matcher::engine::FileRelocationMode::None,
             conflict_resolution: crate::core::matcher::engine::ConflictResolution::AutoRename,
             ai_model: self.config.ai.model.clone(),
+            output_encoding: None,
+            naming: self.config.general.naming_template.parse()?,
+            verification_margin: self.config.ai.verification_margin,
         };
-        Ok(MatchEngine::new(ai_provider, match_config))
+        Ok(MatchEngine::new(ai_provider, match_config)
+            .with_chunking(self.config.ai.max_prompt_tokens)
+            .with_scheduler_config(&self.config))
     }
 
     /// Create a file manager with general configuration.
@@ -204,8 +209,9 @@ pub fn create_ai_provider(ai_config: &crate::config::AIConfig) -> Result<Box<dyn
                 crate::services::ai::azure_openai::AzureOpenAIClient::from_config(ai_config)?;
             Ok(Box::new(client))
         }
+        "local" => Ok(Box::new(crate::services::ai::LocalProvider::new())),
         other => Err(SubXError::config(format!(
-            "Unsupported AI provider: {}. Supported providers: openai, openrouter, anthropic, azure-openai",
+            "Unsupported AI provider: {}. Supported providers: openai, openrouter, anthropic, azure-openai, local",
             other
         ))),
     }
@@ -346,4 +352,13 @@ mod tests {
         let result = create_ai_provider(&config.ai);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_create_ai_provider_local_needs_no_api_key() {
+        let mut config = crate::config::Config::default();
+        config.ai.provider = "local".to_string();
+        config.ai.api_key = None;
+        let result = create_ai_provider(&config.ai);
+        assert!(result.is_ok());
+    }
 }
@@ -76,6 +76,51 @@ impl Color {
     pub fn red() -> Self {
         Color { r: 255, g: 0, b: 0 }
     }
+
+    /// Formats the color as an ASS `&HAABBGGRR` value with full opacity.
+    pub fn to_ass_hex(&self) -> String {
+        format!("&H00{:02X}{:02X}{:02X}", self.b, self.g, self.r)
+    }
+}
+
+impl Default for AssStyle {
+    fn default() -> Self {
+        AssStyle {
+            name: "Default".to_string(),
+            font_name: "Arial".to_string(),
+            font_size: 20,
+            primary_color: Color::white(),
+            secondary_color: Color::red(),
+            outline_color: Color::black(),
+            shadow_color: Color::black(),
+            bold: false,
+            italic: false,
+            underline: false,
+            alignment: 2,
+        }
+    }
+}
+
+impl AssStyle {
+    /// Renders the style as a `Style:` line matching the V4+ `Format:` header
+    /// emitted by [`AssFormat`].
+    pub fn to_style_line(&self) -> String {
+        let flag = |on: bool| if on { -1 } else { 0 };
+        format!(
+            "Style: {},{},{},{},{},{},{},{},{},{},0,100,100,0,0,1,2,0,{},10,10,10,1",
+            self.name,
+            self.font_name,
+            self.font_size,
+            self.primary_color.to_ass_hex(),
+            self.secondary_color.to_ass_hex(),
+            self.outline_color.to_ass_hex(),
+            self.shadow_color.to_ass_hex(),
+            flag(self.bold),
+            flag(self.italic),
+            flag(self.underline),
+            self.alignment
+        )
+    }
 }
 
 /// Subtitle format implementation for ASS/SSA.
@@ -84,6 +129,43 @@ impl Color {
 /// for the ASS/SSA subtitle format.
 pub struct AssFormat;
 
+impl AssFormat {
+    /// Serialize events that reference explicit style definitions.
+    ///
+    /// Each event is a `(style_name, entry)` pair; the style names should
+    /// correspond to entries in `styles`, which are written to the
+    /// `[V4+ Styles]` section in order.
+    pub fn serialize_with_styles(
+        &self,
+        styles: &[AssStyle],
+        events: &[(&str, &SubtitleEntry)],
+    ) -> String {
+        let mut output = String::new();
+        output.push_str("[Script Info]\n");
+        output.push_str("; Script generated by SubX\n");
+        output.push_str("ScriptType: v4.00+\n\n");
+        output.push_str("[V4+ Styles]\n");
+        output.push_str("Format: Name,Fontname,Fontsize,PrimaryColour,SecondaryColour,OutlineColour,BackColour,Bold,Italic,Underline,StrikeOut,ScaleX,ScaleY,Spacing,Angle,BorderStyle,Outline,Shadow,Alignment,MarginL,MarginR,MarginV,Encoding\n");
+        for style in styles {
+            output.push_str(&style.to_style_line());
+            output.push('\n');
+        }
+        output.push('\n');
+        output.push_str("[Events]\n");
+        output.push_str("Format: Layer,Start,End,Style,Name,MarginL,MarginR,MarginV,Effect,Text\n");
+        for (style_name, entry) in events {
+            let text = entry.text.replace('\n', "\\N");
+            let start = format_ass_time(entry.start_time);
+            let end = format_ass_time(entry.end_time);
+            output.push_str(&format!(
+                "Dialogue: 0,{},{},{},,0000,0000,0000,,{}\n",
+                start, end, style_name, text
+            ));
+        }
+        output
+    }
+}
+
 impl SubtitleFormat for AssFormat {
     fn parse(&self, content: &str) -> Result<Subtitle> {
         let mut entries = Vec::new();
@@ -148,25 +230,13 @@ impl SubtitleFormat for AssFormat {
     }
 
     fn serialize(&self, subtitle: &Subtitle) -> Result<String> {
-        let mut output = String::new();
-        output.push_str("[Script Info]\n");
-        output.push_str("; Script generated by SubX\n");
-        output.push_str("ScriptType: v4.00+\n\n");
-        output.push_str("[V4+ Styles]\n");
-        output.push_str("Format: Name,Fontname,Fontsize,PrimaryColour,SecondaryColour,OutlineColour,BackColour,Bold,Italic,Underline,StrikeOut,ScaleX,ScaleY,Spacing,Angle,BorderStyle,Outline,Shadow,Alignment,MarginL,MarginR,MarginV,Encoding\n");
-        output.push_str("Style: Default,Arial,20,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,10,10,10,1\n\n");
-        output.push_str("[Events]\n");
-        output.push_str("Format: Layer,Start,End,Style,Name,MarginL,MarginR,MarginV,Effect,Text\n");
-        for entry in &subtitle.entries {
-            let text = entry.text.replace('\n', "\\N");
-            let start = format_ass_time(entry.start_time);
-            let end = format_ass_time(entry.end_time);
-            output.push_str(&format!(
-                "Dialogue: 0,{},{},Default,,0000,0000,0000,,{}\n",
-                start, end, text
-            ));
-        }
-        Ok(output)
+        let style = AssStyle::default();
+        let events: Vec<(&str, &SubtitleEntry)> = subtitle
+            .entries
+            .iter()
+            .map(|entry| (style.name.as_str(), entry))
+            .collect();
+        Ok(self.serialize_with_styles(std::slice::from_ref(&style), &events))
     }
 
     fn detect(&self, content: &str) -> bool {
@@ -204,6 +274,29 @@ mod tests {
         let out = fmt.serialize(&subtitle).expect("ASS serialize failed");
         assert!(out.contains("Dialogue: 0,0:00:01.00,0:00:02.50"));
         assert!(out.contains("Hello\\NASS"));
+        assert!(out.contains(
+            "Style: Default,Arial,20,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,10,10,10,1"
+        ));
+    }
+
+    #[test]
+    fn test_serialize_with_multiple_styles() {
+        let fmt = AssFormat;
+        let subtitle = fmt.parse(SAMPLE_ASS).expect("ASS parse failed");
+        let top = AssStyle {
+            name: "Top".to_string(),
+            alignment: 8,
+            bold: true,
+            ..AssStyle::default()
+        };
+        let entry = &subtitle.entries[0];
+        let out = fmt.serialize_with_styles(
+            &[top, AssStyle::default()],
+            &[("Top", entry), ("Default", entry)],
+        );
+        assert!(out.contains("Style: Top,Arial,20,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,-1,0,0,0,100,100,0,0,1,2,0,8,"));
+        assert!(out.contains("Dialogue: 0,0:00:01.00,0:00:02.50,Top,,"));
+        assert!(out.contains("Dialogue: 0,0:00:01.00,0:00:02.50,Default,,"));
     }
 }
 
@@ -0,0 +1,404 @@
+//! Bilingual subtitle merging.
+//!
+//! This module combines two subtitle tracks for the same video (for example a
+//! Chinese and an English release) into a single bilingual subtitle. Cues are
+//! paired by time overlap; cues without a counterpart are kept on their own so
+//! no dialogue is lost.
+//!
+//! The merged result can be rendered either as stacked text, suitable for SRT
+//! and VTT, or as an ASS script that places each language in its own style.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use subx_cli::core::formats::bilingual::BilingualMerger;
+//! use subx_cli::core::formats::SubtitleFormatType;
+//!
+//! let merged = BilingualMerger::new().merge(&chinese, &english);
+//! let stacked = merged.to_stacked(SubtitleFormatType::Srt);
+//! ```
+
+use std::path::Path;
+use std::time::Duration;
+
+use crate::core::formats::ass::{AssFormat, AssStyle};
+use crate::core::formats::{Subtitle, SubtitleEntry, SubtitleFormatType, SubtitleMetadata};
+use crate::core::language::LanguageDetector;
+
+/// Default minimum overlap ratio required to pair two cues.
+pub const DEFAULT_MIN_OVERLAP_RATIO: f32 = 0.5;
+
+/// A single cue of a merged bilingual subtitle.
+///
+/// At least one of `primary` and `secondary` is always present.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BilingualCue {
+    /// Start time of the cue
+    pub start_time: Duration,
+    /// End time of the cue
+    pub end_time: Duration,
+    /// Text from the primary track, if any
+    pub primary: Option<String>,
+    /// Text from the secondary track, if any
+    pub secondary: Option<String>,
+}
+
+impl BilingualCue {
+    /// Returns true when the cue carries text from both tracks.
+    pub fn is_paired(&self) -> bool {
+        self.primary.is_some() && self.secondary.is_some()
+    }
+}
+
+/// Result of merging two subtitle tracks.
+#[derive(Debug, Clone)]
+pub struct BilingualSubtitle {
+    /// Merged cues ordered by start time
+    pub cues: Vec<BilingualCue>,
+    /// Language code of the primary track (e.g., "tc", "sc", "en")
+    pub primary_language: Option<String>,
+    /// Language code of the secondary track (e.g., "tc", "sc", "en")
+    pub secondary_language: Option<String>,
+}
+
+/// Merger that pairs the cues of two subtitle tracks by time overlap.
+#[derive(Debug, Clone)]
+pub struct BilingualMerger {
+    min_overlap_ratio: f32,
+}
+
+impl Default for BilingualMerger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BilingualMerger {
+    /// Create a merger using [`DEFAULT_MIN_OVERLAP_RATIO`].
+    pub fn new() -> Self {
+        Self {
+            min_overlap_ratio: DEFAULT_MIN_OVERLAP_RATIO,
+        }
+    }
+
+    /// Set the minimum overlap ratio (0.0-1.0) required to pair two cues.
+    ///
+    /// The ratio is measured against the shorter of the two cues, so a short
+    /// cue fully contained in a longer one always has a ratio of 1.0.
+    pub fn with_min_overlap_ratio(mut self, ratio: f32) -> Self {
+        self.min_overlap_ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Merge two subtitle tracks into a bilingual subtitle.
+    ///
+    /// Each secondary cue is paired with at most one primary cue, preferring
+    /// the pair with the largest overlap. Paired cues take the primary cue's
+    /// timing; unpaired cues from either track are kept unchanged.
+    pub fn merge(&self, primary: &Subtitle, secondary: &Subtitle) -> BilingualSubtitle {
+        let mut candidates = Vec::new();
+        for (pi, p) in primary.entries.iter().enumerate() {
+            for (si, s) in secondary.entries.iter().enumerate() {
+                let ratio = overlap_ratio(p, s);
+                if ratio > 0.0 && ratio >= self.min_overlap_ratio {
+                    candidates.push((ratio, pi, si));
+                }
+            }
+        }
+        candidates.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)));
+
+        let mut primary_pair: Vec<Option<usize>> = vec![None; primary.entries.len()];
+        let mut secondary_used = vec![false; secondary.entries.len()];
+        for (_, pi, si) in candidates {
+            if primary_pair[pi].is_none() && !secondary_used[si] {
+                primary_pair[pi] = Some(si);
+                secondary_used[si] = true;
+            }
+        }
+
+        let mut cues: Vec<BilingualCue> = primary
+            .entries
+            .iter()
+            .zip(&primary_pair)
+            .map(|(p, pair)| BilingualCue {
+                start_time: p.start_time,
+                end_time: p.end_time,
+                primary: Some(p.text.clone()),
+                secondary: pair.map(|si| secondary.entries[si].text.clone()),
+            })
+            .collect();
+        cues.extend(
+            secondary
+                .entries
+                .iter()
+                .zip(&secondary_used)
+                .filter(|(_, used)| !**used)
+                .map(|(s, _)| BilingualCue {
+                    start_time: s.start_time,
+                    end_time: s.end_time,
+                    primary: None,
+                    secondary: Some(s.text.clone()),
+                }),
+        );
+        cues.sort_by_key(|cue| cue.start_time);
+
+        BilingualSubtitle {
+            cues,
+            primary_language: primary.metadata.language.clone(),
+            secondary_language: secondary.metadata.language.clone(),
+        }
+    }
+
+    /// Merge two tracks, detecting each track's language from its file path.
+    ///
+    /// Language codes follow the [`LanguageDetector`] naming conventions
+    /// (`tc`, `sc`, `en`). A language already present in a track's metadata
+    /// takes precedence over the path-based detection.
+    pub fn merge_files(
+        &self,
+        primary: &Subtitle,
+        primary_path: &Path,
+        secondary: &Subtitle,
+        secondary_path: &Path,
+    ) -> BilingualSubtitle {
+        let detector = LanguageDetector::new();
+        let mut merged = self.merge(primary, secondary);
+        if merged.primary_language.is_none() {
+            merged.primary_language = detector.get_primary_language(primary_path);
+        }
+        if merged.secondary_language.is_none() {
+            merged.secondary_language = detector.get_primary_language(secondary_path);
+        }
+        merged
+    }
+}
+
+impl BilingualSubtitle {
+    /// Number of cues that carry text from both tracks.
+    pub fn paired_count(&self) -> usize {
+        self.cues.iter().filter(|c| c.is_paired()).count()
+    }
+
+    /// Combined language tag such as `tc-en`, if both languages are known.
+    pub fn language_tag(&self) -> Option<String> {
+        match (&self.primary_language, &self.secondary_language) {
+            (Some(p), Some(s)) => Some(format!("{}-{}", p, s)),
+            _ => None,
+        }
+    }
+
+    /// Render the merged cues as a single-track subtitle with stacked text.
+    ///
+    /// Paired cues place the primary text above the secondary text, which
+    /// SRT and VTT players display as consecutive lines.
+    pub fn to_stacked(&self, format: SubtitleFormatType) -> Subtitle {
+        let mut metadata = SubtitleMetadata::new(format.clone());
+        metadata.language = self.language_tag();
+        let mut subtitle = Subtitle::new(format, metadata);
+        subtitle.entries = self
+            .cues
+            .iter()
+            .enumerate()
+            .map(|(i, cue)| {
+                let text = [cue.primary.as_deref(), cue.secondary.as_deref()]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                SubtitleEntry {
+                    index: i + 1,
+                    start_time: cue.start_time,
+                    end_time: cue.end_time,
+                    text,
+                    styling: None,
+                }
+            })
+            .collect();
+        subtitle
+    }
+
+    /// Render the merged cues as an ASS script with one style per language.
+    ///
+    /// Primary text is emitted with `top_style` and secondary text with
+    /// `bottom_style`, so each track keeps its own font and position.
+    pub fn to_ass(&self, top_style: &AssStyle, bottom_style: &AssStyle) -> String {
+        let mut lines = Vec::new();
+        for cue in &self.cues {
+            let tracks = [
+                (cue.primary.as_ref(), top_style),
+                (cue.secondary.as_ref(), bottom_style),
+            ];
+            for (text, style) in tracks {
+                if let Some(text) = text {
+                    lines.push((
+                        style.name.as_str(),
+                        SubtitleEntry {
+                            index: lines.len() + 1,
+                            start_time: cue.start_time,
+                            end_time: cue.end_time,
+                            text: text.clone(),
+                            styling: None,
+                        },
+                    ));
+                }
+            }
+        }
+        let events: Vec<(&str, &SubtitleEntry)> =
+            lines.iter().map(|(name, entry)| (*name, entry)).collect();
+        AssFormat.serialize_with_styles(&[top_style.clone(), bottom_style.clone()], &events)
+    }
+}
+
+/// Build a default ASS style for a track in the given language.
+///
+/// Chinese tracks use a CJK font so glyphs render consistently, while other
+/// languages fall back to Arial. `alignment` follows the ASS numpad layout
+/// (8 = top center, 2 = bottom center).
+pub fn default_style_for_language(name: &str, language: Option<&str>, alignment: i32) -> AssStyle {
+    let font_name = match language {
+        Some("tc") => "Microsoft JhengHei",
+        Some("sc") => "Microsoft YaHei",
+        _ => "Arial",
+    };
+    AssStyle {
+        name: name.to_string(),
+        font_name: font_name.to_string(),
+        alignment,
+        ..AssStyle::default()
+    }
+}
+
+fn overlap_ratio(a: &SubtitleEntry, b: &SubtitleEntry) -> f32 {
+    let start = a.start_time.max(b.start_time);
+    let end = a.end_time.min(b.end_time);
+    if end <= start {
+        return 0.0;
+    }
+    let shorter = a.duration().min(b.duration());
+    if shorter.is_zero() {
+        return 0.0;
+    }
+    (end - start).as_secs_f32() / shorter.as_secs_f32()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn track(cues: &[(u64, u64, &str)]) -> Subtitle {
+        let mut subtitle = Subtitle::new(
+            SubtitleFormatType::Srt,
+            SubtitleMetadata::new(SubtitleFormatType::Srt),
+        );
+        subtitle.entries = cues
+            .iter()
+            .enumerate()
+            .map(|(i, (start, end, text))| {
+                SubtitleEntry::new(
+                    i + 1,
+                    Duration::from_millis(*start),
+                    Duration::from_millis(*end),
+                    text.to_string(),
+                )
+            })
+            .collect();
+        subtitle
+    }
+
+    #[test]
+    fn test_merge_pairs_overlapping_cues() {
+        let zh = track(&[(1000, 3000, "你好"), (4000, 6000, "再見")]);
+        let en = track(&[(1100, 2900, "Hello"), (4200, 6100, "Goodbye")]);
+        let merged = BilingualMerger::new().merge(&zh, &en);
+
+        assert_eq!(merged.cues.len(), 2);
+        assert_eq!(merged.paired_count(), 2);
+        assert_eq!(merged.cues[0].primary.as_deref(), Some("你好"));
+        assert_eq!(merged.cues[0].secondary.as_deref(), Some("Hello"));
+        assert_eq!(merged.cues[1].start_time, Duration::from_millis(4000));
+    }
+
+    #[test]
+    fn test_merge_keeps_unpaired_cues_in_order() {
+        let zh = track(&[(1000, 3000, "你好")]);
+        let en = track(&[
+            (500, 900, "Intro"),
+            (1000, 3000, "Hello"),
+            (8000, 9000, "Outro"),
+        ]);
+        let merged = BilingualMerger::new().merge(&zh, &en);
+
+        assert_eq!(merged.cues.len(), 3);
+        assert_eq!(merged.paired_count(), 1);
+        assert_eq!(merged.cues[0].secondary.as_deref(), Some("Intro"));
+        assert!(merged.cues[0].primary.is_none());
+        assert_eq!(merged.cues[2].secondary.as_deref(), Some("Outro"));
+    }
+
+    #[test]
+    fn test_merge_respects_min_overlap_ratio() {
+        let zh = track(&[(1000, 3000, "你好")]);
+        let en = track(&[(2500, 4500, "Hello")]);
+        let merged = BilingualMerger::new().merge(&zh, &en);
+        assert_eq!(merged.paired_count(), 0);
+        assert_eq!(merged.cues.len(), 2);
+
+        let merged = BilingualMerger::new()
+            .with_min_overlap_ratio(0.2)
+            .merge(&zh, &en);
+        assert_eq!(merged.paired_count(), 1);
+    }
+
+    #[test]
+    fn test_secondary_cue_used_once() {
+        let zh = track(&[(1000, 2000, "一"), (2000, 3000, "二")]);
+        let en = track(&[(1000, 3000, "One two")]);
+        let merged = BilingualMerger::new().merge(&zh, &en);
+        assert_eq!(merged.paired_count(), 1);
+        assert_eq!(merged.cues.len(), 2);
+    }
+
+    #[test]
+    fn test_merge_files_detects_languages() {
+        let zh = track(&[(1000, 3000, "你好")]);
+        let en = track(&[(1000, 3000, "Hello")]);
+        let merged = BilingualMerger::new().merge_files(
+            &zh,
+            Path::new("movie.tc.srt"),
+            &en,
+            Path::new("movie.en.srt"),
+        );
+        assert_eq!(merged.primary_language.as_deref(), Some("tc"));
+        assert_eq!(merged.secondary_language.as_deref(), Some("en"));
+        assert_eq!(merged.language_tag().as_deref(), Some("tc-en"));
+    }
+
+    #[test]
+    fn test_to_stacked_joins_text() {
+        let zh = track(&[(1000, 3000, "你好")]);
+        let en = track(&[(1000, 3000, "Hello"), (5000, 6000, "Bye")]);
+        let subtitle = BilingualMerger::new()
+            .merge(&zh, &en)
+            .to_stacked(SubtitleFormatType::Vtt);
+        assert_eq!(subtitle.format, SubtitleFormatType::Vtt);
+        assert_eq!(subtitle.entries.len(), 2);
+        assert_eq!(subtitle.entries[0].text, "你好\nHello");
+        assert_eq!(subtitle.entries[1].text, "Bye");
+        assert_eq!(subtitle.entries[1].index, 2);
+    }
+
+    #[test]
+    fn test_to_ass_uses_two_styles() {
+        let zh = track(&[(1000, 3000, "你好")]);
+        let en = track(&[(1000, 3000, "Hello")]);
+        let merged = BilingualMerger::new().merge(&zh, &en);
+        let top = default_style_for_language("Top", Some("tc"), 8);
+        let bottom = default_style_for_language("Bottom", Some("en"), 2);
+        let ass = merged.to_ass(&top, &bottom);
+
+        assert!(ass.contains("Style: Top,Microsoft JhengHei,"));
+        assert!(ass.contains("Style: Bottom,Arial,"));
+        assert!(ass.contains("Dialogue: 0,0:00:01.00,0:00:03.00,Top,,0000,0000,0000,,你好"));
+        assert!(ass.contains("Dialogue: 0,0:00:01.00,0:00:03.00,Bottom,,0000,0000,0000,,Hello"));
+    }
+}
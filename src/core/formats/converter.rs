@@ -19,7 +19,9 @@ use tokio::sync::Semaphore;
 
 use crate::Result;
 use crate::core::formats::Subtitle;
+use crate::core::formats::encoding::{LineEnding, OutputEncoding, UnmappablePolicy};
 use crate::core::formats::manager::FormatManager;
+use crate::core::formats::sdh::{SdhCleaner, SdhCleanupReport};
 
 /// Subtitle format converter for handling conversion tasks.
 ///
@@ -43,10 +45,18 @@ pub struct ConversionConfig {
     pub preserve_styling: bool,
     /// Target character encoding for the output file
     pub target_encoding: String,
+    /// Whether to write a byte order mark (Unicode encodings only)
+    pub bom: bool,
+    /// Line ending style of the output file, or `None` for LF as serialized
+    pub line_ending: Option<LineEnding>,
+    /// Handling of characters the target encoding cannot represent
+    pub unmappable: UnmappablePolicy,
     /// Whether to keep the original file after conversion
     pub keep_original: bool,
     /// Whether to validate the output after conversion
     pub validate_output: bool,
+    /// Optional SDH annotation cleanup applied before conversion
+    pub sdh_cleaner: Option<SdhCleaner>,
 }
 
 /// Result of a subtitle format conversion operation.
@@ -69,6 +79,8 @@ pub struct ConversionResult {
     pub warnings: Vec<String>,
     /// Errors encountered during conversion
     pub errors: Vec<String>,
+    /// Removed SDH annotations, when SDH cleanup was enabled
+    pub sdh_report: Option<SdhCleanupReport>,
 }
 
 impl FormatConverter {
@@ -89,7 +101,13 @@ impl FormatConverter {
     ) -> crate::Result<ConversionResult> {
         // 1. Read and parse input file
         let input_content = self.read_file_with_encoding(input_path).await?;
-        let input_subtitle = self.format_manager.parse_auto(&input_content)?;
+        let mut input_subtitle = self.format_manager.parse_auto(&input_content)?;
+        let original_entries = input_subtitle.entries.len();
+        let sdh_report = self
+            .config
+            .sdh_cleaner
+            .as_ref()
+            .map(|cleaner| cleaner.clean(&mut input_subtitle));
 
         // 2. Execute format conversion
         let converted_subtitle = self.transform_subtitle(input_subtitle.clone(), target_format)?;
@@ -108,11 +126,12 @@ impl FormatConverter {
         let output_content = target_formatter.serialize(&converted_subtitle)?;
 
         // 4. Write file
-        self.write_file_with_encoding(output_path, &output_content)
+        let encoding_warnings = self
+            .write_file_with_encoding(output_path, &output_content)
             .await?;
 
         // 5. Validate conversion result
-        let result = if self.config.validate_output {
+        let mut result = if self.config.validate_output {
             self.validate_conversion(&input_subtitle, &converted_subtitle)
                 .await?
         } else {
@@ -124,8 +143,12 @@ impl FormatConverter {
                 converted_entries: converted_subtitle.entries.len(),
                 warnings: Vec::new(),
                 errors: Vec::new(),
+                sdh_report: None,
             }
         };
+        result.original_entries = original_entries;
+        result.sdh_report = sdh_report;
+        result.warnings.extend(encoding_warnings);
         Ok(result)
     }
 
@@ -187,10 +210,25 @@ impl FormatConverter {
         Ok(conversion.converted_text)
     }
 
-    /// Write file (temporarily using UTF-8 encoding)
-    async fn write_file_with_encoding(&self, path: &Path, content: &str) -> crate::Result<()> {
-        tokio::fs::write(path, content).await?;
-        Ok(())
+    /// Output encoding described by the configuration
+    pub fn output_encoding(&self) -> crate::Result<OutputEncoding> {
+        Ok(OutputEncoding::from_name(&self.config.target_encoding)?
+            .with_bom(self.config.bom)
+            .with_line_ending(self.config.line_ending)
+            .with_unmappable_policy(self.config.unmappable))
+    }
+
+    /// Write file in the target encoding, returning warnings about
+    /// replaced characters
+    async fn write_file_with_encoding(
+        &self,
+        path: &Path,
+        content: &str,
+    ) -> crate::Result<Vec<String>> {
+        let output = self.output_encoding()?;
+        let encoded = output.encode(content)?;
+        tokio::fs::write(path, &encoded.bytes).await?;
+        Ok(encoded.warnings(&output.charset))
     }
 
     /// Simple conversion quality validation
@@ -217,6 +255,7 @@ impl FormatConverter {
             converted_entries: converted.entries.len(),
             warnings: Vec::new(),
             errors,
+            sdh_report: None,
         })
     }
 }
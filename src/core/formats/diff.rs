@@ -0,0 +1,778 @@
+//! Semantic comparison of two subtitle files.
+//!
+//! A plain textual diff of two SRT files is rarely useful: a single inserted
+//! cue shifts every following index, and a global retime touches every
+//! timestamp line. [`SubtitleDiffer`] instead aligns the cues of two
+//! [`Subtitle`]s by start time and text similarity, then classifies each
+//! aligned pair as unchanged, retimed or edited. Cues without a counterpart
+//! are reported as inserted or deleted.
+//!
+//! Before aligning, a constant offset between both files is detected from
+//! cues whose text is identical, so a re-delivery that was simply shifted is
+//! reported as one offset instead of hundreds of timing changes.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use subx_cli::core::formats::diff::SubtitleDiffer;
+//!
+//! let diff = SubtitleDiffer::new().diff(&old, &new);
+//! if let Some(offset) = diff.constant_offset_ms {
+//!     println!("new file is shifted by {} ms", offset);
+//! }
+//! println!("{}", diff);
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::core::formats::{Subtitle, SubtitleEntry};
+
+/// Default timing difference below which two cues are considered in sync.
+pub const DEFAULT_TIMING_TOLERANCE: Duration = Duration::from_millis(20);
+
+/// Maximum distance between two cue starts (after offset correction) for
+/// them to be aligned with each other.
+const MAX_ALIGN_DISTANCE_MS: i64 = 10_000;
+
+/// Minimum text similarity for two cues to be aligned by text alone.
+const MIN_TEXT_SIMILARITY: f64 = 0.5;
+
+/// Minimum overlap ratio for two cues with dissimilar text to be aligned.
+const MIN_OVERLAP_RATIO: f64 = 0.5;
+
+/// A single difference between two subtitle files.
+///
+/// Indices are the 1-based cue indices of the old and new subtitle, and all
+/// times and deltas are in milliseconds. Deltas are `new - old`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum CueChange {
+    /// Same text, different timing
+    Retimed {
+        /// Index of the cue in the old subtitle
+        old_index: usize,
+        /// Index of the cue in the new subtitle
+        new_index: usize,
+        /// Start time of the cue in the new subtitle
+        start_ms: u64,
+        /// Change of the start time
+        start_delta_ms: i64,
+        /// Change of the end time
+        end_delta_ms: i64,
+        /// Cue text
+        text: String,
+    },
+    /// Edited text, possibly with different timing
+    Edited {
+        /// Index of the cue in the old subtitle
+        old_index: usize,
+        /// Index of the cue in the new subtitle
+        new_index: usize,
+        /// Start time of the cue in the new subtitle
+        start_ms: u64,
+        /// Change of the start time
+        start_delta_ms: i64,
+        /// Change of the end time
+        end_delta_ms: i64,
+        /// Text in the old subtitle
+        old_text: String,
+        /// Text in the new subtitle
+        new_text: String,
+        /// Text similarity between 0.0 and 1.0
+        similarity: f64,
+    },
+    /// Cue only present in the new subtitle
+    Inserted {
+        /// Index of the cue in the new subtitle
+        new_index: usize,
+        /// Start time of the cue
+        start_ms: u64,
+        /// End time of the cue
+        end_ms: u64,
+        /// Cue text
+        text: String,
+    },
+    /// Cue only present in the old subtitle
+    Deleted {
+        /// Index of the cue in the old subtitle
+        old_index: usize,
+        /// Start time of the cue
+        start_ms: u64,
+        /// End time of the cue
+        end_ms: u64,
+        /// Cue text
+        text: String,
+    },
+}
+
+/// Per-kind counts of a [`SubtitleDiff`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct DiffSummary {
+    /// Aligned cues with identical text and timing
+    pub unchanged: usize,
+    /// Aligned cues with identical text and different timing
+    pub retimed: usize,
+    /// Aligned cues with different text
+    pub edited: usize,
+    /// Cues only present in the new subtitle
+    pub inserted: usize,
+    /// Cues only present in the old subtitle
+    pub deleted: usize,
+}
+
+/// Result of comparing two subtitles.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SubtitleDiff {
+    /// Constant offset of the new subtitle relative to the old one, if the
+    /// majority of cues with identical text moved by the same amount
+    pub constant_offset_ms: Option<i64>,
+    /// Timing tolerance used for the comparison
+    pub tolerance_ms: u64,
+    /// Per-kind counts
+    pub summary: DiffSummary,
+    /// Every difference, in timeline order
+    pub changes: Vec<CueChange>,
+}
+
+impl SubtitleDiff {
+    /// Whether both subtitles are equivalent within the timing tolerance.
+    pub fn is_identical(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Whether a retimed cue moved exactly by the detected constant offset.
+    fn follows_offset(&self, start_delta_ms: i64, end_delta_ms: i64) -> bool {
+        let tolerance = self.tolerance_ms as i64;
+        self.constant_offset_ms.is_some_and(|offset| {
+            (start_delta_ms - offset).abs() <= tolerance
+                && (end_delta_ms - offset).abs() <= tolerance
+        })
+    }
+}
+
+impl fmt::Display for SubtitleDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(offset) = self.constant_offset_ms {
+            writeln!(f, "Constant offset: {}", format_delta(offset))?;
+        }
+        let s = &self.summary;
+        write!(
+            f,
+            "{} unchanged, {} retimed, {} edited, {} inserted, {} deleted",
+            s.unchanged, s.retimed, s.edited, s.inserted, s.deleted
+        )?;
+
+        let mut shifted = 0;
+        for change in &self.changes {
+            match change {
+                CueChange::Retimed {
+                    old_index,
+                    new_index,
+                    start_ms,
+                    start_delta_ms,
+                    end_delta_ms,
+                    ..
+                } => {
+                    if self.follows_offset(*start_delta_ms, *end_delta_ms) {
+                        shifted += 1;
+                        continue;
+                    }
+                    write!(
+                        f,
+                        "\n~ #{} -> #{} {} start {} end {}",
+                        old_index,
+                        new_index,
+                        format_timestamp(*start_ms),
+                        format_delta(*start_delta_ms),
+                        format_delta(*end_delta_ms)
+                    )?;
+                }
+                CueChange::Edited {
+                    old_index,
+                    new_index,
+                    start_ms,
+                    start_delta_ms,
+                    end_delta_ms,
+                    old_text,
+                    new_text,
+                    ..
+                } => {
+                    write!(
+                        f,
+                        "\n* #{} -> #{} {} {:?} -> {:?}",
+                        old_index,
+                        new_index,
+                        format_timestamp(*start_ms),
+                        old_text,
+                        new_text
+                    )?;
+                    if !self.follows_offset(*start_delta_ms, *end_delta_ms)
+                        && (*start_delta_ms != 0 || *end_delta_ms != 0)
+                    {
+                        write!(
+                            f,
+                            " (start {} end {})",
+                            format_delta(*start_delta_ms),
+                            format_delta(*end_delta_ms)
+                        )?;
+                    }
+                }
+                CueChange::Inserted {
+                    new_index,
+                    start_ms,
+                    text,
+                    ..
+                } => {
+                    write!(
+                        f,
+                        "\n+ #{} {} {:?}",
+                        new_index,
+                        format_timestamp(*start_ms),
+                        text
+                    )?;
+                }
+                CueChange::Deleted {
+                    old_index,
+                    start_ms,
+                    text,
+                    ..
+                } => {
+                    write!(
+                        f,
+                        "\n- #{} {} {:?}",
+                        old_index,
+                        format_timestamp(*start_ms),
+                        text
+                    )?;
+                }
+            }
+        }
+        if shifted > 0 {
+            write!(f, "\n{} retimed cues follow the constant offset", shifted)?;
+        }
+        Ok(())
+    }
+}
+
+/// Aligns and compares the cues of two subtitles.
+#[derive(Debug, Clone)]
+pub struct SubtitleDiffer {
+    tolerance: Duration,
+}
+
+impl Default for SubtitleDiffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SubtitleDiffer {
+    /// Create a differ using [`DEFAULT_TIMING_TOLERANCE`].
+    pub fn new() -> Self {
+        Self {
+            tolerance: DEFAULT_TIMING_TOLERANCE,
+        }
+    }
+
+    /// Set the timing difference below which two cues count as in sync.
+    pub fn with_tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Compare `old` against `new`.
+    ///
+    /// Cues are aligned in timeline order with a weighted sequence alignment:
+    /// two cues can only be paired if their starts lie within ten seconds of
+    /// each other (after removing the constant offset) and either their text
+    /// is similar or their time ranges mostly overlap.
+    pub fn diff(&self, old: &Subtitle, new: &Subtitle) -> SubtitleDiff {
+        let tolerance_ms = self.tolerance.as_millis() as i64;
+        let offset = detect_constant_offset(&old.entries, &new.entries, tolerance_ms);
+        let alignment = align(&old.entries, &new.entries, offset.unwrap_or(0));
+
+        let mut summary = DiffSummary::default();
+        let mut changes = Vec::new();
+        for step in alignment {
+            match step {
+                AlignStep::Pair(oi, ni, similarity) => {
+                    let (o, n) = (&old.entries[oi], &new.entries[ni]);
+                    let start_delta_ms = millis(n.start_time) - millis(o.start_time);
+                    let end_delta_ms = millis(n.end_time) - millis(o.end_time);
+                    let in_sync =
+                        start_delta_ms.abs() <= tolerance_ms && end_delta_ms.abs() <= tolerance_ms;
+                    if normalize(&o.text) != normalize(&n.text) {
+                        summary.edited += 1;
+                        changes.push(CueChange::Edited {
+                            old_index: o.index,
+                            new_index: n.index,
+                            start_ms: n.start_time.as_millis() as u64,
+                            start_delta_ms,
+                            end_delta_ms,
+                            old_text: o.text.clone(),
+                            new_text: n.text.clone(),
+                            similarity,
+                        });
+                    } else if !in_sync {
+                        summary.retimed += 1;
+                        changes.push(CueChange::Retimed {
+                            old_index: o.index,
+                            new_index: n.index,
+                            start_ms: n.start_time.as_millis() as u64,
+                            start_delta_ms,
+                            end_delta_ms,
+                            text: n.text.clone(),
+                        });
+                    } else {
+                        summary.unchanged += 1;
+                    }
+                }
+                AlignStep::Deleted(oi) => {
+                    let o = &old.entries[oi];
+                    summary.deleted += 1;
+                    changes.push(CueChange::Deleted {
+                        old_index: o.index,
+                        start_ms: o.start_time.as_millis() as u64,
+                        end_ms: o.end_time.as_millis() as u64,
+                        text: o.text.clone(),
+                    });
+                }
+                AlignStep::Inserted(ni) => {
+                    let n = &new.entries[ni];
+                    summary.inserted += 1;
+                    changes.push(CueChange::Inserted {
+                        new_index: n.index,
+                        start_ms: n.start_time.as_millis() as u64,
+                        end_ms: n.end_time.as_millis() as u64,
+                        text: n.text.clone(),
+                    });
+                }
+            }
+        }
+
+        SubtitleDiff {
+            constant_offset_ms: offset,
+            tolerance_ms: tolerance_ms as u64,
+            summary,
+            changes,
+        }
+    }
+}
+
+/// One step of a cue alignment, referring to positions in the entry slices.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum AlignStep {
+    Pair(usize, usize, f64),
+    Deleted(usize),
+    Inserted(usize),
+}
+
+/// Detect a constant offset from cues whose text occurs exactly once in
+/// both subtitles.
+///
+/// The median start delta is returned when more than half of those cues
+/// moved by it (within `tolerance_ms`) and it exceeds the tolerance.
+fn detect_constant_offset(
+    old: &[SubtitleEntry],
+    new: &[SubtitleEntry],
+    tolerance_ms: i64,
+) -> Option<i64> {
+    let unique_starts = |entries: &[SubtitleEntry]| {
+        let mut map: HashMap<String, Option<i64>> = HashMap::new();
+        for entry in entries {
+            map.entry(normalize(&entry.text))
+                .and_modify(|start| *start = None)
+                .or_insert(Some(millis(entry.start_time)));
+        }
+        map
+    };
+    let old_starts = unique_starts(old);
+    let new_starts = unique_starts(new);
+
+    let mut deltas: Vec<i64> = old_starts
+        .iter()
+        .filter_map(|(text, old_start)| {
+            let new_start = new_starts.get(text).copied().flatten()?;
+            Some(new_start - (*old_start)?)
+        })
+        .collect();
+    if deltas.is_empty() {
+        return None;
+    }
+    deltas.sort_unstable();
+    let median = deltas[deltas.len() / 2];
+    let inliers = deltas
+        .iter()
+        .filter(|d| (**d - median).abs() <= tolerance_ms)
+        .count();
+    (inliers * 2 > deltas.len() && median.abs() > tolerance_ms).then_some(median)
+}
+
+/// Scores of one alignment row, kept only for the band of `new` cues that
+/// start within [`MAX_ALIGN_DISTANCE_MS`] of the row's `old` cue.
+///
+/// Outside the band no pairing is possible, so a cell left of it equals the
+/// cell above and a cell right of it is the larger of the band's last score
+/// and the cell above. That keeps memory proportional to the band width
+/// instead of the full `n × m` table.
+#[derive(Debug, Default)]
+struct BandRow {
+    /// First column (1-based `new` position) covered by the band
+    start: usize,
+    scores: Vec<f64>,
+    /// Pair similarity where the best move was a pairing
+    pairs: Vec<Option<f64>>,
+}
+
+impl BandRow {
+    fn get(&self, j: usize) -> Option<usize> {
+        (j >= self.start && j < self.start + self.scores.len()).then(|| j - self.start)
+    }
+}
+
+/// Best total score of aligning `old[..i]` with `new[..j]`.
+fn band_score(rows: &[BandRow], mut i: usize, j: usize) -> f64 {
+    let mut best = 0.0f64;
+    while i > 0 && j > 0 {
+        let row = &rows[i - 1];
+        if let Some(k) = row.get(j) {
+            return best.max(row.scores[k]);
+        }
+        if j >= row.start {
+            if let Some(last) = row.scores.last() {
+                best = best.max(*last);
+            }
+        }
+        i -= 1;
+    }
+    best
+}
+
+/// Positions of `entries` stable-sorted by start time.
+fn timeline_order(entries: &[SubtitleEntry]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..entries.len()).collect();
+    order.sort_by_key(|&i| entries[i].start_time);
+    order
+}
+
+/// Align two cue sequences, maximizing the total pair score.
+///
+/// Cues are aligned in timeline order, whatever the order of the slices;
+/// only `new` cues starting within the alignment window of an `old` cue are
+/// considered for pairing. The steps refer to positions in the slices.
+fn align(old: &[SubtitleEntry], new: &[SubtitleEntry], offset_ms: i64) -> Vec<AlignStep> {
+    let (old_order, new_order) = (timeline_order(old), timeline_order(new));
+    let old: Vec<&SubtitleEntry> = old_order.iter().map(|&i| &old[i]).collect();
+    let new: Vec<&SubtitleEntry> = new_order.iter().map(|&j| &new[j]).collect();
+    let (n, m) = (old.len(), new.len());
+    let old_plain: Vec<String> = old.iter().map(|e| e.plain_text().to_lowercase()).collect();
+    let new_plain: Vec<String> = new.iter().map(|e| e.plain_text().to_lowercase()).collect();
+    let new_starts: Vec<i64> = new.iter().map(|e| millis(e.start_time)).collect();
+
+    let mut rows: Vec<BandRow> = Vec::with_capacity(n);
+    for i in 1..=n {
+        let old_start = millis(old[i - 1].start_time) + offset_ms;
+        let lo = new_starts.partition_point(|s| *s < old_start - MAX_ALIGN_DISTANCE_MS);
+        let hi = new_starts.partition_point(|s| *s <= old_start + MAX_ALIGN_DISTANCE_MS);
+        let mut row = BandRow {
+            start: lo + 1,
+            ..BandRow::default()
+        };
+        for j in lo + 1..=hi {
+            let left = match row.scores.last() {
+                Some(score) => *score,
+                None => band_score(&rows, i - 1, j - 1),
+            };
+            let mut best = band_score(&rows, i - 1, j).max(left);
+            let mut pair = None;
+            if let Some((pair_score, similarity)) = pair_score(
+                old[i - 1],
+                new[j - 1],
+                &old_plain[i - 1],
+                &new_plain[j - 1],
+                offset_ms,
+            ) {
+                let diagonal = band_score(&rows, i - 1, j - 1) + pair_score;
+                if diagonal >= best {
+                    best = diagonal;
+                    pair = Some(similarity);
+                }
+            }
+            row.scores.push(best);
+            row.pairs.push(pair);
+        }
+        rows.push(row);
+    }
+
+    let mut steps = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 {
+            let row = &rows[i - 1];
+            if let Some(similarity) = row.get(j).and_then(|k| row.pairs[k]) {
+                steps.push(AlignStep::Pair(i - 1, j - 1, similarity));
+                i -= 1;
+                j -= 1;
+                continue;
+            }
+        }
+        if i > 0 && (j == 0 || band_score(&rows, i - 1, j) >= band_score(&rows, i, j - 1)) {
+            steps.push(AlignStep::Deleted(i - 1));
+            i -= 1;
+        } else {
+            steps.push(AlignStep::Inserted(j - 1));
+            j -= 1;
+        }
+    }
+    steps.reverse();
+    steps
+        .into_iter()
+        .map(|step| match step {
+            AlignStep::Pair(i, j, similarity) => {
+                AlignStep::Pair(old_order[i], new_order[j], similarity)
+            }
+            AlignStep::Deleted(i) => AlignStep::Deleted(old_order[i]),
+            AlignStep::Inserted(j) => AlignStep::Inserted(new_order[j]),
+        })
+        .collect()
+}
+
+/// Score a candidate pair, returning `(score, text similarity)` if the two
+/// cues may be aligned at all.
+fn pair_score(
+    old: &SubtitleEntry,
+    new: &SubtitleEntry,
+    old_plain: &str,
+    new_plain: &str,
+    offset_ms: i64,
+) -> Option<(f64, f64)> {
+    let old_start = millis(old.start_time) + offset_ms;
+    let old_end = millis(old.end_time) + offset_ms;
+    let distance = (millis(new.start_time) - old_start).abs();
+    if distance > MAX_ALIGN_DISTANCE_MS {
+        return None;
+    }
+
+    let similarity = text_similarity(old_plain, new_plain);
+    let overlap =
+        (old_end.min(millis(new.end_time)) - old_start.max(millis(new.start_time))).max(0);
+    let shorter = (old_end - old_start)
+        .min(millis(new.end_time) - millis(new.start_time))
+        .max(1);
+    let overlap_ratio = overlap as f64 / shorter as f64;
+    if similarity < MIN_TEXT_SIMILARITY && overlap_ratio < MIN_OVERLAP_RATIO {
+        return None;
+    }
+
+    let time_score = 1.0 - distance as f64 / MAX_ALIGN_DISTANCE_MS as f64;
+    Some((1.0 + 0.8 * similarity + 0.2 * time_score, similarity))
+}
+
+/// Normalized Levenshtein similarity of two strings, between 0.0 and 1.0.
+fn text_similarity(a: &str, b: &str) -> f64 {
+    if a == b {
+        return 1.0;
+    }
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    1.0 - previous[b.len()] as f64 / longest as f64
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn millis(duration: Duration) -> i64 {
+    duration.as_millis() as i64
+}
+
+fn format_timestamp(ms: u64) -> String {
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        ms / 3_600_000,
+        (ms % 3_600_000) / 60_000,
+        (ms % 60_000) / 1000,
+        ms % 1000
+    )
+}
+
+fn format_delta(ms: i64) -> String {
+    let sign = if ms < 0 { '-' } else { '+' };
+    format!("{}{:.3}s", sign, ms.unsigned_abs() as f64 / 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::formats::{SubtitleFormatType, SubtitleMetadata};
+
+    fn subtitle(cues: &[(u64, u64, &str)]) -> Subtitle {
+        let mut s = Subtitle::new(
+            SubtitleFormatType::Srt,
+            SubtitleMetadata::new(SubtitleFormatType::Srt),
+        );
+        s.entries = cues
+            .iter()
+            .enumerate()
+            .map(|(i, (start, end, text))| {
+                SubtitleEntry::new(
+                    i + 1,
+                    Duration::from_millis(*start),
+                    Duration::from_millis(*end),
+                    text.to_string(),
+                )
+            })
+            .collect();
+        s
+    }
+
+    #[test]
+    fn test_identical_subtitles() {
+        let old = subtitle(&[(1000, 2000, "Hello"), (3000, 4000, "World")]);
+        let diff = SubtitleDiffer::new().diff(&old, &old.clone());
+        assert!(diff.is_identical());
+        assert_eq!(diff.summary.unchanged, 2);
+        assert_eq!(diff.constant_offset_ms, None);
+    }
+
+    #[test]
+    fn test_constant_offset_detected() {
+        let old = subtitle(&[
+            (1000, 2000, "One"),
+            (3000, 4000, "Two"),
+            (5000, 6000, "Three"),
+        ]);
+        let new = subtitle(&[
+            (2500, 3500, "One"),
+            (4500, 5500, "Two"),
+            (6500, 8000, "Three"),
+        ]);
+        let diff = SubtitleDiffer::new().diff(&old, &new);
+        assert_eq!(diff.constant_offset_ms, Some(1500));
+        assert_eq!(diff.summary.retimed, 3);
+        let text = diff.to_string();
+        assert!(text.contains("Constant offset: +1.500s"));
+        assert!(text.contains("~ #3 -> #3 00:00:06.500 start +1.500s end +2.000s"));
+        assert!(text.contains("2 retimed cues follow the constant offset"));
+    }
+
+    #[test]
+    fn test_inserted_deleted_and_edited_cues() {
+        let old = subtitle(&[
+            (1000, 2000, "Where were you?"),
+            (3000, 4000, "Gone for good"),
+            (5000, 6000, "I was at home."),
+        ]);
+        let new = subtitle(&[
+            (1000, 2000, "Where were you?"),
+            (5000, 6000, "I was at my home."),
+            (7000, 8000, "Really?"),
+        ]);
+        let diff = SubtitleDiffer::new().diff(&old, &new);
+        assert_eq!(
+            diff.summary,
+            DiffSummary {
+                unchanged: 1,
+                retimed: 0,
+                edited: 1,
+                inserted: 1,
+                deleted: 1,
+            }
+        );
+        assert!(matches!(
+            diff.changes[0],
+            CueChange::Deleted { old_index: 2, .. }
+        ));
+        assert!(matches!(
+            diff.changes[1],
+            CueChange::Edited {
+                old_index: 3,
+                new_index: 2,
+                ..
+            }
+        ));
+        assert!(matches!(
+            diff.changes[2],
+            CueChange::Inserted { new_index: 3, .. }
+        ));
+    }
+
+    #[test]
+    fn test_long_subtitles_align_within_band() {
+        let texts: Vec<String> = (0..1500).map(|i| format!("Line number {i}")).collect();
+        let old_cues: Vec<(u64, u64, &str)> = texts
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (i as u64 * 2000, i as u64 * 2000 + 1500, t.as_str()))
+            .collect();
+        let mut new_cues = old_cues.clone();
+        new_cues.remove(700);
+        new_cues[1000].2 = "A different line";
+        let diff = SubtitleDiffer::new().diff(&subtitle(&old_cues), &subtitle(&new_cues));
+        assert_eq!(diff.summary.unchanged, 1498);
+        assert_eq!(diff.summary.deleted, 1);
+        assert_eq!(diff.summary.edited, 1);
+        assert_eq!(diff.summary.inserted, 0);
+    }
+
+    #[test]
+    fn test_unsorted_cues_align_in_timeline_order() {
+        let old = subtitle(&[
+            (5000, 6000, "Three"),
+            (1000, 2000, "One"),
+            (3000, 4000, "Two"),
+        ]);
+        let new = subtitle(&[
+            (3000, 4000, "Two"),
+            (5000, 6000, "Three"),
+            (1000, 2000, "One"),
+            (7000, 8000, "Four"),
+        ]);
+        let diff = SubtitleDiffer::new().diff(&old, &new);
+        assert_eq!(diff.summary.unchanged, 3);
+        assert_eq!(diff.summary.inserted, 1);
+        assert_eq!(diff.summary.deleted, 0);
+        assert!(matches!(
+            diff.changes[0],
+            CueChange::Inserted { new_index: 4, .. }
+        ));
+    }
+
+    #[test]
+    fn test_json_serialization() {
+        let old = subtitle(&[(1000, 2000, "Hello")]);
+        let new = subtitle(&[(1000, 2000, "Hello"), (3000, 4000, "Bye")]);
+        let diff = SubtitleDiffer::new().diff(&old, &new);
+        let json = serde_json::to_value(&diff).unwrap();
+        assert_eq!(json["summary"]["inserted"], 1);
+        assert_eq!(json["changes"][0]["kind"], "inserted");
+        assert_eq!(json["changes"][0]["start_ms"], 3000);
+    }
+
+    #[test]
+    fn test_text_similarity() {
+        assert_eq!(text_similarity("abc", "abc"), 1.0);
+        assert_eq!(text_similarity("", ""), 1.0);
+        assert!((text_similarity("kitten", "sitting") - (1.0 - 3.0 / 7.0)).abs() < 1e-9);
+        assert_eq!(text_similarity("abc", "xyz"), 0.0);
+    }
+}
@@ -1,5 +1,9 @@
 use crate::Result;
 use crate::core::formats::encoding::charset::Charset;
+use encoding_rs::{
+    BIG5, EUC_JP, Encoding, GB18030, GBK, KOI8_R, SHIFT_JIS, WINDOWS_874, WINDOWS_1250,
+    WINDOWS_1251, WINDOWS_1252, WINDOWS_1253, WINDOWS_1255, WINDOWS_1256,
+};
 use std::collections::HashMap;
 
 /// Single-byte and double-byte statistical analyzer
@@ -152,6 +156,7 @@ pub struct AnalysisResult {
 /// detection accuracy beyond simple byte frequency analysis.
 pub struct StatisticalAnalyzer {
     language_models: HashMap<Charset, LanguageModel>,
+    script_models: HashMap<Charset, ScriptModel>,
 }
 
 impl StatisticalAnalyzer {
@@ -162,9 +167,31 @@ impl StatisticalAnalyzer {
     pub fn new() -> Self {
         Self {
             language_models: Self::build_language_models(),
+            script_models: Self::build_script_models(),
         }
     }
 
+    fn build_script_models() -> HashMap<Charset, ScriptModel> {
+        [
+            (Charset::Gbk, GBK, Script::SimplifiedChinese),
+            (Charset::Gb18030, GB18030, Script::SimplifiedChinese),
+            (Charset::Big5, BIG5, Script::TraditionalChinese),
+            (Charset::ShiftJis, SHIFT_JIS, Script::Japanese),
+            (Charset::EucJp, EUC_JP, Script::Japanese),
+            (Charset::Windows1252, WINDOWS_1252, Script::Western),
+            (Charset::Windows1250, WINDOWS_1250, Script::CentralEuropean),
+            (Charset::Windows1251, WINDOWS_1251, Script::Cyrillic),
+            (Charset::Koi8R, KOI8_R, Script::Cyrillic),
+            (Charset::Windows1253, WINDOWS_1253, Script::Greek),
+            (Charset::Windows1255, WINDOWS_1255, Script::Hebrew),
+            (Charset::Windows1256, WINDOWS_1256, Script::Arabic),
+            (Charset::Tis620, WINDOWS_874, Script::Thai),
+        ]
+        .into_iter()
+        .map(|(charset, encoding, script)| (charset, ScriptModel { encoding, script }))
+        .collect()
+    }
+
     fn build_language_models() -> HashMap<Charset, LanguageModel> {
         let mut models = HashMap::new();
         models.insert(
@@ -221,9 +248,44 @@ impl StatisticalAnalyzer {
             let score = self.calculate_model_score(data, model)?;
             scores.insert(cs.clone(), score);
         }
+        for cs in self.script_models.keys() {
+            if let Some(score) = self.script_score(data, cs) {
+                scores.entry(cs.clone()).or_insert(score);
+            }
+        }
         Ok(scores)
     }
 
+    /// Scores how plausible `data` is as text in `charset`.
+    ///
+    /// The data is decoded with `charset` and its non-ASCII characters are
+    /// compared with the character frequency model of the script the
+    /// charset is used for. Text decoded with the wrong code page either
+    /// leaves the script or ends up with a letter distribution that no
+    /// language has, which is what separates single-byte encodings that
+    /// would all decode the same bytes without errors.
+    ///
+    /// Returns a score between 0.0 and 1.0, or `None` if there is no model
+    /// for `charset` or the data contains no non-ASCII text.
+    pub fn script_score(&self, data: &[u8], charset: &Charset) -> Option<f32> {
+        let model = self.script_models.get(charset)?;
+        let (text, _) = model.encoding.decode_without_bom_handling(data);
+        model.score(&text)
+    }
+
+    /// Score already decoded text against the script models.
+    ///
+    /// Like [`StatisticalAnalyzer::script_score`], but for text whose
+    /// encoding is no longer known: the best score of any script is
+    /// returned, so it tells how much the text looks like real writing.
+    /// Returns `None` if the text contains no non-ASCII characters.
+    pub fn text_score(&self, text: &str) -> Option<f32> {
+        self.script_models
+            .values()
+            .filter_map(|model| model.score(text))
+            .reduce(f32::max)
+    }
+
     fn calculate_model_score(&self, data: &[u8], model: &LanguageModel) -> Result<f32> {
         let mut score = 0.0;
         for &b in data {
@@ -254,6 +316,473 @@ struct LanguageModel {
     invalid_patterns: Vec<(u8, f32)>,
 }
 
+/// Character frequency model of the script an encoding is used for.
+#[derive(Debug, Clone)]
+struct ScriptModel {
+    encoding: &'static Encoding,
+    script: Script,
+}
+
+impl ScriptModel {
+    fn score(&self, text: &str) -> Option<f32> {
+        let mut total = 0usize;
+        let mut in_script = 0usize;
+        let mut observed: HashMap<char, f32> = HashMap::new();
+        for c in text.chars().filter(|c| !c.is_ascii()) {
+            total += 1;
+            if is_neutral_punctuation(c) || self.script.contains(c) {
+                in_script += 1;
+                if self.script.contains(c) && !is_cjk_punctuation(c) {
+                    *observed.entry(c).or_insert(0.0) += 1.0;
+                }
+            }
+        }
+        if total == 0 {
+            return None;
+        }
+        let coverage = in_script as f32 / total as f32;
+        let similarity = if observed.is_empty() {
+            1.0
+        } else {
+            self.script.similarity(&observed)
+        };
+        Some(coverage * (0.5 + 0.5 * similarity))
+    }
+}
+
+/// Writing systems with a character frequency model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Script {
+    Western,
+    CentralEuropean,
+    Cyrillic,
+    Greek,
+    Hebrew,
+    Arabic,
+    Thai,
+    SimplifiedChinese,
+    TraditionalChinese,
+    Japanese,
+}
+
+impl Script {
+    fn contains(self, c: char) -> bool {
+        match self {
+            Script::Western | Script::CentralEuropean => {
+                c.is_alphabetic() && ('\u{00C0}'..='\u{024F}').contains(&c)
+            }
+            Script::Cyrillic => ('\u{0400}'..='\u{04FF}').contains(&c),
+            Script::Greek => ('\u{0370}'..='\u{03FF}').contains(&c),
+            Script::Hebrew => ('\u{0590}'..='\u{05FF}').contains(&c),
+            Script::Arabic => ('\u{0600}'..='\u{06FF}').contains(&c),
+            Script::Thai => ('\u{0E00}'..='\u{0E7F}').contains(&c),
+            Script::SimplifiedChinese | Script::TraditionalChinese => {
+                is_han(c) || is_cjk_punctuation(c)
+            }
+            Script::Japanese => is_han(c) || is_kana(c) || is_cjk_punctuation(c),
+        }
+    }
+
+    /// Letter frequencies of the languages written in an alphabetic script.
+    fn letter_frequencies(self) -> &'static [&'static [(char, f32)]] {
+        match self {
+            Script::Western => &[FRENCH, GERMAN, SPANISH, PORTUGUESE, ITALIAN, NORDIC],
+            Script::CentralEuropean => &[POLISH, CZECH, SLOVAK, HUNGARIAN, CROATIAN, ROMANIAN],
+            Script::Cyrillic => &[RUSSIAN],
+            Script::Greek => &[GREEK],
+            Script::Hebrew => &[HEBREW],
+            Script::Arabic => &[ARABIC],
+            Script::Thai => &[THAI],
+            Script::SimplifiedChinese | Script::TraditionalChinese | Script::Japanese => &[],
+        }
+    }
+
+    /// How well the observed characters match the script's languages, from
+    /// 0.0 to 1.0.
+    ///
+    /// Alphabetic scripts use the cosine similarity with the closest
+    /// language's letter frequencies. Ideographic scripts have too many
+    /// characters for that; real text is instead recognised by the share of
+    /// its most frequent characters (kana for Japanese), which text decoded
+    /// with the wrong encoding almost never reaches.
+    fn similarity(self, observed: &HashMap<char, f32>) -> f32 {
+        let frequent = match self {
+            Script::SimplifiedChinese => |c: char| FREQUENT_SIMPLIFIED.contains(c),
+            Script::TraditionalChinese => |c: char| FREQUENT_TRADITIONAL.contains(c),
+            Script::Japanese => is_kana,
+            _ => {
+                return self
+                    .letter_frequencies()
+                    .iter()
+                    .map(|frequencies| frequency_similarity(observed, frequencies))
+                    .fold(0.0, f32::max);
+            }
+        };
+        let total: f32 = observed.values().sum();
+        let hits: f32 = observed
+            .iter()
+            .filter(|(c, _)| frequent(**c))
+            .map(|(_, n)| n)
+            .sum();
+        (hits / total / IDEOGRAPHIC_FREQUENT_SHARE).min(1.0)
+    }
+}
+
+/// Share of the most frequent characters (or kana) in ordinary CJK text.
+const IDEOGRAPHIC_FREQUENT_SHARE: f32 = 0.3;
+
+/// The most frequent characters of written and spoken Simplified Chinese.
+const FREQUENT_SIMPLIFIED: &str = "的一是不了人我在有他这中大来上国个到说们为子和你地出道也时年得就那要下以生会自着去之过家学对可她里后小么心多天而能好都然没日于起还发成事只作当想看文无开手用主行方又如前所本见经头面吗呢吧啊什怎谢";
+
+/// [`FREQUENT_SIMPLIFIED`] in Traditional Chinese.
+const FREQUENT_TRADITIONAL: &str = "的一是不了人我在有他這中大來上國個到說們為子和你地出道也時年得就那要下以生會自著去之過家學對可她裡裏後小麼心多天而能好都然沒日於起還發成事只作當想看文無開手用主行方又如前所本見經頭面嗎呢吧啊什怎謝";
+
+fn is_han(c: char) -> bool {
+    ('\u{4E00}'..='\u{9FFF}').contains(&c) || ('\u{3400}'..='\u{4DBF}').contains(&c)
+}
+
+/// Ideographic punctuation and full-width forms. Half-width katakana are
+/// left out: they are rare in text, but common in mis-decoded Shift-JIS.
+fn is_cjk_punctuation(c: char) -> bool {
+    ('\u{3000}'..='\u{303F}').contains(&c)
+        || ('\u{FF00}'..='\u{FF60}').contains(&c)
+        || ('\u{FFE0}'..='\u{FFEF}').contains(&c)
+}
+
+fn is_kana(c: char) -> bool {
+    ('\u{3040}'..='\u{30FF}').contains(&c)
+}
+
+/// Punctuation shared by all scripts, which says nothing about the encoding.
+fn is_neutral_punctuation(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00A0}' | '«' | '»' | '°' | '·' | '¡' | '¿' | '№' | '€'
+    ) || ('\u{2010}'..='\u{2027}').contains(&c)
+        || ('\u{2030}'..='\u{203A}').contains(&c)
+}
+
+/// Cosine similarity between observed character counts and a language's
+/// letter frequencies.
+fn frequency_similarity(observed: &HashMap<char, f32>, expected: &[(char, f32)]) -> f32 {
+    let dot: f32 = expected
+        .iter()
+        .map(|(c, f)| observed.get(c).copied().unwrap_or(0.0) * f)
+        .sum();
+    let observed_norm = observed.values().map(|v| v * v).sum::<f32>().sqrt();
+    let expected_norm = expected.iter().map(|(_, f)| f * f).sum::<f32>().sqrt();
+    if observed_norm == 0.0 || expected_norm == 0.0 {
+        0.0
+    } else {
+        dot / (observed_norm * expected_norm)
+    }
+}
+
+// Relative frequencies of the non-ASCII letters of each language, in
+// occurrences per thousand letters of running text.
+
+const FRENCH: &[(char, f32)] = &[
+    ('é', 19.0),
+    ('è', 3.0),
+    ('à', 5.0),
+    ('ê', 2.2),
+    ('ç', 0.9),
+    ('â', 0.5),
+    ('ô', 0.5),
+    ('î', 0.4),
+    ('ù', 0.6),
+    ('û', 0.6),
+    ('ë', 0.1),
+    ('ï', 0.1),
+    ('œ', 0.2),
+];
+const GERMAN: &[(char, f32)] = &[('ä', 5.8), ('ö', 2.9), ('ü', 6.5), ('ß', 3.0)];
+const SPANISH: &[(char, f32)] = &[
+    ('á', 5.0),
+    ('é', 4.3),
+    ('í', 7.3),
+    ('ó', 8.3),
+    ('ú', 1.7),
+    ('ñ', 3.1),
+    ('ü', 0.1),
+];
+const PORTUGUESE: &[(char, f32)] = &[
+    ('ã', 7.3),
+    ('á', 1.2),
+    ('é', 3.4),
+    ('í', 1.3),
+    ('ó', 1.6),
+    ('ú', 0.7),
+    ('ç', 5.3),
+    ('â', 0.6),
+    ('ê', 1.1),
+    ('ô', 0.6),
+    ('à', 0.7),
+    ('õ', 0.7),
+];
+const ITALIAN: &[(char, f32)] = &[
+    ('à', 1.5),
+    ('è', 2.6),
+    ('ì', 0.3),
+    ('ò', 0.9),
+    ('ù', 1.7),
+    ('é', 0.4),
+];
+const NORDIC: &[(char, f32)] = &[('å', 13.0), ('ä', 9.0), ('ö', 7.0), ('æ', 8.0), ('ø', 9.0)];
+
+const POLISH: &[(char, f32)] = &[
+    ('ą', 9.9),
+    ('ę', 11.0),
+    ('ó', 8.5),
+    ('ł', 18.0),
+    ('ś', 6.6),
+    ('ż', 8.3),
+    ('ź', 0.8),
+    ('ć', 4.0),
+    ('ń', 2.0),
+];
+const CZECH: &[(char, f32)] = &[
+    ('á', 8.7),
+    ('č', 4.6),
+    ('ď', 0.2),
+    ('é', 6.3),
+    ('ě', 9.1),
+    ('í', 13.0),
+    ('ň', 0.1),
+    ('ř', 3.8),
+    ('š', 6.9),
+    ('ť', 0.1),
+    ('ú', 1.3),
+    ('ů', 2.9),
+    ('ý', 10.2),
+    ('ž', 5.4),
+];
+const SLOVAK: &[(char, f32)] = &[
+    ('á', 8.9),
+    ('ä', 0.1),
+    ('č', 1.5),
+    ('ď', 0.2),
+    ('é', 0.9),
+    ('í', 5.9),
+    ('ĺ', 0.1),
+    ('ľ', 0.6),
+    ('ň', 0.2),
+    ('ó', 0.2),
+    ('ô', 0.3),
+    ('š', 1.3),
+    ('ť', 1.0),
+    ('ú', 1.0),
+    ('ý', 2.0),
+    ('ž', 1.3),
+];
+const HUNGARIAN: &[(char, f32)] = &[
+    ('á', 3.4),
+    ('é', 4.3),
+    ('í', 0.5),
+    ('ó', 0.9),
+    ('ö', 1.0),
+    ('ő', 0.8),
+    ('ú', 0.3),
+    ('ü', 0.6),
+    ('ű', 0.2),
+];
+const CROATIAN: &[(char, f32)] = &[('č', 1.1), ('ć', 0.9), ('đ', 0.4), ('š', 1.0), ('ž', 0.7)];
+const ROMANIAN: &[(char, f32)] = &[('ă', 4.0), ('â', 0.8), ('î', 1.3), ('ş', 1.5), ('ţ', 1.0)];
+
+const RUSSIAN: &[(char, f32)] = &[
+    ('о', 110.0),
+    ('е', 85.0),
+    ('а', 80.0),
+    ('и', 74.0),
+    ('н', 67.0),
+    ('т', 63.0),
+    ('с', 55.0),
+    ('р', 47.0),
+    ('в', 45.0),
+    ('л', 44.0),
+    ('к', 35.0),
+    ('м', 32.0),
+    ('д', 30.0),
+    ('п', 28.0),
+    ('у', 26.0),
+    ('я', 20.0),
+    ('ы', 19.0),
+    ('ь', 17.0),
+    ('г', 17.0),
+    ('з', 16.0),
+    ('б', 16.0),
+    ('ч', 14.0),
+    ('й', 12.0),
+    ('х', 10.0),
+    ('ж', 9.0),
+    ('ш', 7.0),
+    ('ю', 6.0),
+    ('ц', 5.0),
+    ('щ', 4.0),
+    ('э', 3.0),
+    ('ф', 3.0),
+    ('ъ', 0.4),
+    ('ё', 0.4),
+    ('і', 1.0),
+    ('ї', 0.3),
+    ('є', 0.3),
+];
+const GREEK: &[(char, f32)] = &[
+    ('α', 90.0),
+    ('ά', 18.0),
+    ('ο', 85.0),
+    ('ό', 16.0),
+    ('ε', 70.0),
+    ('έ', 15.0),
+    ('ι', 70.0),
+    ('ί', 17.0),
+    ('τ', 80.0),
+    ('σ', 45.0),
+    ('ς', 25.0),
+    ('ν', 65.0),
+    ('η', 45.0),
+    ('ή', 12.0),
+    ('υ', 35.0),
+    ('ύ', 8.0),
+    ('ρ', 42.0),
+    ('π', 40.0),
+    ('κ', 40.0),
+    ('μ', 32.0),
+    ('λ', 28.0),
+    ('δ', 18.0),
+    ('γ', 18.0),
+    ('θ', 13.0),
+    ('χ', 11.0),
+    ('ω', 12.0),
+    ('ώ', 7.0),
+    ('φ', 8.0),
+    ('β', 6.0),
+    ('ξ', 4.0),
+    ('ζ', 3.0),
+    ('ψ', 1.0),
+];
+const HEBREW: &[(char, f32)] = &[
+    ('י', 110.0),
+    ('ו', 100.0),
+    ('ה', 87.0),
+    ('ל', 73.0),
+    ('א', 63.0),
+    ('ר', 56.0),
+    ('ת', 53.0),
+    ('ב', 47.0),
+    ('מ', 47.0),
+    ('ש', 42.0),
+    ('נ', 33.0),
+    ('ע', 32.0),
+    ('ד', 26.0),
+    ('ם', 24.0),
+    ('כ', 23.0),
+    ('ח', 23.0),
+    ('ק', 19.0),
+    ('פ', 14.0),
+    ('ן', 13.0),
+    ('ג', 12.0),
+    ('צ', 10.0),
+    ('ך', 9.0),
+    ('ט', 9.0),
+    ('ז', 8.0),
+    ('ס', 8.0),
+    ('ף', 3.0),
+    ('ץ', 2.0),
+];
+const ARABIC: &[(char, f32)] = &[
+    ('ا', 125.0),
+    ('ل', 105.0),
+    ('ي', 65.0),
+    ('م', 55.0),
+    ('و', 55.0),
+    ('ن', 52.0),
+    ('ر', 43.0),
+    ('ه', 40.0),
+    ('ت', 40.0),
+    ('ب', 35.0),
+    ('ع', 30.0),
+    ('ة', 25.0),
+    ('أ', 25.0),
+    ('د', 25.0),
+    ('ف', 25.0),
+    ('ق', 20.0),
+    ('س', 20.0),
+    ('ك', 20.0),
+    ('ح', 20.0),
+    ('ى', 12.0),
+    ('ج', 10.0),
+    ('إ', 10.0),
+    ('ص', 10.0),
+    ('ش', 8.0),
+    ('خ', 7.0),
+    ('ط', 7.0),
+    ('ذ', 5.0),
+    ('ز', 5.0),
+    ('ث', 4.0),
+    ('غ', 4.0),
+    ('ض', 4.0),
+    ('ئ', 3.0),
+    ('ء', 3.0),
+    ('آ', 2.0),
+    ('ظ', 1.0),
+    ('ؤ', 1.0),
+];
+const THAI: &[(char, f32)] = &[
+    ('า', 95.0),
+    ('น', 70.0),
+    ('ร', 60.0),
+    ('อ', 60.0),
+    ('ก', 55.0),
+    ('เ', 55.0),
+    ('่', 55.0),
+    ('ง', 45.0),
+    ('ม', 45.0),
+    ('้', 35.0),
+    ('ย', 35.0),
+    ('ล', 33.0),
+    ('ว', 32.0),
+    ('ี', 32.0),
+    ('ด', 30.0),
+    ('ท', 30.0),
+    ('ั', 30.0),
+    ('ิ', 30.0),
+    ('ส', 27.0),
+    ('ต', 25.0),
+    ('ะ', 20.0),
+    ('ค', 20.0),
+    ('ไ', 20.0),
+    ('ห', 20.0),
+    ('บ', 20.0),
+    ('ป', 18.0),
+    ('แ', 15.0),
+    ('พ', 15.0),
+    ('ุ', 15.0),
+    ('ข', 14.0),
+    ('จ', 14.0),
+    ('ใ', 10.0),
+    ('ู', 10.0),
+    ('็', 10.0),
+    ('ื', 10.0),
+    ('โ', 10.0),
+    ('ำ', 10.0),
+    ('ช', 10.0),
+    ('์', 10.0),
+    ('ึ', 7.0),
+    ('ศ', 5.0),
+    ('ผ', 5.0),
+    ('ถ', 5.0),
+    ('ณ', 5.0),
+    ('ซ', 5.0),
+    ('ธ', 4.0),
+    ('ษ', 3.0),
+    ('ฟ', 3.0),
+    ('ภ', 3.0),
+    ('ญ', 2.0),
+    ('ๆ', 2.0),
+    ('ฉ', 1.0),
+];
+
 impl Default for ByteAnalyzer {
     fn default() -> Self {
         Self::new()
@@ -393,6 +922,33 @@ mod tests {
         assert!(gbk_scores.get(&Charset::Gbk).unwrap_or(&0.0) > &0.0);
     }
 
+    /// Test script models separate code pages that decode the same bytes
+    #[test]
+    fn test_script_score_separates_code_pages() {
+        let analyzer = StatisticalAnalyzer::new();
+        let text = "Привет, как дела? Я не знаю, что сказать тебе сегодня вечером.";
+        let (cp1251, _, _) = WINDOWS_1251.encode(text);
+
+        let right = analyzer
+            .script_score(&cp1251, &Charset::Windows1251)
+            .unwrap();
+        assert!(right > 0.8);
+        for wrong in [
+            Charset::Koi8R,
+            Charset::Windows1252,
+            Charset::Windows1255,
+            Charset::Gbk,
+        ] {
+            assert!(analyzer.script_score(&cp1251, &wrong).unwrap() < right - 0.1);
+        }
+
+        assert_eq!(
+            analyzer.script_score(b"plain ASCII", &Charset::Windows1251),
+            None
+        );
+        assert_eq!(analyzer.script_score(&cp1251, &Charset::Utf8), None);
+    }
+
     /// Test byte frequency distribution analysis
     #[test]
     fn test_byte_frequency_distribution() {
@@ -26,10 +26,70 @@ pub enum Charset {
     Big5,
     /// EUC-KR encoding (Korean)
     Euckr,
+    /// GB18030 encoding (Chinese, superset of GBK)
+    Gb18030,
+    /// EUC-JP encoding (Japanese)
+    EucJp,
+    /// Windows-1250 encoding (Central European)
+    Windows1250,
+    /// Windows-1251 encoding (Cyrillic)
+    Windows1251,
+    /// KOI8-R encoding (Russian)
+    Koi8R,
+    /// Windows-1253 encoding (Greek)
+    Windows1253,
+    /// Windows-1255 encoding (Hebrew)
+    Windows1255,
+    /// Windows-1256 encoding (Arabic)
+    Windows1256,
+    /// TIS-620 encoding (Thai), decoded as its Windows-874 superset
+    Tis620,
     /// Unknown or undetectable encoding
     Unknown,
 }
 
+impl Charset {
+    /// Look up a charset by one of its common names (case-insensitive),
+    /// such as `utf-8`, `cp1251`, `shift_jis` or `tis-620`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name.to_lowercase().as_str() {
+            "utf-8" | "utf8" => Charset::Utf8,
+            "utf-16" | "utf16" | "utf-16le" | "utf16le" => Charset::Utf16Le,
+            "utf-16be" | "utf16be" => Charset::Utf16Be,
+            "utf-32" | "utf32" | "utf-32le" | "utf32le" => Charset::Utf32Le,
+            "utf-32be" | "utf32be" => Charset::Utf32Be,
+            "gbk" | "gb2312" => Charset::Gbk,
+            "shift-jis" | "shift_jis" | "sjis" => Charset::ShiftJis,
+            "iso-8859-1" | "iso88591" | "latin1" => Charset::Iso88591,
+            "windows-1252" | "windows1252" | "cp1252" => Charset::Windows1252,
+            "big5" => Charset::Big5,
+            "euc-kr" | "euckr" => Charset::Euckr,
+            "gb18030" => Charset::Gb18030,
+            "euc-jp" | "eucjp" => Charset::EucJp,
+            "windows-1250" | "windows1250" | "cp1250" => Charset::Windows1250,
+            "windows-1251" | "windows1251" | "cp1251" => Charset::Windows1251,
+            "koi8-r" | "koi8r" => Charset::Koi8R,
+            "windows-1253" | "windows1253" | "cp1253" => Charset::Windows1253,
+            "windows-1255" | "windows1255" | "cp1255" => Charset::Windows1255,
+            "windows-1256" | "windows1256" | "cp1256" => Charset::Windows1256,
+            "tis-620" | "tis620" | "windows-874" | "cp874" => Charset::Tis620,
+            _ => return None,
+        })
+    }
+
+    /// Whether this is one of the Unicode encodings (UTF-8/16/32).
+    pub fn is_unicode(&self) -> bool {
+        matches!(
+            self,
+            Charset::Utf8
+                | Charset::Utf16Le
+                | Charset::Utf16Be
+                | Charset::Utf32Le
+                | Charset::Utf32Be
+        )
+    }
+}
+
 /// Encoding detection result information
 #[derive(Debug, Clone)]
 pub struct EncodingInfo {
@@ -1,7 +1,11 @@
 use crate::Result;
 use crate::core::formats::encoding::charset::{Charset, EncodingInfo};
 use anyhow::anyhow;
-use encoding_rs::{BIG5, Encoding, GBK, ISO_8859_2, SHIFT_JIS, UTF_8, WINDOWS_1252};
+use encoding_rs::{
+    BIG5, EUC_JP, Encoding, GB18030, GBK, KOI8_R, SHIFT_JIS, UTF_8, UTF_16BE, UTF_16LE,
+    WINDOWS_874, WINDOWS_1250, WINDOWS_1251, WINDOWS_1252, WINDOWS_1253, WINDOWS_1255,
+    WINDOWS_1256,
+};
 use std::collections::HashMap;
 
 /// Result of an encoding conversion operation.
@@ -38,10 +42,28 @@ impl EncodingConverter {
         encoding_map.insert(Charset::ShiftJis, SHIFT_JIS);
         encoding_map.insert(Charset::Big5, BIG5);
         encoding_map.insert(Charset::Windows1252, WINDOWS_1252);
-        encoding_map.insert(Charset::Iso88591, ISO_8859_2);
+        // Like browsers, treat Latin-1 as its Windows-1252 superset
+        encoding_map.insert(Charset::Iso88591, WINDOWS_1252);
+        encoding_map.insert(Charset::Gb18030, GB18030);
+        encoding_map.insert(Charset::EucJp, EUC_JP);
+        encoding_map.insert(Charset::Windows1250, WINDOWS_1250);
+        encoding_map.insert(Charset::Windows1251, WINDOWS_1251);
+        encoding_map.insert(Charset::Koi8R, KOI8_R);
+        encoding_map.insert(Charset::Windows1253, WINDOWS_1253);
+        encoding_map.insert(Charset::Windows1255, WINDOWS_1255);
+        encoding_map.insert(Charset::Windows1256, WINDOWS_1256);
+        encoding_map.insert(Charset::Tis620, WINDOWS_874);
+        // Decoding only: UTF-16 is encoded by `OutputEncoding` itself
+        encoding_map.insert(Charset::Utf16Le, UTF_16LE);
+        encoding_map.insert(Charset::Utf16Be, UTF_16BE);
         Self { encoding_map }
     }
 
+    /// The `encoding_rs` codec used to decode a charset, if supported.
+    pub fn encoding_for(&self, charset: &Charset) -> Option<&'static Encoding> {
+        self.encoding_map.get(charset).copied()
+    }
+
     /// Convert data to UTF-8
     pub fn convert_to_utf8(
         &self,
@@ -58,6 +80,9 @@ impl EncodingConverter {
                 error_count: 0,
             });
         }
+        if matches!(source_encoding, Charset::Utf32Le | Charset::Utf32Be) {
+            return Ok(self.convert_utf32_to_utf8(data, source_encoding));
+        }
         let encoding = self
             .encoding_map
             .get(source_encoding)
@@ -93,6 +118,34 @@ impl EncodingConverter {
         self.convert_to_utf8(slice, &encoding_info.charset)
     }
 
+    fn convert_utf32_to_utf8(&self, data: &[u8], source_encoding: &Charset) -> ConversionResult {
+        let mut error_count = 0;
+        let converted_text = data
+            .chunks(4)
+            .map(|chunk| {
+                let unit = <[u8; 4]>::try_from(chunk).ok().map(|bytes| {
+                    if *source_encoding == Charset::Utf32Le {
+                        u32::from_le_bytes(bytes)
+                    } else {
+                        u32::from_be_bytes(bytes)
+                    }
+                });
+                unit.and_then(char::from_u32).unwrap_or_else(|| {
+                    error_count += 1;
+                    '\u{FFFD}'
+                })
+            })
+            .collect();
+        ConversionResult {
+            converted_text,
+            original_encoding: source_encoding.clone(),
+            target_encoding: Charset::Utf8,
+            bytes_processed: data.len(),
+            had_errors: error_count > 0,
+            error_count,
+        }
+    }
+
     fn skip_bom<'a>(&self, data: &'a [u8], charset: &Charset) -> &'a [u8] {
         match charset {
             Charset::Utf8 if data.starts_with(&[0xEF, 0xBB, 0xBF]) => &data[3..],
@@ -155,3 +208,29 @@ impl Default for EncodingConverter {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_utf16_and_utf32_conversion() {
+        let converter = EncodingConverter::new();
+        let utf16: Vec<u8> = "Hi 你".encode_utf16().flat_map(u16::to_be_bytes).collect();
+        let res = converter
+            .convert_to_utf8(&utf16, &Charset::Utf16Be)
+            .unwrap();
+        assert_eq!(res.converted_text, "Hi 你");
+
+        let mut utf32: Vec<u8> = "Hi 你"
+            .chars()
+            .flat_map(|c| (c as u32).to_le_bytes())
+            .collect();
+        utf32.extend_from_slice(&[0x00, 0x00, 0x11, 0x00]);
+        let res = converter
+            .convert_to_utf8(&utf32, &Charset::Utf32Le)
+            .unwrap();
+        assert_eq!(res.converted_text, "Hi 你\u{FFFD}");
+        assert_eq!(res.error_count, 1);
+    }
+}
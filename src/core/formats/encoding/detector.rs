@@ -1,5 +1,7 @@
 use crate::Result;
+use crate::core::formats::encoding::analyzer::StatisticalAnalyzer;
 use crate::core::formats::encoding::charset::{Charset, EncodingInfo};
+use crate::core::formats::encoding::converter::EncodingConverter;
 use std::fs::File;
 use std::io::Read;
 
@@ -9,6 +11,7 @@ pub struct EncodingDetector {
     max_sample_size: usize,
     supported_charsets: Vec<Charset>,
     default_encoding: String,
+    statistics: StatisticalAnalyzer,
 }
 
 impl EncodingDetector {
@@ -19,6 +22,7 @@ impl EncodingDetector {
             max_sample_size: 8192,
             supported_charsets: Self::default_charsets(),
             default_encoding: config.formats.default_encoding.clone(),
+            statistics: StatisticalAnalyzer::new(),
         }
     }
 
@@ -29,6 +33,7 @@ impl EncodingDetector {
             max_sample_size: 8192,
             supported_charsets: Self::default_charsets(),
             default_encoding: "utf-8".to_string(),
+            statistics: StatisticalAnalyzer::new(),
         }
     }
 
@@ -39,9 +44,17 @@ impl EncodingDetector {
             max_sample_size: 8192,
             supported_charsets: Self::default_charsets(),
             default_encoding: config.formats.default_encoding.clone(),
+            statistics: StatisticalAnalyzer::new(),
         }
     }
 
+    /// Set the confidence below which the default encoding is reported
+    /// instead of the best candidate.
+    pub fn with_confidence_threshold(mut self, threshold: f32) -> Self {
+        self.confidence_threshold = threshold;
+        self
+    }
+
     /// Detect file encoding
     pub fn detect_file_encoding(&self, file_path: &str) -> Result<EncodingInfo> {
         let mut file = File::open(file_path)?;
@@ -123,15 +136,30 @@ impl EncodingDetector {
     }
 
     fn calculate_encoding_confidence(&self, data: &[u8], charset: &Charset) -> Result<f32> {
-        match charset {
-            Charset::Utf8 => self.check_utf8_validity(data),
-            Charset::Gbk => self.check_gbk_patterns(data),
-            Charset::ShiftJis => self.check_shift_jis_patterns(data),
-            Charset::Big5 => self.check_big5_patterns(data),
-            Charset::Iso88591 => self.check_iso88591_patterns(data),
-            Charset::Windows1252 => self.check_windows1252_patterns(data),
-            _ => Ok(0.0),
-        }
+        let structural = match charset {
+            Charset::Utf8 => return self.check_utf8_validity(data),
+            Charset::Iso88591 => return self.check_iso88591_patterns(data),
+            Charset::Gbk => self.check_gbk_patterns(data)?,
+            Charset::Gb18030 => self.check_gb18030_patterns(data)?,
+            Charset::ShiftJis => self.check_shift_jis_patterns(data)?,
+            Charset::EucJp => self.check_euc_jp_patterns(data)?,
+            Charset::Big5 => self.check_big5_patterns(data)?,
+            Charset::Windows1252
+            | Charset::Windows1250
+            | Charset::Windows1251
+            | Charset::Koi8R
+            | Charset::Windows1253
+            | Charset::Windows1255
+            | Charset::Windows1256
+            | Charset::Tis620 => self.check_single_byte_patterns(data)?,
+            _ => return Ok(0.0),
+        };
+        // Byte structure alone cannot tell code pages apart, so weight it by
+        // how well the decoded text fits the script's frequency model.
+        Ok(match self.statistics.script_score(data, charset) {
+            Some(score) => structural * score,
+            None => structural,
+        })
     }
 
     fn check_utf8_validity(&self, data: &[u8]) -> Result<f32> {
@@ -168,13 +196,29 @@ impl EncodingDetector {
             }
         }
 
-        Ok(if total_chars > 0 {
+        let validity = if total_chars > 0 {
             valid_chars as f32 / total_chars as f32
         } else {
             0.0
+        };
+        // Any malformed sequence rules out UTF-8 more strongly than the
+        // ratio of valid characters suggests in mostly-ASCII text.
+        Ok(if Self::is_well_formed_utf8(data) {
+            validity
+        } else {
+            validity * 0.9
         })
     }
 
+    /// Whether `data` is valid UTF-8, allowing a sequence cut off at the end
+    /// of the sample.
+    fn is_well_formed_utf8(data: &[u8]) -> bool {
+        match std::str::from_utf8(data) {
+            Ok(_) => true,
+            Err(e) => e.error_len().is_none(),
+        }
+    }
+
     fn check_gbk_patterns(&self, data: &[u8]) -> Result<f32> {
         let mut valid_chars = 0;
         let mut total_chars = 0;
@@ -208,6 +252,86 @@ impl EncodingDetector {
         })
     }
 
+    fn check_gb18030_patterns(&self, data: &[u8]) -> Result<f32> {
+        let mut valid_chars = 0;
+        let mut total_chars = 0;
+        let mut i = 0;
+
+        while i < data.len() {
+            total_chars += 1;
+            if data[i] < 0x80 {
+                valid_chars += 1;
+                i += 1;
+            } else if i + 3 < data.len()
+                && (0x81..=0xFE).contains(&data[i])
+                && (0x30..=0x39).contains(&data[i + 1])
+                && (0x81..=0xFE).contains(&data[i + 2])
+                && (0x30..=0x39).contains(&data[i + 3])
+            {
+                valid_chars += 1;
+                i += 4;
+            } else if i + 1 < data.len() {
+                let byte1 = data[i];
+                let byte2 = data[i + 1];
+                if (0x81..=0xFE).contains(&byte1)
+                    && ((0x40..=0x7E).contains(&byte2) || (0x80..=0xFE).contains(&byte2))
+                {
+                    valid_chars += 1;
+                }
+                i += 2;
+            } else {
+                i += 1;
+            }
+        }
+
+        Ok(if total_chars > 0 {
+            valid_chars as f32 / total_chars as f32
+        } else {
+            0.0
+        })
+    }
+
+    fn check_euc_jp_patterns(&self, data: &[u8]) -> Result<f32> {
+        let mut valid_chars = 0;
+        let mut total_chars = 0;
+        let mut i = 0;
+        let is_row = |b: u8| (0xA1..=0xFE).contains(&b);
+
+        while i < data.len() {
+            total_chars += 1;
+            let byte1 = data[i];
+            if byte1 < 0x80 {
+                valid_chars += 1;
+                i += 1;
+            } else if byte1 == 0x8E && i + 1 < data.len() {
+                // Half-width katakana
+                if (0xA1..=0xDF).contains(&data[i + 1]) {
+                    valid_chars += 1;
+                }
+                i += 2;
+            } else if byte1 == 0x8F && i + 2 < data.len() {
+                // JIS X 0212 supplementary characters
+                if is_row(data[i + 1]) && is_row(data[i + 2]) {
+                    valid_chars += 1;
+                }
+                i += 3;
+            } else if is_row(byte1) && i + 1 < data.len() {
+                if is_row(data[i + 1]) {
+                    valid_chars += 1;
+                }
+                i += 2;
+            } else {
+                i += 1;
+            }
+        }
+
+        Ok(if total_chars > 0 {
+            valid_chars as f32 / total_chars as f32
+        } else {
+            0.0
+        })
+    }
+
     fn check_shift_jis_patterns(&self, data: &[u8]) -> Result<f32> {
         let mut valid_chars = 0;
         let mut total_chars = 0;
@@ -286,14 +410,15 @@ impl EncodingDetector {
         }
     }
 
-    fn check_windows1252_patterns(&self, data: &[u8]) -> Result<f32> {
-        let control_chars = data.iter().filter(|&&b| (0x80..=0x9F).contains(&b)).count();
-        let extended_chars = data.iter().filter(|&&b| b >= 0xA0).count();
-        if control_chars > 0 || extended_chars > 0 {
-            let utf8_conf = self.check_utf8_validity(data)?;
-            Ok(if utf8_conf < 0.5 { 0.6 } else { 0.1 })
-        } else {
+    /// Structural check shared by the single-byte code pages, which accept
+    /// nearly every byte; the script model decides between them.
+    fn check_single_byte_patterns(&self, data: &[u8]) -> Result<f32> {
+        if data.iter().all(|b| b.is_ascii()) {
             Ok(0.3)
+        } else if Self::is_well_formed_utf8(data) {
+            Ok(0.1)
+        } else {
+            Ok(1.0)
         }
     }
 
@@ -346,7 +471,10 @@ impl EncodingDetector {
         match charset {
             Charset::Utf8 => String::from_utf8(sample_data.to_vec())
                 .or_else(|_| Ok(String::from_utf8_lossy(sample_data).into_owned())),
-            _ => Ok(String::from_utf8_lossy(sample_data).into_owned()),
+            _ => match EncodingConverter::new().convert_to_utf8(sample_data, charset) {
+                Ok(result) => Ok(result.converted_text),
+                Err(_) => Ok(String::from_utf8_lossy(sample_data).into_owned()),
+            },
         }
     }
 
@@ -358,25 +486,21 @@ impl EncodingDetector {
             Charset::Big5,
             Charset::Iso88591,
             Charset::Windows1252,
+            Charset::Gb18030,
+            Charset::EucJp,
+            Charset::Windows1250,
+            Charset::Windows1251,
+            Charset::Koi8R,
+            Charset::Windows1253,
+            Charset::Windows1255,
+            Charset::Windows1256,
+            Charset::Tis620,
         ]
     }
 
     /// Convert encoding name string to Charset enum
     fn parse_charset_name(&self, encoding_name: &str) -> Charset {
-        match encoding_name.to_lowercase().as_str() {
-            "utf-8" | "utf8" => Charset::Utf8,
-            "utf-16le" | "utf16le" => Charset::Utf16Le,
-            "utf-16be" | "utf16be" => Charset::Utf16Be,
-            "utf-32le" | "utf32le" => Charset::Utf32Le,
-            "utf-32be" | "utf32be" => Charset::Utf32Be,
-            "gbk" | "gb2312" => Charset::Gbk,
-            "shift-jis" | "shift_jis" | "sjis" => Charset::ShiftJis,
-            "iso-8859-1" | "iso88591" | "latin1" => Charset::Iso88591,
-            "windows-1252" | "windows1252" | "cp1252" => Charset::Windows1252,
-            "big5" => Charset::Big5,
-            "euc-kr" | "euckr" => Charset::Euckr,
-            _ => Charset::Utf8, // Default fallback
-        }
+        Charset::from_name(encoding_name).unwrap_or(Charset::Utf8) // Default fallback
     }
 }
 
@@ -404,6 +528,7 @@ mod tests {
             max_sample_size: 8192,
             supported_charsets: EncodingDetector::default_charsets(),
             default_encoding: "utf-8".to_string(),
+            statistics: StatisticalAnalyzer::new(),
         }
     }
 
@@ -605,6 +730,7 @@ mod tests {
             max_sample_size: 8192,
             supported_charsets: EncodingDetector::default_charsets(),
             default_encoding: "gbk".to_string(),
+            statistics: StatisticalAnalyzer::new(),
         };
 
         // Use truly ambiguous data that won't meet very high confidence threshold
@@ -624,6 +750,87 @@ mod tests {
         assert!(result.sample_text.contains("utf-16le") || result.sample_text.contains("default"));
     }
 
+    /// Test single-byte and legacy code pages are told apart by script models
+    #[test]
+    fn test_extended_charset_detection() {
+        use encoding_rs::{
+            BIG5, EUC_JP, GBK, KOI8_R, SHIFT_JIS, WINDOWS_874, WINDOWS_1250, WINDOWS_1251,
+            WINDOWS_1252, WINDOWS_1253, WINDOWS_1255, WINDOWS_1256,
+        };
+
+        let detector = create_test_detector();
+        let cases = [
+            (
+                WINDOWS_1251,
+                Charset::Windows1251,
+                "Привет, как дела? Я не знаю, что сказать тебе сегодня вечером.",
+            ),
+            (
+                KOI8_R,
+                Charset::Koi8R,
+                "Привет, как дела? Я не знаю, что сказать тебе сегодня вечером.",
+            ),
+            (
+                WINDOWS_1250,
+                Charset::Windows1250,
+                "Zażółć gęślą jaźń. Dzień dobry, proszę pana, czy mogę wejść?",
+            ),
+            (
+                WINDOWS_1252,
+                Charset::Windows1252,
+                "Où est la bibliothèque? Je suis allé à l'école très tôt, déjà.",
+            ),
+            (
+                WINDOWS_1253,
+                Charset::Windows1253,
+                "Καλημέρα σας, τι κάνετε; Είμαι πολύ καλά, ευχαριστώ για την ερώτηση.",
+            ),
+            (
+                WINDOWS_1255,
+                Charset::Windows1255,
+                "שלום, מה שלומך? אני לא יודע מה לומר לך היום בערב.",
+            ),
+            (
+                WINDOWS_1256,
+                Charset::Windows1256,
+                "مرحبا، كيف حالك؟ لا أعرف ماذا أقول لك هذا المساء.",
+            ),
+            (
+                WINDOWS_874,
+                Charset::Tis620,
+                "สวัสดีครับ คุณสบายดีไหม ผมไม่รู้ว่าจะพูดอะไรกับคุณในคืนนี้",
+            ),
+            (
+                EUC_JP,
+                Charset::EucJp,
+                "こんにちは、お元気ですか？今夜はあなたに何を言えばいいのか分かりません。",
+            ),
+            (
+                SHIFT_JIS,
+                Charset::ShiftJis,
+                "こんにちは、お元気ですか？今夜はあなたに何を言えばいいのか分かりません。",
+            ),
+            (
+                GBK,
+                Charset::Gbk,
+                "你好，今天晚上我们去哪里吃饭？我不知道应该说什么才好。",
+            ),
+            (
+                BIG5,
+                Charset::Big5,
+                "你好，今天晚上我們去哪裡吃飯？我不知道應該說什麼才好。",
+            ),
+        ];
+
+        for (encoding, expected, text) in cases {
+            let srt = format!("1\n00:00:01,000 --> 00:00:03,000\n{}\n\n", text);
+            let (bytes, _, _) = encoding.encode(&srt);
+            let result = detector.detect_encoding(&bytes).unwrap();
+            assert_eq!(result.charset, expected, "{}", text);
+            assert!(result.sample_text.contains(text), "{}", result.sample_text);
+        }
+    }
+
     /// Test encoding name parsing
     #[test]
     fn test_encoding_name_parsing() {
@@ -642,6 +849,11 @@ mod tests {
             detector.parse_charset_name("windows-1252"),
             Charset::Windows1252
         );
+        assert_eq!(detector.parse_charset_name("CP1251"), Charset::Windows1251);
+        assert_eq!(detector.parse_charset_name("koi8-r"), Charset::Koi8R);
+        assert_eq!(detector.parse_charset_name("tis-620"), Charset::Tis620);
+        assert_eq!(detector.parse_charset_name("euc-jp"), Charset::EucJp);
+        assert_eq!(detector.parse_charset_name("gb18030"), Charset::Gb18030);
 
         // Test unknown encoding fallback
         assert_eq!(
@@ -10,6 +10,8 @@
 //! - [`charset`] - Character set definitions and encoding information
 //! - [`converter`] - Encoding conversion functionality
 //! - [`detector`] - High-level encoding detection interface
+//! - [`mojibake`] - Detection and repair of text decoded with the wrong encoding
+//! - [`output`] - Encoding of text for writing, with BOM and line ending control
 //!
 //! # Examples
 //!
@@ -41,7 +43,17 @@ pub mod converter;
 /// High-level encoding detection interface
 pub mod detector;
 
+/// Detection and repair of text decoded with the wrong encoding
+pub mod mojibake;
+
+/// Encoding of text for writing, with BOM and line ending control
+pub mod output;
+
 pub use analyzer::{ByteAnalyzer, StatisticalAnalyzer};
 pub use charset::{Charset, EncodingInfo};
 pub use converter::{ConversionResult, EncodingConverter};
 pub use detector::EncodingDetector;
+pub use mojibake::{MojibakeAnalyzer, MojibakeRepair};
+pub use output::{
+    EncodedOutput, LineEnding, OutputEncoding, UnmappableCharacter, UnmappablePolicy,
+};
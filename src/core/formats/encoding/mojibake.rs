@@ -0,0 +1,304 @@
+//! Detection and repair of mojibake in decoded text.
+//!
+//! Mojibake is text whose bytes were decoded with the wrong code page and
+//! then saved again, typically as UTF-8: `é` becomes `Ã©`, `你好` becomes
+//! `浣犲ソ`, and Big5 subtitles turn into GBK garbage. The damage is done
+//! before the file reaches [`EncodingDetector`](super::EncodingDetector),
+//! whose byte-level analysis sees perfectly valid UTF-8.
+//!
+//! [`MojibakeAnalyzer`] looks for these double-encoding patterns in the
+//! decoded text. For each common pair of wrong and actual encodings it
+//! re-encodes runs of non-ASCII characters with the wrong code page and
+//! decodes the bytes with the actual one. A pair is a candidate when that
+//! reverse transcoding is lossless, and it is reported when the repaired
+//! text fits a writing system markedly better than the damaged one.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use subx_cli::core::formats::encoding::{Charset, MojibakeAnalyzer};
+//!
+//! let analyzer = MojibakeAnalyzer::new();
+//! let repair = analyzer.analyze("Ã‡a a Ã©tÃ© un trÃ¨s bel Ã©tÃ©.").unwrap();
+//! assert_eq!(repair.misread_as, Charset::Windows1252);
+//! assert_eq!(repair.original, Charset::Utf8);
+//! assert_eq!(repair.repaired_text, "Ça a été un très bel été.");
+//! ```
+
+use encoding_rs::Encoding;
+
+use crate::core::formats::encoding::analyzer::StatisticalAnalyzer;
+use crate::core::formats::encoding::charset::Charset;
+use crate::core::formats::encoding::converter::EncodingConverter;
+
+/// Pairs of (encoding the text was wrongly decoded with, encoding its bytes
+/// were actually in) that are checked, most common first.
+const CANDIDATES: &[(Charset, Charset)] = &[
+    (Charset::Windows1252, Charset::Utf8),
+    (Charset::Windows1250, Charset::Utf8),
+    (Charset::Windows1251, Charset::Utf8),
+    (Charset::Gbk, Charset::Utf8),
+    (Charset::Big5, Charset::Utf8),
+    (Charset::ShiftJis, Charset::Utf8),
+    (Charset::Gbk, Charset::Big5),
+    (Charset::Big5, Charset::Gbk),
+    (Charset::Windows1252, Charset::Windows1251),
+];
+
+/// Character decoders substitute for bytes they cannot map.
+const REPLACEMENT_CHARACTER: char = '\u{FFFD}';
+
+/// Minimum confidence for a repair to be reported.
+const MIN_CONFIDENCE: f32 = 0.5;
+
+/// A diagnosed mis-decoding and the text with it reversed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MojibakeRepair {
+    /// Encoding the text was wrongly decoded with
+    pub misread_as: Charset,
+    /// Encoding the original bytes were actually in
+    pub original: Charset,
+    /// Confidence of the diagnosis, from 0.0 to 1.0
+    pub confidence: f32,
+    /// Number of characters recovered by the repair
+    pub repaired_characters: usize,
+    /// The text with the mis-decoding reversed
+    pub repaired_text: String,
+}
+
+/// Detects double-encoded text and proposes the reverse transcoding.
+pub struct MojibakeAnalyzer {
+    statistics: StatisticalAnalyzer,
+    converter: EncodingConverter,
+}
+
+impl MojibakeAnalyzer {
+    /// Create an analyzer with the built-in script models.
+    pub fn new() -> Self {
+        Self {
+            statistics: StatisticalAnalyzer::new(),
+            converter: EncodingConverter::new(),
+        }
+    }
+
+    /// Diagnose mojibake in `text`.
+    ///
+    /// Returns the most likely repair, or `None` if the text shows no
+    /// recognizable double-encoding pattern.
+    pub fn analyze(&self, text: &str) -> Option<MojibakeRepair> {
+        let before = self.statistics.text_score(text)?;
+        CANDIDATES
+            .iter()
+            .filter_map(|(misread_as, original)| {
+                let (repaired_text, repaired_characters) =
+                    self.reverse(text, misread_as, original)?;
+                let after = self.statistics.text_score(&repaired_text)?;
+                if after <= before {
+                    return None;
+                }
+                // Gain relative to the room for improvement, weighted by how
+                // likely so many runs were to survive the round trip by chance.
+                let gain = (after - before) / (1.0 - before);
+                let evidence = 1.0 - 0.5f32.powi(repaired_characters.min(64) as i32);
+                Some(MojibakeRepair {
+                    misread_as: misread_as.clone(),
+                    original: original.clone(),
+                    confidence: gain * evidence,
+                    repaired_characters,
+                    repaired_text,
+                })
+            })
+            .filter(|repair| repair.confidence >= MIN_CONFIDENCE)
+            .max_by(|a, b| a.confidence.total_cmp(&b.confidence))
+    }
+
+    /// Re-encode every non-ASCII run of `text` with `misread_as` and decode
+    /// it with `original`, keeping runs that do not survive unchanged.
+    ///
+    /// Returns the new text and the number of characters it recovered, or
+    /// `None` if no run could be transcoded.
+    fn reverse(
+        &self,
+        text: &str,
+        misread_as: &Charset,
+        original: &Charset,
+    ) -> Option<(String, usize)> {
+        let misread_as = self.converter.encoding_for(misread_as)?;
+        let original = match original {
+            Charset::Utf8 => None,
+            other => Some(self.converter.encoding_for(other)?),
+        };
+
+        let mut repaired = String::with_capacity(text.len());
+        let mut recovered = 0;
+        for (run, is_ascii) in runs(text) {
+            if is_ascii {
+                repaired.push_str(run);
+                continue;
+            }
+            // Bytes the wrong decoder could not map are gone; transcode the
+            // pieces between them on their own.
+            let mut truncated = false;
+            for (i, piece) in run.split(REPLACEMENT_CHARACTER).enumerate() {
+                // A sequence cut short already ends in a replacement character
+                if i > 0 && !std::mem::take(&mut truncated) {
+                    repaired.push(REPLACEMENT_CHARACTER);
+                }
+                match transcode(piece, misread_as, original) {
+                    Some((fixed, cut)) if fixed != piece => {
+                        recovered += fixed.chars().count();
+                        repaired.push_str(&fixed);
+                        truncated = cut;
+                    }
+                    _ => repaired.push_str(piece),
+                }
+            }
+        }
+        (recovered > 0).then_some((repaired, recovered))
+    }
+}
+
+impl Default for MojibakeAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reverse one mis-decoding of `run`, or `None` if it is not lossless.
+///
+/// UTF-8 may end in a sequence whose last bytes were lost; it is then
+/// closed with a replacement character and the flag is set.
+fn transcode(
+    run: &str,
+    misread_as: &'static Encoding,
+    original: Option<&'static Encoding>,
+) -> Option<(String, bool)> {
+    if run.is_empty() {
+        return None;
+    }
+    let (bytes, _, unmappable) = misread_as.encode(run);
+    if unmappable {
+        return None;
+    }
+    match original {
+        None => match std::str::from_utf8(&bytes) {
+            Ok(text) => Some((text.to_string(), false)),
+            Err(e) if e.error_len().is_none() && e.valid_up_to() > 0 => {
+                let mut text = String::from_utf8_lossy(&bytes[..e.valid_up_to()]).into_owned();
+                text.push(REPLACEMENT_CHARACTER);
+                Some((text, true))
+            }
+            Err(_) => None,
+        },
+        Some(encoding) => encoding
+            .decode_without_bom_handling_and_without_replacement(&bytes)
+            .map(|text| (text.into_owned(), false)),
+    }
+}
+
+/// Split `text` into maximal runs of ASCII and non-ASCII characters.
+fn runs(text: &str) -> impl Iterator<Item = (&str, bool)> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        let first = rest.chars().next()?;
+        let is_ascii = first.is_ascii();
+        let end = rest
+            .char_indices()
+            .find(|(_, c)| c.is_ascii() != is_ascii)
+            .map_or(rest.len(), |(i, _)| i);
+        let (run, tail) = rest.split_at(end);
+        rest = tail;
+        Some((run, is_ascii))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn misread(text: &str, original: &'static Encoding, misread_as: &'static Encoding) -> String {
+        let (bytes, _, _) = original.encode(text);
+        misread_as
+            .decode_without_bom_handling(&bytes)
+            .0
+            .into_owned()
+    }
+
+    #[test]
+    fn test_repairs_utf8_misread_as_legacy_code_pages() {
+        let analyzer = MojibakeAnalyzer::new();
+        let cases = [
+            (
+                "Ça a été un très bel été, non ?",
+                encoding_rs::WINDOWS_1252,
+                Charset::Windows1252,
+            ),
+            (
+                "Привет, как дела? Всё хорошо.",
+                encoding_rs::WINDOWS_1252,
+                Charset::Windows1252,
+            ),
+            (
+                "Привет, как дела? Всё хорошо.",
+                encoding_rs::WINDOWS_1251,
+                Charset::Windows1251,
+            ),
+            ("你好，我们明天去看电影吧", encoding_rs::GBK, Charset::Gbk),
+        ];
+        for (text, encoding, charset) in cases {
+            let damaged = misread(text, encoding_rs::UTF_8, encoding);
+            let repair = analyzer.analyze(&damaged).expect(text);
+            assert_eq!(repair.misread_as, charset, "{text}");
+            assert_eq!(repair.original, Charset::Utf8);
+            assert_eq!(repair.repaired_text, text);
+            assert!(repair.confidence >= MIN_CONFIDENCE);
+        }
+    }
+
+    #[test]
+    fn test_lost_bytes_stay_replaced() {
+        // GBK reads the final byte of `。` as a lone, unmappable byte
+        let damaged = misread(
+            "你好，我们今天去看电影吧。",
+            encoding_rs::UTF_8,
+            encoding_rs::GBK,
+        );
+        assert!(damaged.ends_with(REPLACEMENT_CHARACTER));
+        let repair = MojibakeAnalyzer::new().analyze(&damaged).unwrap();
+        assert_eq!(repair.misread_as, Charset::Gbk);
+        assert_eq!(repair.repaired_text, "你好，我们今天去看电影吧\u{FFFD}");
+    }
+
+    #[test]
+    fn test_repairs_big5_misread_as_gbk() {
+        let text = "這是一個很好的問題，我們明天再說吧。";
+        let damaged = misread(text, encoding_rs::BIG5, encoding_rs::GBK);
+        let repair = MojibakeAnalyzer::new().analyze(&damaged).unwrap();
+        assert_eq!(repair.misread_as, Charset::Gbk);
+        assert_eq!(repair.original, Charset::Big5);
+        assert_eq!(repair.repaired_text, text);
+    }
+
+    #[test]
+    fn test_leaves_correct_text_alone() {
+        let analyzer = MojibakeAnalyzer::new();
+        for text in [
+            "Hello world",
+            "Ça a été un très bel été, non ?",
+            "Привет, как дела? Всё хорошо.",
+            "你好，我们今天去看电影吧。",
+            "這是一個很好的問題，我們明天再說吧。",
+            "こんにちは、元気ですか？",
+        ] {
+            assert_eq!(analyzer.analyze(text), None, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_partial_damage_keeps_correct_runs() {
+        let text = "Déjà vu\nCafÃ© crÃ¨me, trÃ¨s Ã©lÃ©gant";
+        let repair = MojibakeAnalyzer::new().analyze(text).unwrap();
+        assert_eq!(repair.repaired_text, "Déjà vu\nCafé crème, très élégant");
+        assert_eq!(repair.repaired_characters, 5);
+    }
+}
@@ -0,0 +1,325 @@
+//! Encoding of subtitle text for writing to disk.
+//!
+//! Subtitle content is handled as UTF-8 internally. [`OutputEncoding`]
+//! turns it into the bytes of the target file: it encodes into the chosen
+//! charset, optionally prefixes a byte order mark and normalizes line
+//! endings. Characters the target charset cannot represent are either
+//! replaced with `?` and reported, or make the write fail.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use subx_cli::core::formats::encoding::{Charset, LineEnding, OutputEncoding};
+//!
+//! let output = OutputEncoding::new(Charset::Windows1252)
+//!     .with_line_ending(Some(LineEnding::Crlf));
+//! let encoded = output.encode("Café\n").unwrap();
+//! assert_eq!(encoded.bytes, b"Caf\xE9\r\n");
+//! ```
+
+use anyhow::anyhow;
+use encoding_rs::EncoderResult;
+
+use crate::Result;
+use crate::core::formats::encoding::charset::Charset;
+use crate::core::formats::encoding::converter::EncodingConverter;
+use crate::error::SubXError;
+
+/// Maximum number of unmappable characters listed in a warning.
+const MAX_REPORTED_CHARACTERS: usize = 5;
+
+/// Line ending style of written files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    /// Unix style `\n`
+    Lf,
+    /// Windows style `\r\n`
+    Crlf,
+}
+
+/// What to do with characters the target charset cannot represent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnmappablePolicy {
+    /// Replace them with `?` and report them as warnings
+    Report,
+    /// Fail without writing anything
+    #[default]
+    Fail,
+}
+
+/// Charset, byte order mark and line ending settings for written files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputEncoding {
+    /// Target character set
+    pub charset: Charset,
+    /// Whether to write a byte order mark (Unicode charsets only)
+    pub bom: bool,
+    /// Line ending style, or `None` to keep the line endings as they are
+    pub line_ending: Option<LineEnding>,
+    /// Handling of characters the charset cannot represent
+    pub unmappable: UnmappablePolicy,
+}
+
+/// A character that could not be represented in the target charset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnmappableCharacter {
+    /// 1-based line of the character in the written text
+    pub line: usize,
+    /// The character that was replaced
+    pub character: char,
+}
+
+/// Bytes produced by [`OutputEncoding::encode`].
+#[derive(Debug, Clone)]
+pub struct EncodedOutput {
+    /// Encoded file content, including the BOM if requested
+    pub bytes: Vec<u8>,
+    /// Characters replaced with `?`
+    pub unmappable: Vec<UnmappableCharacter>,
+}
+
+impl EncodedOutput {
+    /// Human-readable warnings about replaced characters.
+    pub fn warnings(&self, charset: &Charset) -> Vec<String> {
+        if self.unmappable.is_empty() {
+            return Vec::new();
+        }
+        let mut listed: Vec<String> = self
+            .unmappable
+            .iter()
+            .take(MAX_REPORTED_CHARACTERS)
+            .map(|u| format!("'{}' (line {})", u.character, u.line))
+            .collect();
+        if self.unmappable.len() > MAX_REPORTED_CHARACTERS {
+            listed.push(format!(
+                "and {} more",
+                self.unmappable.len() - MAX_REPORTED_CHARACTERS
+            ));
+        }
+        vec![format!(
+            "{} character(s) cannot be represented in {:?} and were replaced with '?': {}",
+            self.unmappable.len(),
+            charset,
+            listed.join(", ")
+        )]
+    }
+}
+
+impl Default for OutputEncoding {
+    fn default() -> Self {
+        Self::new(Charset::Utf8)
+    }
+}
+
+impl OutputEncoding {
+    /// Output in `charset` without BOM, keeping line endings.
+    pub fn new(charset: Charset) -> Self {
+        Self {
+            charset,
+            bom: false,
+            line_ending: None,
+            unmappable: UnmappablePolicy::default(),
+        }
+    }
+
+    /// Output in the charset called `name` (see [`Charset::from_name`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if the name is unknown.
+    pub fn from_name(name: &str) -> Result<Self> {
+        Charset::from_name(name)
+            .map(Self::new)
+            .ok_or_else(|| SubXError::config(format!("Unsupported output encoding: {}", name)))
+    }
+
+    /// Set whether a byte order mark is written.
+    pub fn with_bom(mut self, bom: bool) -> Self {
+        self.bom = bom;
+        self
+    }
+
+    /// Set the line ending style.
+    pub fn with_line_ending(mut self, line_ending: Option<LineEnding>) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    /// Set the handling of unrepresentable characters.
+    pub fn with_unmappable_policy(mut self, policy: UnmappablePolicy) -> Self {
+        self.unmappable = policy;
+        self
+    }
+
+    /// Whether writing with these settings leaves UTF-8 text unchanged.
+    pub fn is_plain_utf8(&self) -> bool {
+        self.charset == Charset::Utf8 && !self.bom && self.line_ending.is_none()
+    }
+
+    /// Encode `text` into the bytes of the output file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a BOM is requested for a non-Unicode charset,
+    /// the charset cannot be encoded, or a character is unrepresentable and
+    /// the policy is [`UnmappablePolicy::Fail`].
+    pub fn encode(&self, text: &str) -> Result<EncodedOutput> {
+        if self.bom && !self.charset.is_unicode() {
+            return Err(SubXError::config(format!(
+                "A byte order mark can only be written for Unicode encodings, not {:?}",
+                self.charset
+            )));
+        }
+        let text = self.normalize_line_endings(text);
+
+        let mut bytes = Vec::with_capacity(text.len() + 4);
+        if self.bom {
+            bytes.extend_from_slice(bom_bytes(&self.charset));
+        }
+        let mut unmappable = Vec::new();
+        match self.charset {
+            Charset::Utf8 => bytes.extend_from_slice(text.as_bytes()),
+            Charset::Utf16Le => bytes.extend(text.encode_utf16().flat_map(u16::to_le_bytes)),
+            Charset::Utf16Be => bytes.extend(text.encode_utf16().flat_map(u16::to_be_bytes)),
+            Charset::Utf32Le => bytes.extend(text.chars().flat_map(|c| (c as u32).to_le_bytes())),
+            Charset::Utf32Be => bytes.extend(text.chars().flat_map(|c| (c as u32).to_be_bytes())),
+            _ => unmappable = self.encode_legacy(&text, &mut bytes)?,
+        }
+
+        if !unmappable.is_empty() && self.unmappable == UnmappablePolicy::Fail {
+            let first = &unmappable[0];
+            return Err(SubXError::Other(anyhow!(
+                "Character '{}' on line {} cannot be represented in {:?} ({} unrepresentable character(s) in total)",
+                first.character,
+                first.line,
+                self.charset,
+                unmappable.len()
+            )));
+        }
+        Ok(EncodedOutput { bytes, unmappable })
+    }
+
+    /// Encode `text` and write it to `path`, returning warnings about
+    /// replaced characters.
+    ///
+    /// A link at `path` is replaced rather than written through (see
+    /// [`write_replacing`](crate::core::fs_util::write_replacing)).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if encoding fails (see [`OutputEncoding::encode`])
+    /// or the file cannot be written.
+    pub fn write(&self, path: &std::path::Path, text: &str) -> Result<Vec<String>> {
+        let encoded = self.encode(text)?;
+        crate::core::fs_util::write_replacing(path, &encoded.bytes)?;
+        Ok(encoded.warnings(&self.charset))
+    }
+
+    fn normalize_line_endings<'a>(&self, text: &'a str) -> std::borrow::Cow<'a, str> {
+        match self.line_ending {
+            None => text.into(),
+            Some(LineEnding::Lf) => text.replace("\r\n", "\n").into(),
+            Some(LineEnding::Crlf) => text.replace("\r\n", "\n").replace('\n', "\r\n").into(),
+        }
+    }
+
+    fn encode_legacy(&self, text: &str, out: &mut Vec<u8>) -> Result<Vec<UnmappableCharacter>> {
+        let encoding = EncodingConverter::new()
+            .encoding_for(&self.charset)
+            .ok_or_else(|| anyhow!("Unsupported output encoding: {:?}", self.charset))?;
+        let mut encoder = encoding.new_encoder();
+        let mut unmappable = Vec::new();
+        let mut line = 1;
+        let mut rest = text;
+        let mut buffer = vec![0u8; 4096];
+        loop {
+            let (result, read, written) =
+                encoder.encode_from_utf8_without_replacement(rest, &mut buffer, true);
+            out.extend_from_slice(&buffer[..written]);
+            line += rest[..read].matches('\n').count();
+            rest = &rest[read..];
+            match result {
+                EncoderResult::InputEmpty => break,
+                EncoderResult::OutputFull => continue,
+                EncoderResult::Unmappable(character) => {
+                    unmappable.push(UnmappableCharacter { line, character });
+                    out.push(b'?');
+                }
+            }
+        }
+        Ok(unmappable)
+    }
+}
+
+fn bom_bytes(charset: &Charset) -> &'static [u8] {
+    match charset {
+        Charset::Utf8 => &[0xEF, 0xBB, 0xBF],
+        Charset::Utf16Le => &[0xFF, 0xFE],
+        Charset::Utf16Be => &[0xFE, 0xFF],
+        Charset::Utf32Le => &[0xFF, 0xFE, 0x00, 0x00],
+        Charset::Utf32Be => &[0x00, 0x00, 0xFE, 0xFF],
+        _ => &[],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_utf8_bom_and_crlf() {
+        let output = OutputEncoding::new(Charset::Utf8)
+            .with_bom(true)
+            .with_line_ending(Some(LineEnding::Crlf));
+        let encoded = output.encode("a\nb\r\nc").unwrap();
+        assert_eq!(encoded.bytes, b"\xEF\xBB\xBFa\r\nb\r\nc");
+        assert!(!output.is_plain_utf8());
+        assert!(OutputEncoding::default().is_plain_utf8());
+    }
+
+    #[test]
+    fn test_legacy_encodings() {
+        let big5 = OutputEncoding::from_name("big5")
+            .unwrap()
+            .encode("你好")
+            .unwrap();
+        assert_eq!(big5.bytes, [0xA7, 0x41, 0xA6, 0x6E]);
+
+        let utf16 = OutputEncoding::new(Charset::Utf16Le)
+            .with_bom(true)
+            .encode("Hi")
+            .unwrap();
+        assert_eq!(utf16.bytes, [0xFF, 0xFE, b'H', 0, b'i', 0]);
+
+        assert!(OutputEncoding::from_name("klingon").is_err());
+        assert!(
+            OutputEncoding::new(Charset::Windows1252)
+                .with_bom(true)
+                .encode("x")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_unmappable_characters() {
+        let text = "Hello\nПривет ё\n";
+        let failing = OutputEncoding::new(Charset::Windows1252);
+        assert!(failing.encode(text).is_err());
+
+        let reporting = failing.with_unmappable_policy(UnmappablePolicy::Report);
+        let encoded = reporting.encode(text).unwrap();
+        assert_eq!(encoded.bytes, b"Hello\n?????? ?\n");
+        assert_eq!(encoded.unmappable.len(), 7);
+        assert_eq!(
+            encoded.unmappable[0],
+            UnmappableCharacter {
+                line: 2,
+                character: 'П'
+            }
+        );
+        let warnings = encoded.warnings(&Charset::Windows1252);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("'П' (line 2)"));
+        assert!(warnings[0].contains("and 2 more"));
+    }
+}
@@ -12,7 +12,9 @@
 //! let subtitle = manager.parse_auto(content).unwrap();
 //! ```
 
+use crate::core::formats::encoding::OutputEncoding;
 use crate::core::formats::{Subtitle, SubtitleFormat};
+use crate::core::language::TextLanguageIdentifier;
 use log::{info, warn};
 
 /// Manager for subtitle format detection and parser dispatch.
@@ -44,15 +46,23 @@ impl FormatManager {
 
     /// Auto-detect format and parse
     pub fn parse_auto(&self, content: &str) -> crate::Result<Subtitle> {
-        for fmt in &self.formats {
-            if fmt.detect(content) {
-                return fmt.parse(content);
-            }
+        match self.detect_format(content) {
+            Some(fmt) => fmt.parse(content),
+            None => Err(crate::error::SubXError::subtitle_format(
+                "Unknown",
+                "Unknown subtitle format",
+            )),
         }
-        Err(crate::error::SubXError::subtitle_format(
-            "Unknown",
-            "Unknown subtitle format",
-        ))
+    }
+
+    /// Get the first registered format whose detector accepts the content
+    ///
+    /// This is the parser `parse_auto` dispatches to.
+    pub fn detect_format(&self, content: &str) -> Option<&dyn SubtitleFormat> {
+        self.formats
+            .iter()
+            .find(|f| f.detect(content))
+            .map(|f| f.as_ref())
     }
 
     /// Get parser by format name
@@ -100,12 +110,22 @@ impl FormatManager {
     }
 
     /// Load subtitle from file with encoding detection and parsing
+    ///
+    /// When the file does not declare a language, `metadata.language` is
+    /// filled in from the cue text with [`TextLanguageIdentifier`], which
+    /// only reads a bounded sample of the cues.
     pub fn load_subtitle(&self, file_path: &std::path::Path) -> crate::Result<Subtitle> {
         let content =
             self.read_subtitle_with_encoding_detection(file_path.to_str().ok_or_else(|| {
                 crate::error::SubXError::subtitle_format("", "Invalid file path encoding")
             })?)?;
-        self.parse_auto(&content)
+        let mut subtitle = self.parse_auto(&content)?;
+        if subtitle.metadata.language.is_none() {
+            subtitle.metadata.language = TextLanguageIdentifier::new()
+                .identify_subtitle(&subtitle)
+                .map(|info| info.code);
+        }
+        Ok(subtitle)
     }
 
     /// Save subtitle to file in the same format as extension
@@ -114,13 +134,26 @@ impl FormatManager {
         subtitle: &Subtitle,
         file_path: &std::path::Path,
     ) -> crate::Result<()> {
+        self.save_subtitle_with_encoding(subtitle, file_path, &OutputEncoding::default())?;
+        Ok(())
+    }
+
+    /// Save subtitle to file in the same format as extension, encoded as
+    /// described by `encoding`.
+    ///
+    /// Returns warnings about characters that had to be replaced.
+    pub fn save_subtitle_with_encoding(
+        &self,
+        subtitle: &Subtitle,
+        file_path: &std::path::Path,
+        encoding: &OutputEncoding,
+    ) -> crate::Result<Vec<String>> {
         let ext = file_path.extension().and_then(|s| s.to_str()).unwrap_or("");
         let fmt = self.get_format_by_extension(ext).ok_or_else(|| {
             crate::error::SubXError::subtitle_format(ext, "Unsupported subtitle format for saving")
         })?;
         let out = fmt.serialize(subtitle)?;
-        std::fs::write(file_path, out)?;
-        Ok(())
+        encoding.write(file_path, &out)
     }
 }
 
@@ -158,6 +191,20 @@ mod tests {
         assert!(err.is_err());
     }
 
+    #[test]
+    fn test_load_subtitle_identifies_language_from_cues() {
+        let content = "1\n00:00:01,000 --> 00:00:02,000\nOù étais-tu hier soir ?\n\n\
+                       2\n00:00:03,000 --> 00:00:04,000\nJe t'ai attendu pendant des heures.\n";
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("movie.srt");
+        std::fs::write(&path, content).unwrap();
+
+        let mgr = FormatManager::new();
+        assert_eq!(mgr.parse_auto(content).unwrap().metadata.language, None);
+        let subtitle = mgr.load_subtitle(&path).expect("load_subtitle srt");
+        assert_eq!(subtitle.metadata.language.as_deref(), Some("fr"));
+    }
+
     #[test]
     fn test_webvtt_parse_auto_first_subtitle_content() {
         let mgr = FormatManager::new();
@@ -132,11 +132,16 @@
 #![allow(dead_code)]
 
 pub mod ass;
+pub mod bilingual;
 pub mod converter;
+pub mod diff;
 pub mod encoding;
 pub mod manager;
+pub mod sdh;
+pub mod split_join;
 /// SubRip Text (.srt) subtitle format support
 pub mod srt;
+pub mod stats;
 pub mod styling;
 pub mod sub;
 pub mod transformers;
@@ -0,0 +1,334 @@
+//! Hearing-impaired (SDH) annotation removal.
+//!
+//! This module provides [`SdhCleaner`], a text-cleanup transform over
+//! [`SubtitleEntry::text`](crate::core::formats::SubtitleEntry) that strips
+//! annotations intended for deaf and hard-of-hearing viewers, producing a
+//! plain dialogue track for non-SDH deliverables.
+//!
+//! Rules are grouped into per-language rule sets:
+//!
+//! - **English**: `[MUSIC]`, `(laughs)`, `♪` lyric lines and `JOHN:` speaker labels
+//! - **CJK**: full-width annotations such as `（笑）`, `【音樂】` and `〔拍手〕`
+//!
+//! Additional custom patterns can be appended. Cues whose text becomes empty
+//! are dropped, and every removal is recorded in an [`SdhCleanupReport`].
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use subx_cli::core::formats::sdh::{SdhCleaner, SdhLanguage};
+//!
+//! let cleaner = SdhCleaner::new(SdhLanguage::English);
+//! let report = cleaner.clean(&mut subtitle);
+//! println!("{} annotations removed", report.removals.len());
+//! ```
+
+use std::fmt;
+use std::time::Duration;
+
+use regex::Regex;
+
+use crate::core::formats::Subtitle;
+use crate::error::SubXError;
+
+/// Language rule set used for SDH annotation removal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SdhLanguage {
+    /// Apply every built-in rule set
+    #[default]
+    Auto,
+    /// English conventions: brackets, parentheses, lyrics and speaker labels
+    English,
+    /// CJK conventions: full-width parentheses and brackets
+    Cjk,
+}
+
+/// A single named cleanup rule.
+#[derive(Debug, Clone)]
+pub struct SdhRule {
+    /// Rule name shown in the removal report
+    pub name: String,
+    pattern: Regex,
+    replacement: &'static str,
+}
+
+impl SdhRule {
+    /// Create a rule from a regular expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if the pattern is not a valid regex.
+    pub fn new(name: impl Into<String>, pattern: &str) -> crate::Result<Self> {
+        let pattern = Regex::new(pattern)
+            .map_err(|e| SubXError::config(format!("Invalid SDH pattern '{}': {}", pattern, e)))?;
+        Ok(Self {
+            name: name.into(),
+            pattern,
+            replacement: "",
+        })
+    }
+
+    fn builtin(name: &str, pattern: &str) -> Self {
+        Self::new(name, pattern).expect("built-in SDH pattern must compile")
+    }
+
+    /// Replace matches with `replacement` (e.g. to keep a dialogue dash).
+    fn replacing(mut self, replacement: &'static str) -> Self {
+        self.replacement = replacement;
+        self
+    }
+}
+
+/// One piece of text removed from a cue.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SdhRemoval {
+    /// Index of the cue in the original subtitle
+    pub cue_index: usize,
+    /// Start time of the cue
+    pub start_time: Duration,
+    /// Name of the rule that matched
+    pub rule: String,
+    /// The removed text
+    pub text: String,
+}
+
+/// Summary of an SDH cleanup pass.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SdhCleanupReport {
+    /// Every annotation that was removed, in cue order
+    pub removals: Vec<SdhRemoval>,
+    /// Original indices of cues dropped because no text remained
+    pub dropped_cues: Vec<usize>,
+}
+
+impl SdhCleanupReport {
+    /// Whether the cleanup changed nothing.
+    pub fn is_empty(&self) -> bool {
+        self.removals.is_empty() && self.dropped_cues.is_empty()
+    }
+}
+
+impl fmt::Display for SdhCleanupReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for removal in &self.removals {
+            writeln!(
+                f,
+                "  #{} [{}] {:?}",
+                removal.cue_index, removal.rule, removal.text
+            )?;
+        }
+        write!(
+            f,
+            "  {} annotations removed, {} empty cues dropped",
+            self.removals.len(),
+            self.dropped_cues.len()
+        )
+    }
+}
+
+/// Removes SDH annotations from subtitle text using language rule sets.
+#[derive(Debug, Clone)]
+pub struct SdhCleaner {
+    rules: Vec<SdhRule>,
+}
+
+impl SdhCleaner {
+    /// Create a cleaner with the built-in rules for `language`.
+    pub fn new(language: SdhLanguage) -> Self {
+        // Lyric lines are marked the same way in every language
+        let mut rules = vec![SdhRule::builtin("lyrics", r"(?m)^.*[♪♫].*$")];
+        if matches!(language, SdhLanguage::Auto | SdhLanguage::English) {
+            rules.extend(english_rules());
+        }
+        if matches!(language, SdhLanguage::Auto | SdhLanguage::Cjk) {
+            rules.extend(cjk_rules());
+        }
+        Self { rules }
+    }
+
+    /// Append a custom rule after the built-in ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if the pattern is not a valid regex.
+    pub fn with_pattern(mut self, pattern: &str) -> crate::Result<Self> {
+        self.rules.push(SdhRule::new("custom", pattern)?);
+        Ok(self)
+    }
+
+    /// The active rules, in application order.
+    pub fn rules(&self) -> &[SdhRule] {
+        &self.rules
+    }
+
+    /// Clean a single text block, appending `(rule, removed text)` pairs.
+    ///
+    /// Text without any match is returned unchanged; otherwise whitespace
+    /// left behind by the removals is collapsed and empty lines are dropped.
+    fn clean_text(&self, text: &str, removals: &mut Vec<(String, String)>) -> String {
+        let mut current = text.to_string();
+        let mut changed = false;
+        for rule in &self.rules {
+            for m in rule.pattern.find_iter(&current) {
+                let removed = m.as_str().trim();
+                if !removed.is_empty() {
+                    removals.push((rule.name.clone(), removed.to_string()));
+                    changed = true;
+                }
+            }
+            current = rule
+                .pattern
+                .replace_all(&current, rule.replacement)
+                .into_owned();
+        }
+        if !changed {
+            return current;
+        }
+        current
+            .lines()
+            .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|line| !line.is_empty() && line != "-")
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Remove SDH annotations from every entry of `subtitle`.
+    ///
+    /// Entries whose text becomes empty are dropped and the remaining entries
+    /// are re-indexed.
+    pub fn clean(&self, subtitle: &mut Subtitle) -> SdhCleanupReport {
+        let mut report = SdhCleanupReport::default();
+        let entries = std::mem::take(&mut subtitle.entries);
+        for mut entry in entries {
+            let mut removals = Vec::new();
+            let cleaned = self.clean_text(&entry.text, &mut removals);
+            report
+                .removals
+                .extend(removals.into_iter().map(|(rule, text)| SdhRemoval {
+                    cue_index: entry.index,
+                    start_time: entry.start_time,
+                    rule,
+                    text,
+                }));
+            if cleaned.is_empty() {
+                report.dropped_cues.push(entry.index);
+                continue;
+            }
+            entry.text = cleaned;
+            subtitle.entries.push(entry);
+        }
+        for (i, entry) in subtitle.entries.iter_mut().enumerate() {
+            entry.index = i + 1;
+        }
+        report
+    }
+}
+
+fn english_rules() -> Vec<SdhRule> {
+    vec![
+        SdhRule::builtin("brackets", r"\[[^\]\n]*\]"),
+        SdhRule::builtin("parentheses", r"\([^)\n]*\)"),
+        SdhRule::builtin(
+            "speaker",
+            r"(?m)^(?P<dash>-\s*)?[A-Z][A-Z0-9 .'\-]*(?:\s*\([^)\n]*\))?:\s*",
+        )
+        .replacing("$dash"),
+    ]
+}
+
+fn cjk_rules() -> Vec<SdhRule> {
+    vec![
+        SdhRule::builtin("fullwidth-parentheses", r"（[^）\n]*）"),
+        SdhRule::builtin(
+            "fullwidth-brackets",
+            r"【[^】\n]*】|〔[^〕\n]*〕|［[^］\n]*］",
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::formats::{SubtitleEntry, SubtitleFormatType, SubtitleMetadata};
+
+    fn subtitle(texts: &[&str]) -> Subtitle {
+        let mut s = Subtitle::new(
+            SubtitleFormatType::Srt,
+            SubtitleMetadata::new(SubtitleFormatType::Srt),
+        );
+        s.entries = texts
+            .iter()
+            .enumerate()
+            .map(|(i, text)| {
+                SubtitleEntry::new(
+                    i + 1,
+                    Duration::from_secs(i as u64 * 5),
+                    Duration::from_secs(i as u64 * 5 + 2),
+                    text.to_string(),
+                )
+            })
+            .collect();
+        s
+    }
+
+    #[test]
+    fn test_english_rules_remove_annotations() {
+        let mut s = subtitle(&[
+            "[MUSIC PLAYING]",
+            "JOHN: Where were you?",
+            "(laughs) That's funny.",
+            "♪ Never gonna give you up ♪",
+            "- MARY: Hi.\n- (sighs) Hello.",
+        ]);
+        let report = SdhCleaner::new(SdhLanguage::English).clean(&mut s);
+        let texts: Vec<_> = s.entries.iter().map(|e| e.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec!["Where were you?", "That's funny.", "- Hi.\n- Hello."]
+        );
+        assert_eq!(report.dropped_cues, vec![1, 4]);
+        assert!(
+            report
+                .removals
+                .iter()
+                .any(|r| r.rule == "speaker" && r.text == "JOHN:")
+        );
+        assert_eq!(s.entries[0].index, 1);
+        assert_eq!(s.entries[2].index, 3);
+    }
+
+    #[test]
+    fn test_cjk_rules_remove_fullwidth_annotations() {
+        let mut s = subtitle(&["（笑）真的嗎？", "【音樂】", "你好"]);
+        let report = SdhCleaner::new(SdhLanguage::Cjk).clean(&mut s);
+        let texts: Vec<_> = s.entries.iter().map(|e| e.text.as_str()).collect();
+        assert_eq!(texts, vec!["真的嗎？", "你好"]);
+        assert_eq!(report.dropped_cues, vec![2]);
+        assert_eq!(report.removals.len(), 2);
+    }
+
+    #[test]
+    fn test_english_rules_keep_regular_dialogue() {
+        let mut s = subtitle(&["Meet me at 10:30.", "I said: no."]);
+        let report = SdhCleaner::new(SdhLanguage::English).clean(&mut s);
+        assert!(report.is_empty());
+        assert_eq!(s.entries[0].text, "Meet me at 10:30.");
+        assert_eq!(s.entries[1].text, "I said: no.");
+    }
+
+    #[test]
+    fn test_custom_pattern() {
+        let mut s = subtitle(&["<<door slams>> Run!"]);
+        let cleaner = SdhCleaner::new(SdhLanguage::English)
+            .with_pattern(r"<<[^>]*>>")
+            .unwrap();
+        let report = cleaner.clean(&mut s);
+        assert_eq!(s.entries[0].text, "Run!");
+        assert_eq!(report.removals[0].rule, "custom");
+        assert!(
+            SdhCleaner::new(SdhLanguage::Auto)
+                .with_pattern("(")
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,232 @@
+//! Splitting and joining of subtitle tracks.
+//!
+//! This module adds operations on [`Subtitle`] for two common multi-part
+//! situations:
+//!
+//! - **Join**: `CD1`/`CD2` subtitle pairs released for a single-file video are
+//!   appended into one track, shifting each later part by the running length
+//!   of the preceding parts.
+//! - **Split**: A double-episode subtitle is cut at a timestamp or cue index,
+//!   and the second half is rebased so that it starts at zero.
+//!
+//! All operations work on the unified data model and are therefore
+//! independent of the on-disk subtitle format.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use std::time::Duration;
+//! use subx_cli::core::formats::split_join::SplitPoint;
+//!
+//! cd1.append(&cd2, Duration::from_secs(52 * 60));
+//! let (first, second) = episode.split(SplitPoint::Time(Duration::from_secs(1320)))?;
+//! ```
+
+use std::time::Duration;
+
+use crate::core::formats::Subtitle;
+use crate::error::SubXError;
+
+/// Position at which a subtitle is split in two.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitPoint {
+    /// Split at a timestamp; cues starting at or after it go to the second part.
+    Time(Duration),
+    /// Split before the cue with this 1-based index, which starts the second part.
+    Index(usize),
+}
+
+impl Subtitle {
+    /// Append the entries of `other`, shifted later by `offset`.
+    ///
+    /// The offset is typically the duration of the video that belongs to
+    /// this subtitle. Entries are re-indexed after appending.
+    pub fn append(&mut self, other: &Subtitle, offset: Duration) {
+        self.entries
+            .extend(other.entries.iter().cloned().map(|mut entry| {
+                entry.start_time += offset;
+                entry.end_time += offset;
+                entry
+            }));
+        self.reindex();
+    }
+
+    /// Join several subtitle parts into one.
+    ///
+    /// `offsets[i]` is the length of part `i`, added to every later part, so
+    /// exactly `parts.len() - 1` offsets are required. The first part's
+    /// metadata and format are kept for the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no parts are given or the number of offsets does
+    /// not match the number of part boundaries.
+    pub fn join(parts: &[Subtitle], offsets: &[Duration]) -> crate::Result<Subtitle> {
+        let (first, rest) = parts
+            .split_first()
+            .ok_or_else(|| SubXError::CommandExecution("No subtitle parts to join".to_string()))?;
+        if offsets.len() != rest.len() {
+            return Err(SubXError::CommandExecution(format!(
+                "Joining {} parts requires {} offsets, got {}",
+                parts.len(),
+                rest.len(),
+                offsets.len()
+            )));
+        }
+        let mut joined = first.clone();
+        let mut total = Duration::ZERO;
+        for (part, offset) in rest.iter().zip(offsets) {
+            total += *offset;
+            joined.append(part, total);
+        }
+        Ok(joined)
+    }
+
+    /// Split the subtitle in two at the given point.
+    ///
+    /// The second part is rebased to zero: for [`SplitPoint::Time`] the split
+    /// time is subtracted, for [`SplitPoint::Index`] the start time of the
+    /// first cue of the second part is subtracted. A cue that spans a split
+    /// timestamp stays in the first part. Both parts are re-indexed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the split point would leave either part empty.
+    pub fn split(&self, point: SplitPoint) -> crate::Result<(Subtitle, Subtitle)> {
+        let (at, rebase) = match point {
+            SplitPoint::Time(time) => {
+                let at = self
+                    .entries
+                    .iter()
+                    .position(|e| e.start_time >= time)
+                    .unwrap_or(self.entries.len());
+                (at, time)
+            }
+            SplitPoint::Index(index) => {
+                let at = index.saturating_sub(1);
+                let rebase = self
+                    .entries
+                    .get(at)
+                    .map(|e| e.start_time)
+                    .unwrap_or_default();
+                (at, rebase)
+            }
+        };
+        if at == 0 || at >= self.entries.len() {
+            return Err(SubXError::CommandExecution(format!(
+                "Split point {:?} leaves one part empty ({} entries in total)",
+                point,
+                self.entries.len()
+            )));
+        }
+
+        let mut first = self.clone();
+        let mut second = self.clone();
+        second.entries = first.entries.split_off(at);
+        for entry in &mut second.entries {
+            entry.start_time = entry.start_time.saturating_sub(rebase);
+            entry.end_time = entry.end_time.saturating_sub(rebase);
+        }
+        first.reindex();
+        second.reindex();
+        Ok((first, second))
+    }
+
+    fn reindex(&mut self) {
+        for (i, entry) in self.entries.iter_mut().enumerate() {
+            entry.index = i + 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::formats::{SubtitleEntry, SubtitleFormatType, SubtitleMetadata};
+
+    fn subtitle(cues: &[(u64, u64)]) -> Subtitle {
+        let mut s = Subtitle::new(
+            SubtitleFormatType::Srt,
+            SubtitleMetadata::new(SubtitleFormatType::Srt),
+        );
+        s.entries = cues
+            .iter()
+            .enumerate()
+            .map(|(i, (start, end))| {
+                SubtitleEntry::new(
+                    i + 1,
+                    Duration::from_secs(*start),
+                    Duration::from_secs(*end),
+                    format!("line {}", i + 1),
+                )
+            })
+            .collect();
+        s
+    }
+
+    #[test]
+    fn test_append_shifts_and_reindexes() {
+        let mut cd1 = subtitle(&[(1, 2), (10, 12)]);
+        let cd2 = subtitle(&[(3, 4)]);
+        cd1.append(&cd2, Duration::from_secs(100));
+        assert_eq!(cd1.entries.len(), 3);
+        assert_eq!(cd1.entries[2].start_time, Duration::from_secs(103));
+        assert_eq!(cd1.entries[2].end_time, Duration::from_secs(104));
+        assert_eq!(cd1.entries[2].index, 3);
+    }
+
+    #[test]
+    fn test_join_accumulates_offsets() {
+        let parts = vec![
+            subtitle(&[(1, 2)]),
+            subtitle(&[(1, 2)]),
+            subtitle(&[(1, 2)]),
+        ];
+        let joined =
+            Subtitle::join(&parts, &[Duration::from_secs(60), Duration::from_secs(30)]).unwrap();
+        let starts: Vec<_> = joined
+            .entries
+            .iter()
+            .map(|e| e.start_time.as_secs())
+            .collect();
+        assert_eq!(starts, vec![1, 61, 91]);
+    }
+
+    #[test]
+    fn test_join_rejects_offset_count_mismatch() {
+        let parts = vec![subtitle(&[(1, 2)]), subtitle(&[(1, 2)])];
+        assert!(Subtitle::join(&parts, &[]).is_err());
+        assert!(Subtitle::join(&[], &[]).is_err());
+    }
+
+    #[test]
+    fn test_split_at_time_rebases_second_half() {
+        let s = subtitle(&[(1, 2), (5, 9), (10, 12), (20, 22)]);
+        let (first, second) = s.split(SplitPoint::Time(Duration::from_secs(8))).unwrap();
+        // The cue spanning 8s stays in the first part
+        assert_eq!(first.entries.len(), 2);
+        assert_eq!(second.entries.len(), 2);
+        assert_eq!(second.entries[0].start_time, Duration::from_secs(2));
+        assert_eq!(second.entries[1].start_time, Duration::from_secs(12));
+        assert_eq!(second.entries[0].index, 1);
+    }
+
+    #[test]
+    fn test_split_at_index_rebases_to_cue_start() {
+        let s = subtitle(&[(1, 2), (5, 9), (10, 12)]);
+        let (first, second) = s.split(SplitPoint::Index(3)).unwrap();
+        assert_eq!(first.entries.len(), 2);
+        assert_eq!(second.entries.len(), 1);
+        assert_eq!(second.entries[0].start_time, Duration::ZERO);
+        assert_eq!(second.entries[0].end_time, Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_split_rejects_empty_parts() {
+        let s = subtitle(&[(1, 2), (5, 9)]);
+        assert!(s.split(SplitPoint::Index(1)).is_err());
+        assert!(s.split(SplitPoint::Index(3)).is_err());
+        assert!(s.split(SplitPoint::Time(Duration::from_secs(30))).is_err());
+        assert!(s.split(SplitPoint::Time(Duration::ZERO)).is_err());
+    }
+}
@@ -0,0 +1,255 @@
+//! Timing and text statistics of a subtitle.
+//!
+//! This module computes the figures reported by `subx info`: cue count,
+//! first and last cue, total duration, overlapping cues, reading speed
+//! (characters per second) and line length distributions, and how many cues
+//! carry styling.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use subx_cli::core::formats::stats::SubtitleStats;
+//!
+//! let stats = SubtitleStats::from_subtitle(&subtitle);
+//! if let Some(cps) = &stats.cps {
+//!     println!("median reading speed: {:.1} CPS", cps.median);
+//! }
+//! ```
+
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+
+use crate::core::formats::{Subtitle, SubtitleEntry};
+
+/// Reading speed above which a cue is considered hard to read.
+pub const MAX_COMFORTABLE_CPS: f64 = 20.0;
+
+/// Line length above which a line is considered too long.
+pub const MAX_COMFORTABLE_LINE_LENGTH: usize = 42;
+
+/// Inline markup: HTML-like tags (SRT/VTT) and ASS override blocks.
+static MARKUP: Lazy<Regex> = Lazy::new(|| Regex::new(r"<[^>]*>|\{\\[^}]*\}").unwrap());
+
+/// Summary of a cue used to show the first and last cue.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CueSummary {
+    /// Index of the cue
+    pub index: usize,
+    /// Start time in milliseconds
+    pub start_ms: u64,
+    /// End time in milliseconds
+    pub end_ms: u64,
+    /// Cue text
+    pub text: String,
+}
+
+impl From<&SubtitleEntry> for CueSummary {
+    fn from(entry: &SubtitleEntry) -> Self {
+        Self {
+            index: entry.index,
+            start_ms: entry.start_time.as_millis() as u64,
+            end_ms: entry.end_time.as_millis() as u64,
+            text: entry.text.clone(),
+        }
+    }
+}
+
+/// Distribution of a set of measurements.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Distribution {
+    /// Smallest value
+    pub min: f64,
+    /// Arithmetic mean
+    pub mean: f64,
+    /// Median value
+    pub median: f64,
+    /// 95th percentile
+    pub p95: f64,
+    /// Largest value
+    pub max: f64,
+}
+
+impl Distribution {
+    /// Compute the distribution of `values`, or `None` if there are none.
+    pub fn from_values(mut values: Vec<f64>) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_by(f64::total_cmp);
+        let percentile = |p: f64| values[((values.len() - 1) as f64 * p).round() as usize];
+        Some(Self {
+            min: values[0],
+            mean: values.iter().sum::<f64>() / values.len() as f64,
+            median: percentile(0.5),
+            p95: percentile(0.95),
+            max: values[values.len() - 1],
+        })
+    }
+}
+
+/// Timing and text statistics of a subtitle.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SubtitleStats {
+    /// Number of cues
+    pub entry_count: usize,
+    /// First cue in file order
+    pub first_cue: Option<CueSummary>,
+    /// Last cue in file order
+    pub last_cue: Option<CueSummary>,
+    /// Time from the first cue start to the last cue end, in milliseconds
+    pub total_duration_ms: u64,
+    /// Number of cues that start before an earlier cue has ended
+    pub overlap_count: usize,
+    /// Reading speed in characters per second, over cues with a duration
+    pub cps: Option<Distribution>,
+    /// Number of cues faster than [`MAX_COMFORTABLE_CPS`]
+    pub fast_cues: usize,
+    /// Line length in characters, over all non-empty lines
+    pub line_length: Option<Distribution>,
+    /// Number of lines longer than [`MAX_COMFORTABLE_LINE_LENGTH`]
+    pub long_lines: usize,
+    /// Number of cues with styling information or inline markup
+    pub styled_cues: usize,
+}
+
+impl SubtitleStats {
+    /// Compute the statistics of `subtitle`.
+    pub fn from_subtitle(subtitle: &Subtitle) -> Self {
+        let entries = &subtitle.entries;
+
+        let mut cps_values = Vec::new();
+        let mut line_lengths = Vec::new();
+        let mut styled_cues = 0;
+        for entry in entries {
+            let text = visible_text(&entry.text);
+            let seconds = entry.duration().as_secs_f64();
+            if seconds > 0.0 {
+                let chars = text.chars().filter(|c| *c != '\n').count();
+                cps_values.push(chars as f64 / seconds);
+            }
+            line_lengths.extend(
+                text.lines()
+                    .map(|line| line.trim().chars().count())
+                    .filter(|len| *len > 0)
+                    .map(|len| len as f64),
+            );
+            let has_styling = entry.styling.as_ref().is_some_and(|s| s.has_any_styling());
+            if has_styling || MARKUP.is_match(&entry.text) {
+                styled_cues += 1;
+            }
+        }
+
+        Self {
+            entry_count: entries.len(),
+            first_cue: entries.first().map(CueSummary::from),
+            last_cue: entries.last().map(CueSummary::from),
+            total_duration_ms: subtitle.total_duration().as_millis() as u64,
+            overlap_count: count_overlaps(entries),
+            fast_cues: cps_values
+                .iter()
+                .filter(|cps| **cps > MAX_COMFORTABLE_CPS)
+                .count(),
+            cps: Distribution::from_values(cps_values),
+            long_lines: line_lengths
+                .iter()
+                .filter(|len| **len > MAX_COMFORTABLE_LINE_LENGTH as f64)
+                .count(),
+            line_length: Distribution::from_values(line_lengths),
+            styled_cues,
+        }
+    }
+}
+
+/// Text as displayed: markup removed and `\N` line breaks expanded.
+fn visible_text(text: &str) -> String {
+    MARKUP
+        .replace_all(text, "")
+        .replace("\\N", "\n")
+        .replace("\\n", "\n")
+}
+
+/// Count cues that start before the latest end time of the cues before them.
+fn count_overlaps(entries: &[SubtitleEntry]) -> usize {
+    let mut sorted: Vec<&SubtitleEntry> = entries.iter().collect();
+    sorted.sort_by_key(|e| e.start_time);
+    let mut latest_end = Duration::ZERO;
+    let mut overlaps = 0;
+    for entry in sorted {
+        if entry.start_time < latest_end {
+            overlaps += 1;
+        }
+        latest_end = latest_end.max(entry.end_time);
+    }
+    overlaps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::formats::{SubtitleFormatType, SubtitleMetadata};
+
+    fn subtitle(cues: &[(u64, u64, &str)]) -> Subtitle {
+        let mut s = Subtitle::new(
+            SubtitleFormatType::Srt,
+            SubtitleMetadata::new(SubtitleFormatType::Srt),
+        );
+        s.entries = cues
+            .iter()
+            .enumerate()
+            .map(|(i, (start, end, text))| {
+                SubtitleEntry::new(
+                    i + 1,
+                    Duration::from_millis(*start),
+                    Duration::from_millis(*end),
+                    text.to_string(),
+                )
+            })
+            .collect();
+        s
+    }
+
+    #[test]
+    fn test_stats_basic_figures() {
+        let s = subtitle(&[
+            (1000, 3000, "Hello there"),
+            (2500, 4000, "<i>Overlap</i>"),
+            (5000, 6000, "This line is much too fast to read comfortably"),
+        ]);
+        let stats = SubtitleStats::from_subtitle(&s);
+        assert_eq!(stats.entry_count, 3);
+        assert_eq!(stats.total_duration_ms, 5000);
+        assert_eq!(stats.overlap_count, 1);
+        assert_eq!(stats.styled_cues, 1);
+        assert_eq!(stats.fast_cues, 1);
+        assert_eq!(stats.long_lines, 1);
+        assert_eq!(stats.first_cue.unwrap().text, "Hello there");
+        assert_eq!(stats.last_cue.unwrap().index, 3);
+
+        let cps = stats.cps.unwrap();
+        assert!((cps.min - 7.0 / 1.5).abs() < 1e-9);
+        assert_eq!(cps.max, 46.0);
+        let lines = stats.line_length.unwrap();
+        assert_eq!(lines.min, 7.0);
+        assert_eq!(lines.median, 11.0);
+    }
+
+    #[test]
+    fn test_stats_empty_subtitle() {
+        let stats = SubtitleStats::from_subtitle(&subtitle(&[]));
+        assert_eq!(stats.entry_count, 0);
+        assert!(stats.first_cue.is_none());
+        assert!(stats.cps.is_none());
+        assert!(stats.line_length.is_none());
+    }
+
+    #[test]
+    fn test_ass_markup_is_not_counted() {
+        let s = subtitle(&[(0, 1000, r"{\i1}Hi{\i0}\Nthere")]);
+        let stats = SubtitleStats::from_subtitle(&s);
+        assert_eq!(stats.styled_cues, 1);
+        assert_eq!(stats.line_length.unwrap().max, 5.0);
+    }
+}
@@ -1,11 +1,13 @@
 //! Utility functions for filesystem operations with CIFS compatibility.
 //!
 //! Provides helpers to perform file copy operations that avoid POSIX metadata
-//! copy which may not be supported on CIFS (SMB) filesystems.
+//! copy which may not be supported on CIFS (SMB) filesystems, to link
+//! files for the symlink and hardlink relocation modes, and to replace files
+//! without writing through such links.
 
 use std::fs::File;
 use std::io::{self, copy};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Copies file contents from `source` to `destination` without copying metadata.
 ///
@@ -22,6 +24,125 @@ pub fn copy_file_cifs_safe(source: &Path, destination: &Path) -> io::Result<u64>
     copy(&mut src, &mut dst)
 }
 
+/// Creates a symbolic link at `link` pointing to `source`.
+///
+/// With `relative`, the link stores the path of `source` relative to the
+/// link's directory, so that both can be moved together (e.g. when a NAS
+/// share is mounted at another path). Otherwise the absolute path of
+/// `source` is stored.
+///
+/// # Errors
+///
+/// Returns an `io::Error` if the link cannot be created, for instance on
+/// filesystems without symlink support.
+pub fn symlink_file(source: &Path, link: &Path, relative: bool) -> io::Result<()> {
+    let source = std::path::absolute(source)?;
+    let pointer = if relative {
+        let link_dir = std::path::absolute(link)?
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        relative_path(&link_dir, &source)
+    } else {
+        source
+    };
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(pointer, link)
+    }
+    #[cfg(windows)]
+    {
+        std::os::windows::fs::symlink_file(pointer, link)
+    }
+}
+
+/// Creates a hard link at `target` to `source`, copying the file instead
+/// when the two paths are on different filesystems or the filesystem does
+/// not support hard links.
+///
+/// Returns `true` if a hard link was created and `false` if the file was
+/// copied.
+///
+/// # Errors
+///
+/// Returns an `io::Error` if neither linking nor copying succeeds.
+pub fn hard_link_or_copy(source: &Path, target: &Path) -> io::Result<bool> {
+    match std::fs::hard_link(source, target) {
+        Ok(()) => Ok(true),
+        Err(e)
+            if matches!(
+                e.kind(),
+                io::ErrorKind::CrossesDevices | io::ErrorKind::Unsupported
+            ) =>
+        {
+            copy_file_cifs_safe(source, target)?;
+            Ok(false)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Whether `a` and `b` name the same file: a symlink and its target, or on
+/// Unix two hard links to one inode. Paths that cannot be resolved are only
+/// the same when they are equal.
+pub fn same_file(a: &Path, b: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        if let (Ok(a), Ok(b)) = (std::fs::metadata(a), std::fs::metadata(b)) {
+            return (a.dev(), a.ino()) == (b.dev(), b.ino());
+        }
+    }
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+/// Hidden file next to `path` that its new content is written to before it
+/// replaces `path`.
+pub fn staging_path(path: &Path) -> PathBuf {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    path.with_file_name(format!(".{name}.subx-tmp"))
+}
+
+/// Replaces the file at `path` with `contents`.
+///
+/// The contents are written to [`staging_path`] and renamed over `path`, so
+/// a symlink or hard link at `path` is replaced by a regular file instead of
+/// changing the file it shares its content with.
+///
+/// # Errors
+///
+/// Returns an `io::Error` if the staging file cannot be written or renamed;
+/// the staging file is removed in that case.
+pub fn write_replacing(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let staging = staging_path(path);
+    let result = std::fs::write(&staging, contents).and_then(|()| std::fs::rename(&staging, path));
+    if result.is_err() {
+        let _ = std::fs::remove_file(&staging);
+    }
+    result
+}
+
+/// Path leading from directory `base` to `target`, both absolute.
+fn relative_path(base: &Path, target: &Path) -> PathBuf {
+    let base: Vec<_> = base.components().collect();
+    let target: Vec<_> = target.components().collect();
+    let common = base.iter().zip(&target).take_while(|(a, b)| a == b).count();
+    let mut path = PathBuf::new();
+    for _ in common..base.len() {
+        path.push("..");
+    }
+    for component in &target[common..] {
+        path.push(component);
+    }
+    path
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -41,4 +162,82 @@ mod tests {
         assert_eq!(copied, content);
         Ok(())
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlink_file_absolute_and_relative() -> io::Result<()> {
+        let temp = TempDir::new()?;
+        let archive = temp.path().join("archive");
+        let media = temp.path().join("media").join("Show");
+        fs::create_dir_all(&archive)?;
+        fs::create_dir_all(&media)?;
+        let source = archive.join("show.srt");
+        fs::write(&source, "subtitle")?;
+
+        let absolute = media.join("Show.S01E01.srt");
+        symlink_file(&source, &absolute, false)?;
+        assert_eq!(fs::read_link(&absolute)?, source);
+
+        let relative = media.join("Show.S01E01.en.srt");
+        symlink_file(&source, &relative, true)?;
+        assert_eq!(
+            fs::read_link(&relative)?,
+            PathBuf::from("../../archive/show.srt")
+        );
+        assert_eq!(fs::read_to_string(&relative)?, "subtitle");
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_replacing_breaks_links() -> io::Result<()> {
+        let temp = TempDir::new()?;
+        let original = temp.path().join("original.srt");
+        let symlink = temp.path().join("symlink.srt");
+        let hardlink = temp.path().join("hardlink.srt");
+        fs::write(&original, "original")?;
+        symlink_file(&original, &symlink, false)?;
+        fs::hard_link(&original, &hardlink)?;
+
+        write_replacing(&symlink, b"synced")?;
+        write_replacing(&hardlink, b"converted")?;
+        assert_eq!(fs::read_to_string(&original)?, "original");
+        assert!(!fs::symlink_metadata(&symlink)?.file_type().is_symlink());
+        assert_eq!(fs::read_to_string(&symlink)?, "synced");
+        assert_eq!(fs::read_to_string(&hardlink)?, "converted");
+        assert!(!staging_path(&symlink).exists());
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_same_file_follows_links() -> io::Result<()> {
+        let temp = TempDir::new()?;
+        let original = temp.path().join("original.srt");
+        let symlink = temp.path().join("symlink.srt");
+        let hardlink = temp.path().join("hardlink.srt");
+        let copy = temp.path().join("copy.srt");
+        fs::write(&original, "original")?;
+        symlink_file(&original, &symlink, true)?;
+        fs::hard_link(&original, &hardlink)?;
+        fs::write(&copy, "original")?;
+
+        assert!(same_file(&symlink, &original));
+        assert!(same_file(&hardlink, &symlink));
+        assert!(!same_file(&copy, &original));
+        assert!(!same_file(&temp.path().join("missing.srt"), &original));
+        Ok(())
+    }
+
+    #[test]
+    fn test_hard_link_or_copy_links_on_same_filesystem() -> io::Result<()> {
+        let temp = TempDir::new()?;
+        let source = temp.path().join("a.srt");
+        let target = temp.path().join("b.srt");
+        fs::write(&source, "subtitle")?;
+        assert!(hard_link_or_copy(&source, &target)?);
+        fs::write(&source, "changed")?;
+        assert_eq!(fs::read_to_string(&target)?, "changed");
+        Ok(())
+    }
 }
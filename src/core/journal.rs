@@ -0,0 +1,549 @@
+//! Persistent journal of the file changes made by SubX commands.
+//!
+//! Every file that `match`, `convert` or `sync` renames, moves, copies,
+//! writes or deletes is recorded in an append-only JSON Lines file next to
+//! the match cache (`~/.config/subx/journal.jsonl`), tagged with the id of the
+//! run that made the change, a timestamp and an MD5 hash of the content the
+//! run left behind. Content that a run overwrote or deleted is saved under
+//! `~/.config/subx/journal/<run id>/` first.
+//!
+//! [`Journal::undo`] reverts a run in reverse order. Before touching
+//! anything it checks that every file still holds the content the run left
+//! and that no file would be overwritten; if any check fails, nothing is
+//! changed.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use subx_cli::core::journal::Journal;
+//! use std::path::Path;
+//!
+//! let journal = Journal::start("convert")?;
+//! let previous = journal.preserve(Path::new("movie.vtt"))?;
+//! // ... write movie.vtt ...
+//! journal.record_written(Path::new("movie.vtt"), previous)?;
+//!
+//! // Later: revert the most recent run
+//! let runs = Journal::open_default()?.runs()?;
+//! if let Some(run) = runs.iter().rev().find(|run| !run.undone) {
+//!     Journal::open_default()?.undo(&run.id, false)?;
+//! }
+//! # Ok::<(), subx_cli::error::SubXError>(())
+//! ```
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Result;
+use crate::error::SubXError;
+
+/// What a run did to the file at a [`JournalEntry`]'s path.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum JournalAction {
+    /// `source` was renamed or moved to the path
+    Moved {
+        /// Original location
+        source: PathBuf,
+    },
+    /// `source` was copied to the path
+    Copied {
+        /// File the copy was made from
+        source: PathBuf,
+    },
+    /// The path was written; `previous` holds the content it replaced
+    Written {
+        /// Saved copy of the overwritten file, if there was one
+        previous: Option<PathBuf>,
+    },
+    /// The path was deleted after saving its content to `saved`
+    Removed {
+        /// Saved copy of the deleted file
+        saved: PathBuf,
+    },
+}
+
+/// One recorded file change.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// Run that made the change
+    pub run_id: String,
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    /// Command that made the change (`match`, `convert`, `sync`)
+    pub command: String,
+    /// File the change produced or deleted
+    pub path: PathBuf,
+    /// MD5 hash of the path's content after the change (`None` if removed)
+    pub hash: Option<String>,
+    /// The change itself
+    #[serde(flatten)]
+    pub action: JournalAction,
+}
+
+/// A line of the journal file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "record", rename_all = "snake_case")]
+enum JournalRecord {
+    Change(JournalEntry),
+    Undone { run_id: String, timestamp: u64 },
+}
+
+/// Summary of one run in the journal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JournalRun {
+    /// Run id
+    pub id: String,
+    /// Command of the run
+    pub command: String,
+    /// Time of the first change, in seconds since the Unix epoch
+    pub timestamp: u64,
+    /// Changes in the order they were made
+    pub entries: Vec<JournalEntry>,
+    /// Whether the run has been undone
+    pub undone: bool,
+}
+
+/// Reason a run cannot be undone safely.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UndoConflict {
+    /// File the problem concerns
+    pub path: PathBuf,
+    /// What is wrong with it
+    pub reason: String,
+}
+
+/// Append-only journal of file changes, writing to one run at a time.
+#[derive(Debug)]
+pub struct Journal {
+    path: PathBuf,
+    run_id: String,
+    command: String,
+    saved_files: AtomicUsize,
+}
+
+impl Journal {
+    /// Location of the journal file: `journal.jsonl` in the SubX config
+    /// directory, which honours `XDG_CONFIG_HOME` like the match cache.
+    pub fn default_path() -> Result<PathBuf> {
+        Ok(subx_config_dir()?.join("journal.jsonl"))
+    }
+
+    /// Start a new run of `command` in the default journal. Nothing is
+    /// written until the first change is recorded.
+    pub fn start(command: &str) -> Result<Self> {
+        Ok(Self::start_at(Self::default_path()?, command))
+    }
+
+    /// Start a new run of `command` in the journal at `path`.
+    pub fn start_at(path: PathBuf, command: &str) -> Self {
+        let id = uuid::Uuid::new_v4().simple().to_string();
+        Self {
+            path,
+            run_id: format!("{}-{}", now(), &id[..8]),
+            command: command.to_string(),
+            saved_files: AtomicUsize::new(0),
+        }
+    }
+
+    /// Open the default journal for reading and undoing runs.
+    pub fn open_default() -> Result<Self> {
+        Self::start("undo")
+    }
+
+    /// Id of the run this journal records into.
+    pub fn run_id(&self) -> &str {
+        &self.run_id
+    }
+
+    /// Save the current content of `path` before it is overwritten, so that
+    /// the change can be undone. Returns `None` if the file does not exist.
+    pub fn preserve(&self, path: &Path) -> Result<Option<PathBuf>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let dir = self.saved_dir(&self.run_id);
+        fs::create_dir_all(&dir)?;
+        let saved = dir.join(self.saved_files.fetch_add(1, Ordering::SeqCst).to_string());
+        fs::copy(path, &saved)?;
+        Ok(Some(saved))
+    }
+
+    /// Record that `source` was renamed or moved to `target`.
+    pub fn record_moved(&self, source: &Path, target: &Path) -> Result<()> {
+        self.append_change(
+            target,
+            JournalAction::Moved {
+                source: source.to_path_buf(),
+            },
+        )
+    }
+
+    /// Record that `source` was copied to `target`.
+    pub fn record_copied(&self, source: &Path, target: &Path) -> Result<()> {
+        self.append_change(
+            target,
+            JournalAction::Copied {
+                source: source.to_path_buf(),
+            },
+        )
+    }
+
+    /// Record that `path` was written, replacing the content saved by
+    /// [`preserve`](Self::preserve) if any.
+    pub fn record_written(&self, path: &Path, previous: Option<PathBuf>) -> Result<()> {
+        self.append_change(path, JournalAction::Written { previous })
+    }
+
+    /// Record that `path`, whose content was saved to `saved` by
+    /// [`preserve`](Self::preserve), is gone or about to be replaced.
+    pub fn record_removed(&self, path: &Path, saved: PathBuf) -> Result<()> {
+        self.append_change(path, JournalAction::Removed { saved })
+    }
+
+    /// Save `path` and delete it, recording the removal.
+    pub fn remove_file(&self, path: &Path) -> Result<()> {
+        let saved = self
+            .preserve(path)?
+            .ok_or_else(|| SubXError::FileNotFound(path.display().to_string()))?;
+        fs::remove_file(path)?;
+        self.record_removed(path, saved)
+    }
+
+    /// All runs in the journal, oldest first.
+    pub fn runs(&self) -> Result<Vec<JournalRun>> {
+        let content = match fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut runs: Vec<JournalRun> = Vec::new();
+        for line in content.lines().filter(|l| !l.trim().is_empty()) {
+            let record: JournalRecord = serde_json::from_str(line).map_err(|e| {
+                SubXError::FileOperationFailed(format!("Corrupt journal entry: {}", e))
+            })?;
+            match record {
+                JournalRecord::Change(entry) => {
+                    match runs.iter_mut().find(|run| run.id == entry.run_id) {
+                        Some(run) => run.entries.push(entry),
+                        None => runs.push(JournalRun {
+                            id: entry.run_id.clone(),
+                            command: entry.command.clone(),
+                            timestamp: entry.timestamp,
+                            entries: vec![entry],
+                            undone: false,
+                        }),
+                    }
+                }
+                JournalRecord::Undone { run_id, .. } => {
+                    if let Some(run) = runs.iter_mut().find(|run| run.id == run_id) {
+                        run.undone = true;
+                    }
+                }
+            }
+        }
+        Ok(runs)
+    }
+
+    /// Problems that prevent undoing `run`: files changed or deleted since
+    /// the run, and files an undo would overwrite.
+    pub fn check_undo(&self, run: &JournalRun) -> Vec<UndoConflict> {
+        let mut conflicts = Vec::new();
+        let conflict = |path: &Path, reason: &str| UndoConflict {
+            path: path.to_path_buf(),
+            reason: reason.to_string(),
+        };
+        // Paths the undo will have restored or freed by the time it gets to
+        // an entry, walking backwards through the run
+        let mut restored: Vec<&Path> = Vec::new();
+        let mut freed: Vec<&Path> = Vec::new();
+        for entry in run.entries.iter().rev() {
+            let path = entry.path.as_path();
+            match &entry.action {
+                JournalAction::Removed { saved } => {
+                    if path.exists() && !freed.contains(&path) {
+                        conflicts.push(conflict(path, "a file was created here since the run"));
+                    }
+                    if !saved.exists() {
+                        conflicts.push(conflict(path, "the saved copy is missing"));
+                    }
+                    restored.push(path);
+                }
+                action => {
+                    if !restored.contains(&path) {
+                        match hash_file(path) {
+                            Ok(hash) if Some(&hash) == entry.hash.as_ref() => {}
+                            Ok(_) => conflicts.push(conflict(path, "changed since the run")),
+                            Err(_) => conflicts.push(conflict(path, "missing")),
+                        }
+                    }
+                    match action {
+                        JournalAction::Moved { source } => {
+                            if source.exists() && !freed.contains(&source.as_path()) {
+                                conflicts.push(conflict(source, "would be overwritten"));
+                            }
+                            freed.push(path);
+                            restored.push(source);
+                        }
+                        JournalAction::Written {
+                            previous: Some(previous),
+                        } => {
+                            if !previous.exists() {
+                                conflicts.push(conflict(path, "the saved copy is missing"));
+                            }
+                            restored.push(path);
+                        }
+                        _ => freed.push(path),
+                    }
+                }
+            }
+        }
+        conflicts
+    }
+
+    /// Revert the run `run_id`, newest change first, and mark it undone.
+    ///
+    /// With `dry_run`, only checks and describes the steps. Returns the
+    /// description of each step.
+    ///
+    /// # Errors
+    ///
+    /// Fails without changing any file if the run is unknown or already
+    /// undone, or if [`check_undo`](Self::check_undo) finds a conflict.
+    pub fn undo(&self, run_id: &str, dry_run: bool) -> Result<Vec<String>> {
+        let runs = self.runs()?;
+        let run = runs
+            .iter()
+            .find(|run| run.id == run_id)
+            .ok_or_else(|| SubXError::CommandExecution(format!("Unknown run id: {}", run_id)))?;
+        if run.undone {
+            return Err(SubXError::CommandExecution(format!(
+                "Run {} has already been undone",
+                run_id
+            )));
+        }
+        let conflicts = self.check_undo(run);
+        if !conflicts.is_empty() {
+            let details: Vec<String> = conflicts
+                .iter()
+                .map(|c| format!("  {}: {}", c.path.display(), c.reason))
+                .collect();
+            return Err(SubXError::CommandExecution(format!(
+                "Refusing to undo run {}:\n{}",
+                run_id,
+                details.join("\n")
+            )));
+        }
+
+        let mut steps = Vec::new();
+        for entry in run.entries.iter().rev() {
+            let path = &entry.path;
+            let step = match &entry.action {
+                JournalAction::Moved { source } => {
+                    if !dry_run {
+                        if let Some(parent) = source.parent() {
+                            fs::create_dir_all(parent)?;
+                        }
+                        fs::rename(path, source)?;
+                    }
+                    format!("Move {} back to {}", path.display(), source.display())
+                }
+                JournalAction::Copied { .. } | JournalAction::Written { previous: None } => {
+                    if !dry_run {
+                        fs::remove_file(path)?;
+                    }
+                    format!("Delete {}", path.display())
+                }
+                JournalAction::Written {
+                    previous: Some(previous),
+                }
+                | JournalAction::Removed { saved: previous } => {
+                    if !dry_run {
+                        fs::copy(previous, path)?;
+                    }
+                    format!("Restore {}", path.display())
+                }
+            };
+            steps.push(step);
+        }
+        if !dry_run {
+            self.append(&JournalRecord::Undone {
+                run_id: run_id.to_string(),
+                timestamp: now(),
+            })?;
+        }
+        Ok(steps)
+    }
+
+    fn append_change(&self, path: &Path, action: JournalAction) -> Result<()> {
+        let hash = match action {
+            JournalAction::Removed { .. } => None,
+            _ => Some(hash_file(path)?),
+        };
+        self.append(&JournalRecord::Change(JournalEntry {
+            run_id: self.run_id.clone(),
+            timestamp: now(),
+            command: self.command.clone(),
+            path: absolute(path),
+            hash,
+            action: match action {
+                JournalAction::Moved { source } => JournalAction::Moved {
+                    source: absolute(&source),
+                },
+                JournalAction::Copied { source } => JournalAction::Copied {
+                    source: absolute(&source),
+                },
+                action => action,
+            },
+        }))
+    }
+
+    fn append(&self, record: &JournalRecord) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let line = serde_json::to_string(record).map_err(|e| {
+            SubXError::FileOperationFailed(format!("Failed to serialize journal entry: {}", e))
+        })?;
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", line)?;
+        Ok(())
+    }
+
+    fn saved_dir(&self, run_id: &str) -> PathBuf {
+        self.path.with_extension("").join(run_id)
+    }
+}
+
+/// The `subx` directory under the user's config directory, honouring
+/// `XDG_CONFIG_HOME`.
+pub(crate) fn subx_config_dir() -> Result<PathBuf> {
+    let dir = if let Some(xdg_config) = std::env::var_os("XDG_CONFIG_HOME") {
+        PathBuf::from(xdg_config)
+    } else {
+        dirs::config_dir()
+            .ok_or_else(|| SubXError::config("Unable to determine cache directory"))?
+    };
+    Ok(dir.join("subx"))
+}
+
+/// MD5 hash of a file's content in hex.
+pub fn hash_file(path: &Path) -> Result<String> {
+    Ok(format!("{:x}", md5::compute(fs::read(path)?)))
+}
+
+/// Format seconds since the Unix epoch as `YYYY-MM-DD HH:MM:SS UTC`.
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = (timestamp / 86_400) as i64;
+    let seconds = timestamp % 86_400;
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        seconds / 3_600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn journal(dir: &TempDir, command: &str) -> Journal {
+        Journal::start_at(dir.path().join("state").join("journal.jsonl"), command)
+    }
+
+    #[test]
+    fn test_undo_reverts_moves_writes_and_removals() {
+        let dir = TempDir::new().unwrap();
+        let original = dir.path().join("a.srt");
+        let moved = dir.path().join("Movie.srt");
+        let output = dir.path().join("Movie.vtt");
+        fs::write(&original, "subtitle").unwrap();
+        fs::write(&output, "old output").unwrap();
+
+        let run = journal(&dir, "match");
+        fs::rename(&original, &moved).unwrap();
+        run.record_moved(&original, &moved).unwrap();
+        let previous = run.preserve(&output).unwrap();
+        fs::write(&output, "new output").unwrap();
+        run.record_written(&output, previous).unwrap();
+        run.remove_file(&moved).unwrap();
+
+        let runs = run.runs().unwrap();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].entries.len(), 3);
+
+        let steps = run.undo(run.run_id(), false).unwrap();
+        assert_eq!(steps.len(), 3);
+        assert_eq!(fs::read_to_string(&original).unwrap(), "subtitle");
+        assert!(!moved.exists());
+        assert_eq!(fs::read_to_string(&output).unwrap(), "old output");
+        assert!(run.runs().unwrap()[0].undone);
+        assert!(run.undo(run.run_id(), false).is_err());
+    }
+
+    #[test]
+    fn test_undo_refuses_to_clobber_changed_files() {
+        let dir = TempDir::new().unwrap();
+        let source = dir.path().join("a.srt");
+        let target = dir.path().join("b.srt");
+        let copy = dir.path().join("c.srt");
+        fs::write(&source, "subtitle").unwrap();
+
+        let run = journal(&dir, "match");
+        fs::copy(&source, &copy).unwrap();
+        run.record_copied(&source, &copy).unwrap();
+        fs::rename(&source, &target).unwrap();
+        run.record_moved(&source, &target).unwrap();
+
+        // The copy was edited and a new file took the original name
+        fs::write(&copy, "edited").unwrap();
+        fs::write(&source, "new file").unwrap();
+
+        let conflicts = run.check_undo(&run.runs().unwrap()[0]);
+        assert_eq!(conflicts.len(), 2);
+        assert!(run.undo(run.run_id(), false).is_err());
+        assert_eq!(fs::read_to_string(&copy).unwrap(), "edited");
+        assert_eq!(fs::read_to_string(&source).unwrap(), "new file");
+        assert!(target.exists());
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00:00 UTC");
+        assert_eq!(format_timestamp(1_760_790_896), "2025-10-18 12:34:56 UTC");
+    }
+}
@@ -0,0 +1,74 @@
+//! Standard code forms for SubX language codes.
+//!
+//! SubX tags languages with short codes such as `en`, `ja`, `tc` and `sc`
+//! (Traditional and Simplified Chinese). Media servers expect standard
+//! forms instead: ISO 639-1 (`en`), ISO 639-2/B (`eng`) or BCP-47
+//! (`zh-Hant`).
+
+/// Standard forms of one language code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LanguageCodes {
+    /// Two-letter ISO 639-1 code (e.g. `en`, `zh`).
+    pub iso639_1: &'static str,
+    /// Three-letter ISO 639-2 bibliographic code (e.g. `eng`, `chi`).
+    pub iso639_2: &'static str,
+    /// BCP-47 tag, with a script subtag for Chinese (e.g. `zh-Hant`).
+    pub bcp47: &'static str,
+}
+
+/// SubX code, ISO 639-1, ISO 639-2/B, BCP-47.
+const CODE_TABLE: &[(&str, &str, &str, &str)] = &[
+    ("tc", "zh", "chi", "zh-Hant"),
+    ("sc", "zh", "chi", "zh-Hans"),
+    ("zh", "zh", "chi", "zh"),
+    ("en", "en", "eng", "en"),
+    ("ja", "ja", "jpn", "ja"),
+    ("ko", "ko", "kor", "ko"),
+    ("fr", "fr", "fre", "fr"),
+    ("de", "de", "ger", "de"),
+    ("es", "es", "spa", "es"),
+    ("it", "it", "ita", "it"),
+    ("pt", "pt", "por", "pt"),
+    ("nl", "nl", "dut", "nl"),
+    ("ru", "ru", "rus", "ru"),
+    ("uk", "uk", "ukr", "uk"),
+    ("el", "el", "gre", "el"),
+    ("he", "he", "heb", "he"),
+    ("ar", "ar", "ara", "ar"),
+    ("th", "th", "tha", "th"),
+];
+
+/// Standard code forms for a SubX language code, or `None` for codes
+/// outside the table.
+pub fn standard_codes(code: &str) -> Option<LanguageCodes> {
+    let code = code.to_lowercase();
+    CODE_TABLE
+        .iter()
+        .find(|(subx, ..)| *subx == code)
+        .map(|&(_, iso639_1, iso639_2, bcp47)| LanguageCodes {
+            iso639_1,
+            iso639_2,
+            bcp47,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chinese_scripts_map_to_bcp47_script_subtags() {
+        let tc = standard_codes("tc").unwrap();
+        assert_eq!(
+            (tc.iso639_1, tc.iso639_2, tc.bcp47),
+            ("zh", "chi", "zh-Hant")
+        );
+        assert_eq!(standard_codes("sc").unwrap().bcp47, "zh-Hans");
+    }
+
+    #[test]
+    fn test_unknown_code_has_no_standard_forms() {
+        assert_eq!(standard_codes("en").unwrap().iso639_2, "eng");
+        assert!(standard_codes("xx").is_none());
+    }
+}
@@ -0,0 +1,463 @@
+//! Language identification from subtitle text.
+//!
+//! [`TextLanguageIdentifier`] decides the language of a subtitle from its
+//! cue text when the file name and directories carry no language tag. It
+//! works in two stages:
+//!
+//! 1. **Script detection** counts letters per writing system. Kana marks
+//!    Japanese even among Han characters; Hangul, Cyrillic, Greek, Hebrew,
+//!    Arabic and Thai map directly to a language. Han text is split into
+//!    Traditional (`tc`) and Simplified (`sc`) Chinese by the characters that
+//!    exist in only one script.
+//! 2. **Trigram models** tell Latin-script languages apart (English, French,
+//!    German, Spanish, Italian, Portuguese and Dutch) by comparing the
+//!    character trigram profile of the text with profiles built from the
+//!    embedded `trigram_corpus.txt`.
+//!
+//! Codes follow the [`LanguageDetector`](super::LanguageDetector)
+//! conventions (`tc`, `sc`, `en`, ...).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use subx_cli::core::language::TextLanguageIdentifier;
+//!
+//! let identifier = TextLanguageIdentifier::new();
+//! let info = identifier
+//!     .identify("Where are you going? I told you to wait for me at the station.")
+//!     .unwrap();
+//! assert_eq!(info.code, "en");
+//!
+//! let info = identifier.identify("你這樣說話，他們會覺得很奇怪。").unwrap();
+//! assert_eq!(info.code, "tc");
+//! ```
+
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+
+use super::{LanguageInfo, LanguageSource};
+use crate::core::chinese::count_script_characters;
+use crate::core::formats::Subtitle;
+
+const TRIGRAM_CORPUS: &str = include_str!("trigram_corpus.txt");
+
+/// Weighted letters needed before a guess is made; a couple of words such
+/// as `Test subtitle` are too little to tell languages apart.
+const MIN_LETTERS: usize = 30;
+
+/// Characters of cue text looked at; more adds time but little accuracy.
+const MAX_SAMPLE_CHARS: usize = 20_000;
+
+/// Results below this confidence are not reported.
+const MIN_CONFIDENCE: f32 = 0.4;
+
+/// Share of kana among Han and kana characters from which text is Japanese.
+const KANA_SHARE_FOR_JAPANESE: f32 = 0.1;
+
+/// Trigram profiles of the Latin-script languages, built once.
+static MODELS: Lazy<Vec<TrigramModel>> = Lazy::new(|| parse_corpus(TRIGRAM_CORPUS));
+
+/// Writing systems recognized by script detection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Script {
+    Latin,
+    Han,
+    Kana,
+    Hangul,
+    Cyrillic,
+    Greek,
+    Hebrew,
+    Arabic,
+    Thai,
+}
+
+impl Script {
+    fn of(c: char) -> Option<Self> {
+        Some(match c as u32 {
+            0x41..=0x5A | 0x61..=0x7A | 0xC0..=0x24F => Self::Latin,
+            0x370..=0x3FF | 0x1F00..=0x1FFF => Self::Greek,
+            0x400..=0x4FF => Self::Cyrillic,
+            0x590..=0x5FF => Self::Hebrew,
+            0x600..=0x6FF | 0x750..=0x77F => Self::Arabic,
+            0xE00..=0xE7F => Self::Thai,
+            0x1100..=0x11FF | 0x3130..=0x318F | 0xAC00..=0xD7AF => Self::Hangul,
+            0x3040..=0x30FF | 0x31F0..=0x31FF | 0xFF66..=0xFF9F => Self::Kana,
+            0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF => Self::Han,
+            _ => return None,
+        })
+    }
+
+    /// Letters are counted per character; a CJK character carries about as
+    /// much text as a short word, so it weighs more.
+    fn weight(self) -> usize {
+        match self {
+            Self::Han | Self::Kana | Self::Hangul => 3,
+            _ => 1,
+        }
+    }
+}
+
+/// Character trigram frequencies of one language.
+#[derive(Debug)]
+struct TrigramModel {
+    code: String,
+    profile: Profile,
+}
+
+/// Normalized trigram frequency vector.
+#[derive(Debug, Default)]
+struct Profile {
+    frequencies: HashMap<[char; 3], f32>,
+}
+
+impl Profile {
+    /// Build a profile from the trigrams of each word padded with spaces, so
+    /// that word starts and endings (` th`, `ng `) are part of the model.
+    fn from_text(text: &str) -> Self {
+        let mut counts: HashMap<[char; 3], f32> = HashMap::new();
+        for word in text
+            .split(|c: char| !c.is_alphabetic() && c != '\'')
+            .filter(|w| !w.is_empty())
+        {
+            let chars: Vec<char> = std::iter::once(' ')
+                .chain(word.chars().flat_map(char::to_lowercase))
+                .chain(std::iter::once(' '))
+                .collect();
+            for window in chars.windows(3) {
+                *counts.entry([window[0], window[1], window[2]]).or_default() += 1.0;
+            }
+        }
+        let norm = counts.values().map(|v| v * v).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            for value in counts.values_mut() {
+                *value /= norm;
+            }
+        }
+        Self {
+            frequencies: counts,
+        }
+    }
+
+    /// Cosine similarity with another profile.
+    fn similarity(&self, other: &Profile) -> f32 {
+        let (small, large) = if self.frequencies.len() < other.frequencies.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        small
+            .frequencies
+            .iter()
+            .filter_map(|(trigram, a)| large.frequencies.get(trigram).map(|b| a * b))
+            .sum()
+    }
+}
+
+/// Identifies the language of subtitle text by script and trigram profile.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TextLanguageIdentifier;
+
+impl TextLanguageIdentifier {
+    /// Create a new identifier.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Identify the language of a subtitle from the text of its cues.
+    pub fn identify_subtitle(&self, subtitle: &Subtitle) -> Option<LanguageInfo> {
+        let mut text = String::new();
+        for entry in &subtitle.entries {
+            if text.len() >= MAX_SAMPLE_CHARS {
+                break;
+            }
+            text.push_str(&entry.text);
+            text.push('\n');
+        }
+        self.identify(&text)
+    }
+
+    /// Identify the language of a piece of text.
+    ///
+    /// Returns `None` when the text holds too few letters or the guess is
+    /// too uncertain.
+    /// The result's source is [`LanguageSource::Content`].
+    pub fn identify(&self, text: &str) -> Option<LanguageInfo> {
+        let text = strip_markup(text);
+        let mut counts: HashMap<Script, usize> = HashMap::new();
+        for script in text
+            .chars()
+            .filter(|c| c.is_alphabetic())
+            .filter_map(Script::of)
+        {
+            *counts.entry(script).or_default() += 1;
+        }
+        let weighted = |script: Script| counts.get(&script).copied().unwrap_or(0) * script.weight();
+        let total: usize = counts.iter().map(|(s, n)| n * s.weight()).sum();
+        if total < MIN_LETTERS {
+            return None;
+        }
+
+        // Han and kana form one group so that kanji-heavy Japanese stays Japanese
+        let cjk = weighted(Script::Han) + weighted(Script::Kana);
+        let (script, count) = counts
+            .keys()
+            .filter(|s| !matches!(s, Script::Han | Script::Kana))
+            .map(|&s| (s, weighted(s)))
+            .chain(std::iter::once((Script::Han, cjk)))
+            .max_by_key(|&(_, count)| count)?;
+        let share = count as f32 / total as f32;
+
+        let (code, confidence) = match script {
+            Script::Latin => identify_latin(&text)?,
+            Script::Han => identify_cjk(&text, &counts),
+            Script::Hangul => ("ko".to_string(), 1.0),
+            Script::Cyrillic => (identify_cyrillic(&text).to_string(), 0.9),
+            Script::Greek => ("el".to_string(), 1.0),
+            Script::Hebrew => ("he".to_string(), 1.0),
+            Script::Arabic => ("ar".to_string(), 0.9),
+            Script::Thai => ("th".to_string(), 1.0),
+            Script::Kana => unreachable!("kana is counted with Han"),
+        };
+        let confidence = (confidence * share).clamp(0.0, 1.0);
+        (confidence >= MIN_CONFIDENCE).then_some(LanguageInfo {
+            code,
+            source: LanguageSource::Content,
+            confidence,
+        })
+    }
+}
+
+/// Japanese when kana make up a noticeable share, otherwise Chinese in the
+/// script its distinctive characters point to (`zh` when there are none).
+fn identify_cjk(text: &str, counts: &HashMap<Script, usize>) -> (String, f32) {
+    let han = counts.get(&Script::Han).copied().unwrap_or(0);
+    let kana = counts.get(&Script::Kana).copied().unwrap_or(0);
+    if kana as f32 / (han + kana) as f32 >= KANA_SHARE_FOR_JAPANESE {
+        return ("ja".to_string(), 1.0);
+    }
+    let scripts = count_script_characters(text);
+    let distinctive = scripts.simplified + scripts.traditional;
+    if distinctive == 0 {
+        return ("zh".to_string(), 0.6);
+    }
+    let (code, count) = if scripts.traditional > scripts.simplified {
+        ("tc", scripts.traditional)
+    } else {
+        ("sc", scripts.simplified)
+    };
+    (code.to_string(), count as f32 / distinctive as f32)
+}
+
+/// Ukrainian has letters Russian lacks (`і`, `ї`, `є`, `ґ`) and the reverse
+/// (`ы`, `э`, `ъ`).
+fn identify_cyrillic(text: &str) -> &'static str {
+    let count = |letters: &[char]| {
+        text.chars()
+            .flat_map(char::to_lowercase)
+            .filter(|c| letters.contains(c))
+            .count()
+    };
+    if count(&['і', 'ї', 'є', 'ґ']) > count(&['ы', 'э', 'ъ']) {
+        "uk"
+    } else {
+        "ru"
+    }
+}
+
+/// Pick the trigram model closest to the text. Confidence reflects how far
+/// the best model is ahead of the runner-up.
+fn identify_latin(text: &str) -> Option<(String, f32)> {
+    let profile = Profile::from_text(text);
+    let mut scores: Vec<(&str, f32)> = MODELS
+        .iter()
+        .map(|model| (model.code.as_str(), model.profile.similarity(&profile)))
+        .collect();
+    scores.sort_by(|a, b| b.1.total_cmp(&a.1));
+    let (code, best) = *scores.first()?;
+    if best <= 0.0 {
+        return None;
+    }
+    let runner_up = scores.get(1).map_or(0.0, |s| s.1);
+    let margin = (best - runner_up) / best;
+    Some((code.to_string(), (0.5 + 2.0 * margin).min(1.0)))
+}
+
+/// Drop ASS override blocks (`{\an8}`), HTML-style tags (`<i>`) and ASS line
+/// breaks (`\N`) from cue text.
+fn strip_markup(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut closing = None;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (closing, c) {
+            (Some(end), c) if c == end => closing = None,
+            (Some(_), _) => {}
+            (None, '{') => closing = Some('}'),
+            (None, '<') => closing = Some('>'),
+            (None, '\\') if matches!(chars.peek(), Some('N' | 'n' | 'h')) => {
+                chars.next();
+                output.push(' ');
+            }
+            (None, c) => output.push(c),
+        }
+    }
+    output
+}
+
+/// Split the corpus into `[code]` sections and build one model per section.
+fn parse_corpus(corpus: &str) -> Vec<TrigramModel> {
+    let mut sections: Vec<(String, String)> = Vec::new();
+    for line in corpus.lines().filter(|line| !line.starts_with('#')) {
+        if let Some(code) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            sections.push((code.to_string(), String::new()));
+        } else if let Some((_, text)) = sections.last_mut() {
+            text.push_str(line);
+            text.push('\n');
+        }
+    }
+    sections
+        .into_iter()
+        .map(|(code, text)| TrigramModel {
+            code,
+            profile: Profile::from_text(&text),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::formats::{SubtitleEntry, SubtitleFormatType, SubtitleMetadata};
+    use std::time::Duration;
+
+    fn code(text: &str) -> Option<String> {
+        TextLanguageIdentifier::new().identify(text).map(|l| l.code)
+    }
+
+    #[test]
+    fn test_european_languages() {
+        let cases = [
+            (
+                "en",
+                "I don't want to go back there. Why are you always asking me the same thing?",
+            ),
+            (
+                "fr",
+                "Je ne veux pas y retourner. Pourquoi est-ce que tu me demandes toujours la même chose ?",
+            ),
+            (
+                "de",
+                "Ich will nicht dorthin zurück. Warum fragst du mich immer wieder dasselbe?",
+            ),
+            (
+                "es",
+                "No quiero volver allí. ¿Por qué siempre me preguntas lo mismo?",
+            ),
+            (
+                "it",
+                "Non voglio tornare lì. Perché mi chiedi sempre la stessa cosa?",
+            ),
+            (
+                "pt",
+                "Eu não quero voltar para lá. Por que você sempre me pergunta a mesma coisa?",
+            ),
+            (
+                "nl",
+                "Ik wil daar niet naar terug. Waarom vraag je me altijd hetzelfde?",
+            ),
+        ];
+        for (expected, text) in cases {
+            assert_eq!(code(text).as_deref(), Some(expected), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_chinese_scripts_and_japanese() {
+        assert_eq!(
+            code("我们现在就出发吧，这里太危险了。").as_deref(),
+            Some("sc")
+        );
+        assert_eq!(
+            code("我們現在就出發吧，這裡太危險了。").as_deref(),
+            Some("tc")
+        );
+        assert_eq!(
+            code("今日は本当にありがとうございました。また明日会いましょう。").as_deref(),
+            Some("ja")
+        );
+        // Kanji-heavy Japanese is still Japanese
+        assert_eq!(
+            code("東京駅で友人と待ち合わせをした。").as_deref(),
+            Some("ja")
+        );
+    }
+
+    #[test]
+    fn test_other_scripts() {
+        let cases = [
+            ("ko", "정말 고마워요. 내일 다시 만나요."),
+            (
+                "ru",
+                "Что ты здесь делаешь? Мы уже уходим, быстрее собирайся.",
+            ),
+            (
+                "uk",
+                "Що ти тут робиш? Ми вже їдемо додому, збирайся швидше.",
+            ),
+            (
+                "el",
+                "Τι κάνεις εδώ; Πρέπει να φύγουμε αμέσως από αυτό το σπίτι.",
+            ),
+            ("he", "מה אתה עושה כאן? אנחנו צריכים ללכת מהבית הזה עכשיו."),
+            (
+                "ar",
+                "ماذا تفعل هنا؟ يجب أن نذهب الآن قبل أن يعودوا إلى البيت.",
+            ),
+            ("th", "คุณทำอะไรอยู่ที่นี่ เราต้องไปแล้ว ก่อนที่พวกเขาจะกลับมา"),
+        ];
+        for (expected, text) in cases {
+            assert_eq!(code(text).as_deref(), Some(expected), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_too_little_text() {
+        assert_eq!(code(""), None);
+        assert_eq!(code("OK"), None);
+        assert_eq!(code("♪ ♪ 123"), None);
+    }
+
+    #[test]
+    fn test_markup_is_ignored() {
+        assert_eq!(strip_markup(r"{\an8}<i>Hello</i>\Nthere"), "Hello there");
+    }
+
+    #[test]
+    fn test_identify_subtitle_entries() {
+        let mut subtitle = Subtitle::new(
+            SubtitleFormatType::Srt,
+            SubtitleMetadata::new(SubtitleFormatType::Srt),
+        );
+        for (i, text) in [
+            "Où étais-tu hier soir ?",
+            "Je t'ai attendu pendant des heures.",
+        ]
+        .iter()
+        .enumerate()
+        {
+            subtitle.entries.push(SubtitleEntry::new(
+                i + 1,
+                Duration::from_secs(i as u64),
+                Duration::from_secs(i as u64 + 1),
+                text.to_string(),
+            ));
+        }
+        let info = TextLanguageIdentifier::new()
+            .identify_subtitle(&subtitle)
+            .unwrap();
+        assert_eq!(info.code, "fr");
+        assert_eq!(info.source, LanguageSource::Content);
+        assert!(info.confidence > 0.5);
+    }
+}
@@ -1,7 +1,10 @@
 //! Language detection module.
 //!
 //! Provides utilities to detect language codes from file paths and names,
-//! using directory names, filename patterns, and file extensions.
+//! using directory names, filename patterns, and file extensions, and
+//! [`TextLanguageIdentifier`] for identifying the language of subtitle text
+//! when the path carries no hint. [`standard_codes`] maps the detected
+//! codes to ISO 639 and BCP-47 forms.
 //!
 //! # Examples
 //!
@@ -17,6 +20,12 @@ use regex::Regex;
 use std::collections::HashMap;
 use std::path::Path;
 
+mod codes;
+mod identifier;
+
+pub use codes::{LanguageCodes, standard_codes};
+pub use identifier::TextLanguageIdentifier;
+
 /// Source of detected language information.
 #[derive(Debug, Clone, PartialEq)]
 pub enum LanguageSource {
@@ -26,6 +35,8 @@ pub enum LanguageSource {
     Filename,
     /// Derived from the file extension or naming convention.
     Extension,
+    /// Identified from the subtitle text itself.
+    Content,
 }
 impl Default for LanguageDetector {
     fn default() -> Self {
@@ -0,0 +1,345 @@
+//! Splitting large match batches into AI requests that fit a prompt budget.
+//!
+//! A single [`AnalysisRequest`](crate::services::ai::AnalysisRequest) that
+//! lists every file of a big library can exceed the model's context window.
+//! [`ChunkPlanner`] groups the files by parsed show and season (or by
+//! directory when the name carries no title), drops videos no subtitle could
+//! belong to, and packs the groups into chunks whose estimated prompt size
+//! stays within the budget. A group too large on its own is split, in which
+//! case one subtitle can appear in several chunks; the caller keeps its best
+//! match.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use subx_cli::core::matcher::chunking::estimate_tokens;
+//!
+//! assert_eq!(estimate_tokens("Show.S01E01.mkv"), 4);
+//! // CJK text costs about one token per character
+//! assert_eq!(estimate_tokens("進擊的巨人"), 5);
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::core::matcher::MediaFile;
+
+/// Default prompt budget for one analysis request, in estimated tokens.
+pub const DEFAULT_MAX_PROMPT_TOKENS: usize = 32_000;
+
+/// Estimated size of the fixed instructions and response format of an
+/// analysis prompt.
+const PROMPT_OVERHEAD_TOKENS: usize = 400;
+
+/// Rough token count of `text`: four ASCII characters or one other
+/// character per token.
+pub fn estimate_tokens(text: &str) -> usize {
+    let (ascii, other) = text.chars().fold((0usize, 0usize), |(ascii, other), c| {
+        if c.is_ascii() {
+            (ascii + 1, other)
+        } else {
+            (ascii, other + 1)
+        }
+    });
+    ascii.div_ceil(4) + other
+}
+
+/// Files sent together in one analysis request.
+#[derive(Debug, Clone, Default)]
+pub struct MatchChunk<'a> {
+    /// Candidate videos
+    pub videos: Vec<&'a MediaFile>,
+    /// Subtitles to pair with them
+    pub subtitles: Vec<&'a MediaFile>,
+}
+
+/// Plans the analysis requests for a batch of files.
+#[derive(Debug, Clone)]
+pub struct ChunkPlanner {
+    max_prompt_tokens: usize,
+}
+
+impl Default for ChunkPlanner {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_PROMPT_TOKENS)
+    }
+}
+
+impl ChunkPlanner {
+    /// Planner keeping each request within `max_prompt_tokens`.
+    pub fn new(max_prompt_tokens: usize) -> Self {
+        Self { max_prompt_tokens }
+    }
+
+    /// The prompt budget of one request.
+    pub fn max_prompt_tokens(&self) -> usize {
+        self.max_prompt_tokens
+    }
+
+    /// Split `videos` and `subtitles` into requests. `cost` estimates the
+    /// prompt tokens one file adds, including its content sample.
+    ///
+    /// A batch that fits the budget is returned as a single chunk unchanged.
+    pub fn plan<'a>(
+        &self,
+        videos: &[&'a MediaFile],
+        subtitles: &[&'a MediaFile],
+        cost: impl Fn(&MediaFile) -> usize,
+    ) -> Vec<MatchChunk<'a>> {
+        let total: usize = videos.iter().chain(subtitles).map(|f| cost(f)).sum();
+        if PROMPT_OVERHEAD_TOKENS + total <= self.max_prompt_tokens {
+            return vec![MatchChunk {
+                videos: videos.to_vec(),
+                subtitles: subtitles.to_vec(),
+            }];
+        }
+
+        // Group videos by show and season; subtitles join the group with
+        // the same key, else one holding a video from their directory
+        let mut groups: BTreeMap<String, MatchChunk<'a>> = BTreeMap::new();
+        for video in videos {
+            groups
+                .entry(group_key(video))
+                .or_default()
+                .videos
+                .push(video);
+        }
+        let mut orphans = Vec::new();
+        for subtitle in subtitles {
+            let key = group_key(subtitle);
+            let key = if groups.contains_key(&key) {
+                Some(key)
+            } else {
+                let directory = parent_directory(subtitle);
+                groups
+                    .iter()
+                    .find(|(_, group)| {
+                        group
+                            .videos
+                            .iter()
+                            .any(|v| parent_directory(v) == directory)
+                    })
+                    .map(|(key, _)| key.clone())
+            };
+            match key {
+                Some(key) => groups.get_mut(&key).unwrap().subtitles.push(subtitle),
+                None => orphans.push(*subtitle),
+            }
+        }
+
+        let mut groups: Vec<MatchChunk<'a>> = groups
+            .into_values()
+            .filter(|group| !group.subtitles.is_empty())
+            .collect();
+        // Subtitles that fit no group are compared with every video
+        if !orphans.is_empty() {
+            groups.push(MatchChunk {
+                videos: videos.to_vec(),
+                subtitles: orphans,
+            });
+        }
+
+        // Pack whole groups together while they fit, split the rest
+        let mut chunks: Vec<MatchChunk<'a>> = Vec::new();
+        let mut current = MatchChunk::default();
+        let mut current_cost = PROMPT_OVERHEAD_TOKENS;
+        for group in groups {
+            let group_cost: usize = group
+                .videos
+                .iter()
+                .chain(&group.subtitles)
+                .map(|f| cost(f))
+                .sum();
+            if PROMPT_OVERHEAD_TOKENS + group_cost > self.max_prompt_tokens {
+                chunks.extend(self.split(group, &cost));
+                continue;
+            }
+            if current_cost + group_cost > self.max_prompt_tokens {
+                chunks.push(std::mem::take(&mut current));
+                current_cost = PROMPT_OVERHEAD_TOKENS;
+            }
+            for video in group.videos {
+                if !current.videos.iter().any(|v| v.id == video.id) {
+                    current.videos.push(video);
+                }
+            }
+            current.subtitles.extend(group.subtitles);
+            current_cost += group_cost;
+        }
+        if !current.subtitles.is_empty() {
+            chunks.push(current);
+        }
+        chunks
+    }
+
+    /// Split a group that exceeds the budget: videos take at most half of
+    /// it, and every video slice is paired with subtitle slices filling the
+    /// rest.
+    fn split<'a>(
+        &self,
+        group: MatchChunk<'a>,
+        cost: &impl Fn(&MediaFile) -> usize,
+    ) -> Vec<MatchChunk<'a>> {
+        let budget = self
+            .max_prompt_tokens
+            .saturating_sub(PROMPT_OVERHEAD_TOKENS);
+        let video_slices = slices(&group.videos, budget / 2, cost);
+        let mut chunks = Vec::new();
+        for videos in video_slices {
+            let video_cost: usize = videos.iter().map(|v| cost(v)).sum();
+            for subtitles in slices(&group.subtitles, budget.saturating_sub(video_cost), cost) {
+                chunks.push(MatchChunk {
+                    videos: videos.clone(),
+                    subtitles,
+                });
+            }
+        }
+        chunks
+    }
+}
+
+/// Consecutive runs of `files` costing at most `budget` each; a file that
+/// exceeds the budget alone gets a run of its own.
+fn slices<'a>(
+    files: &[&'a MediaFile],
+    budget: usize,
+    cost: &impl Fn(&MediaFile) -> usize,
+) -> Vec<Vec<&'a MediaFile>> {
+    let mut slices = Vec::new();
+    let mut current = Vec::new();
+    let mut current_cost = 0;
+    for file in files {
+        let file_cost = cost(file);
+        if !current.is_empty() && current_cost + file_cost > budget {
+            slices.push(std::mem::take(&mut current));
+            current_cost = 0;
+        }
+        current.push(*file);
+        current_cost += file_cost;
+    }
+    if !current.is_empty() {
+        slices.push(current);
+    }
+    slices
+}
+
+/// Show title and season of a file, or its directory when the name has no
+/// title.
+pub(crate) fn group_key(file: &MediaFile) -> String {
+    let parsed = file.parsed_name();
+    let title = parsed.title_tokens().join(" ");
+    if title.is_empty() {
+        return format!("dir:{}", parent_directory(file));
+    }
+    match parsed.season {
+        Some(season) => format!("{title} s{season}"),
+        None => title,
+    }
+}
+
+fn parent_directory(file: &MediaFile) -> &str {
+    file.relative_path
+        .rsplit_once(['/', '\\'])
+        .map(|(dir, _)| dir)
+        .unwrap_or("")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::matcher::MediaFileType;
+    use std::path::Path;
+
+    fn media(relative_path: &str, file_type: MediaFileType) -> MediaFile {
+        MediaFile::for_tests(Path::new(""), relative_path, file_type)
+    }
+
+    fn library(shows: &[&str], episodes: u32) -> (Vec<MediaFile>, Vec<MediaFile>) {
+        let mut videos = Vec::new();
+        let mut subtitles = Vec::new();
+        for show in shows {
+            for episode in 1..=episodes {
+                videos.push(media(
+                    &format!("{show}/{show}.S01E{episode:02}.mkv"),
+                    MediaFileType::Video,
+                ));
+                subtitles.push(media(
+                    &format!("{show}/{show}.S01E{episode:02}.srt"),
+                    MediaFileType::Subtitle,
+                ));
+            }
+        }
+        (videos, subtitles)
+    }
+
+    #[test]
+    fn test_small_batch_is_one_chunk() {
+        let (videos, subtitles) = library(&["Alpha", "Beta"], 3);
+        let videos: Vec<_> = videos.iter().collect();
+        let subtitles: Vec<_> = subtitles.iter().collect();
+        let chunks = ChunkPlanner::default().plan(&videos, &subtitles, |_| 50);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].videos.len(), 6);
+        assert_eq!(chunks[0].subtitles.len(), 6);
+    }
+
+    #[test]
+    fn test_shows_are_packed_within_the_budget() {
+        let (videos, subtitles) = library(&["Alpha", "Beta", "Gamma"], 4);
+        let videos: Vec<_> = videos.iter().collect();
+        let subtitles: Vec<_> = subtitles.iter().collect();
+        // Each show costs 8 * 100, two fit next to the overhead, three don't
+        let chunks = ChunkPlanner::new(2100).plan(&videos, &subtitles, |_| 100);
+        assert_eq!(chunks.len(), 2);
+        for chunk in &chunks {
+            for subtitle in &chunk.subtitles {
+                let show = subtitle.relative_path.split('/').next().unwrap();
+                assert!(
+                    chunk
+                        .videos
+                        .iter()
+                        .any(|v| v.relative_path.starts_with(show))
+                );
+            }
+        }
+        let assigned: usize = chunks.iter().map(|c| c.subtitles.len()).sum();
+        assert_eq!(assigned, 12);
+    }
+
+    #[test]
+    fn test_oversized_group_is_split() {
+        let (videos, subtitles) = library(&["Alpha"], 40);
+        let videos: Vec<_> = videos.iter().collect();
+        let subtitles: Vec<_> = subtitles.iter().collect();
+        let planner = ChunkPlanner::new(2400);
+        let chunks = planner.plan(&videos, &subtitles, |_| 100);
+        assert!(chunks.len() > 1);
+        for chunk in &chunks {
+            let size = (chunk.videos.len() + chunk.subtitles.len()) * 100;
+            assert!(PROMPT_OVERHEAD_TOKENS + size <= planner.max_prompt_tokens());
+        }
+        // Every subtitle meets every video in some chunk
+        for subtitle in &subtitles {
+            for video in &videos {
+                assert!(chunks.iter().any(|c| {
+                    c.subtitles.iter().any(|s| s.id == subtitle.id)
+                        && c.videos.iter().any(|v| v.id == video.id)
+                }));
+            }
+        }
+    }
+
+    #[test]
+    fn test_unrelated_subtitles_are_compared_with_all_videos() {
+        let (videos, subtitles) = library(&["Alpha", "Beta"], 2);
+        let stray = media("downloads/episode.srt", MediaFileType::Subtitle);
+        let videos: Vec<_> = videos.iter().collect();
+        let subtitles = vec![&subtitles[0], &subtitles[1], &stray];
+        let chunks = ChunkPlanner::new(1000).plan(&videos, &subtitles, |_| 100);
+        assert_eq!(chunks.len(), 2);
+        // Beta has no subtitles, so only Alpha's videos are sent with them
+        assert_eq!(chunks[0].videos.len(), 2);
+        assert_eq!(chunks[1].subtitles.len(), 1);
+        assert_eq!(chunks[1].subtitles[0].id, stray.id);
+        assert_eq!(chunks[1].videos.len(), 4);
+    }
+}
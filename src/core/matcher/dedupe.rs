@@ -0,0 +1,256 @@
+//! Detection of duplicate subtitles next to a video.
+//!
+//! Re-running matching over a library that is already organised, or
+//! downloading the same subtitle twice, leaves several copies of one
+//! subtitle beside a video, typically as `Movie.en.srt` and
+//! `Movie.en.1.srt`. [`find_duplicates`] groups the subtitles named after a
+//! video that are duplicates of each other:
+//!
+//! - **byte-identical** files have the same content hash
+//! - **semantically identical** files of the same format parse to the same
+//!   cues, differing only in encoding, line endings, cue numbering or
+//!   whitespace
+//!
+//! Each [`DuplicateGroup`] keeps the file the current run just wrote, if it
+//! is in the group, and otherwise the file with the shortest name, which is
+//! the one without a numeric suffix. The others are listed for removal,
+//! except symlinks and hard links to the kept file and the file a kept link
+//! points to: those are the same file, not a copy of it.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use std::path::Path;
+//! use subx_cli::core::matcher::dedupe::find_duplicates;
+//!
+//! for group in find_duplicates(Path::new("Movie.mkv"), &[])? {
+//!     for duplicate in &group.duplicates {
+//!         println!("{} duplicates {}", duplicate.display(), group.keep.display());
+//!     }
+//! }
+//! # Ok::<(), subx_cli::error::SubXError>(())
+//! ```
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+use crate::Result;
+use crate::core::formats::manager::FormatManager;
+use crate::core::fs_util::same_file;
+use crate::core::journal::hash_file;
+use crate::core::matcher::{FileDiscovery, MediaFileType};
+
+/// Subtitles next to a video that duplicate each other.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicateGroup {
+    /// The copy to keep
+    pub keep: PathBuf,
+    /// The copies that can be removed
+    pub duplicates: Vec<PathBuf>,
+    /// Whether some copies are only semantically identical to the others
+    pub semantic: bool,
+}
+
+struct Candidate {
+    path: PathBuf,
+    hash: String,
+    cues: Option<u64>,
+}
+
+/// Group the subtitles in the directory of `video` whose names start with
+/// the video's stem into sets of duplicates. Subtitles without a duplicate
+/// are left out, and so are dangling symlinks. A group containing one of the
+/// `written` paths keeps that one. Paths resolving to the kept file are
+/// never listed as duplicates.
+pub fn find_duplicates(video: &Path, written: &[PathBuf]) -> Result<Vec<DuplicateGroup>> {
+    let (Some(dir), Some(stem)) = (video.parent(), video.file_stem()) else {
+        return Ok(Vec::new());
+    };
+    let dir = if dir.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        dir
+    };
+    let prefix = format!("{}.", stem.to_string_lossy());
+    let mut subtitles: Vec<PathBuf> = FileDiscovery::new()
+        .scan_directory(dir, false)?
+        .into_iter()
+        .filter(|file| matches!(file.file_type, MediaFileType::Subtitle))
+        .filter(|file| file.name.starts_with(&prefix))
+        .filter(|file| file.path.canonicalize().is_ok())
+        .map(|file| file.path)
+        .collect();
+    subtitles.sort_by_key(|path| {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        (name.len(), name.to_string())
+    });
+
+    let mut groups: Vec<Vec<Candidate>> = Vec::new();
+    for path in subtitles {
+        let candidate = Candidate {
+            hash: hash_file(&path)?,
+            cues: cue_fingerprint(&path),
+            path,
+        };
+        let same = |other: &Candidate| {
+            other.hash == candidate.hash
+                || (candidate.cues.is_some() && other.cues == candidate.cues)
+        };
+        match groups.iter_mut().find(|group| group.iter().any(same)) {
+            Some(group) => group.push(candidate),
+            None => groups.push(vec![candidate]),
+        }
+    }
+
+    Ok(groups
+        .into_iter()
+        .filter_map(|mut group| {
+            let index = group
+                .iter()
+                .position(|c| written.contains(&c.path))
+                .unwrap_or(0);
+            let keep = group.remove(index);
+            group.retain(|c| !same_file(&c.path, &keep.path));
+            if group.is_empty() {
+                return None;
+            }
+            Some(DuplicateGroup {
+                semantic: group.iter().any(|c| c.hash != keep.hash),
+                keep: keep.path,
+                duplicates: group.into_iter().map(|c| c.path).collect(),
+            })
+        })
+        .collect())
+}
+
+/// Fingerprint of the cues of the subtitle at `path` and its format, with
+/// line endings normalized and whitespace in the text collapsed, or `None`
+/// if it does not parse or has no cues.
+fn cue_fingerprint(path: &Path) -> Option<u64> {
+    let manager = FormatManager::new();
+    let content = manager
+        .read_subtitle_with_encoding_detection(path.to_str()?)
+        .ok()?;
+    let content = content.trim_start_matches('\u{feff}').replace("\r\n", "\n");
+    let subtitle = manager.parse_auto(&content).ok()?;
+    if subtitle.entries.is_empty() {
+        return None;
+    }
+    let mut hasher = DefaultHasher::new();
+    path.extension()
+        .map(|ext| ext.to_ascii_lowercase())
+        .hash(&mut hasher);
+    for entry in &subtitle.entries {
+        entry.start_time.as_millis().hash(&mut hasher);
+        entry.end_time.as_millis().hash(&mut hasher);
+        for word in entry.text.split_whitespace() {
+            word.hash(&mut hasher);
+        }
+    }
+    Some(hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    const CUES: &str = "1\n00:00:01,000 --> 00:00:02,000\nHello there\n\n\
+                        2\n00:00:03,000 --> 00:00:04,000\nGeneral Kenobi\n";
+
+    #[test]
+    fn test_find_duplicates_by_content_and_cues() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let video = temp.path().join("Movie.mkv");
+        fs::write(&video, b"video").unwrap();
+        fs::write(temp.path().join("Movie.en.srt"), CUES).unwrap();
+        fs::write(temp.path().join("Movie.en.1.srt"), CUES).unwrap();
+        // Same cues with CRLF line endings and extra spaces
+        let crlf = CUES
+            .replace('\n', "\r\n")
+            .replace("Hello there", "Hello  there");
+        fs::write(temp.path().join("Movie.en.2.srt"), crlf).unwrap();
+        // Different text, and a file named after another video
+        fs::write(
+            temp.path().join("Movie.fr.srt"),
+            CUES.replace("Hello", "Bonjour"),
+        )
+        .unwrap();
+        fs::write(temp.path().join("Other.en.srt"), CUES).unwrap();
+
+        let groups = find_duplicates(&video, &[]).unwrap();
+        assert_eq!(
+            groups,
+            vec![DuplicateGroup {
+                keep: temp.path().join("Movie.en.srt"),
+                duplicates: vec![
+                    temp.path().join("Movie.en.1.srt"),
+                    temp.path().join("Movie.en.2.srt"),
+                ],
+                semantic: true,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_find_duplicates_keeps_written_copy() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let video = temp.path().join("Movie.mkv");
+        fs::write(&video, b"video").unwrap();
+        fs::write(temp.path().join("Movie.en.srt"), CUES).unwrap();
+        fs::write(temp.path().join("Movie.en.1.srt"), CUES).unwrap();
+
+        let written = vec![temp.path().join("Movie.en.1.srt")];
+        let groups = find_duplicates(&video, &written).unwrap();
+        assert_eq!(groups[0].keep, written[0]);
+        assert_eq!(groups[0].duplicates, vec![temp.path().join("Movie.en.srt")]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_find_duplicates_treats_links_as_their_target() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let video = temp.path().join("Movie.mkv");
+        fs::write(&video, b"video").unwrap();
+        let original = temp.path().join("Movie.English.srt");
+        fs::write(&original, CUES).unwrap();
+        let symlink = temp.path().join("Movie.en.srt");
+        let hardlink = temp.path().join("Movie.en.1.srt");
+        crate::core::fs_util::symlink_file(&original, &symlink, true).unwrap();
+        fs::hard_link(&original, &hardlink).unwrap();
+        // A dangling link is not a candidate
+        crate::core::fs_util::symlink_file(
+            &temp.path().join("gone.srt"),
+            &temp.path().join("Movie.fr.srt"),
+            false,
+        )
+        .unwrap();
+
+        let written = vec![symlink];
+        assert!(find_duplicates(&video, &written).unwrap().is_empty());
+        assert!(find_duplicates(&video, &[hardlink]).unwrap().is_empty());
+
+        // A real copy is still removed, but never the linked original
+        let copy = temp.path().join("Movie.en.2.srt");
+        fs::write(&copy, CUES).unwrap();
+        let groups = find_duplicates(&video, &written).unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].keep, written[0]);
+        assert_eq!(groups[0].duplicates, vec![copy]);
+    }
+
+    #[test]
+    fn test_find_duplicates_keeps_distinct_subtitles() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let video = temp.path().join("Movie.mkv");
+        fs::write(&video, b"video").unwrap();
+        fs::write(temp.path().join("Movie.en.srt"), CUES).unwrap();
+        fs::write(
+            temp.path().join("Movie.en.1.srt"),
+            CUES.replace("00:00:04,000", "00:00:05,000"),
+        )
+        .unwrap();
+        assert!(find_duplicates(&video, &[]).unwrap().is_empty());
+    }
+}
@@ -17,6 +17,7 @@ use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
 use crate::Result;
+use crate::core::matcher::{FilenameAnalyzer, ParsedFilename};
 
 /// Media file record representing a discovered file.
 ///
@@ -39,6 +40,33 @@ pub struct MediaFile {
     /// Relative path from scan root for recursive matching
     pub relative_path: String,
 }
+
+impl MediaFile {
+    /// Release information parsed from the file name, with its relative
+    /// path filling in a season or title the name leaves out.
+    pub fn parsed_name(&self) -> ParsedFilename {
+        FilenameAnalyzer::new().parse_with_path(&self.name, &self.relative_path)
+    }
+}
+
+#[cfg(test)]
+impl MediaFile {
+    /// Media file at `relative_path` under `root` for tests, identified by
+    /// its relative path and without a size.
+    pub(crate) fn for_tests(root: &Path, relative_path: &str, file_type: MediaFileType) -> Self {
+        let name = relative_path.rsplit('/').next().unwrap_or_default();
+        Self {
+            id: relative_path.to_string(),
+            path: root.join(relative_path),
+            file_type,
+            size: 0,
+            name: name.to_string(),
+            extension: name.rsplit('.').next().unwrap_or_default().to_string(),
+            relative_path: relative_path.to_string(),
+        }
+    }
+}
+
 /// Generate a deterministic unique identifier for a media file
 ///
 /// Uses a fast hash algorithm combining the absolute path and file size to
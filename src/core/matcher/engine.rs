@@ -12,19 +12,51 @@
 //! let engine = MatchEngine::new(Box::new(DummyAI), config);
 //! ```
 
-use crate::services::ai::{AIProvider, AnalysisRequest, ContentSample, MatchResult};
+use crate::services::ai::local::LOCAL_MODEL_NAME;
+use crate::services::ai::{
+    AIProvider, AnalysisRequest, ContentSample, FileMatch, MatchResult, VerificationRequest,
+};
+use std::io::IsTerminal;
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 
 use crate::Result;
+use crate::config::Config;
+use crate::core::formats::encoding::OutputEncoding;
+use crate::core::formats::manager::FormatManager;
+use crate::core::fs_util::same_file;
+use crate::core::journal::{Journal, hash_file};
 use crate::core::language::LanguageDetector;
 use crate::core::matcher::cache::{CacheData, OpItem};
+use crate::core::matcher::chunking::{ChunkPlanner, MatchChunk, estimate_tokens, group_key};
+use crate::core::matcher::dedupe::find_duplicates;
 use crate::core::matcher::discovery::generate_file_id;
-use crate::core::matcher::{FileDiscovery, MediaFile, MediaFileType};
-use crate::core::parallel::{FileProcessingTask, ProcessingOperation, Task, TaskResult};
+use crate::core::matcher::naming::{NamingFields, NamingTemplate};
+use crate::core::matcher::ranking::{CandidateAssessment, CandidateSelection, rank_candidates};
+use crate::core::matcher::review::{ConflictDecision, ReviewPrompter, TerminalPrompter};
+use crate::core::matcher::speech_pattern::{PatternCorrelation, SpeechPattern};
+use crate::core::matcher::{FileDiscovery, HeuristicMatcher, MediaFile, MediaFileType};
+use crate::core::media_duration::{DurationFit, cues_end, format_hms, probe_media_duration};
+use crate::core::parallel::{
+    FileProcessingTask, ProcessingOperation, Task, TaskResult, TaskScheduler,
+};
 use crate::error::SubXError;
+use crate::services::vad::{LocalVadDetector, VadConfig};
+use async_trait::async_trait;
 use dirs;
 use serde_json;
 
+/// Minimum speech-pattern correlation for moving a subtitle to another video.
+const REASSIGN_MIN_CORRELATION: f32 = 0.3;
+
+/// How much better another video's speech must correlate before a subtitle
+/// is moved to it.
+const REASSIGN_MARGIN: f32 = 0.15;
+
+/// Minimum filename-heuristic score for the local pre-filter to settle a
+/// pair without asking the AI provider.
+pub const PREFILTER_CONFIDENCE: f32 = 0.9;
+
 /// File relocation mode for matched subtitle files
 #[derive(Debug, Clone, PartialEq)]
 pub enum FileRelocationMode {
@@ -34,6 +66,47 @@ pub enum FileRelocationMode {
     Copy,
     /// Move subtitle files to video folders
     Move,
+    /// Link video folders to the original subtitle files, which stay in place
+    Symlink {
+        /// Store the subtitle's path relative to the video folder
+        relative: bool,
+    },
+    /// Hard link subtitle files into video folders, copying them when the
+    /// folders are on another filesystem
+    Hardlink,
+}
+
+impl FileRelocationMode {
+    /// Whether the original subtitle file stays where it is.
+    pub fn keeps_source(&self) -> bool {
+        matches!(
+            self,
+            FileRelocationMode::Copy
+                | FileRelocationMode::Symlink { .. }
+                | FileRelocationMode::Hardlink
+        )
+    }
+
+    /// Whether the target is a link sharing the original's content, so
+    /// writing to it would change the original.
+    pub fn is_link(&self) -> bool {
+        matches!(
+            self,
+            FileRelocationMode::Symlink { .. } | FileRelocationMode::Hardlink
+        )
+    }
+
+    /// Verb describing the relocation, e.g. in previews; empty for `None`.
+    pub fn verb(&self) -> &'static str {
+        match self {
+            FileRelocationMode::None => "",
+            FileRelocationMode::Copy => "Copy",
+            FileRelocationMode::Move => "Move",
+            FileRelocationMode::Symlink { relative: false } => "Symlink",
+            FileRelocationMode::Symlink { relative: true } => "Relative symlink",
+            FileRelocationMode::Hardlink => "Hardlink",
+        }
+    }
 }
 
 /// Strategy for handling filename conflicts during relocation
@@ -45,6 +118,31 @@ pub enum ConflictResolution {
     AutoRename,
     /// Prompt user for decision (interactive mode only)
     Prompt,
+    /// Replace the existing file if its content is identical, otherwise
+    /// rename with numeric suffix
+    OverwriteIfIdentical,
+    /// Replace the existing file if the subtitle was modified more recently,
+    /// otherwise skip
+    KeepNewer,
+    /// Replace the existing file if the subtitle is larger, otherwise skip
+    KeepLarger,
+    /// Move the existing file to a `.backup` file, then replace it
+    BackupAndReplace,
+}
+
+/// What to do about the target of an operation, as decided by the
+/// [`ConflictResolution`] policy.
+#[derive(Debug, Clone, PartialEq)]
+enum ConflictOutcome {
+    /// Write to the path, which is free
+    Write(PathBuf),
+    /// Replace the existing file at `target`, first moving it to `backup`
+    Replace {
+        target: PathBuf,
+        backup: Option<PathBuf>,
+    },
+    /// Leave the subtitle alone, for the given reason
+    Skip(String),
 }
 
 /// Configuration settings for the file matching engine.
@@ -67,12 +165,40 @@ pub struct MatchConfig {
     pub conflict_resolution: ConflictResolution,
     /// AI model name used for analysis
     pub ai_model: String,
+    /// Re-encode renamed or copied subtitles, or `None` to keep them as is
+    pub output_encoding: Option<OutputEncoding>,
+    /// Template for the new subtitle file names
+    pub naming: NamingTemplate,
+    /// Width of the band below `confidence_threshold` whose AI matches are
+    /// re-checked with `verify_match` (0.0 disables the second pass)
+    pub verification_margin: f32,
+}
+
+#[cfg(test)]
+impl MatchConfig {
+    /// Configuration unit tests start from: no threshold, no content
+    /// analysis, no relocation, and conflicts skipped.
+    pub(crate) fn for_tests() -> Self {
+        Self {
+            confidence_threshold: 0.0,
+            max_sample_length: 0,
+            enable_content_analysis: false,
+            backup_enabled: false,
+            relocation_mode: FileRelocationMode::None,
+            conflict_resolution: ConflictResolution::Skip,
+            ai_model: "test-model".to_string(),
+            output_encoding: None,
+            naming: NamingTemplate::default(),
+            verification_margin: 0.0,
+        }
+    }
 }
 
 #[cfg(test)]
 mod language_name_tests {
     use super::*;
     use crate::core::matcher::discovery::{MediaFile, MediaFileType};
+    use crate::core::matcher::speech_pattern::dialogue;
     use crate::services::ai::{
         AIProvider, AnalysisRequest, ConfidenceScore, MatchResult, VerificationRequest,
     };
@@ -92,18 +218,7 @@ mod language_name_tests {
 
     #[test]
     fn test_generate_subtitle_name_with_directory_language() {
-        let engine = MatchEngine::new(
-            Box::new(DummyAI),
-            MatchConfig {
-                confidence_threshold: 0.0,
-                max_sample_length: 0,
-                enable_content_analysis: false,
-                backup_enabled: false,
-                relocation_mode: FileRelocationMode::None,
-                conflict_resolution: ConflictResolution::Skip,
-                ai_model: "test-model".to_string(),
-            },
-        );
+        let engine = MatchEngine::new(Box::new(DummyAI), MatchConfig::for_tests());
         let video = MediaFile {
             id: "".to_string(),
             relative_path: "".to_string(),
@@ -128,18 +243,7 @@ mod language_name_tests {
 
     #[test]
     fn test_generate_subtitle_name_with_filename_language() {
-        let engine = MatchEngine::new(
-            Box::new(DummyAI),
-            MatchConfig {
-                confidence_threshold: 0.0,
-                max_sample_length: 0,
-                enable_content_analysis: false,
-                backup_enabled: false,
-                relocation_mode: FileRelocationMode::None,
-                conflict_resolution: ConflictResolution::Skip,
-                ai_model: "test-model".to_string(),
-            },
-        );
+        let engine = MatchEngine::new(Box::new(DummyAI), MatchConfig::for_tests());
         let video = MediaFile {
             id: "".to_string(),
             relative_path: "".to_string(),
@@ -164,18 +268,7 @@ mod language_name_tests {
 
     #[test]
     fn test_generate_subtitle_name_without_language() {
-        let engine = MatchEngine::new(
-            Box::new(DummyAI),
-            MatchConfig {
-                confidence_threshold: 0.0,
-                max_sample_length: 0,
-                enable_content_analysis: false,
-                backup_enabled: false,
-                relocation_mode: FileRelocationMode::None,
-                conflict_resolution: ConflictResolution::Skip,
-                ai_model: "test-model".to_string(),
-            },
-        );
+        let engine = MatchEngine::new(Box::new(DummyAI), MatchConfig::for_tests());
         let video = MediaFile {
             id: "".to_string(),
             relative_path: "".to_string(),
@@ -198,19 +291,301 @@ mod language_name_tests {
         assert_eq!(new_name, "movie03.ass");
     }
     #[test]
-    fn test_generate_subtitle_name_removes_video_extension() {
+    fn test_generate_subtitle_name_with_content_language() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("subtitle04.srt"),
+            "1\n00:00:01,000 --> 00:00:03,000\nWarum hast du mir das nicht früher gesagt?\n\n\
+             2\n00:00:04,000 --> 00:00:06,000\nIch wollte dich nicht beunruhigen.\n\n",
+        )
+        .unwrap();
+        let engine = MatchEngine::new(Box::new(DummyAI), MatchConfig::for_tests());
+        let video = MediaFile::for_tests(temp.path(), "movie04.mp4", MediaFileType::Video);
+        let subtitle = MediaFile::for_tests(temp.path(), "subtitle04.srt", MediaFileType::Subtitle);
+        let new_name = engine.generate_subtitle_name(&video, &subtitle);
+        assert_eq!(new_name, "movie04.de.srt");
+    }
+    #[test]
+    fn test_naming_preset_numbers_colliding_targets() {
+        let temp = tempfile::TempDir::new().unwrap();
+        // An unrelated file already holds the first candidate name
+        std::fs::write(temp.path().join("Movie (2020).zh-Hant.srt"), "").unwrap();
         let engine = MatchEngine::new(
             Box::new(DummyAI),
             MatchConfig {
-                confidence_threshold: 0.0,
-                max_sample_length: 0,
-                enable_content_analysis: false,
-                backup_enabled: false,
-                relocation_mode: FileRelocationMode::None,
-                conflict_resolution: ConflictResolution::Skip,
-                ai_model: "test-model".to_string(),
+                conflict_resolution: ConflictResolution::AutoRename,
+                naming: "jellyfin".parse().unwrap(),
+                ..MatchConfig::for_tests()
             },
         );
+        let file = |name: &str, file_type| MediaFile::for_tests(temp.path(), name, file_type);
+        let video = file("Movie (2020).mkv", MediaFileType::Video);
+        let mut operations = vec![
+            engine.create_operation(
+                &video,
+                &file("movie.tc.srt", MediaFileType::Subtitle),
+                0.9,
+                Vec::new(),
+            ),
+            engine.create_operation(
+                &video,
+                &file("movie.cht.forced.srt", MediaFileType::Subtitle),
+                0.9,
+                Vec::new(),
+            ),
+            engine.create_operation(
+                &video,
+                &file("movie.big5.srt", MediaFileType::Subtitle),
+                0.9,
+                Vec::new(),
+            ),
+        ];
+        engine.assign_unique_names(&mut operations);
+        let names: Vec<_> = operations.iter().map(|op| &op.new_subtitle_name).collect();
+        assert_eq!(
+            names,
+            vec![
+                "Movie (2020).1.zh-Hant.srt",
+                "Movie (2020).zh-Hant.forced.srt",
+                "Movie (2020).2.zh-Hant.srt",
+            ]
+        );
+    }
+    #[test]
+    fn test_duration_check_rejects_overrunning_and_flags_short_subtitles() {
+        let temp = tempfile::TempDir::new().unwrap();
+        // Three seconds of silence stand in for the video
+        crate::services::vad::audio_loader::write_silent_wav(&temp.path().join("movie.wav"), 3);
+        let subtitle = |name: &str, end: &str| {
+            std::fs::write(
+                temp.path().join(name),
+                format!("1\n00:00:00,500 --> {}\nHello there.\n\n", end),
+            )
+            .unwrap();
+            MediaFile::for_tests(temp.path(), name, MediaFileType::Subtitle)
+        };
+        let video = MediaFile::for_tests(temp.path(), "movie.wav", MediaFileType::Video);
+        let engine =
+            MatchEngine::new(Box::new(DummyAI), MatchConfig::for_tests()).with_duration_check(true);
+        let mut operations: Vec<_> = [
+            subtitle("fits.srt", "00:00:02,500"),
+            subtitle("overruns.srt", "00:10:00,000"),
+            subtitle("short.srt", "00:00:01,000"),
+        ]
+        .iter()
+        .map(|sub| engine.create_operation(&video, sub, 0.9, Vec::new()))
+        .collect();
+        engine.check_durations(&mut operations);
+
+        let names: Vec<_> = operations
+            .iter()
+            .map(|op| op.subtitle_file.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["fits.srt", "short.srt"]);
+        assert!(operations[0].reasoning.is_empty());
+        assert!(operations[1].reasoning[0].contains("covering only 33%"));
+    }
+    #[test]
+    fn test_speech_reassignment_swaps_or_keeps_owned_videos() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let timestamp = |seconds: f64| {
+            let ms = (seconds * 1000.0) as u64;
+            format!(
+                "{:02}:{:02}:{:02},{:03}",
+                ms / 3_600_000,
+                ms / 60_000 % 60,
+                ms / 1000 % 60,
+                ms % 1000
+            )
+        };
+        let subtitle = |name: &str, seed: u64| {
+            let content: String = dialogue(seed, 200)
+                .iter()
+                .enumerate()
+                .map(|(i, (start, end))| {
+                    format!(
+                        "{}\n{} --> {}\nLine\n\n",
+                        i + 1,
+                        timestamp(*start),
+                        timestamp(*end)
+                    )
+                })
+                .collect();
+            std::fs::write(temp.path().join(name), content).unwrap();
+            MediaFile::for_tests(temp.path(), name, MediaFileType::Subtitle)
+        };
+        let video = |name: &str| MediaFile::for_tests(temp.path(), name, MediaFileType::Video);
+        let (ep1, ep2) = (video("ep1.mkv"), video("ep2.mkv"));
+        let speech: std::collections::HashMap<_, _> = [
+            (
+                ep1.id.clone(),
+                SpeechPattern::from_intervals(dialogue(1, 200)),
+            ),
+            (
+                ep2.id.clone(),
+                SpeechPattern::from_intervals(dialogue(2, 200)),
+            ),
+        ]
+        .into();
+        let (sub1, sub2, copy1) = (
+            subtitle("a.srt", 1),
+            subtitle("b.srt", 2),
+            subtitle("c.srt", 1),
+        );
+        let engine = MatchEngine::new(Box::new(DummyAI), MatchConfig::for_tests());
+        let assigned = |operations: &[MatchOperation]| {
+            operations
+                .iter()
+                .map(|op| (op.subtitle_file.name.clone(), op.video_file.name.clone()))
+                .collect::<Vec<_>>()
+        };
+        let pair = |a: &str, b: &str| (a.to_string(), b.to_string());
+
+        // Crossed matches swap
+        let mut operations = vec![
+            engine.create_operation(&ep2, &sub1, 0.9, Vec::new()),
+            engine.create_operation(&ep1, &sub2, 0.9, Vec::new()),
+        ];
+        let candidates = vec![vec![&ep1, &ep2]; 2];
+        engine.reassign_by_speech(&mut operations, &candidates, &speech);
+        assert_eq!(
+            assigned(&operations),
+            vec![pair("a.srt", "ep1.mkv"), pair("b.srt", "ep2.mkv")]
+        );
+
+        // A subtitle moves to a video nothing else matched
+        let mut operations = vec![engine.create_operation(&ep2, &sub1, 0.9, Vec::new())];
+        engine.reassign_by_speech(&mut operations, &candidates[..1], &speech);
+        assert_eq!(assigned(&operations), vec![pair("a.srt", "ep1.mkv")]);
+
+        // A video whose own subtitle fits it is not taken away
+        let mut operations = vec![
+            engine.create_operation(&ep2, &sub1, 0.9, Vec::new()),
+            engine.create_operation(&ep1, &copy1, 0.9, Vec::new()),
+        ];
+        engine.reassign_by_speech(&mut operations, &candidates, &speech);
+        assert_eq!(
+            assigned(&operations),
+            vec![pair("a.srt", "ep2.mkv"), pair("c.srt", "ep1.mkv")]
+        );
+        assert!(operations[0].reasoning[0].contains("is matched to c.srt"));
+
+        // A subtitle in another language does not hold the video
+        let (english, french) = (subtitle("a.en.srt", 1), subtitle("c.fr.srt", 1));
+        let mut operations = vec![
+            engine.create_operation(&ep2, &english, 0.9, Vec::new()),
+            engine.create_operation(&ep1, &french, 0.9, Vec::new()),
+        ];
+        engine.reassign_by_speech(&mut operations, &candidates, &speech);
+        assert_eq!(
+            assigned(&operations),
+            vec![pair("a.en.srt", "ep1.mkv"), pair("c.fr.srt", "ep1.mkv")]
+        );
+    }
+    #[test]
+    fn test_speech_candidates_stay_within_show_and_season() {
+        let file =
+            |name: &str, file_type| MediaFile::for_tests(std::path::Path::new(""), name, file_type);
+        let videos = [
+            file("Show.S01E01.mkv", MediaFileType::Video),
+            file("Show.S01E02.mkv", MediaFileType::Video),
+            file("Show.S02E01.mkv", MediaFileType::Video),
+            file("Other.S01E01.mkv", MediaFileType::Video),
+        ];
+        let keyed: Vec<_> = videos.iter().map(|v| (v, group_key(v))).collect();
+        let engine = MatchEngine::new(Box::new(DummyAI), MatchConfig::for_tests());
+        let op = engine.create_operation(
+            &videos[0],
+            &file("Show.S01E02.srt", MediaFileType::Subtitle),
+            0.9,
+            Vec::new(),
+        );
+        let names: Vec<_> = speech_candidates(&keyed, &op)
+            .iter()
+            .map(|v| v.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Show.S01E01.mkv", "Show.S01E02.mkv"]);
+    }
+    #[test]
+    fn test_candidate_selection_names_by_rank_or_keeps_best() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let cues = |count: usize| {
+            (0..count)
+                .map(|i| {
+                    format!(
+                        "{}\n00:00:{:02},000 --> 00:00:{:02},500\nWhere are you going tonight?\n\n",
+                        i + 1,
+                        i * 3,
+                        i * 3 + 2
+                    )
+                })
+                .collect::<String>()
+        };
+        let file = |name: &str, file_type| MediaFile::for_tests(temp.path(), name, file_type);
+        let video = file("Show.S01E01.1080p.WEB-DL-NTb.mkv", MediaFileType::Video);
+        let names = [
+            ("Show.S01E01.partial.srt", 2),
+            ("Show.S01E01.720p.HDTV-KILLERS.srt", 10),
+            ("Show.S01E01.1080p.WEB-DL-NTb.srt", 10),
+        ];
+        for (name, count) in names {
+            std::fs::write(temp.path().join(name), cues(count)).unwrap();
+        }
+
+        for (selection, expected) in [
+            (
+                CandidateSelection::Ranked,
+                vec![
+                    "Show.S01E01.1080p.WEB-DL-NTb.en.srt",
+                    "Show.S01E01.1080p.WEB-DL-NTb.en.1.srt",
+                    "Show.S01E01.1080p.WEB-DL-NTb.en.2.srt",
+                ],
+            ),
+            (
+                CandidateSelection::Best,
+                vec!["Show.S01E01.1080p.WEB-DL-NTb.en.srt"],
+            ),
+        ] {
+            let engine = MatchEngine::new(
+                Box::new(DummyAI),
+                MatchConfig {
+                    conflict_resolution: ConflictResolution::AutoRename,
+                    ..MatchConfig::for_tests()
+                },
+            );
+            let mut operations: Vec<_> = names
+                .iter()
+                .map(|(name, _)| {
+                    engine.create_operation(
+                        &video,
+                        &file(name, MediaFileType::Subtitle),
+                        0.9,
+                        Vec::new(),
+                    )
+                })
+                .collect();
+            engine.select_candidates(
+                &mut operations,
+                &std::collections::HashMap::new(),
+                selection,
+            );
+            engine.assign_unique_names(&mut operations);
+
+            let targets: Vec<_> = operations
+                .iter()
+                .map(|op| op.new_subtitle_name.as_str())
+                .collect();
+            assert_eq!(targets, expected);
+            assert_eq!(
+                operations[0].subtitle_file.name,
+                "Show.S01E01.1080p.WEB-DL-NTb.srt"
+            );
+            assert!(operations[0].reasoning[0].starts_with("Candidate rank 1 of 3"));
+        }
+    }
+    #[test]
+    fn test_generate_subtitle_name_removes_video_extension() {
+        let engine = MatchEngine::new(Box::new(DummyAI), MatchConfig::for_tests());
         let video = MediaFile {
             id: "".to_string(),
             relative_path: "".to_string(),
@@ -235,18 +610,7 @@ mod language_name_tests {
 
     #[test]
     fn test_generate_subtitle_name_with_language_removes_video_extension() {
-        let engine = MatchEngine::new(
-            Box::new(DummyAI),
-            MatchConfig {
-                confidence_threshold: 0.0,
-                max_sample_length: 0,
-                enable_content_analysis: false,
-                backup_enabled: false,
-                relocation_mode: FileRelocationMode::None,
-                conflict_resolution: ConflictResolution::Skip,
-                ai_model: "test-model".to_string(),
-            },
-        );
+        let engine = MatchEngine::new(Box::new(DummyAI), MatchConfig::for_tests());
         let video = MediaFile {
             id: "".to_string(),
             relative_path: "".to_string(),
@@ -271,18 +635,7 @@ mod language_name_tests {
 
     #[test]
     fn test_generate_subtitle_name_edge_cases() {
-        let engine = MatchEngine::new(
-            Box::new(DummyAI),
-            MatchConfig {
-                confidence_threshold: 0.0,
-                max_sample_length: 0,
-                enable_content_analysis: false,
-                backup_enabled: false,
-                relocation_mode: FileRelocationMode::None,
-                conflict_resolution: ConflictResolution::Skip,
-                ai_model: "test-model".to_string(),
-            },
-        );
+        let engine = MatchEngine::new(Box::new(DummyAI), MatchConfig::for_tests());
         // File name contains multiple dots and no extension case
         let video = MediaFile {
             id: "".to_string(),
@@ -323,18 +676,7 @@ mod language_name_tests {
         .unwrap();
 
         // Create a test MatchEngine
-        let engine = MatchEngine::new(
-            Box::new(DummyAI),
-            MatchConfig {
-                confidence_threshold: 0.0,
-                max_sample_length: 0,
-                enable_content_analysis: false,
-                backup_enabled: false,
-                relocation_mode: FileRelocationMode::None,
-                conflict_resolution: ConflictResolution::Skip,
-                ai_model: "test-model".to_string(),
-            },
-        );
+        let engine = MatchEngine::new(Box::new(DummyAI), MatchConfig::for_tests());
 
         // Create a MatchOperation
         let subtitle_file = MediaFile {
@@ -402,18 +744,7 @@ mod language_name_tests {
         .unwrap();
 
         // Create a test MatchEngine
-        let engine = MatchEngine::new(
-            Box::new(DummyAI),
-            MatchConfig {
-                confidence_threshold: 0.0,
-                max_sample_length: 0,
-                enable_content_analysis: false,
-                backup_enabled: false,
-                relocation_mode: FileRelocationMode::None,
-                conflict_resolution: ConflictResolution::Skip,
-                ai_model: "test-model".to_string(),
-            },
-        );
+        let engine = MatchEngine::new(Box::new(DummyAI), MatchConfig::for_tests());
 
         // Create a MatchOperation
         let subtitle_file = MediaFile {
@@ -479,6 +810,109 @@ mod language_name_tests {
         // Because we cannot easily simulate the scenario where the file system operation succeeds but the file does not exist
     }
 
+    #[tokio::test]
+    async fn test_conflict_policies_replace_skip_or_back_up() {
+        use std::fs;
+        use tempfile::TempDir;
+
+        async fn run(policy: ConflictResolution, source: &str, existing: &str) -> TempDir {
+            let temp = TempDir::new().unwrap();
+            let subtitle = temp.path().join("download.srt");
+            fs::write(&subtitle, source).unwrap();
+            fs::write(temp.path().join("Movie.srt"), existing).unwrap();
+            let engine = MatchEngine::new(
+                Box::new(DummyAI),
+                MatchConfig {
+                    conflict_resolution: policy,
+                    ..MatchConfig::for_tests()
+                },
+            );
+            let media = |name: &str, file_type| MediaFile::for_tests(temp.path(), name, file_type);
+            let op = MatchOperation {
+                video_file: media("Movie.mkv", MediaFileType::Video),
+                subtitle_file: media("download.srt", MediaFileType::Subtitle),
+                new_subtitle_name: "Movie.srt".to_string(),
+                confidence: 95.0,
+                reasoning: vec![],
+                requires_relocation: false,
+                relocation_target_path: None,
+                relocation_mode: FileRelocationMode::None,
+            };
+            engine.execute_operations(&[op], false).await.unwrap();
+            temp
+        }
+        let read = |temp: &TempDir, name: &str| fs::read_to_string(temp.path().join(name)).ok();
+
+        let temp = run(ConflictResolution::OverwriteIfIdentical, "same", "same").await;
+        assert_eq!(read(&temp, "Movie.srt").as_deref(), Some("same"));
+        assert_eq!(read(&temp, "Movie.1.srt"), None);
+        assert_eq!(read(&temp, "download.srt"), None);
+
+        let temp = run(ConflictResolution::OverwriteIfIdentical, "new", "old").await;
+        assert_eq!(read(&temp, "Movie.srt").as_deref(), Some("old"));
+        assert_eq!(read(&temp, "Movie.1.srt").as_deref(), Some("new"));
+
+        let temp = run(ConflictResolution::KeepLarger, "new", "older").await;
+        assert_eq!(read(&temp, "Movie.srt").as_deref(), Some("older"));
+        assert_eq!(read(&temp, "download.srt").as_deref(), Some("new"));
+
+        let temp = run(ConflictResolution::KeepLarger, "newest", "old").await;
+        assert_eq!(read(&temp, "Movie.srt").as_deref(), Some("newest"));
+
+        let temp = run(ConflictResolution::Skip, "new", "old").await;
+        assert_eq!(read(&temp, "Movie.srt").as_deref(), Some("old"));
+        assert_eq!(read(&temp, "download.srt").as_deref(), Some("new"));
+
+        let temp = run(ConflictResolution::BackupAndReplace, "new", "old").await;
+        assert_eq!(read(&temp, "Movie.srt").as_deref(), Some("new"));
+        assert_eq!(read(&temp, "Movie.srt.backup").as_deref(), Some("old"));
+    }
+
+    #[tokio::test]
+    async fn test_backup_replace_restores_target_when_operation_fails() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let existing = temp.path().join("Movie.srt");
+        std::fs::write(&existing, "old").unwrap();
+        let engine = MatchEngine::new(
+            Box::new(DummyAI),
+            MatchConfig {
+                conflict_resolution: ConflictResolution::BackupAndReplace,
+                ..MatchConfig::for_tests()
+            },
+        );
+        let media = |name: &str, file_type| MediaFile::for_tests(temp.path(), name, file_type);
+        // The subtitle disappeared after matching, so the rename fails
+        let op = MatchOperation {
+            video_file: media("Movie.mkv", MediaFileType::Video),
+            subtitle_file: media("download.srt", MediaFileType::Subtitle),
+            new_subtitle_name: "Movie.srt".to_string(),
+            confidence: 95.0,
+            reasoning: vec![],
+            requires_relocation: false,
+            relocation_target_path: None,
+            relocation_mode: FileRelocationMode::None,
+        };
+        assert!(engine.execute_operations(&[op], false).await.is_err());
+        assert_eq!(std::fs::read_to_string(&existing).unwrap(), "old");
+        assert!(!temp.path().join("Movie.srt.backup").exists());
+    }
+
+    #[test]
+    fn test_request_entry_includes_parsed_release_fields() {
+        let video = MediaFile {
+            id: "file_1".to_string(),
+            ..MediaFile::for_tests(
+                std::path::Path::new(""),
+                "Show/Show.S01E02.1080p.WEB-DL.x264-GRP.mkv",
+                MediaFileType::Video,
+            )
+        };
+        assert_eq!(
+            request_entry(&video),
+            "ID:file_1 | Name:Show.S01E02.1080p.WEB-DL.x264-GRP.mkv | Path:Show/Show.S01E02.1080p.WEB-DL.x264-GRP.mkv | Parsed:title=Show; episode=S01E02; resolution=1080p; source=WEB-DL; codec=H.264; group=GRP"
+        );
+    }
+
     #[test]
     fn test_file_operation_message_format() {
         // Test error message format
@@ -571,23 +1005,119 @@ pub struct MatchOperation {
     pub requires_relocation: bool,
 }
 
+impl MatchOperation {
+    /// Where the subtitle ends up once the operation is executed.
+    pub fn target_path(&self) -> PathBuf {
+        match &self.relocation_target_path {
+            Some(target) if self.requires_relocation => target.clone(),
+            _ => self
+                .subtitle_file
+                .path
+                .with_file_name(&self.new_subtitle_name),
+        }
+    }
+}
+
 /// Engine for matching video and subtitle files using AI analysis.
 pub struct MatchEngine {
-    ai_client: Box<dyn AIProvider>,
+    ai_client: Arc<dyn AIProvider>,
     discovery: FileDiscovery,
     config: MatchConfig,
+    local_prefilter: bool,
+    audio_verification: Option<VadConfig>,
+    candidate_selection: Option<CandidateSelection>,
+    duration_check: bool,
+    chunk_planner: ChunkPlanner,
+    /// Settings of the [`TaskScheduler`] running AI chunks and audio analysis
+    scheduler_config: Config,
+    journal: Option<Journal>,
+    /// Languages identified from subtitle cue text, keyed by subtitle path
+    content_languages: Mutex<std::collections::HashMap<PathBuf, Option<String>>>,
+    /// Subtitle files written by `execute_operations`
+    written: Mutex<Vec<PathBuf>>,
 }
 
 impl MatchEngine {
     /// Creates a new `MatchEngine` with the given AI provider and configuration.
     pub fn new(ai_client: Box<dyn AIProvider>, config: MatchConfig) -> Self {
         Self {
-            ai_client,
+            ai_client: Arc::from(ai_client),
             discovery: FileDiscovery::new(),
             config,
+            local_prefilter: false,
+            audio_verification: None,
+            candidate_selection: None,
+            duration_check: false,
+            chunk_planner: ChunkPlanner::default(),
+            scheduler_config: Config::default(),
+            journal: None,
+            content_languages: Mutex::new(std::collections::HashMap::new()),
+            written: Mutex::new(Vec::new()),
         }
     }
 
+    /// Accept confident filename-heuristic matches before querying the AI
+    /// provider, which then only sees the subtitles left unmatched.
+    ///
+    /// A pair is accepted locally when its [`HeuristicMatcher`] score reaches
+    /// both the confidence threshold and [`PREFILTER_CONFIDENCE`].
+    pub fn with_local_prefilter(mut self, enabled: bool) -> Self {
+        self.local_prefilter = enabled;
+        self
+    }
+
+    /// Check every match against the videos' speech, detected with these
+    /// VAD settings, and move a subtitle to another video of its show and
+    /// season when its cues follow that video's dialogue clearly better.
+    ///
+    /// The [`SpeechPattern`] correlation is recorded in each operation's
+    /// reasoning. `None` (the default) skips the audio analysis.
+    pub fn with_audio_verification(mut self, vad: Option<VadConfig>) -> Self {
+        self.audio_verification = vad;
+        self
+    }
+
+    /// Rank subtitles that would land on the same target name for one video
+    /// (see [`CandidateAssessment`]) and keep the best one, or all of them
+    /// named in rank order. `None` (the default) keeps them in the order the
+    /// matcher returned them.
+    pub fn with_candidate_selection(mut self, selection: Option<CandidateSelection>) -> Self {
+        self.candidate_selection = selection;
+        self
+    }
+
+    /// Compare where each matched subtitle's cues end with the video's
+    /// duration, read from the container metadata: matches running well past
+    /// the end of the video are dropped, and subtitles covering less than
+    /// half of it are flagged in the reasoning. See [`DurationFit`].
+    pub fn with_duration_check(mut self, enabled: bool) -> Self {
+        self.duration_check = enabled;
+        self
+    }
+
+    /// Keep each analysis request within `max_prompt_tokens` (estimated).
+    /// See [`ChunkPlanner`] for how files are grouped.
+    pub fn with_chunking(mut self, max_prompt_tokens: usize) -> Self {
+        self.chunk_planner = ChunkPlanner::new(max_prompt_tokens);
+        self
+    }
+
+    /// Run the chunks of a large batch and the audio analysis on a
+    /// [`TaskScheduler`] created from `config`, which sets how many run at
+    /// once (`general.max_concurrent_jobs`) and the per-task timeout.
+    pub fn with_scheduler_config(mut self, config: &Config) -> Self {
+        self.scheduler_config = config.clone();
+        self
+    }
+
+    /// Record the renames, moves, copies and backups made by
+    /// [`execute_operations`](Self::execute_operations) in `journal`, so that
+    /// `subx undo` can revert them.
+    pub fn with_journal(mut self, journal: Journal) -> Self {
+        self.journal = Some(journal);
+        self
+    }
+
     /// Matches video and subtitle files from a specified list of files.
     ///
     /// This method processes a user-provided list of files, filtering them into
@@ -625,32 +1155,242 @@ impl MatchEngine {
             return Ok(ops);
         }
 
-        // 3. Content sampling
+        // 3. Local pre-filter: settle confident pairs without the AI provider
+        let mut operations = Vec::new();
+        let mut remaining = subtitles.clone();
+        if self.local_prefilter {
+            let threshold = self.config.confidence_threshold.max(PREFILTER_CONFIDENCE);
+            for local_match in HeuristicMatcher::new().match_files(&videos, &subtitles) {
+                if local_match.confidence >= threshold {
+                    operations.push(self.create_operation(
+                        local_match.video,
+                        local_match.subtitle,
+                        local_match.confidence,
+                        local_match.reasons,
+                    ));
+                }
+            }
+            remaining.retain(|s| !operations.iter().any(|op| op.subtitle_file.id == s.id));
+            eprintln!(
+                "🔍 Local pre-filter matched {} of {} subtitle files",
+                operations.len(),
+                subtitles.len()
+            );
+        }
+
+        // 4. AI analysis of the subtitles still unmatched
+        if !remaining.is_empty() {
+            operations.extend(self.match_with_ai(&videos, &remaining).await?);
+        }
+
+        // 5. Optional speech-pattern check, which may move subtitles
+        let speech = match &self.audio_verification {
+            Some(vad) => {
+                self.verify_speech_patterns(&videos, &mut operations, vad)
+                    .await
+            }
+            None => std::collections::HashMap::new(),
+        };
+
+        // 6. Optional check of the cue timing against the video durations
+        if self.duration_check {
+            self.check_durations(&mut operations);
+        }
+
+        // 7. Optional ranking of subtitles competing for the same target
+        if let Some(selection) = self.candidate_selection {
+            self.select_candidates(&mut operations, &speech, selection);
+        }
+
+        // 8. Keep targets distinct within the batch and from existing files
+        self.assign_unique_names(&mut operations);
+
+        // 9. Save to cache for future use
+        self.save_file_list_cache(&cache_key, &operations).await?;
+
+        Ok(operations)
+    }
+
+    /// The video files among `file_paths`, e.g. to offer alternatives to a
+    /// proposed match.
+    pub fn scan_videos(&self, file_paths: &[PathBuf]) -> Result<Vec<MediaFile>> {
+        Ok(self
+            .discovery
+            .scan_file_list(file_paths)?
+            .into_iter()
+            .filter(|f| matches!(f.file_type, MediaFileType::Video))
+            .collect())
+    }
+
+    /// Operation for `op`'s subtitle renamed after `video` instead, adding
+    /// `reason` to the reasoning.
+    pub fn retarget(&self, op: &MatchOperation, video: &MediaFile, reason: &str) -> MatchOperation {
+        let mut reasoning = op.reasoning.clone();
+        reasoning.push(reason.to_string());
+        self.create_operation(video, &op.subtitle_file, op.confidence, reasoning)
+    }
+
+    /// Give `op`'s subtitle the file name `name`, in the video's folder when
+    /// the subtitle is relocated.
+    pub fn set_target_name(&self, op: &mut MatchOperation, name: &str) {
+        op.new_subtitle_name = name.to_string();
+        if op.requires_relocation {
+            op.relocation_target_path = op.video_file.path.parent().map(|dir| dir.join(name));
+        }
+    }
+
+    /// Store `operations` as the cached result for `file_paths`, so that a
+    /// later run over the same files and configuration applies them as they
+    /// are, e.g. after they were reviewed interactively.
+    pub async fn save_reviewed(
+        &self,
+        file_paths: &[PathBuf],
+        operations: &[MatchOperation],
+    ) -> Result<()> {
+        let cache_key = self.calculate_file_list_cache_key(file_paths)?;
+        self.save_file_list_cache(&cache_key, operations).await
+    }
+
+    /// Ask the AI provider to pair `subtitles` with `videos` and turn the
+    /// answers that meet the confidence threshold into operations.
+    ///
+    /// Batches too large for one prompt are split by the [`ChunkPlanner`]
+    /// and the chunks run on the [`TaskScheduler`]; a subtitle suggested by
+    /// several of them keeps its most confident pair. Failed chunks are
+    /// reported and skipped, unless every chunk failed.
+    async fn match_with_ai(
+        &self,
+        videos: &[&MediaFile],
+        subtitles: &[&MediaFile],
+    ) -> Result<Vec<MatchOperation>> {
+        let chunks = self
+            .chunk_planner
+            .plan(videos, subtitles, |file| self.estimate_prompt_tokens(file));
+        if chunks.len() > 1 {
+            eprintln!(
+                "🔍 Splitting {} video and {} subtitle files into {} AI requests (~{} tokens each)",
+                videos.len(),
+                subtitles.len(),
+                chunks.len(),
+                self.chunk_planner.max_prompt_tokens()
+            );
+        }
+
+        let mut requests = Vec::with_capacity(chunks.len());
+        for chunk in &chunks {
+            requests.push(self.chunk_request(chunk).await?);
+        }
+        let results = if requests.len() == 1 {
+            vec![self.ai_client.analyze_content(requests.remove(0)).await]
+        } else {
+            let jobs = requests.into_iter().map(|request| {
+                let ai_client = self.ai_client.clone();
+                Box::pin(async move { ai_client.analyze_content(request).await })
+                    as ScheduledFuture<_>
+            });
+            self.run_scheduled("ai_match_chunk", jobs.collect()).await?
+        };
+
+        // A subtitle is assigned at most once, to its most confident video.
+        // Failed chunks are reported and the answers of the others kept.
+        let mut pairs: Vec<(&MediaFile, &MediaFile, FileMatch)> = Vec::new();
+        let mut failures = Vec::new();
+        for (index, (chunk, result)) in chunks.iter().zip(results).enumerate() {
+            let match_result = match result {
+                Ok(match_result) => match_result,
+                Err(e) => {
+                    eprintln!(
+                        "⚠️  AI request {} of {} failed ({} video and {} subtitle files): {}",
+                        index + 1,
+                        chunks.len(),
+                        chunk.videos.len(),
+                        chunk.subtitles.len(),
+                        e
+                    );
+                    failures.push(e);
+                    continue;
+                }
+            };
+            for (video, subtitle, ai_match) in self.resolve_chunk(chunk, match_result) {
+                match pairs.iter_mut().find(|(_, s, _)| s.id == subtitle.id) {
+                    Some(pair) if pair.2.confidence < ai_match.confidence => {
+                        *pair = (video, subtitle, ai_match);
+                    }
+                    Some(_) => {}
+                    None => pairs.push((video, subtitle, ai_match)),
+                }
+            }
+        }
+        if failures.len() == chunks.len() {
+            if let Some(error) = failures.into_iter().next() {
+                return Err(error);
+            }
+        }
+
+        // Assemble match operation list; pairs just below the threshold are
+        // held back for a verification pass. The local provider would only
+        // repeat its own score, so it is not asked twice.
+        let mut operations = Vec::new();
+        let mut grey_zone = Vec::new();
+        let verification_floor =
+            if self.config.verification_margin > 0.0 && self.config.ai_model != LOCAL_MODEL_NAME {
+                self.config.confidence_threshold - self.config.verification_margin
+            } else {
+                self.config.confidence_threshold
+            };
+
+        for (video, subtitle, ai_match) in pairs {
+            if ai_match.confidence >= self.config.confidence_threshold {
+                operations.push(self.create_operation(
+                    video,
+                    subtitle,
+                    ai_match.confidence,
+                    ai_match.match_factors,
+                ));
+            } else if ai_match.confidence >= verification_floor {
+                grey_zone.push((video, subtitle, ai_match));
+            }
+        }
+
+        if !grey_zone.is_empty() {
+            self.verify_grey_zone(grey_zone, &mut operations).await;
+        }
+
+        Ok(operations)
+    }
+
+    /// Build the analysis request for one chunk.
+    async fn chunk_request(&self, chunk: &MatchChunk<'_>) -> Result<AnalysisRequest> {
+        let videos = &chunk.videos;
+        let subtitles = &chunk.subtitles;
+
+        // Content sampling
         let content_samples = if self.config.enable_content_analysis {
-            self.extract_content_samples(&subtitles).await?
+            self.extract_content_samples(subtitles).await?
         } else {
             Vec::new()
         };
 
-        // 4. AI analysis request
         // Generate AI analysis request: include file IDs for precise matching
-        let video_files: Vec<String> = videos
-            .iter()
-            .map(|v| format!("ID:{} | Name:{} | Path:{}", v.id, v.name, v.relative_path))
-            .collect();
-        let subtitle_files: Vec<String> = subtitles
-            .iter()
-            .map(|s| format!("ID:{} | Name:{} | Path:{}", s.id, s.name, s.relative_path))
-            .collect();
+        // and the parsed release fields so the model need not re-derive them
+        let video_files: Vec<String> = videos.iter().map(|v| request_entry(v)).collect();
+        let subtitle_files: Vec<String> = subtitles.iter().map(|s| request_entry(s)).collect();
 
-        let analysis_request = AnalysisRequest {
+        Ok(AnalysisRequest {
             video_files,
             subtitle_files,
             content_samples,
-        };
+        })
+    }
 
-        // 5. Query AI service
-        let match_result = self.ai_client.analyze_content(analysis_request).await?;
+    /// Resolve the pairs the AI provider suggested for one chunk to its files.
+    fn resolve_chunk<'a>(
+        &self,
+        chunk: &MatchChunk<'a>,
+        match_result: MatchResult,
+    ) -> Vec<(&'a MediaFile, &'a MediaFile, FileMatch)> {
+        let videos = &chunk.videos;
+        let subtitles = &chunk.subtitles;
 
         // Debug: Log AI analysis results
         eprintln!("🔍 AI Analysis Results:");
@@ -666,69 +1406,496 @@ impl MatchEngine {
             );
         }
 
-        // 6. Assemble match operation list
-        let mut operations = Vec::new();
-
+        let mut pairs = Vec::new();
         for ai_match in match_result.matches {
-            if ai_match.confidence >= self.config.confidence_threshold {
-                let video_match =
-                    Self::find_media_file_by_id_or_path(&videos, &ai_match.video_file_id, None);
-                let subtitle_match = Self::find_media_file_by_id_or_path(
-                    &subtitles,
-                    &ai_match.subtitle_file_id,
-                    None,
-                );
-                match (video_match, subtitle_match) {
-                    (Some(video), Some(subtitle)) => {
-                        let new_name = self.generate_subtitle_name(video, subtitle);
+            let video_match =
+                Self::find_media_file_by_id_or_path(videos, &ai_match.video_file_id, None);
+            let subtitle_match =
+                Self::find_media_file_by_id_or_path(subtitles, &ai_match.subtitle_file_id, None);
+            match (video_match, subtitle_match) {
+                (Some(video), Some(subtitle)) => pairs.push((video, subtitle, ai_match)),
+                _ => {
+                    eprintln!(
+                        "⚠️  Cannot find AI-suggested file pair:\n     Video ID: '{}'\n     Subtitle ID: '{}'",
+                        ai_match.video_file_id, ai_match.subtitle_file_id
+                    );
+                    eprintln!("❌ No matching files found that meet the criteria");
+                    eprintln!("🔍 Available file statistics:");
+                    eprintln!("   Video files ({} files):", videos.len());
+                    for video in videos {
+                        eprintln!("     - ID: {} | {}", video.id, video.name);
+                    }
+                    eprintln!("   Subtitle files ({} files):", subtitles.len());
+                    for subtitle in subtitles {
+                        eprintln!("     - ID: {} | {}", subtitle.id, subtitle.name);
+                    }
+                }
+            }
+        }
+        pairs
+    }
 
-                        // Determine if relocation is needed
-                        let requires_relocation = self.config.relocation_mode
-                            != FileRelocationMode::None
-                            && subtitle.path.parent() != video.path.parent();
+    /// Run `jobs` on a [`TaskScheduler`] and return their results in order.
+    /// A job that times out on the scheduler yields an error.
+    async fn run_scheduled<T: Send + 'static>(
+        &self,
+        task_type: &'static str,
+        jobs: Vec<ScheduledFuture<T>>,
+    ) -> Result<Vec<Result<T>>> {
+        let scheduler = TaskScheduler::new_with_config(&self.scheduler_config)?;
+        let mut slots = Vec::with_capacity(jobs.len());
+        let mut tasks: Vec<Box<dyn Task + Send + Sync>> = Vec::with_capacity(jobs.len());
+        for (index, job) in jobs.into_iter().enumerate() {
+            let slot = Arc::new(Mutex::new(None));
+            tasks.push(Box::new(ScheduledJob {
+                task_type,
+                index,
+                job: Mutex::new(Some(job)),
+                slot: slot.clone(),
+            }));
+            slots.push(slot);
+        }
+        let task_results = scheduler.submit_batch_tasks(tasks).await;
+        Ok(slots
+            .into_iter()
+            .enumerate()
+            .map(|(index, slot)| {
+                slot.lock().unwrap().take().unwrap_or_else(|| {
+                    let reason = match task_results.get(index) {
+                        Some(TaskResult::Failed(reason)) => reason.clone(),
+                        _ => "the task did not run".to_string(),
+                    };
+                    Err(SubXError::parallel_processing(reason))
+                })
+            })
+            .collect())
+    }
 
-                        let relocation_target_path = if requires_relocation {
-                            let video_dir = video.path.parent().unwrap();
-                            Some(video_dir.join(&new_name))
-                        } else {
-                            None
-                        };
+    /// Prompt tokens `file` adds to an analysis request, counting the
+    /// content preview of a subtitle at up to three bytes per token.
+    fn estimate_prompt_tokens(&self, file: &MediaFile) -> usize {
+        let mut tokens = estimate_tokens(&request_entry(file));
+        if self.config.enable_content_analysis && matches!(file.file_type, MediaFileType::Subtitle)
+        {
+            tokens += (file.size as usize).min(self.config.max_sample_length) / 3;
+        }
+        tokens
+    }
 
-                        operations.push(MatchOperation {
-                            video_file: (*video).clone(),
-                            subtitle_file: (*subtitle).clone(),
-                            new_subtitle_name: new_name,
-                            confidence: ai_match.confidence,
-                            reasoning: ai_match.match_factors,
-                            relocation_mode: self.config.relocation_mode.clone(),
-                            relocation_target_path,
-                            requires_relocation,
-                        });
-                    }
-                    _ => {
-                        eprintln!(
-                            "⚠️  Cannot find AI-suggested file pair:\n     Video ID: '{}'\n     Subtitle ID: '{}'",
-                            ai_match.video_file_id, ai_match.subtitle_file_id
-                        );
-                        eprintln!("❌ No matching files found that meet the criteria");
-                        eprintln!("🔍 Available file statistics:");
-                        eprintln!("   Video files ({} files):", videos.len());
-                        for video in &videos {
-                            eprintln!("     - ID: {} | {}", video.id, video.name);
-                        }
-                        eprintln!("   Subtitle files ({} files):", subtitles.len());
-                        for subtitle in &subtitles {
-                            eprintln!("     - ID: {} | {}", subtitle.id, subtitle.name);
+    /// Ask the AI provider to verify pairs that scored just below the
+    /// confidence threshold, giving it a longer content sample and the
+    /// neighboring file names, and add the pairs whose verified score reaches
+    /// the threshold to `operations`.
+    async fn verify_grey_zone(
+        &self,
+        candidates: Vec<(&MediaFile, &MediaFile, FileMatch)>,
+        operations: &mut Vec<MatchOperation>,
+    ) {
+        let threshold = self.config.confidence_threshold;
+        let (mut calls, mut promoted, mut rejected) = (0, 0, 0);
+        eprintln!("🔍 Verifying {} low-confidence matches:", candidates.len());
+        for (video, subtitle, ai_match) in candidates {
+            // A confident pair already claimed this subtitle
+            if operations
+                .iter()
+                .any(|op| op.subtitle_file.id == subtitle.id)
+            {
+                continue;
+            }
+            let request = VerificationRequest {
+                video_file: video.relative_path.clone(),
+                subtitle_file: subtitle.relative_path.clone(),
+                match_factors: ai_match.match_factors.clone(),
+                content_sample: self.verification_sample(subtitle),
+                directory_listing: directory_listing(video, subtitle),
+            };
+            calls += 1;
+            match self.ai_client.verify_match(request).await {
+                Ok(score) if score.score >= threshold => {
+                    promoted += 1;
+                    eprintln!(
+                        "   - ✅ {} -> {} (confidence: {:.2} → {:.2})",
+                        video.name, subtitle.name, ai_match.confidence, score.score
+                    );
+                    let mut reasoning = ai_match.match_factors;
+                    reasoning.extend(score.factors);
+                    operations.push(self.create_operation(video, subtitle, score.score, reasoning));
+                }
+                Ok(score) => {
+                    rejected += 1;
+                    eprintln!(
+                        "   - ❌ {} -> {} (confidence: {:.2} → {:.2})",
+                        video.name, subtitle.name, ai_match.confidence, score.score
+                    );
+                }
+                Err(e) => {
+                    rejected += 1;
+                    eprintln!(
+                        "   - ⚠️  {} -> {} could not be verified: {}",
+                        video.name, subtitle.name, e
+                    );
+                }
+            }
+        }
+        eprintln!(
+            "   - Verification calls: {} ({} promoted, {} rejected)",
+            calls, promoted, rejected
+        );
+    }
+
+    /// Correlate each matched subtitle's cue timing with the speech of the
+    /// videos in its show and season group (see [`ChunkPlanner`]), record
+    /// the score in the operation's reasoning and reassign the subtitle when
+    /// another video fits clearly better. Only the videos some subtitle could
+    /// move to are analyzed, on the [`TaskScheduler`]; videos whose audio
+    /// cannot be analyzed are left out.
+    ///
+    /// Returns the speech patterns found, keyed by video ID.
+    async fn verify_speech_patterns(
+        &self,
+        videos: &[&MediaFile],
+        operations: &mut [MatchOperation],
+        vad: &VadConfig,
+    ) -> std::collections::HashMap<String, SpeechPattern> {
+        let mut speech = std::collections::HashMap::new();
+        if let Err(e) = LocalVadDetector::new(vad.clone()) {
+            eprintln!("⚠️  Speech-pattern verification unavailable: {}", e);
+            return speech;
+        }
+
+        let keyed: Vec<(&MediaFile, String)> = videos.iter().map(|v| (*v, group_key(v))).collect();
+        let candidates: Vec<Vec<&MediaFile>> = operations
+            .iter()
+            .map(|op| speech_candidates(&keyed, op))
+            .collect();
+        let mut analyzed: Vec<&MediaFile> = Vec::new();
+        for video in candidates.iter().flatten() {
+            if !analyzed.iter().any(|v| v.id == video.id) {
+                analyzed.push(video);
+            }
+        }
+        eprintln!("🔍 Analyzing speech in {} video files...", analyzed.len());
+        let jobs = analyzed.iter().map(|video| {
+            let vad = vad.clone();
+            let path = video.path.clone();
+            Box::pin(async move {
+                let detector = LocalVadDetector::new(vad)?;
+                let audio = detector
+                    .audio_processor()
+                    .load_and_prepare_audio_direct(&path)
+                    .await?;
+                let result = detector.detect_speech_from_data(audio).await?;
+                Ok(SpeechPattern::from_segments(&result.speech_segments))
+            }) as ScheduledFuture<_>
+        });
+        match self.run_scheduled("speech_pattern", jobs.collect()).await {
+            Ok(results) => {
+                for (video, result) in analyzed.iter().zip(results) {
+                    match result {
+                        Ok(pattern) => {
+                            speech.insert(video.id.clone(), pattern);
                         }
+                        Err(e) => eprintln!("   - ⚠️  Skipping {}: {}", video.name, e),
                     }
                 }
             }
+            Err(e) => {
+                eprintln!("⚠️  Speech-pattern verification unavailable: {}", e);
+                return speech;
+            }
         }
 
-        // 7. Save to cache for future use
-        self.save_file_list_cache(&cache_key, &operations).await?;
+        self.reassign_by_speech(operations, &candidates, &speech);
+        speech
+    }
 
-        Ok(operations)
+    /// Move subtitles whose cues follow another candidate video's `speech`
+    /// clearly better than their matched video's.
+    ///
+    /// A video already matched by another operation for the same subtitle
+    /// name is only taken when that operation's subtitle fits the vacated
+    /// video at least as well, in which case the two subtitles swap videos;
+    /// otherwise the subtitle stays.
+    fn reassign_by_speech(
+        &self,
+        operations: &mut [MatchOperation],
+        candidates: &[Vec<&MediaFile>],
+        speech: &std::collections::HashMap<String, SpeechPattern>,
+    ) {
+        let format_manager = FormatManager::new();
+        let cues: Vec<Option<SpeechPattern>> = operations
+            .iter()
+            .map(|op| {
+                let subtitle = format_manager.load_subtitle(&op.subtitle_file.path).ok()?;
+                Some(SpeechPattern::from_subtitle(&subtitle))
+            })
+            .collect();
+        let correlate = |index: usize, video_id: &str| {
+            let cues = cues[index].as_ref()?;
+            Some(cues.correlate(speech.get(video_id)?))
+        };
+        let reassigned_reason = |correlation: &PatternCorrelation, from: &MediaFile, score: f32| {
+            format!(
+                "Speech pattern correlation {:.2} at {:+.1}s (reassigned from {}, which scored {:.2})",
+                correlation.score, correlation.offset_seconds, from.name, score
+            )
+        };
+
+        let mut settled = vec![false; operations.len()];
+        for index in 0..operations.len() {
+            if settled[index] || cues[index].is_none() {
+                continue;
+            }
+            let current_video = operations[index].video_file.clone();
+            let current = correlate(index, &current_video.id);
+            let current_score = current.as_ref().map_or(0.0, |c| c.score);
+            let best = candidates[index]
+                .iter()
+                .filter(|video| video.id != current_video.id)
+                .filter_map(|video| correlate(index, &video.id).map(|c| (*video, c)))
+                .max_by(|a, b| a.1.score.total_cmp(&b.1.score))
+                .filter(|(_, better)| {
+                    better.score >= REASSIGN_MIN_CORRELATION
+                        && better.score - current_score >= REASSIGN_MARGIN
+                });
+
+            let Some((video, better)) = best else {
+                if let Some(current) = current {
+                    operations[index].reasoning.push(format!(
+                        "Speech pattern correlation {:.2} at {:+.1}s",
+                        current.score, current.offset_seconds
+                    ));
+                }
+                continue;
+            };
+
+            // Only an operation writing the same name next to the video is
+            // in the way, not e.g. a subtitle in another language
+            let target_name = self.generate_subtitle_name(video, &operations[index].subtitle_file);
+            let owner = operations.iter().position(|other| {
+                other.video_file.id == video.id && other.new_subtitle_name == target_name
+            });
+            if let Some(other) = owner {
+                let kept = correlate(other, &video.id).map_or(0.0, |c| c.score);
+                let Some(swapped) =
+                    correlate(other, &current_video.id).filter(|swapped| swapped.score >= kept)
+                else {
+                    operations[index].reasoning.push(format!(
+                        "Speech pattern correlation {:.2} at {:+.1}s ({} fits better at {:.2} but is matched to {})",
+                        current_score,
+                        current.as_ref().map_or(0.0, |c| c.offset_seconds),
+                        video.name,
+                        better.score,
+                        operations[other].subtitle_file.name
+                    ));
+                    continue;
+                };
+                eprintln!(
+                    "   - 🔁 {}: {} -> {} (speech correlation {:.2} -> {:.2})",
+                    operations[other].subtitle_file.name,
+                    video.name,
+                    current_video.name,
+                    kept,
+                    swapped.score
+                );
+                let mut reasoning = operations[other].reasoning.clone();
+                reasoning.push(reassigned_reason(&swapped, video, kept));
+                let op = &operations[other];
+                operations[other] = self.create_operation(
+                    &current_video,
+                    &op.subtitle_file,
+                    op.confidence,
+                    reasoning,
+                );
+                settled[other] = true;
+            }
+
+            eprintln!(
+                "   - 🔁 {}: {} -> {} (speech correlation {:.2} -> {:.2})",
+                operations[index].subtitle_file.name,
+                current_video.name,
+                video.name,
+                current_score,
+                better.score
+            );
+            let mut reasoning = operations[index].reasoning.clone();
+            reasoning.push(reassigned_reason(&better, &current_video, current_score));
+            let op = &operations[index];
+            operations[index] =
+                self.create_operation(video, &op.subtitle_file, op.confidence, reasoning);
+            settled[index] = true;
+        }
+    }
+
+    /// Drop operations whose subtitle ends well after the video and flag
+    /// those covering only part of it. Videos without duration metadata and
+    /// unreadable subtitles are not checked.
+    fn check_durations(&self, operations: &mut Vec<MatchOperation>) {
+        let mut durations = std::collections::HashMap::new();
+        let format_manager = FormatManager::new();
+        operations.retain_mut(|op| {
+            let video = *durations
+                .entry(op.video_file.id.clone())
+                .or_insert_with(|| probe_media_duration(&op.video_file.path));
+            let Some(video) = video else {
+                return true;
+            };
+            let Some(end) = format_manager
+                .load_subtitle(&op.subtitle_file.path)
+                .ok()
+                .and_then(|subtitle| cues_end(&subtitle))
+            else {
+                return true;
+            };
+            match DurationFit::check(end, video) {
+                DurationFit::Fits => true,
+                DurationFit::Overruns { overrun } => {
+                    eprintln!(
+                        "   - ✗ Rejecting {} for {}: cues end at {}, {} after the video ({})",
+                        op.subtitle_file.name,
+                        op.video_file.name,
+                        format_hms(end),
+                        format_hms(overrun),
+                        format_hms(video)
+                    );
+                    false
+                }
+                DurationFit::Short { coverage } => {
+                    eprintln!(
+                        "   - ⚠️  {} covers only {:.0}% of {}",
+                        op.subtitle_file.name,
+                        coverage * 100.0,
+                        op.video_file.name
+                    );
+                    op.reasoning.push(format!(
+                        "Cues end at {}, covering only {:.0}% of the video ({})",
+                        format_hms(end),
+                        coverage * 100.0,
+                        format_hms(video)
+                    ));
+                    true
+                }
+            }
+        });
+    }
+
+    /// Rank the operations of each video that would land on the same target
+    /// name, using the videos' `speech` for the timing factor. The best
+    /// candidate of a group moves to the group's first position so that it
+    /// keeps the plain name; with [`CandidateSelection::Best`] the others
+    /// are dropped.
+    fn select_candidates(
+        &self,
+        operations: &mut Vec<MatchOperation>,
+        speech: &std::collections::HashMap<String, SpeechPattern>,
+        selection: CandidateSelection,
+    ) {
+        let mut groups: Vec<(PathBuf, Vec<usize>)> = Vec::new();
+        for (index, op) in operations.iter().enumerate() {
+            let target = op.target_path();
+            match groups.iter_mut().find(|(t, _)| *t == target) {
+                Some((_, members)) => members.push(index),
+                None => groups.push((target, vec![index])),
+            }
+        }
+
+        let mut slots: Vec<Option<MatchOperation>> = operations.drain(..).map(Some).collect();
+        let mut placed: Vec<Option<MatchOperation>> = (0..slots.len()).map(|_| None).collect();
+        for (target, members) in groups {
+            if members.len() == 1 {
+                placed[members[0]] = slots[members[0]].take();
+                continue;
+            }
+            let assessments: Vec<CandidateAssessment> = members
+                .iter()
+                .map(|&i| {
+                    let op = slots[i].as_ref().expect("each operation is placed once");
+                    CandidateAssessment::assess(
+                        &op.video_file,
+                        &op.subtitle_file,
+                        speech.get(&op.video_file.id),
+                    )
+                })
+                .collect();
+            let most_cues = assessments.iter().map(|a| a.cue_count).max().unwrap_or(0);
+            eprintln!(
+                "🏅 Ranking {} candidates for {}",
+                members.len(),
+                target.display()
+            );
+            for (rank, &position) in rank_candidates(&assessments).iter().enumerate() {
+                let assessment = &assessments[position];
+                let mut op = slots[members[position]]
+                    .take()
+                    .expect("each operation is placed once");
+                op.reasoning.push(format!(
+                    "Candidate rank {} of {} (score {:.2}: {})",
+                    rank + 1,
+                    members.len(),
+                    assessment.score(most_cues),
+                    assessment.summary()
+                ));
+                if rank > 0 && selection == CandidateSelection::Best {
+                    eprintln!("   - Dropping {}", op.subtitle_file.name);
+                    continue;
+                }
+                eprintln!(
+                    "   {}. {} ({:.2})",
+                    rank + 1,
+                    op.subtitle_file.name,
+                    assessment.score(most_cues)
+                );
+                placed[members[rank]] = Some(op);
+            }
+        }
+        operations.extend(placed.into_iter().flatten());
+    }
+
+    /// Longer excerpt of `subtitle` for verification: up to three times the
+    /// analysis sample length.
+    fn verification_sample(&self, subtitle: &MediaFile) -> Option<String> {
+        let content = std::fs::read_to_string(&subtitle.path).ok()?;
+        let limit = self.config.max_sample_length.saturating_mul(3);
+        let mut sample: String = content.lines().take(60).collect::<Vec<_>>().join("\n");
+        if let Some((cut, _)) = sample.char_indices().nth(limit) {
+            sample.truncate(cut);
+            sample.push_str("...");
+        }
+        Some(sample)
+    }
+
+    /// Build the operation that renames (and possibly relocates) `subtitle`
+    /// after `video`.
+    fn create_operation(
+        &self,
+        video: &MediaFile,
+        subtitle: &MediaFile,
+        confidence: f32,
+        reasoning: Vec<String>,
+    ) -> MatchOperation {
+        let new_name = self.generate_subtitle_name(video, subtitle);
+
+        // Determine if relocation is needed
+        let requires_relocation = self.config.relocation_mode != FileRelocationMode::None
+            && subtitle.path.parent() != video.path.parent();
+
+        let relocation_target_path = if requires_relocation {
+            let video_dir = video.path.parent().unwrap();
+            Some(video_dir.join(&new_name))
+        } else {
+            None
+        };
+
+        MatchOperation {
+            video_file: video.clone(),
+            subtitle_file: subtitle.clone(),
+            new_subtitle_name: new_name,
+            confidence,
+            reasoning,
+            relocation_mode: self.config.relocation_mode.clone(),
+            relocation_target_path,
+            requires_relocation,
+        }
     }
 
     async fn extract_content_samples(
@@ -763,6 +1930,13 @@ impl MatchEngine {
     }
 
     fn generate_subtitle_name(&self, video: &MediaFile, subtitle: &MediaFile) -> String {
+        self.config
+            .naming
+            .render(&self.naming_fields(video, subtitle), 0)
+    }
+
+    /// Values the naming template can use for `subtitle` renamed after `video`.
+    fn naming_fields(&self, video: &MediaFile, subtitle: &MediaFile) -> NamingFields {
         let detector = LanguageDetector::new();
 
         // Remove the extension from the video file name (if any)
@@ -775,10 +1949,77 @@ impl MatchEngine {
             &video.name
         };
 
-        if let Some(code) = detector.get_primary_language(&subtitle.path) {
-            format!("{}.{}.{}", video_base_name, code, subtitle.extension)
-        } else {
-            format!("{}.{}", video_base_name, subtitle.extension)
+        // Fall back to a single release-name language tag, then to the cue text
+        let subtitle_name = subtitle.parsed_name();
+        let language = detector
+            .get_primary_language(&subtitle.path)
+            .or_else(|| match subtitle_name.languages.as_slice() {
+                [code] => Some(code.clone()),
+                _ => None,
+            })
+            .or_else(|| self.content_language(&subtitle.path));
+        NamingFields {
+            video_stem: video_base_name.to_string(),
+            extension: subtitle.extension.clone(),
+            language,
+            video: video.parsed_name(),
+            forced: subtitle_name.forced,
+            hearing_impaired: subtitle_name.hearing_impaired,
+        }
+    }
+
+    /// Language declared by the subtitle file or identified from its cue
+    /// text, loaded once per subtitle.
+    fn content_language(&self, path: &std::path::Path) -> Option<String> {
+        let mut cache = self.content_languages.lock().unwrap();
+        cache
+            .entry(path.to_path_buf())
+            .or_insert_with(|| {
+                FormatManager::new()
+                    .load_subtitle(path)
+                    .ok()?
+                    .metadata
+                    .language
+            })
+            .clone()
+    }
+
+    /// Number the names of operations that would otherwise land on the same
+    /// target, or on an existing file other than the subtitle itself.
+    fn assign_unique_names(&self, operations: &mut [MatchOperation]) {
+        if !matches!(
+            self.config.conflict_resolution,
+            ConflictResolution::AutoRename
+        ) {
+            return;
+        }
+        let target_of = |op: &MatchOperation| match &op.relocation_target_path {
+            Some(target) => target.clone(),
+            None => op.subtitle_file.path.with_file_name(&op.new_subtitle_name),
+        };
+        let mut claimed = std::collections::HashSet::new();
+        for op in operations.iter_mut() {
+            let source = op.subtitle_file.path.clone();
+            let taken = |claimed: &std::collections::HashSet<PathBuf>, target: &PathBuf| {
+                claimed.contains(target) || (target.exists() && *target != source)
+            };
+            let mut target = target_of(op);
+            if taken(&claimed, &target) {
+                let fields = self.naming_fields(&op.video_file, &op.subtitle_file);
+                for number in 1..1000 {
+                    let name = self.config.naming.render(&fields, number);
+                    let candidate = target.with_file_name(&name);
+                    if !taken(&claimed, &candidate) {
+                        op.new_subtitle_name = name;
+                        if op.relocation_target_path.is_some() {
+                            op.relocation_target_path = Some(candidate.clone());
+                        }
+                        target = candidate;
+                        break;
+                    }
+                }
+            }
+            claimed.insert(target);
         }
     }
 
@@ -789,50 +2030,142 @@ impl MatchEngine {
         dry_run: bool,
     ) -> Result<()> {
         for op in operations {
+            let source = &op.subtitle_file.path;
             if dry_run {
                 println!(
                     "Preview: {} -> {}",
                     op.subtitle_file.name, op.new_subtitle_name
                 );
-                if op.requires_relocation {
-                    if let Some(target_path) = &op.relocation_target_path {
-                        let operation_verb = match op.relocation_mode {
-                            FileRelocationMode::Copy => "Copy",
-                            FileRelocationMode::Move => "Move",
-                            _ => "",
-                        };
-                        println!(
-                            "Preview: {} {} to {}",
-                            operation_verb,
-                            op.subtitle_file.path.display(),
-                            target_path.display()
-                        );
+                // Prompting is left to the actual run
+                let outcome = match self.config.conflict_resolution {
+                    ConflictResolution::Prompt => ConflictOutcome::Write(op.target_path()),
+                    _ => self.resolve_filename_conflict(source, op.target_path())?,
+                };
+                let target = match outcome {
+                    ConflictOutcome::Write(target) => target,
+                    ConflictOutcome::Skip(reason) => {
+                        println!("Preview: Skip {}: {}", source.display(), reason);
+                        continue;
+                    }
+                    ConflictOutcome::Replace { target, backup } => {
+                        match backup {
+                            Some(backup) => println!(
+                                "Preview: Back up {} to {}",
+                                target.display(),
+                                backup.display()
+                            ),
+                            None => println!("Preview: Replace {}", target.display()),
+                        }
+                        target
                     }
+                };
+                if (op.requires_relocation && op.relocation_target_path.is_some())
+                    || op.relocation_mode.is_link()
+                {
+                    println!(
+                        "Preview: {} {} to {}",
+                        op.relocation_mode.verb(),
+                        source.display(),
+                        target.display()
+                    );
                 }
             } else {
+                let (target, set_aside) =
+                    match self.resolve_filename_conflict(source, op.target_path())? {
+                        ConflictOutcome::Write(target) => (target, None),
+                        ConflictOutcome::Skip(reason) => {
+                            eprintln!("Warning: Skipping {}: {}", source.display(), reason);
+                            continue;
+                        }
+                        ConflictOutcome::Replace { target, backup } => {
+                            let set_aside = backup.map(|backup| (target.clone(), backup));
+                            (target, set_aside)
+                        }
+                    };
                 // Delegate file operations to FileProcessingTask
                 let mut tasks = Vec::new();
                 // Backup source if move and enabled
                 if op.relocation_mode == FileRelocationMode::Move && self.config.backup_enabled {
-                    tasks.push(
-                        self.create_backup_task(
-                            &op.subtitle_file.path,
-                            &op.subtitle_file.extension,
-                        ),
-                    );
+                    tasks.push(self.create_backup_task(source, &op.subtitle_file.extension));
                 }
                 // Copy or local copy with rename
                 if op.relocation_mode == FileRelocationMode::Copy {
-                    tasks.push(self.create_copy_task(op));
+                    tasks.push(self.create_copy_task(op, target.clone()));
+                }
+                // Link to the original, which stays in place
+                if op.relocation_mode.is_link() && target != *source {
+                    tasks.push(self.create_link_task(op, target.clone()));
                 }
                 // Rename original file if any
-                if op.relocation_mode != FileRelocationMode::Copy {
-                    tasks.push(self.create_rename_task(op));
+                if !op.relocation_mode.keeps_source() {
+                    tasks.push(self.create_rename_task(op, target.clone()));
+                }
+                let written = tasks.last().and_then(|t| t.output_path.clone());
+                let backup = (tasks.len() > 1)
+                    .then(|| tasks[0].output_path.clone())
+                    .flatten();
+                // Keep a file the operation will overwrite so it can be undone
+                let replaced = match (&self.journal, &written) {
+                    (Some(journal), Some(path))
+                        if *path != op.subtitle_file.path && set_aside.is_none() =>
+                    {
+                        journal.preserve(path)?
+                    }
+                    _ => None,
+                };
+                // Move the file being replaced to its backup or clear it,
+                // links cannot overwrite it
+                if let Some((target, backup)) = &set_aside {
+                    std::fs::rename(target, backup)?;
+                } else if target != *source && target.symlink_metadata().is_ok() {
+                    std::fs::remove_file(&target)?;
                 }
                 // Execute all tasks sequentially
                 for t in tasks {
-                    if let TaskResult::Failed(err) = t.execute().await {
-                        return Err(SubXError::FileOperationFailed(err));
+                    match t.execute().await {
+                        TaskResult::Failed(err) => {
+                            // Put the backed up file back in place
+                            if let Some((target, backup)) = &set_aside {
+                                std::fs::rename(backup, target)?;
+                            }
+                            return Err(SubXError::FileOperationFailed(err));
+                        }
+                        TaskResult::PartialSuccess(done, warning) => {
+                            eprintln!("Warning: {}: {}", done, warning);
+                        }
+                        _ => {}
+                    }
+                }
+                if let (Some(journal), Some((target, backup))) = (&self.journal, &set_aside) {
+                    journal.record_moved(target, backup)?;
+                }
+                if let (Some(journal), Some(path)) = (&self.journal, &written) {
+                    self.record_operation(journal, op, path, backup.as_deref(), replaced)?;
+                }
+                if let Some(path) = &written {
+                    self.written.lock().unwrap().push(path.clone());
+                }
+                if let (Some(_), Some(path)) = (&self.config.output_encoding, &written) {
+                    if op.relocation_mode.is_link() {
+                        // Re-encoding would replace the link with a copy
+                        eprintln!(
+                            "Warning: {}: not re-encoded, it is linked to {}",
+                            path.display(),
+                            op.subtitle_file.path.display()
+                        );
+                    }
+                }
+                if let (Some(encoding), Some(path)) = (
+                    &self.config.output_encoding,
+                    written.filter(|_| !op.relocation_mode.is_link()),
+                ) {
+                    let previous = match &self.journal {
+                        Some(journal) => journal.preserve(&path)?,
+                        None => None,
+                    };
+                    self.reencode_subtitle(&path, encoding)?;
+                    if let Some(journal) = &self.journal {
+                        journal.record_written(&path, previous)?;
                     }
                 }
             }
@@ -840,9 +2173,112 @@ impl MatchEngine {
         Ok(())
     }
 
+    /// Remove subtitles duplicating another one next to the videos of
+    /// `operations`, keeping one copy of each, preferably the one an
+    /// operation wrote (or would write, in a dry run). The subtitles that
+    /// symlink and hardlink operations link to are never removed. Returns
+    /// the number of files removed, or that would be.
+    pub fn remove_duplicates(&self, operations: &[MatchOperation], dry_run: bool) -> Result<usize> {
+        let written = match self.written.lock().unwrap().clone() {
+            written if written.is_empty() => {
+                operations.iter().map(MatchOperation::target_path).collect()
+            }
+            written => written,
+        };
+        let linked: Vec<&std::path::Path> = operations
+            .iter()
+            .filter(|op| {
+                matches!(
+                    op.relocation_mode,
+                    FileRelocationMode::Symlink { .. } | FileRelocationMode::Hardlink
+                )
+            })
+            .map(|op| op.subtitle_file.path.as_path())
+            .collect();
+        let mut videos: Vec<&std::path::Path> = operations
+            .iter()
+            .map(|op| op.video_file.path.as_path())
+            .collect();
+        videos.sort();
+        videos.dedup();
+        let mut removed = 0;
+        for video in videos {
+            for group in find_duplicates(video, &written)? {
+                let kind = if group.semantic {
+                    "same cues as"
+                } else {
+                    "identical to"
+                };
+                for duplicate in &group.duplicates {
+                    if linked.iter().any(|source| same_file(source, duplicate)) {
+                        continue;
+                    }
+                    let reason = format!(
+                        "{} ({} {})",
+                        duplicate.display(),
+                        kind,
+                        group.keep.display()
+                    );
+                    if dry_run {
+                        println!("Preview: Remove duplicate {reason}");
+                    } else {
+                        match &self.journal {
+                            Some(journal) => journal.remove_file(duplicate)?,
+                            None => std::fs::remove_file(duplicate)?,
+                        }
+                        println!("Removed duplicate {reason}");
+                    }
+                    removed += 1;
+                }
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Journal what executing `op` did: the backup, the file `written`
+    /// replaced, and the copy or move itself.
+    fn record_operation(
+        &self,
+        journal: &Journal,
+        op: &MatchOperation,
+        written: &std::path::Path,
+        backup: Option<&std::path::Path>,
+        replaced: Option<PathBuf>,
+    ) -> Result<()> {
+        let source = &op.subtitle_file.path;
+        if let Some(backup) = backup {
+            journal.record_copied(source, backup)?;
+        }
+        if written == source {
+            return Ok(());
+        }
+        if let Some(saved) = replaced {
+            journal.record_removed(written, saved)?;
+        }
+        // Undoing a copy or link deletes it and leaves the source alone
+        if op.relocation_mode.keeps_source() {
+            journal.record_copied(source, written)
+        } else {
+            journal.record_moved(source, written)
+        }
+    }
+
+    /// Rewrite a relocated subtitle file in the configured output encoding
+    fn reencode_subtitle(&self, path: &std::path::Path, encoding: &OutputEncoding) -> Result<()> {
+        let path_str = path
+            .to_str()
+            .ok_or_else(|| SubXError::subtitle_format("", "Invalid file path encoding"))?;
+        let content = FormatManager::new().read_subtitle_with_encoding_detection(path_str)?;
+        let content = content.strip_prefix('\u{feff}').unwrap_or(&content);
+        for warning in encoding.write(path, content)? {
+            eprintln!("Warning: {}: {}", path.display(), warning);
+        }
+        Ok(())
+    }
+
     /// Rename subtitle file by delegating to FileProcessingTask
     async fn rename_file(&self, op: &MatchOperation) -> Result<()> {
-        let task = self.create_rename_task(op);
+        let task = self.create_rename_task(op, op.target_path());
         match task.execute().await {
             TaskResult::Success(_) => Ok(()),
             TaskResult::Failed(err) => Err(SubXError::FileOperationFailed(err)),
@@ -853,64 +2289,118 @@ impl MatchEngine {
         }
     }
 
-    /// Resolve filename conflicts by adding numeric suffix
-    fn resolve_filename_conflict(&self, target: std::path::PathBuf) -> Result<std::path::PathBuf> {
-        if !target.exists() {
-            return Ok(target);
+    /// Decide how to write `source` to `target` when a file already exists
+    /// there, following the configured conflict policy
+    fn resolve_filename_conflict(
+        &self,
+        source: &std::path::Path,
+        target: PathBuf,
+    ) -> Result<ConflictOutcome> {
+        if !target.exists() || target == source {
+            return Ok(ConflictOutcome::Write(target));
         }
+        let numbered = |target: &std::path::Path| {
+            numbered_path(target, |_| false).ok_or_else(|| {
+                SubXError::FileOperationFailed("Could not resolve filename conflict".to_string())
+            })
+        };
+        let replace = ConflictOutcome::Replace {
+            target: target.clone(),
+            backup: None,
+        };
         match self.config.conflict_resolution {
-            ConflictResolution::Skip => {
-                eprintln!(
-                    "Warning: Skipping relocation due to existing file: {}",
-                    target.display()
-                );
-                Ok(target)
-            }
-            ConflictResolution::AutoRename => {
-                let file_stem = target
-                    .file_stem()
-                    .and_then(|s| s.to_str())
-                    .unwrap_or("file");
-                let extension = target.extension().and_then(|s| s.to_str()).unwrap_or("");
-                let parent = target.parent().unwrap_or_else(|| std::path::Path::new("."));
-                for i in 1..1000 {
-                    let new_name = if extension.is_empty() {
-                        format!("{}.{}", file_stem, i)
-                    } else {
-                        format!("{}.{}.{}", file_stem, i, extension)
-                    };
-                    let new_path = parent.join(new_name);
-                    if !new_path.exists() {
-                        return Ok(new_path);
+            ConflictResolution::Skip => Ok(ConflictOutcome::Skip(format!(
+                "{} already exists",
+                target.display()
+            ))),
+            ConflictResolution::AutoRename => Ok(ConflictOutcome::Write(numbered(&target)?)),
+            ConflictResolution::Prompt => {
+                let suggestion = numbered(&target)?;
+                if !std::io::stdin().is_terminal() {
+                    eprintln!("Warning: No terminal to prompt on, using auto-rename");
+                    return Ok(ConflictOutcome::Write(suggestion));
+                }
+                match TerminalPrompter.resolve_conflict(&target, &suggestion, false)? {
+                    ConflictDecision::Rename(name) => {
+                        self.resolve_filename_conflict(source, target.with_file_name(name))
                     }
+                    _ => Ok(ConflictOutcome::Write(suggestion)),
                 }
-                Err(SubXError::FileOperationFailed(
-                    "Could not resolve filename conflict".to_string(),
-                ))
             }
-            ConflictResolution::Prompt => {
-                eprintln!("Warning: Conflict resolution prompt not implemented, using auto-rename");
-                self.resolve_filename_conflict(target)
+            ConflictResolution::OverwriteIfIdentical => {
+                if hash_file(source)? == hash_file(&target)? {
+                    Ok(replace)
+                } else {
+                    Ok(ConflictOutcome::Write(numbered(&target)?))
+                }
+            }
+            ConflictResolution::KeepNewer => {
+                let modified =
+                    |path: &std::path::Path| std::fs::metadata(path).and_then(|m| m.modified());
+                if modified(source)? > modified(&target)? {
+                    Ok(replace)
+                } else {
+                    Ok(ConflictOutcome::Skip(format!(
+                        "{} is not older",
+                        target.display()
+                    )))
+                }
+            }
+            ConflictResolution::KeepLarger => {
+                if std::fs::metadata(source)?.len() > std::fs::metadata(&target)?.len() {
+                    Ok(replace)
+                } else {
+                    Ok(ConflictOutcome::Skip(format!(
+                        "{} is not smaller",
+                        target.display()
+                    )))
+                }
+            }
+            ConflictResolution::BackupAndReplace => {
+                let ext = target
+                    .extension()
+                    .map(|ext| ext.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                let mut backup = target.with_extension(format!("{ext}.backup"));
+                if backup.exists() {
+                    backup = numbered(&backup)?;
+                }
+                Ok(ConflictOutcome::Replace {
+                    target,
+                    backup: Some(backup),
+                })
             }
         }
     }
 
-    /// Create a task to copy (or rename) a file with new name
-    fn create_copy_task(&self, op: &MatchOperation) -> FileProcessingTask {
+    /// Create a task to copy a file to `target` under its new name
+    fn create_copy_task(&self, op: &MatchOperation, target: PathBuf) -> FileProcessingTask {
         // In copy mode, always use the original subtitle file as source
         let source = op.subtitle_file.path.clone();
-        let target_base = op.relocation_target_path.clone().unwrap();
-        let final_target = self.resolve_filename_conflict(target_base).unwrap();
         FileProcessingTask::new(
             source.clone(),
-            Some(final_target.clone()),
-            ProcessingOperation::CopyWithRename {
-                source,
-                target: final_target,
-            },
+            Some(target.clone()),
+            ProcessingOperation::CopyWithRename { source, target },
         )
     }
 
+    /// Create a task to link `target` to the original file
+    fn create_link_task(&self, op: &MatchOperation, target: PathBuf) -> FileProcessingTask {
+        let source = op.subtitle_file.path.clone();
+        let operation = match op.relocation_mode {
+            FileRelocationMode::Symlink { relative } => ProcessingOperation::SymlinkWithRename {
+                source: source.clone(),
+                target: target.clone(),
+                relative,
+            },
+            _ => ProcessingOperation::HardlinkWithRename {
+                source: source.clone(),
+                target: target.clone(),
+            },
+        };
+        FileProcessingTask::new(source, Some(target), operation)
+    }
+
     /// Create a task to backup a file
     fn create_backup_task(&self, source: &std::path::Path, ext: &str) -> FileProcessingTask {
         let backup_path = source.with_extension(format!("{}.backup", ext));
@@ -924,23 +2414,15 @@ impl MatchEngine {
         )
     }
 
-    /// Create a task to rename (move) a file
-    fn create_rename_task(&self, op: &MatchOperation) -> FileProcessingTask {
+    /// Create a task to rename (move) a file to `target`
+    fn create_rename_task(&self, op: &MatchOperation, target: PathBuf) -> FileProcessingTask {
         let old = op.subtitle_file.path.clone();
-        // If relocation is required, use the relocation target path
-        let new_path = if op.requires_relocation && op.relocation_target_path.is_some() {
-            let target_base = op.relocation_target_path.clone().unwrap();
-            self.resolve_filename_conflict(target_base).unwrap()
-        } else {
-            old.with_file_name(&op.new_subtitle_name)
-        };
-
         FileProcessingTask::new(
             old.clone(),
-            Some(new_path.clone()),
+            Some(target.clone()),
             ProcessingOperation::RenameFile {
                 source: old,
-                target: new_path,
+                target,
             },
         )
     }
@@ -1131,14 +2613,21 @@ impl MatchEngine {
         // Add configuration items that affect cache validity to the hash
         format!("{:?}", self.config.relocation_mode).hash(&mut hasher);
         self.config.backup_enabled.hash(&mut hasher);
-        // Add other relevant configuration items
+        self.config.ai_model.hash(&mut hasher);
+        self.config.naming.as_str().hash(&mut hasher);
+        self.config.verification_margin.to_bits().hash(&mut hasher);
+        self.local_prefilter.hash(&mut hasher);
+        self.audio_verification.is_some().hash(&mut hasher);
+        format!("{:?}", self.candidate_selection).hash(&mut hasher);
+        self.duration_check.hash(&mut hasher);
+        self.chunk_planner.max_prompt_tokens().hash(&mut hasher);
 
         Ok(format!("{:016x}", hasher.finish()))
     }
 
     /// Find a media file by ID, with an optional fallback to relative path or name.
     fn find_media_file_by_id_or_path<'a>(
-        files: &'a [&MediaFile],
+        files: &[&'a MediaFile],
         file_id: &str,
         fallback_path: Option<&str>,
     ) -> Option<&'a MediaFile> {
@@ -1199,3 +2688,119 @@ impl MatchEngine {
         }
     }
 }
+
+/// Videos `op`'s subtitle may be reassigned to by speech-pattern
+/// verification: its matched video and the videos sharing the show and
+/// season group of either the subtitle or that video. `videos` holds each
+/// video with its [`group_key`].
+fn speech_candidates<'a>(
+    videos: &[(&'a MediaFile, String)],
+    op: &MatchOperation,
+) -> Vec<&'a MediaFile> {
+    let groups = [group_key(&op.subtitle_file), group_key(&op.video_file)];
+    videos
+        .iter()
+        .filter(|(video, key)| video.id == op.video_file.id || groups.contains(key))
+        .map(|(video, _)| *video)
+        .collect()
+}
+
+/// Work handed to [`MatchEngine::run_scheduled`].
+type ScheduledFuture<T> = std::pin::Pin<Box<dyn std::future::Future<Output = Result<T>> + Send>>;
+
+/// A [`Task`] running one future and storing its output in `slot`, since
+/// [`TaskResult`] only carries a message.
+struct ScheduledJob<T> {
+    task_type: &'static str,
+    index: usize,
+    job: Mutex<Option<ScheduledFuture<T>>>,
+    slot: Arc<Mutex<Option<Result<T>>>>,
+}
+
+#[async_trait]
+impl<T: Send + 'static> Task for ScheduledJob<T> {
+    async fn execute(&self) -> TaskResult {
+        let Some(job) = self.job.lock().unwrap().take() else {
+            return TaskResult::Cancelled;
+        };
+        let result = job.await;
+        let outcome = match &result {
+            Ok(_) => TaskResult::Success(self.task_id()),
+            Err(e) => TaskResult::Failed(e.to_string()),
+        };
+        *self.slot.lock().unwrap() = Some(result);
+        outcome
+    }
+
+    fn task_type(&self) -> &'static str {
+        self.task_type
+    }
+
+    fn task_id(&self) -> String {
+        format!("{}_{}", self.task_type, self.index)
+    }
+}
+
+/// Maximum number of neighboring file names sent with a verification request.
+const MAX_LISTING_ENTRIES: usize = 40;
+
+/// First `stem.N.ext` next to `target` (N from 1) that does not exist and
+/// is not `taken`.
+pub(crate) fn numbered_path(
+    target: &std::path::Path,
+    taken: impl Fn(&std::path::Path) -> bool,
+) -> Option<PathBuf> {
+    let file_stem = target
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("file");
+    let extension = target.extension().and_then(|s| s.to_str()).unwrap_or("");
+    let parent = target.parent().unwrap_or_else(|| std::path::Path::new("."));
+    (1..1000)
+        .map(|i| {
+            if extension.is_empty() {
+                parent.join(format!("{}.{}", file_stem, i))
+            } else {
+                parent.join(format!("{}.{}.{}", file_stem, i, extension))
+            }
+        })
+        .find(|path| !path.exists() && !taken(path))
+}
+
+/// Names of the files in the video's and the subtitle's directories.
+fn directory_listing(video: &MediaFile, subtitle: &MediaFile) -> Vec<String> {
+    let mut dirs: Vec<_> = [video.path.parent(), subtitle.path.parent()]
+        .into_iter()
+        .flatten()
+        .collect();
+    dirs.dedup();
+    let mut names = Vec::new();
+    for dir in dirs {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            continue;
+        };
+        let mut dir_names: Vec<String> = entries
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().is_file())
+            .map(|e| e.file_name().to_string_lossy().into_owned())
+            .collect();
+        dir_names.sort();
+        names.extend(dir_names);
+    }
+    names.truncate(MAX_LISTING_ENTRIES);
+    names
+}
+
+/// Describe a file for an AI analysis request as
+/// `ID:<id> | Name:<name> | Path:<relative path>`, followed by
+/// ` | Parsed:<summary>` when release fields were recognized in its name.
+fn request_entry(file: &MediaFile) -> String {
+    let entry = format!(
+        "ID:{} | Name:{} | Path:{}",
+        file.id, file.name, file.relative_path
+    );
+    match file.parsed_name().summary() {
+        summary if summary.is_empty() => entry,
+        summary => format!("{entry} | Parsed:{summary}"),
+    }
+}
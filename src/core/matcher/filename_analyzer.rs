@@ -0,0 +1,812 @@
+//! Release-name parsing for video and subtitle file names.
+//!
+//! [`FilenameAnalyzer`] splits scene-style names
+//! (`Show.Name.S01E02.1080p.WEB-DL.x264-GROUP.mkv`) and fansub-style names
+//! (`[Group] Show Name - 02 [1080p][ABCD1234].mkv`) into a
+//! [`ParsedFilename`]: title, year, season, episode (including ranges such as
+//! `S01E01-E03` and specials such as `OVA` or `S00E01`), resolution, source,
+//! video codec, release group, CRC32 tag and language tags.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use subx_cli::core::matcher::FilenameAnalyzer;
+//!
+//! let analyzer = FilenameAnalyzer::new();
+//! let parsed = analyzer.parse("Movie.Name.2023.1080p.BluRay.x264-GROUP.mkv");
+//! assert_eq!(parsed.title, "Movie Name");
+//! assert_eq!(parsed.year, Some(2023));
+//! assert_eq!(parsed.resolution.as_deref(), Some("1080p"));
+//! assert_eq!(parsed.source.as_deref(), Some("BluRay"));
+//! assert_eq!(parsed.video_codec.as_deref(), Some("H.264"));
+//! assert_eq!(parsed.release_group.as_deref(), Some("GROUP"));
+//!
+//! let parsed = analyzer.parse("[SubGroup] Show Name - 12 [720p][ABCD1234].chs.ass");
+//! assert_eq!(parsed.title, "Show Name");
+//! assert_eq!(parsed.episode, Some(12));
+//! assert_eq!(parsed.crc32.as_deref(), Some("ABCD1234"));
+//! assert_eq!(parsed.languages, vec!["sc"]);
+//! ```
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Numbers written with digits, full-width digits or Chinese numerals.
+const NUMBER: &str = r"[0-9０-９]+|[〇零一二两三四五六七八九十百]+";
+
+static SEASON_EPISODE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)(?:^|[^a-z0-9])s(\d{1,2})[ ._-]?e(\d{1,4})(?:(?:-?e|-)(\d{1,4}))?(?:[^0-9]|$)")
+        .unwrap()
+});
+static CROSS_EPISODE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)(?:^|[^a-z0-9])(\d{1,2})x(\d{2,3})(?:-(\d{2,3}))?(?:[^0-9]|$)").unwrap()
+});
+static CJK_EPISODE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r"第\s*({NUMBER})\s*(?:[-~～]\s*第?\s*({NUMBER})\s*)?[話话集回]"
+    ))
+    .unwrap()
+});
+static SPECIAL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)(?:^|[^a-z0-9])(?:(?:sp|ova|oad|ona)[ ._-]?(\d{1,3})?|special[ ._-]?(\d{1,3}))(?:[^a-z0-9]|$)|\[special\]|特別篇|特别篇|番外篇?",
+    )
+    .unwrap()
+});
+static EP_EPISODE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)(?:^|[^a-z0-9])(?:episode|ep|e)[ ._-]?(\d{1,4})(?:\s*-\s*(?:ep?)?(\d{1,4}))?(?:v\d)?(?:[^0-9a-z]|$)",
+    )
+    .unwrap()
+});
+static DASH_EPISODE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)(?:^|\s)-\s+(\d{1,4})(?:\s*-\s*(\d{1,4}))?(?:v\d)?(?:\s|\[|\(|$)|\[(\d{1,3})(?:-(\d{1,3}))?(?:v\d)?(?:\s?end)?\]|^(\d{1,3})$",
+    )
+    .unwrap()
+});
+static SEASON: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)(?:^|[^a-z0-9])(?:season[ ._-]*|s)(\d{1,2})(?:[^0-9a-z]|$)").unwrap()
+});
+static CJK_SEASON: Lazy<Regex> =
+    Lazy::new(|| Regex::new(&format!(r"第\s*({NUMBER})\s*[季期部]")).unwrap());
+static YEAR: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)(?:^|[^a-z0-9])((?:19|20)\d{2})(?:[^0-9a-z]|$)").unwrap());
+static RESOLUTION: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)(?:^|[^a-z0-9])(?:(360|480|540|576|720|1080|1440|2160|4320)[pi]|(4k|uhd))(?:[^a-z0-9]|$)",
+    )
+    .unwrap()
+});
+static DIMENSIONS: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)(?:^|[^0-9])\d{3,4}x(\d{3,4})(?:[^0-9]|$)").unwrap());
+static LEADING_GROUP: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\s*(?:\[([^\]]+)\]|【([^】]+)】)").unwrap());
+static BRACKET_TAG: Lazy<Regex> = Lazy::new(|| Regex::new(r"\[([^\]]+)\]|【([^】]+)】").unwrap());
+static BRACKETED: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\[[^\]]*\]|【[^】]*】|\([^)]*\)|（[^）]*）|「[^」]*」").unwrap());
+static SCENE_GROUP: Lazy<Regex> = Lazy::new(|| Regex::new(r"[^\s\-]-([A-Za-z0-9]+)$").unwrap());
+static CRC32: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[0-9A-Fa-f]{8}$").unwrap());
+
+/// Release sources, most specific first, with their canonical names.
+static SOURCES: Lazy<Vec<(Regex, &'static str)>> = Lazy::new(|| {
+    [
+        (r"web-?dl", "WEB-DL"),
+        (r"web-?rip", "WEBRip"),
+        (r"bd-?rip|br-?rip", "BDRip"),
+        (r"blu-?ray|bd-?remux|bd", "BluRay"),
+        (r"hdtv", "HDTV"),
+        (r"dvd-?rip", "DVDRip"),
+        (r"dvd(?:5|9)?", "DVD"),
+        (r"hd-?rip", "HDRip"),
+        (r"web", "WEB"),
+    ]
+    .into_iter()
+    .map(|(pattern, name)| (bounded(pattern), name))
+    .collect()
+});
+
+/// Video codecs with their canonical names.
+static CODECS: Lazy<Vec<(Regex, &'static str)>> = Lazy::new(|| {
+    [
+        (r"x\.?265|h\.?265|hevc", "H.265"),
+        (r"x\.?264|h\.?264|avc", "H.264"),
+        (r"av1", "AV1"),
+        (r"vp9", "VP9"),
+        (r"xvid", "XviD"),
+        (r"divx", "DivX"),
+    ]
+    .into_iter()
+    .map(|(pattern, name)| (bounded(pattern), name))
+    .collect()
+});
+
+/// Trailing tags that mark a subtitle variant rather than a language.
+const VARIANT_TAGS: &[&str] = &["forced", "sdh", "cc", "default"];
+
+/// Suffixes that look like release groups but are part of a source name.
+const NOT_GROUPS: &[&str] = &["dl", "rip", "ray", "remux"];
+
+/// Release information parsed from a file name.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParsedFilename {
+    /// Title of the movie or show, with separators turned into spaces
+    pub title: String,
+    /// Release year
+    pub year: Option<u32>,
+    /// Season number
+    pub season: Option<u32>,
+    /// Episode number, or the first episode of a range
+    pub episode: Option<u32>,
+    /// Last episode of a multi-episode range such as `S01E01-E03`
+    pub episode_end: Option<u32>,
+    /// Whether this is a special (`S00E01`, `SP01`, `OVA`, `特別篇`)
+    pub special: bool,
+    /// Vertical resolution such as `1080p`
+    pub resolution: Option<String>,
+    /// Release source such as `BluRay` or `WEB-DL`
+    pub source: Option<String>,
+    /// Video codec such as `H.264`
+    pub video_codec: Option<String>,
+    /// Scene (`-GROUP`) or fansub (`[Group]`) release group
+    pub release_group: Option<String>,
+    /// Upper-case CRC32 checksum tag such as `ABCD1234`
+    pub crc32: Option<String>,
+    /// Language codes from tags such as `.en`, `.chs` or `[繁體]`
+    pub languages: Vec<String>,
+    /// Whether a `.forced` tag marks a forced-narrative subtitle
+    pub forced: bool,
+    /// Whether an `.sdh` or `.cc` tag marks a hearing-impaired subtitle
+    pub hearing_impaired: bool,
+}
+
+impl ParsedFilename {
+    /// Lowercase title words for comparison. CJK titles are split into
+    /// single characters since they are written without spaces.
+    pub fn title_tokens(&self) -> Vec<String> {
+        let mut tokens = Vec::new();
+        for word in self
+            .title
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+        {
+            let word = word.to_lowercase();
+            if word.is_ascii() {
+                tokens.push(word);
+            } else {
+                tokens.extend(word.chars().map(String::from));
+            }
+        }
+        tokens
+    }
+
+    /// Season and episode in `S01E02`, `S01E01-E03` or `E02` form, if any.
+    pub fn episode_tag(&self) -> Option<String> {
+        let episode = self.episode?;
+        let mut tag = match self.season {
+            Some(season) => format!("S{season:02}E{episode:02}"),
+            None => format!("E{episode:02}"),
+        };
+        if let Some(end) = self.episode_end {
+            tag.push_str(&format!("-E{end:02}"));
+        }
+        Some(tag)
+    }
+
+    /// Compact `key=value` summary of the parsed fields, e.g.
+    /// `title=Show Name; episode=S01E02; resolution=1080p`.
+    pub fn summary(&self) -> String {
+        let mut fields = Vec::new();
+        if !self.title.is_empty() {
+            fields.push(format!("title={}", self.title));
+        }
+        if let Some(year) = self.year {
+            fields.push(format!("year={year}"));
+        }
+        match self.episode_tag() {
+            Some(tag) => fields.push(format!("episode={tag}")),
+            None => {
+                if let Some(season) = self.season {
+                    fields.push(format!("season={season}"));
+                }
+            }
+        }
+        if self.special {
+            fields.push("special".to_string());
+        }
+        let named = [
+            ("resolution", &self.resolution),
+            ("source", &self.source),
+            ("codec", &self.video_codec),
+            ("group", &self.release_group),
+            ("crc32", &self.crc32),
+        ];
+        for (key, value) in named {
+            if let Some(value) = value {
+                fields.push(format!("{key}={value}"));
+            }
+        }
+        if !self.languages.is_empty() {
+            fields.push(format!("languages={}", self.languages.join(",")));
+        }
+        fields.join("; ")
+    }
+}
+
+/// Parser for scene and fansub release names.
+#[derive(Debug, Default)]
+pub struct FilenameAnalyzer;
+
+impl FilenameAnalyzer {
+    /// Create a new analyzer.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Parse a file name (with or without extension).
+    pub fn parse(&self, name: &str) -> ParsedFilename {
+        let full_stem = strip_extension(name);
+        let (stem, languages) = strip_language_tags(full_stem);
+        let mut parsed = ParsedFilename {
+            languages,
+            ..ParsedFilename::default()
+        };
+        for tag in full_stem[stem.len()..].split(['.', '_', '-']) {
+            match tag.to_lowercase().as_str() {
+                "forced" => parsed.forced = true,
+                "sdh" | "cc" => parsed.hearing_impaired = true,
+                _ => {}
+            }
+        }
+        let mut title_end = stem.len();
+
+        // Bracketed tags: CRC32 checksums and languages
+        for caps in BRACKET_TAG.captures_iter(stem) {
+            let Some(content) = caps.get(1).or_else(|| caps.get(2)) else {
+                continue;
+            };
+            let start = caps.get(0).map_or(0, |m| m.start());
+            if CRC32.is_match(content.as_str())
+                && content.as_str().chars().any(|c| c.is_ascii_digit())
+            {
+                parsed.crc32 = Some(content.as_str().to_uppercase());
+                title_end = title_end.min(start);
+            } else if let Some(codes) = language_codes(content.as_str()) {
+                for code in codes {
+                    if !parsed.languages.contains(&code) {
+                        parsed.languages.push(code);
+                    }
+                }
+                if start > 0 {
+                    title_end = title_end.min(start);
+                }
+            }
+        }
+
+        if let Some(episode) = find_episode(stem) {
+            parsed.season = episode.season;
+            parsed.episode = episode.episode;
+            parsed.episode_end = episode.episode_end;
+            parsed.special = episode.special;
+            title_end = title_end.min(episode.start);
+        }
+        if parsed.season.is_none() {
+            if let Some((start, season)) = find_season(stem) {
+                parsed.season = Some(season);
+                title_end = title_end.min(start);
+            }
+        }
+        if let Some((start, year)) = find_year(stem) {
+            parsed.year = Some(year);
+            title_end = title_end.min(start);
+        }
+
+        if let Some(caps) = RESOLUTION.captures(stem) {
+            parsed.resolution = Some(match caps.get(1) {
+                Some(height) => format!("{}p", height.as_str()),
+                None => "2160p".to_string(),
+            });
+            title_end = title_end.min(caps.get(0).map_or(0, |m| m.start()));
+        } else if let Some(caps) = DIMENSIONS.captures(stem) {
+            parsed.resolution = Some(format!("{}p", &caps[1]));
+            title_end = title_end.min(caps.get(0).map_or(0, |m| m.start()));
+        }
+        if let Some((start, source)) = find_first(&SOURCES, stem) {
+            parsed.source = Some(source.to_string());
+            title_end = title_end.min(start);
+        }
+        if let Some((start, codec)) = find_first(&CODECS, stem) {
+            parsed.video_codec = Some(codec.to_string());
+            title_end = title_end.min(start);
+        }
+
+        // A leading bracket is the fansub group, unless it holds the title
+        let leading = LEADING_GROUP.captures(stem).and_then(|caps| {
+            let content = caps.get(1).or_else(|| caps.get(2))?;
+            let end = caps.get(0)?.end();
+            (end <= title_end).then(|| (content.as_str().trim().to_string(), end))
+        });
+        match leading {
+            Some((group, end)) => {
+                parsed.title = clean_title(&stem[end..title_end]);
+                if parsed.title.is_empty() {
+                    parsed.title = clean_title(&group);
+                } else {
+                    parsed.release_group = Some(group);
+                }
+            }
+            None => parsed.title = clean_title(&stem[..title_end]),
+        }
+
+        // Scene releases end in `-GROUP`
+        let has_release_info =
+            parsed.resolution.is_some() || parsed.source.is_some() || parsed.video_codec.is_some();
+        if parsed.release_group.is_none() && has_release_info {
+            if let Some(caps) = SCENE_GROUP.captures(stem) {
+                let group = &caps[1];
+                if !NOT_GROUPS.contains(&group.to_lowercase().as_str())
+                    && find_first(&SOURCES, group).is_none()
+                    && find_first(&CODECS, group).is_none()
+                {
+                    parsed.release_group = Some(group.to_string());
+                }
+            }
+        }
+
+        parsed
+    }
+
+    /// Parse a file name, using the directories of its relative path for a
+    /// season (`Season 2`, `S02`, `第2季`) or a title when the name alone
+    /// carries none.
+    pub fn parse_with_path(&self, name: &str, relative_path: &str) -> ParsedFilename {
+        let mut parsed = self.parse(name);
+        let directories: Vec<&str> = relative_path.split(['/', '\\']).rev().skip(1).collect();
+        if parsed.season.is_none() {
+            parsed.season = directories
+                .iter()
+                .find_map(|dir| find_season(dir).map(|(_, season)| season));
+        }
+        if parsed.title.is_empty() {
+            parsed.title = directories
+                .iter()
+                .map(|dir| self.parse(dir).title)
+                .find(|title| !title.is_empty() && language_codes(title).is_none())
+                .unwrap_or_default();
+        }
+        parsed
+    }
+}
+
+/// Episode information found in a name and where its marker starts.
+struct EpisodeMatch {
+    start: usize,
+    season: Option<u32>,
+    episode: Option<u32>,
+    episode_end: Option<u32>,
+    special: bool,
+}
+
+/// Find an episode marker. Markers carrying a season are preferred over
+/// bare episode numbers.
+fn find_episode(stem: &str) -> Option<EpisodeMatch> {
+    let number =
+        |caps: &regex::Captures, i: usize| caps.get(i).and_then(|m| parse_number(m.as_str()));
+
+    for re in [&*SEASON_EPISODE, &*CROSS_EPISODE] {
+        if let Some(caps) = re.captures(stem) {
+            let season = number(&caps, 1);
+            return Some(EpisodeMatch {
+                start: caps.get(0)?.start(),
+                season,
+                episode: number(&caps, 2),
+                episode_end: number(&caps, 3),
+                special: season == Some(0),
+            });
+        }
+    }
+    if let Some(caps) = CJK_EPISODE.captures(stem) {
+        return Some(EpisodeMatch {
+            start: caps.get(0)?.start(),
+            season: None,
+            episode: number(&caps, 1),
+            episode_end: number(&caps, 2),
+            special: false,
+        });
+    }
+    if let Some(caps) = SPECIAL.captures(stem) {
+        return Some(EpisodeMatch {
+            start: caps.get(0)?.start(),
+            season: None,
+            episode: number(&caps, 1).or_else(|| number(&caps, 2)),
+            episode_end: None,
+            special: true,
+        });
+    }
+    for re in [&*EP_EPISODE, &*DASH_EPISODE] {
+        for caps in re.captures_iter(stem) {
+            let numbers: Vec<u32> = caps
+                .iter()
+                .skip(1)
+                .flatten()
+                .filter_map(|m| parse_number(m.as_str()))
+                .collect();
+            // A four-digit "episode" such as `- 2019` is a year
+            if numbers.first().is_none_or(|n| (1900..2100).contains(n)) {
+                continue;
+            }
+            return Some(EpisodeMatch {
+                start: caps.get(0)?.start(),
+                season: None,
+                episode: numbers.first().copied(),
+                episode_end: numbers.get(1).copied(),
+                special: false,
+            });
+        }
+    }
+    None
+}
+
+/// Find a season marker such as `Season 2`, `S02` or `第2季`.
+fn find_season(text: &str) -> Option<(usize, u32)> {
+    if let Some(caps) = SEASON.captures(text) {
+        return Some((caps.get(0)?.start(), caps[1].parse().ok()?));
+    }
+    CJK_SEASON
+        .captures(text)
+        .and_then(|caps| Some((caps.get(0)?.start(), parse_number(&caps[1])?)))
+}
+
+/// Find a release year, ignoring one that opens the name (it is more likely
+/// part of the title, as in `2001 A Space Odyssey`).
+fn find_year(stem: &str) -> Option<(usize, u32)> {
+    YEAR.captures_iter(stem).find_map(|caps| {
+        let year = caps.get(1)?;
+        if year.start() == 0 {
+            return None;
+        }
+        Some((caps.get(0)?.start(), year.as_str().parse().ok()?))
+    })
+}
+
+/// First entry of `table` found in `text`, with its position.
+fn find_first(table: &[(Regex, &'static str)], text: &str) -> Option<(usize, &'static str)> {
+    table
+        .iter()
+        .find_map(|(re, name)| re.find(text).map(|m| (m.start(), *name)))
+}
+
+/// Case-insensitive pattern that must stand alone between separators.
+fn bounded(pattern: &str) -> Regex {
+    Regex::new(&format!(r"(?i)(?:^|[^a-z0-9])(?:{pattern})(?:[^a-z0-9]|$)")).unwrap()
+}
+
+/// Turn the title part of a name into words separated by single spaces.
+fn clean_title(raw: &str) -> String {
+    let without_tags = BRACKETED.replace_all(raw, " ");
+    let source = if without_tags.trim().is_empty() {
+        // The title itself may be bracketed, as in `[Title][01]`
+        BRACKET_TAG
+            .captures(raw)
+            .and_then(|caps| caps.get(1).or_else(|| caps.get(2)))
+            .map_or(String::new(), |m| m.as_str().to_string())
+    } else {
+        without_tags.into_owned()
+    };
+    source
+        .replace(['.', '_'], " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_matches(|c: char| c == '-' || c == ':' || c.is_whitespace())
+        .to_string()
+}
+
+/// Drop a short trailing extension such as `.mkv` or `.srt`.
+pub(crate) fn strip_extension(name: &str) -> &str {
+    match name.rsplit_once('.') {
+        Some((stem, ext))
+            if !stem.is_empty()
+                && (1..=4).contains(&ext.len())
+                && ext.chars().all(|c| c.is_ascii_alphanumeric())
+                && !ext.chars().all(|c| c.is_ascii_digit()) =>
+        {
+            stem
+        }
+        _ => name,
+    }
+}
+
+/// Split trailing language and variant tags (`.en`, `_chs`, `.forced`) off
+/// a file stem, returning the rest and the language codes in name order.
+pub(crate) fn strip_language_tags(stem: &str) -> (&str, Vec<String>) {
+    let mut rest = stem;
+    let mut languages = Vec::new();
+    while let Some(mut i) = rest.rfind(['.', '_', '-']) {
+        if i == 0 {
+            break;
+        }
+        // Region and script subtags such as `zh-Hant` or `pt-BR`
+        if rest[i..].starts_with('-') {
+            if let Some(j) = rest[..i].rfind(['.', '_']) {
+                if j > 0 && language_codes(&rest[j + 1..]).is_some() {
+                    i = j;
+                }
+            }
+        }
+        let tag = &rest[i + 1..];
+        if let Some(codes) = language_codes(tag) {
+            languages.splice(0..0, codes);
+        } else if !VARIANT_TAGS.contains(&tag.to_lowercase().as_str()) {
+            break;
+        }
+        rest = &rest[..i];
+    }
+    (rest, languages)
+}
+
+/// Language codes for a tag such as `en`, `chs`, `zh-Hant`, `简日` or
+/// `chs_jp`, normalized to the codes used by
+/// [`LanguageDetector`](crate::core::language::LanguageDetector)
+/// (`tc`/`sc` for Chinese scripts).
+pub(crate) fn language_codes(tag: &str) -> Option<Vec<String>> {
+    let lower = tag.trim().to_lowercase();
+    if let Some(code) = language_code(&lower) {
+        return Some(vec![code.to_string()]);
+    }
+    let parts: Vec<&str> = lower
+        .split(['_', '&', '+', ' ', '.'])
+        .filter(|p| !p.is_empty())
+        .collect();
+    if parts.len() > 1 {
+        return parts
+            .iter()
+            .map(|p| language_code(p).map(str::to_string))
+            .collect();
+    }
+    // Combined CJK tags such as 简日 or 繁日双语
+    let combined = lower
+        .trim_end_matches("双语")
+        .trim_end_matches("雙語")
+        .trim_end_matches("内嵌")
+        .trim_end_matches("內嵌");
+    let count = combined.chars().count();
+    if !(2..=3).contains(&count) {
+        return None;
+    }
+    combined
+        .chars()
+        .map(|c| match c {
+            '简' | '簡' => Some("sc".to_string()),
+            '繁' => Some("tc".to_string()),
+            '日' => Some("ja".to_string()),
+            '中' => Some("zh".to_string()),
+            '英' => Some("en".to_string()),
+            '韩' | '韓' => Some("ko".to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn language_code(tag: &str) -> Option<&'static str> {
+    Some(match tag {
+        "tc" | "cht" | "big5" | "繁體" | "繁体" | "繁中" | "zh-hant" | "zh-tw" | "zh-hk" => {
+            "tc"
+        }
+        "sc" | "chs" | "gb" | "简体" | "簡體" | "简中" | "zh-hans" | "zh-cn" => "sc",
+        "zh" | "chi" | "zho" | "chinese" | "中文" => "zh",
+        "en" | "eng" | "english" | "英文" => "en",
+        "ja" | "jp" | "jpn" | "japanese" | "日本語" | "日文" => "ja",
+        "ko" | "kor" | "korean" | "韓文" | "韩文" => "ko",
+        "fr" | "fre" | "fra" | "french" => "fr",
+        "de" | "ger" | "deu" | "german" => "de",
+        "es" | "spa" | "spanish" => "es",
+        "it" | "ita" | "italian" => "it",
+        "pt" | "por" | "portuguese" => "pt",
+        "ru" | "rus" | "russian" => "ru",
+        _ => return None,
+    })
+}
+
+/// Parse Arabic, full-width or simple Chinese numerals up to 999.
+fn parse_number(text: &str) -> Option<u32> {
+    if let Ok(n) = text.parse() {
+        return Some(n);
+    }
+    if text.chars().all(|c| ('０'..='９').contains(&c)) {
+        return text
+            .chars()
+            .map(|c| c as u32 - '０' as u32)
+            .try_fold(0u32, |acc, d| acc.checked_mul(10)?.checked_add(d));
+    }
+    let digit = |c: char| "〇一二三四五六七八九".chars().position(|d| d == c);
+    let mut total = 0;
+    let mut current = 0;
+    for c in text.chars() {
+        match c {
+            '零' => {}
+            '两' => current = 2,
+            '十' => {
+                total += current.max(1) * 10;
+                current = 0;
+            }
+            '百' => {
+                total += current.max(1) * 100;
+                current = 0;
+            }
+            c => current = digit(c)? as u32,
+        }
+    }
+    Some(total + current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(name: &str) -> ParsedFilename {
+        FilenameAnalyzer::new().parse(name)
+    }
+
+    #[test]
+    fn test_scene_episode_release() {
+        let parsed = parse("The.Show.Name.S02E05.720p.HDTV.x265-RlsGrp.mkv");
+        assert_eq!(parsed.title, "The Show Name");
+        assert_eq!(parsed.season, Some(2));
+        assert_eq!(parsed.episode, Some(5));
+        assert_eq!(parsed.episode_end, None);
+        assert!(!parsed.special);
+        assert_eq!(parsed.resolution.as_deref(), Some("720p"));
+        assert_eq!(parsed.source.as_deref(), Some("HDTV"));
+        assert_eq!(parsed.video_codec.as_deref(), Some("H.265"));
+        assert_eq!(parsed.release_group.as_deref(), Some("RlsGrp"));
+    }
+
+    #[test]
+    fn test_web_dl_is_not_a_release_group() {
+        let parsed = parse("Show.S01E02.1080p.WEB-DL.mkv");
+        assert_eq!(parsed.source.as_deref(), Some("WEB-DL"));
+        assert_eq!(parsed.release_group, None);
+    }
+
+    #[test]
+    fn test_episode_ranges() {
+        for name in [
+            "Show.S01E01-E03.mkv",
+            "Show.S01E01E02E03.mkv",
+            "Show 1x01-03.mkv",
+        ] {
+            let parsed = parse(name);
+            assert_eq!(parsed.season, Some(1), "{name}");
+            assert_eq!(parsed.episode, Some(1), "{name}");
+            assert!(parsed.episode_end.is_some(), "{name}");
+        }
+        assert_eq!(parse("Show.S01E01-E03.mkv").episode_end, Some(3));
+        assert_eq!(parse("某动画 第01-03话.mkv").episode_end, Some(3));
+        assert_eq!(
+            parse("[Grp] Show - 01-12 [1080p].mkv").episode_end,
+            Some(12)
+        );
+        assert_eq!(
+            parse("Show.S01E01-E03.mkv").episode_tag().as_deref(),
+            Some("S01E01-E03")
+        );
+    }
+
+    #[test]
+    fn test_specials() {
+        let parsed = parse("Show.S00E03.1080p.mkv");
+        assert!(parsed.special);
+        assert_eq!(parsed.episode, Some(3));
+
+        let parsed = parse("[Grp] Show OVA 02 [720p].mkv");
+        assert!(parsed.special);
+        assert_eq!(parsed.episode, Some(2));
+        assert_eq!(parsed.title, "Show");
+
+        let parsed = parse("Show.Special.2.720p.mkv");
+        assert!(parsed.special);
+        assert_eq!(parsed.episode, Some(2));
+
+        let parsed = parse("某动画 特別篇.ass");
+        assert!(parsed.special);
+        assert_eq!(parsed.title, "某动画");
+    }
+
+    #[test]
+    fn test_fansub_release() {
+        let parsed = parse("[SubGroup] Show Name - 07v2 [1080p][HEVC][0A1B2C3D].mkv");
+        assert_eq!(parsed.release_group.as_deref(), Some("SubGroup"));
+        assert_eq!(parsed.title, "Show Name");
+        assert_eq!(parsed.episode, Some(7));
+        assert_eq!(parsed.resolution.as_deref(), Some("1080p"));
+        assert_eq!(parsed.video_codec.as_deref(), Some("H.265"));
+        assert_eq!(parsed.crc32.as_deref(), Some("0A1B2C3D"));
+    }
+
+    #[test]
+    fn test_bracketed_title_is_not_a_group() {
+        let parsed = parse("[Noumin Kanren no Skill][01][BDRIP][1080P][H264_FLACx2].mkv");
+        assert_eq!(parsed.title, "Noumin Kanren no Skill");
+        assert_eq!(parsed.release_group, None);
+        assert_eq!(parsed.episode, Some(1));
+        assert_eq!(parsed.source.as_deref(), Some("BDRip"));
+        assert_eq!(parsed.video_codec.as_deref(), Some("H.264"));
+    }
+
+    #[test]
+    fn test_cjk_release_with_language_tag() {
+        let parsed =
+            parse("夜桜さんちの大作戦 第01話 「桜の指輪」 (BD 1920x1080 SVT-AV1 ALAC).tc.ass");
+        assert_eq!(parsed.title, "夜桜さんちの大作戦");
+        assert_eq!(parsed.episode, Some(1));
+        assert_eq!(parsed.resolution.as_deref(), Some("1080p"));
+        assert_eq!(parsed.source.as_deref(), Some("BluRay"));
+        assert_eq!(parsed.video_codec.as_deref(), Some("AV1"));
+        assert_eq!(parsed.languages, vec!["tc"]);
+        assert_eq!(parse("某动画 第十二集.ass").episode, Some(12));
+    }
+
+    #[test]
+    fn test_language_tags() {
+        assert_eq!(parse("Movie.2020.en.forced.srt").languages, vec!["en"]);
+        assert!(parse("Movie.2020.en.forced.srt").forced);
+        assert!(parse("Movie.2020.en.sdh.srt").hearing_impaired);
+        assert!(!parse("Movie.2020.en.srt").forced);
+        assert_eq!(parse("Movie.2020.zh-Hant.srt").languages, vec!["tc"]);
+        assert_eq!(parse("Movie.2020.chs.eng.srt").languages, vec!["sc", "en"]);
+        assert_eq!(
+            parse("[Grp] Show - 01 [简日双语].ass").languages,
+            vec!["sc", "ja"]
+        );
+        assert_eq!(
+            parse("[Grp] Show - 01 [CHS_JP].ass").languages,
+            vec!["sc", "ja"]
+        );
+        assert!(parse("Movie.2020.1080p.mkv").languages.is_empty());
+    }
+
+    #[test]
+    fn test_year_and_leading_number_title() {
+        let parsed = parse("Movie Name (2019) [2160p].mkv");
+        assert_eq!(parsed.title, "Movie Name");
+        assert_eq!(parsed.year, Some(2019));
+        assert_eq!(parsed.resolution.as_deref(), Some("2160p"));
+
+        let parsed = parse("2001.A.Space.Odyssey.1968.mkv");
+        assert_eq!(parsed.title, "2001 A Space Odyssey");
+        assert_eq!(parsed.year, Some(1968));
+    }
+
+    #[test]
+    fn test_parse_with_path_uses_directories() {
+        let analyzer = FilenameAnalyzer::new();
+        let parsed = analyzer.parse_with_path("E05.mkv", "Great Show/Season 2/E05.mkv");
+        assert_eq!(parsed.title, "Great Show");
+        assert_eq!(parsed.season, Some(2));
+        assert_eq!(parsed.episode, Some(5));
+
+        let parsed = analyzer.parse_with_path("05.srt", "Great Show/English/05.srt");
+        assert_eq!(parsed.title, "Great Show");
+        assert_eq!(parsed.episode, Some(5));
+    }
+
+    #[test]
+    fn test_summary() {
+        let parsed = parse("Show.S01E02.1080p.WEB-DL.x264-GRP.en.srt");
+        assert_eq!(
+            parsed.summary(),
+            "title=Show; episode=S01E02; resolution=1080p; source=WEB-DL; codec=H.264; group=GRP; languages=en"
+        );
+        assert_eq!(ParsedFilename::default().summary(), "");
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number("02"), Some(2));
+        assert_eq!(parse_number("１２"), Some(12));
+        assert_eq!(parse_number("十"), Some(10));
+        assert_eq!(parse_number("二十三"), Some(23));
+        assert_eq!(parse_number("一百零五"), Some(105));
+    }
+}
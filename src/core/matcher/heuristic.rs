@@ -0,0 +1,495 @@
+//! Offline matching of videos and subtitles by filename heuristics.
+//!
+//! [`HeuristicMatcher`] pairs files without any AI provider. It uses
+//! [`FilenameAnalyzer`] to read season and episode numbers (`S01E02`, `1x02`,
+//! `第02話`, `EP02`, `Title - 02`), release years and normalized title words
+//! from each file name and its relative path, scores every video/subtitle pair and then solves the
+//! assignment optimally with the Hungarian algorithm, so that each video
+//! receives at most one subtitle per language.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use subx_cli::core::matcher::heuristic::ParsedMediaName;
+//!
+//! let name = "Show.Name.S01E02.1080p.WEB-DL.mkv";
+//! let parsed = ParsedMediaName::parse(name, name);
+//! assert_eq!(parsed.season, Some(1));
+//! assert_eq!(parsed.episode, Some(2));
+//! assert_eq!(parsed.title_tokens, vec!["show", "name"]);
+//! ```
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use crate::core::language::LanguageDetector;
+use crate::core::matcher::MediaFile;
+use crate::core::matcher::filename_analyzer::{
+    FilenameAnalyzer, strip_extension, strip_language_tags,
+};
+
+/// Score given to pairs whose names are identical apart from extension and
+/// language tag.
+const IDENTICAL_NAME_SCORE: f32 = 0.98;
+
+/// Upper bound for scores derived from parsed name components.
+const MAX_COMPONENT_SCORE: f32 = 0.97;
+
+/// Words that describe a release rather than its title.
+const NOISE_WORDS: &[&str] = &[
+    "480p",
+    "576p",
+    "720p",
+    "1080p",
+    "1080i",
+    "2160p",
+    "4k",
+    "uhd",
+    "hdr",
+    "bluray",
+    "bdrip",
+    "brrip",
+    "webrip",
+    "web",
+    "dl",
+    "webdl",
+    "hdtv",
+    "dvdrip",
+    "remux",
+    "x264",
+    "x265",
+    "h264",
+    "h265",
+    "hevc",
+    "avc",
+    "aac",
+    "ac3",
+    "dts",
+    "flac",
+    "10bit",
+    "8bit",
+    "proper",
+    "repack",
+    "sub",
+    "subs",
+    "subtitle",
+    "subtitles",
+    "en",
+    "eng",
+    "english",
+    "tc",
+    "sc",
+    "cht",
+    "chs",
+    "zh",
+    "ja",
+    "jp",
+    "jpn",
+];
+
+/// Season, episode, year and title words parsed from a file name and path.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParsedMediaName {
+    /// File name without extension or trailing language tag, lowercased
+    pub base_name: String,
+    /// Normalized words of the title
+    pub title_tokens: Vec<String>,
+    /// Season number, from the name or a `Season N` directory
+    pub season: Option<u32>,
+    /// Episode number
+    pub episode: Option<u32>,
+    /// Release year
+    pub year: Option<u32>,
+}
+
+impl ParsedMediaName {
+    /// Parse a file name, using its relative path for a season directory or
+    /// a title when the name alone carries none.
+    pub fn parse(name: &str, relative_path: &str) -> Self {
+        let (base_name, _) = strip_language_tags(strip_extension(name));
+        let parsed = FilenameAnalyzer::new().parse_with_path(name, relative_path);
+        Self {
+            base_name: base_name.to_lowercase(),
+            title_tokens: parsed
+                .title_tokens()
+                .into_iter()
+                .filter(|token| !NOISE_WORDS.contains(&token.as_str()))
+                .collect(),
+            season: parsed.season,
+            episode: parsed.episode,
+            year: parsed.year,
+        }
+    }
+}
+
+/// A subtitle assigned to a video by [`HeuristicMatcher`].
+#[derive(Debug, Clone)]
+pub struct HeuristicMatch<'a> {
+    /// The matched video file
+    pub video: &'a MediaFile,
+    /// The matched subtitle file
+    pub subtitle: &'a MediaFile,
+    /// Confidence score of the pair (0.0 to 1.0)
+    pub confidence: f32,
+    /// Human-readable reasons behind the score
+    pub reasons: Vec<String>,
+}
+
+/// Pairs videos with subtitles from their names and paths alone.
+pub struct HeuristicMatcher {
+    language_detector: LanguageDetector,
+}
+
+impl HeuristicMatcher {
+    /// Create a matcher with the default language detector.
+    pub fn new() -> Self {
+        Self {
+            language_detector: LanguageDetector::new(),
+        }
+    }
+
+    /// Score a single video/subtitle pair.
+    ///
+    /// Returns a confidence from 0.0 (incompatible, e.g. different episodes)
+    /// to 1.0 together with the reasons behind it.
+    pub fn score(&self, video: &MediaFile, subtitle: &MediaFile) -> (f32, Vec<String>) {
+        let video_name = ParsedMediaName::parse(&video.name, &video.relative_path);
+        let subtitle_name = ParsedMediaName::parse(&subtitle.name, &subtitle.relative_path);
+        let same_directory =
+            Path::new(&video.relative_path).parent() == Path::new(&subtitle.relative_path).parent();
+        score_names(&video_name, &subtitle_name, same_directory)
+    }
+
+    /// Assign subtitles to videos so that the total score is maximal.
+    ///
+    /// Subtitles are grouped by detected language and each group is solved
+    /// separately, so a video receives at most one subtitle per language.
+    /// Incompatible pairs are never returned; filtering by a confidence
+    /// threshold is left to the caller.
+    pub fn match_files<'a>(
+        &self,
+        videos: &[&'a MediaFile],
+        subtitles: &[&'a MediaFile],
+    ) -> Vec<HeuristicMatch<'a>> {
+        let video_names: Vec<_> = videos
+            .iter()
+            .map(|v| ParsedMediaName::parse(&v.name, &v.relative_path))
+            .collect();
+
+        let mut groups: BTreeMap<Option<String>, Vec<&'a MediaFile>> = BTreeMap::new();
+        for subtitle in subtitles {
+            let language = self.language_detector.get_primary_language(&subtitle.path);
+            groups.entry(language).or_default().push(subtitle);
+        }
+
+        let mut matches = Vec::new();
+        for group in groups.values() {
+            let subtitle_names: Vec<_> = group
+                .iter()
+                .map(|s| ParsedMediaName::parse(&s.name, &s.relative_path))
+                .collect();
+            let scores: Vec<Vec<(f32, Vec<String>)>> = videos
+                .iter()
+                .zip(&video_names)
+                .map(|(video, video_name)| {
+                    group
+                        .iter()
+                        .zip(&subtitle_names)
+                        .map(|(subtitle, subtitle_name)| {
+                            let same_directory = Path::new(&video.relative_path).parent()
+                                == Path::new(&subtitle.relative_path).parent();
+                            score_names(video_name, subtitle_name, same_directory)
+                        })
+                        .collect()
+                })
+                .collect();
+            let costs: Vec<Vec<f64>> = scores
+                .iter()
+                .map(|row| row.iter().map(|(score, _)| 1.0 - *score as f64).collect())
+                .collect();
+
+            for (v, s) in assign(&costs) {
+                let (confidence, reasons) = scores[v][s].clone();
+                if confidence > 0.0 {
+                    matches.push(HeuristicMatch {
+                        video: videos[v],
+                        subtitle: group[s],
+                        confidence,
+                        reasons,
+                    });
+                }
+            }
+        }
+        matches
+    }
+}
+
+impl Default for HeuristicMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Score two parsed names against each other.
+fn score_names(
+    video: &ParsedMediaName,
+    subtitle: &ParsedMediaName,
+    same_directory: bool,
+) -> (f32, Vec<String>) {
+    if !video.base_name.is_empty() && video.base_name == subtitle.base_name {
+        return (
+            IDENTICAL_NAME_SCORE,
+            vec!["Identical file name apart from extension and language tag".to_string()],
+        );
+    }
+
+    if let (Some(a), Some(b)) = (video.season, subtitle.season) {
+        if a != b {
+            return (0.0, vec![format!("Season mismatch ({a} vs {b})")]);
+        }
+    }
+    if let (Some(a), Some(b)) = (video.episode, subtitle.episode) {
+        if a != b {
+            return (0.0, vec![format!("Episode mismatch ({a} vs {b})")]);
+        }
+    }
+    if let (Some(a), Some(b)) = (video.year, subtitle.year) {
+        if a != b {
+            return (0.0, vec![format!("Year mismatch ({a} vs {b})")]);
+        }
+    }
+
+    let mut reasons = Vec::new();
+    let title_similarity = if video.title_tokens.is_empty() && subtitle.title_tokens.is_empty() {
+        0.5
+    } else {
+        let similarity = jaccard(&video.title_tokens, &subtitle.title_tokens);
+        reasons.push(format!("Title similarity {:.0}%", similarity * 100.0));
+        similarity
+    };
+    let mut score = 0.5 * title_similarity;
+
+    match (video.episode, subtitle.episode) {
+        (Some(episode), Some(_)) => {
+            score += 0.4;
+            reasons.push(match video.season.or(subtitle.season) {
+                Some(season) => format!("Episode S{season:02}E{episode:02} matches"),
+                None => format!("Episode {episode:02} matches"),
+            });
+        }
+        (None, None) => score += 0.4 * title_similarity,
+        _ => reasons.push("Only one file has an episode number".to_string()),
+    }
+    if let (Some(year), Some(_)) = (video.year, subtitle.year) {
+        score += 0.1;
+        reasons.push(format!("Year {year} matches"));
+    }
+    if same_directory {
+        score += 0.05;
+        reasons.push("Same directory".to_string());
+    }
+    (score.min(MAX_COMPONENT_SCORE), reasons)
+}
+
+/// Jaccard similarity of two token sets.
+fn jaccard(a: &[String], b: &[String]) -> f32 {
+    let a: HashSet<&String> = a.iter().collect();
+    let b: HashSet<&String> = b.iter().collect();
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(&b).count() as f32 / union as f32
+}
+
+/// Solve the assignment problem for a cost matrix, returning the assigned
+/// `(row, column)` pairs.
+///
+/// Uses the Hungarian algorithm with potentials; rectangular matrices are
+/// transposed so that every row of the smaller side is assigned.
+fn assign(costs: &[Vec<f64>]) -> Vec<(usize, usize)> {
+    let rows = costs.len();
+    let cols = costs.first().map_or(0, Vec::len);
+    if rows == 0 || cols == 0 {
+        return Vec::new();
+    }
+    if rows > cols {
+        let transposed: Vec<Vec<f64>> = (0..cols)
+            .map(|c| (0..rows).map(|r| costs[r][c]).collect())
+            .collect();
+        return assign(&transposed)
+            .into_iter()
+            .map(|(c, r)| (r, c))
+            .collect();
+    }
+
+    // Potentials and matching use 1-based indices with 0 as a sentinel
+    let mut u = vec![0.0; rows + 1];
+    let mut v = vec![0.0; cols + 1];
+    let mut owner = vec![0usize; cols + 1];
+    let mut way = vec![0usize; cols + 1];
+    for row in 1..=rows {
+        owner[0] = row;
+        let mut col0 = 0;
+        let mut min_slack = vec![f64::INFINITY; cols + 1];
+        let mut used = vec![false; cols + 1];
+        loop {
+            used[col0] = true;
+            let row0 = owner[col0];
+            let mut delta = f64::INFINITY;
+            let mut col1 = 0;
+            for col in 1..=cols {
+                if used[col] {
+                    continue;
+                }
+                let slack = costs[row0 - 1][col - 1] - u[row0] - v[col];
+                if slack < min_slack[col] {
+                    min_slack[col] = slack;
+                    way[col] = col0;
+                }
+                if min_slack[col] < delta {
+                    delta = min_slack[col];
+                    col1 = col;
+                }
+            }
+            for col in 0..=cols {
+                if used[col] {
+                    u[owner[col]] += delta;
+                    v[col] -= delta;
+                } else {
+                    min_slack[col] -= delta;
+                }
+            }
+            col0 = col1;
+            if owner[col0] == 0 {
+                break;
+            }
+        }
+        while col0 != 0 {
+            let col1 = way[col0];
+            owner[col0] = owner[col1];
+            col0 = col1;
+        }
+    }
+
+    (1..=cols)
+        .filter(|&col| owner[col] != 0)
+        .map(|col| (owner[col] - 1, col - 1))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::matcher::MediaFileType;
+    use std::path::Path;
+
+    fn media(relative_path: &str, file_type: MediaFileType) -> MediaFile {
+        MediaFile::for_tests(Path::new(""), relative_path, file_type)
+    }
+
+    #[test]
+    fn test_parses_episode_markers() {
+        let cases = [
+            ("Show.S01E02.1080p.mkv", Some(1), Some(2)),
+            ("Show 1x02.srt", Some(1), Some(2)),
+            ("某动画 第02話.ass", None, Some(2)),
+            ("某动画 第十二集.ass", None, Some(12)),
+            ("Show EP02.mkv", None, Some(2)),
+            ("[Group] Show - 02 [1080p].mkv", None, Some(2)),
+            ("Movie.Name.2020.1080p.mkv", None, None),
+        ];
+        for (name, season, episode) in cases {
+            let parsed = ParsedMediaName::parse(name, name);
+            assert_eq!(parsed.season, season, "{name}");
+            assert_eq!(parsed.episode, episode, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_parses_title_year_and_season_directory() {
+        let parsed =
+            ParsedMediaName::parse("Movie.Name.2020.1080p.mkv", "Movie.Name.2020.1080p.mkv");
+        assert_eq!(parsed.title_tokens, vec!["movie", "name"]);
+        assert_eq!(parsed.year, Some(2020));
+
+        let parsed = ParsedMediaName::parse("E05.mkv", "Great Show/Season 2/E05.mkv");
+        assert_eq!(parsed.season, Some(2));
+        assert_eq!(parsed.episode, Some(5));
+        assert_eq!(parsed.title_tokens, vec!["great", "show"]);
+    }
+
+    #[test]
+    fn test_episode_mismatch_is_incompatible() {
+        let matcher = HeuristicMatcher::new();
+        let video = media("Show.S01E02.mkv", MediaFileType::Video);
+        let subtitle = media("Show.S01E03.srt", MediaFileType::Subtitle);
+        let (score, reasons) = matcher.score(&video, &subtitle);
+        assert_eq!(score, 0.0);
+        assert!(reasons[0].contains("Episode mismatch"));
+    }
+
+    #[test]
+    fn test_assignment_pairs_episodes_across_naming_styles() {
+        let videos = [
+            media("Show/Show.S01E01.1080p.mkv", MediaFileType::Video),
+            media("Show/Show.S01E02.1080p.mkv", MediaFileType::Video),
+            media("Show/Show.S01E03.1080p.mkv", MediaFileType::Video),
+        ];
+        let subtitles = [
+            media("Show/Show 1x03.srt", MediaFileType::Subtitle),
+            media("Show/Show - 01.srt", MediaFileType::Subtitle),
+            media("Show/Show EP02.srt", MediaFileType::Subtitle),
+        ];
+        let videos: Vec<_> = videos.iter().collect();
+        let subtitles: Vec<_> = subtitles.iter().collect();
+
+        let mut matches = HeuristicMatcher::new().match_files(&videos, &subtitles);
+        matches.sort_by(|a, b| a.video.name.cmp(&b.video.name));
+        let pairs: Vec<_> = matches
+            .iter()
+            .map(|m| (m.video.name.as_str(), m.subtitle.name.as_str()))
+            .collect();
+        assert_eq!(
+            pairs,
+            vec![
+                ("Show.S01E01.1080p.mkv", "Show - 01.srt"),
+                ("Show.S01E02.1080p.mkv", "Show EP02.srt"),
+                ("Show.S01E03.1080p.mkv", "Show 1x03.srt"),
+            ]
+        );
+        assert!(matches.iter().all(|m| m.confidence >= 0.8));
+        assert!(matches.iter().all(|m| !m.reasons.is_empty()));
+    }
+
+    #[test]
+    fn test_assignment_gives_one_subtitle_per_language() {
+        let videos = [media("movie.mkv", MediaFileType::Video)];
+        let subtitles = [
+            media("movie.en.srt", MediaFileType::Subtitle),
+            media("movie.tc.srt", MediaFileType::Subtitle),
+            media("other.en.srt", MediaFileType::Subtitle),
+        ];
+        let videos: Vec<_> = videos.iter().collect();
+        let subtitles: Vec<_> = subtitles.iter().collect();
+
+        let matches = HeuristicMatcher::new().match_files(&videos, &subtitles);
+        let mut names: Vec<_> = matches.iter().map(|m| m.subtitle.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["movie.en.srt", "movie.tc.srt"]);
+    }
+
+    #[test]
+    fn test_assign_is_optimal() {
+        // Greedy would take (0, 0) and be left with a cost of 10
+        let costs = vec![vec![1.0, 2.0], vec![2.0, 10.0]];
+        let mut pairs = assign(&costs);
+        pairs.sort();
+        assert_eq!(pairs, vec![(0, 1), (1, 0)]);
+
+        let wide = vec![vec![3.0, 1.0, 2.0]];
+        assert_eq!(assign(&wide), vec![(0, 1)]);
+        let tall = vec![vec![3.0], vec![1.0], vec![2.0]];
+        assert_eq!(assign(&tall), vec![(1, 0)]);
+    }
+}
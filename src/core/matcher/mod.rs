@@ -119,8 +119,10 @@
 //!
 //! ## 1. Filename Analysis
 //! - **Pattern Extraction**: Identifies common patterns like episode numbers, years, quality markers
+//! - **Release Names**: [`FilenameAnalyzer`] parses scene and fansub names into a [`ParsedFilename`]
 //! - **Language Code Detection**: Recognizes language codes in various formats (en, eng, english, etc.)
 //! - **Normalization**: Standardizes filenames for comparison by removing common variations
+//! - **Output Naming**: [`NamingTemplate`] renders the new subtitle name, with presets for Plex, Jellyfin, Emby and Kodi
 //!
 //! ## 2. AI Semantic Analysis
 //! - **Title Extraction**: Uses AI to identify actual titles from complex filenames
@@ -160,13 +162,27 @@
 
 #![allow(dead_code)]
 
+pub mod chunking;
+pub mod dedupe;
 pub mod discovery;
 pub mod engine;
-// Filename analyzer removed to simplify matching logic.
-
+pub mod filename_analyzer;
+pub mod heuristic;
+pub mod naming;
+pub mod ranking;
+pub mod review;
+pub mod speech_pattern;
+
+pub use chunking::{ChunkPlanner, MatchChunk};
+pub use dedupe::{DuplicateGroup, find_duplicates};
 pub use discovery::{FileDiscovery, MediaFile, MediaFileType};
 pub use engine::{MatchConfig, MatchEngine, MatchOperation};
-// pub use filename_analyzer::{FilenameAnalyzer, ParsedFilename};
+pub use filename_analyzer::{FilenameAnalyzer, ParsedFilename};
+pub use heuristic::{HeuristicMatch, HeuristicMatcher};
+pub use naming::NamingTemplate;
+pub use ranking::{CandidateAssessment, CandidateSelection, rank_candidates};
+pub use review::{ReviewDecision, ReviewPrompter, TerminalPrompter, review_operations};
+pub use speech_pattern::{PatternCorrelation, SpeechPattern};
 pub mod cache;
 use crate::Result;
 use crate::core::language::{LanguageDetector, LanguageInfo};
@@ -0,0 +1,413 @@
+//! Output naming templates for matched subtitles.
+//!
+//! A [`NamingTemplate`] decides the file name a matched subtitle is renamed
+//! to. It is either one of the media-server presets or a template string
+//! made of literal text and `{placeholder}` fields:
+//!
+//! | Placeholder        | Value                                              |
+//! |--------------------|----------------------------------------------------|
+//! | `{video_stem}`     | Video file name without extension                  |
+//! | `{ext}`            | Subtitle extension (required)                      |
+//! | `{lang}`           | SubX language code (`en`, `tc`, `sc`, ...)         |
+//! | `{lang_iso639_1}`  | ISO 639-1 code (`en`, `zh`)                        |
+//! | `{lang_iso639_2}`  | ISO 639-2/B code (`eng`, `chi`)                    |
+//! | `{lang_bcp47}`     | BCP-47 tag (`en`, `zh-Hant`, `zh-Hans`)            |
+//! | `{title}`, `{year}`, `{season}`, `{episode}` | Parsed from the video name |
+//! | `{forced}`, `{sdh}`| `forced` / `sdh` when the subtitle is tagged so    |
+//! | `{n}`              | Collision number, empty for the first file         |
+//!
+//! Writing `{.name}` instead of `{name}` prefixes the value with a dot and
+//! drops both when the value is empty, so `{video_stem}{.lang}.{ext}` yields
+//! `movie.en.srt` or `movie.srt`. Without an `{n}` field the collision
+//! number goes right before the extension.
+//!
+//! Presets:
+//!
+//! | Preset     | Template                                                  |
+//! |------------|-----------------------------------------------------------|
+//! | `subx`     | `{video_stem}{.lang}{.n}.{ext}`                           |
+//! | `plex`     | `{video_stem}{.n}{.lang_iso639_1}{.forced}{.sdh}.{ext}`   |
+//! | `jellyfin` | `{video_stem}{.n}{.lang_bcp47}{.forced}{.sdh}.{ext}`      |
+//! | `emby`     | `{video_stem}{.n}{.lang_iso639_2}{.forced}{.sdh}.{ext}`   |
+//! | `kodi`     | `{video_stem}{.n}{.lang_iso639_2}{.forced}{.sdh}.{ext}`   |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use subx_cli::core::matcher::naming::{NamingFields, NamingTemplate};
+//!
+//! let template: NamingTemplate = "jellyfin".parse().unwrap();
+//! let fields = NamingFields {
+//!     video_stem: "Movie (2020)".to_string(),
+//!     extension: "srt".to_string(),
+//!     language: Some("tc".to_string()),
+//!     forced: true,
+//!     ..NamingFields::default()
+//! };
+//! assert_eq!(template.render(&fields, 0), "Movie (2020).zh-Hant.forced.srt");
+//! assert_eq!(template.render(&fields, 1), "Movie (2020).1.zh-Hant.forced.srt");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::core::language::standard_codes;
+use crate::core::matcher::ParsedFilename;
+use crate::error::SubXError;
+
+/// Preset names with their templates.
+const PRESETS: &[(&str, &str)] = &[
+    ("subx", "{video_stem}{.lang}{.n}.{ext}"),
+    (
+        "plex",
+        "{video_stem}{.n}{.lang_iso639_1}{.forced}{.sdh}.{ext}",
+    ),
+    (
+        "jellyfin",
+        "{video_stem}{.n}{.lang_bcp47}{.forced}{.sdh}.{ext}",
+    ),
+    (
+        "emby",
+        "{video_stem}{.n}{.lang_iso639_2}{.forced}{.sdh}.{ext}",
+    ),
+    (
+        "kodi",
+        "{video_stem}{.n}{.lang_iso639_2}{.forced}{.sdh}.{ext}",
+    ),
+];
+
+/// Name of the default preset, which keeps the historical
+/// `{video_stem}.{lang}.{ext}` naming.
+pub const DEFAULT_NAMING: &str = "subx";
+
+/// Values a template can refer to.
+#[derive(Debug, Clone, Default)]
+pub struct NamingFields {
+    /// Video file name without extension
+    pub video_stem: String,
+    /// Subtitle extension without the leading dot
+    pub extension: String,
+    /// SubX language code of the subtitle, if known
+    pub language: Option<String>,
+    /// Release fields parsed from the video file name
+    pub video: ParsedFilename,
+    /// Whether the subtitle is a forced-narrative track
+    pub forced: bool,
+    /// Whether the subtitle is for the deaf and hard of hearing
+    pub hearing_impaired: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Field {
+    VideoStem,
+    Ext,
+    Lang,
+    LangIso639_1,
+    LangIso639_2,
+    LangBcp47,
+    Title,
+    Year,
+    Season,
+    Episode,
+    Forced,
+    Sdh,
+    Number,
+}
+
+impl Field {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "video_stem" => Field::VideoStem,
+            "ext" => Field::Ext,
+            "lang" => Field::Lang,
+            "lang_iso639_1" => Field::LangIso639_1,
+            "lang_iso639_2" => Field::LangIso639_2,
+            "lang_bcp47" => Field::LangBcp47,
+            "title" => Field::Title,
+            "year" => Field::Year,
+            "season" => Field::Season,
+            "episode" => Field::Episode,
+            "forced" => Field::Forced,
+            "sdh" => Field::Sdh,
+            "n" => Field::Number,
+            _ => return None,
+        })
+    }
+
+    fn value(self, fields: &NamingFields, number: u32) -> String {
+        let language = fields.language.as_deref();
+        let standard = language.and_then(standard_codes);
+        let flag = |set: bool, text: &str| if set { text.to_string() } else { String::new() };
+        match self {
+            Field::VideoStem => fields.video_stem.clone(),
+            Field::Ext => fields.extension.clone(),
+            Field::Lang => language.unwrap_or_default().to_string(),
+            // Codes outside the mapping table are kept as detected
+            Field::LangIso639_1 => standard
+                .map(|c| c.iso639_1)
+                .or(language)
+                .unwrap_or_default()
+                .to_string(),
+            Field::LangIso639_2 => standard
+                .map(|c| c.iso639_2)
+                .or(language)
+                .unwrap_or_default()
+                .to_string(),
+            Field::LangBcp47 => standard
+                .map(|c| c.bcp47)
+                .or(language)
+                .unwrap_or_default()
+                .to_string(),
+            Field::Title => fields.video.title.clone(),
+            Field::Year => fields.video.year.map(|y| y.to_string()).unwrap_or_default(),
+            Field::Season => fields
+                .video
+                .season
+                .map(|s| format!("{s:02}"))
+                .unwrap_or_default(),
+            Field::Episode => fields
+                .video
+                .episode
+                .map(|e| format!("{e:02}"))
+                .unwrap_or_default(),
+            Field::Forced => flag(fields.forced, "forced"),
+            Field::Sdh => flag(fields.hearing_impaired, "sdh"),
+            Field::Number => {
+                if number == 0 {
+                    String::new()
+                } else {
+                    number.to_string()
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Literal(String),
+    Field { field: Field, dotted: bool },
+}
+
+/// Template deciding the file name of a matched subtitle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamingTemplate {
+    spec: String,
+    segments: Vec<Segment>,
+}
+
+impl NamingTemplate {
+    /// Names of the built-in presets.
+    pub fn preset_names() -> Vec<&'static str> {
+        PRESETS.iter().map(|(name, _)| *name).collect()
+    }
+
+    /// Built-in preset by name (case-insensitive).
+    pub fn preset(name: &str) -> Option<Self> {
+        let name = name.to_lowercase();
+        let (preset, template) = PRESETS.iter().find(|(preset, _)| *preset == name)?;
+        let mut parsed = Self::parse_template(template).ok()?;
+        parsed.spec = preset.to_string();
+        Some(parsed)
+    }
+
+    /// Parse a template string such as `{video_stem}{.lang_bcp47}.{ext}`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error for unbalanced braces, unknown
+    /// placeholders or a template without `{ext}`.
+    pub fn parse_template(template: &str) -> Result<Self, SubXError> {
+        let mut segments = Vec::new();
+        let mut rest = template;
+        while let Some(open) = rest.find(['{', '}']) {
+            if rest[open..].starts_with('}') {
+                return Err(SubXError::config(format!(
+                    "Unbalanced '}}' in naming template: {template}"
+                )));
+            }
+            if open > 0 {
+                segments.push(Segment::Literal(rest[..open].to_string()));
+            }
+            let close = rest[open..].find('}').ok_or_else(|| {
+                SubXError::config(format!("Unclosed '{{' in naming template: {template}"))
+            })? + open;
+            let name = &rest[open + 1..close];
+            let (name, dotted) = match name.strip_prefix('.') {
+                Some(name) => (name, true),
+                None => (name, false),
+            };
+            let field = Field::from_name(name).ok_or_else(|| {
+                SubXError::config(format!(
+                    "Unknown placeholder {{{name}}} in naming template: {template}"
+                ))
+            })?;
+            segments.push(Segment::Field { field, dotted });
+            rest = &rest[close + 1..];
+        }
+        if !rest.is_empty() {
+            segments.push(Segment::Literal(rest.to_string()));
+        }
+        if !segments.iter().any(|s| {
+            matches!(
+                s,
+                Segment::Field {
+                    field: Field::Ext,
+                    ..
+                }
+            )
+        }) {
+            return Err(SubXError::config(format!(
+                "Naming template must contain {{ext}}: {template}"
+            )));
+        }
+        Ok(Self {
+            spec: template.to_string(),
+            segments,
+        })
+    }
+
+    /// Preset name or template string this template was created from.
+    pub fn as_str(&self) -> &str {
+        &self.spec
+    }
+
+    /// File name for `fields`, with collision number `number` (0 for the
+    /// first file claiming a name).
+    pub fn render(&self, fields: &NamingFields, number: u32) -> String {
+        let mut name = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(text) => name.push_str(text),
+                Segment::Field { field, dotted } => {
+                    let value = field.value(fields, number);
+                    if *dotted && !value.is_empty() {
+                        name.push('.');
+                    }
+                    name.push_str(&value);
+                }
+            }
+        }
+        let has_number = self.segments.iter().any(|s| {
+            matches!(
+                s,
+                Segment::Field {
+                    field: Field::Number,
+                    ..
+                }
+            )
+        });
+        if number > 0 && !has_number {
+            let at = name.rfind('.').unwrap_or(name.len());
+            name.insert_str(at, &format!(".{number}"));
+        }
+        // Empty fields between literal dots would leave `movie..srt`
+        while name.contains("..") {
+            name = name.replace("..", ".");
+        }
+        name
+    }
+}
+
+impl Default for NamingTemplate {
+    fn default() -> Self {
+        Self::preset(DEFAULT_NAMING).expect("default naming preset")
+    }
+}
+
+impl FromStr for NamingTemplate {
+    type Err = SubXError;
+
+    /// Accepts a preset name or, when the text contains `{`, a template.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains('{') || s.contains('}') {
+            return Self::parse_template(s);
+        }
+        Self::preset(s).ok_or_else(|| {
+            SubXError::config(format!(
+                "Unknown naming preset '{}'. Use one of: {}, or a template such as {{video_stem}}{{.lang}}.{{ext}}",
+                s,
+                Self::preset_names().join(", ")
+            ))
+        })
+    }
+}
+
+impl fmt::Display for NamingTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.spec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::matcher::FilenameAnalyzer;
+
+    fn fields(language: Option<&str>) -> NamingFields {
+        NamingFields {
+            video_stem: "Show.S01E02.1080p".to_string(),
+            extension: "srt".to_string(),
+            language: language.map(str::to_string),
+            video: FilenameAnalyzer::new().parse("Show.S01E02.1080p.mkv"),
+            ..NamingFields::default()
+        }
+    }
+
+    #[test]
+    fn test_subx_preset_keeps_historical_names() {
+        let template = NamingTemplate::default();
+        assert_eq!(
+            template.render(&fields(Some("tc")), 0),
+            "Show.S01E02.1080p.tc.srt"
+        );
+        assert_eq!(template.render(&fields(None), 0), "Show.S01E02.1080p.srt");
+        assert_eq!(template.render(&fields(None), 2), "Show.S01E02.1080p.2.srt");
+    }
+
+    #[test]
+    fn test_media_server_presets_map_language_codes() {
+        let mut f = fields(Some("sc"));
+        f.hearing_impaired = true;
+        let render = |preset: &str| preset.parse::<NamingTemplate>().unwrap().render(&f, 0);
+        assert_eq!(render("plex"), "Show.S01E02.1080p.zh.sdh.srt");
+        assert_eq!(render("Jellyfin"), "Show.S01E02.1080p.zh-Hans.sdh.srt");
+        assert_eq!(render("kodi"), "Show.S01E02.1080p.chi.sdh.srt");
+        // Codes outside the table pass through unchanged
+        let f = fields(Some("xx"));
+        let plex: NamingTemplate = "plex".parse().unwrap();
+        assert_eq!(plex.render(&f, 1), "Show.S01E02.1080p.1.xx.srt");
+    }
+
+    #[test]
+    fn test_custom_template_with_parsed_fields() {
+        let template: NamingTemplate = "{title} - S{season}E{episode}{.lang_iso639_2}.{ext}"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            template.render(&fields(Some("en")), 0),
+            "Show - S01E02.eng.srt"
+        );
+        // Without an {n} field the number goes before the extension
+        assert_eq!(
+            template.render(&fields(Some("en")), 3),
+            "Show - S01E02.eng.3.srt"
+        );
+        // Empty plain fields do not leave doubled dots
+        let template: NamingTemplate = "{video_stem}.{lang}.{ext}".parse().unwrap();
+        assert_eq!(template.render(&fields(None), 0), "Show.S01E02.1080p.srt");
+    }
+
+    #[test]
+    fn test_invalid_templates_are_rejected() {
+        assert!("{video_stem}.srt".parse::<NamingTemplate>().is_err());
+        assert!(
+            "{video_stem}.{language}.{ext}"
+                .parse::<NamingTemplate>()
+                .is_err()
+        );
+        assert!("{video_stem.{ext}".parse::<NamingTemplate>().is_err());
+        assert!("{video_stem}}.{ext}".parse::<NamingTemplate>().is_err());
+        assert!("infuse".parse::<NamingTemplate>().is_err());
+    }
+}
@@ -0,0 +1,277 @@
+//! Ranking of competing subtitle candidates for one video.
+//!
+//! Downloads often carry several subtitles in the same language for one
+//! episode, taken from different releases. They all match the video, so
+//! they would land on the same target name. A [`CandidateAssessment`]
+//! rates one such candidate on the evidence SubX can gather locally:
+//!
+//! - **timing**: correlation of the cues with the video's detected speech,
+//!   when audio verification ran
+//! - **release group**: whether the subtitle comes from the video's release
+//! - **encoding**: Unicode and confidently detected encodings rate higher,
+//!   text showing mojibake rates lowest
+//! - **cue count**: relative to the fullest candidate, so partial files lose
+//! - **SDH/forced**: plain subtitles before SDH ones, forced ones last
+//! - **lint**: share of cues free of overlaps, fast cues and long lines
+//!
+//! [`rank_candidates`] orders a group of assessments from best to worst.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use subx_cli::core::matcher::ranking::{CandidateAssessment, rank_candidates};
+//!
+//! let full = CandidateAssessment {
+//!     timing: None,
+//!     release_group: 1.0,
+//!     encoding: 1.0,
+//!     cue_count: 600,
+//!     sdh: 1.0,
+//!     lint: 0.95,
+//! };
+//! let forced = CandidateAssessment {
+//!     cue_count: 12,
+//!     sdh: 0.0,
+//!     ..full.clone()
+//! };
+//! assert_eq!(rank_candidates(&[forced, full]), vec![1, 0]);
+//! ```
+
+use std::path::Path;
+
+use crate::core::formats::Subtitle;
+use crate::core::formats::encoding::{Charset, EncodingDetector, MojibakeAnalyzer};
+use crate::core::formats::sdh::{SdhCleaner, SdhLanguage};
+use crate::core::formats::stats::SubtitleStats;
+use crate::core::matcher::MediaFile;
+use crate::core::matcher::speech_pattern::SpeechPattern;
+
+/// What to do with several subtitles competing for the same target name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CandidateSelection {
+    /// Keep every candidate; the best takes the plain name and the others
+    /// are numbered in rank order
+    Ranked,
+    /// Keep only the best candidate
+    Best,
+}
+
+/// Weight of each factor in [`CandidateAssessment::score`]. Timing is left
+/// out, and the others scaled up, when no speech was analyzed.
+const TIMING_WEIGHT: f32 = 0.3;
+const RELEASE_GROUP_WEIGHT: f32 = 0.2;
+const ENCODING_WEIGHT: f32 = 0.15;
+const CUE_COUNT_WEIGHT: f32 = 0.15;
+const SDH_WEIGHT: f32 = 0.1;
+const LINT_WEIGHT: f32 = 0.1;
+
+/// Share of cues carrying SDH annotations above which a subtitle counts as
+/// SDH even without a tag in its name.
+const SDH_CUE_SHARE: f32 = 0.1;
+
+/// Factor ratings of one candidate, each from 0.0 (worst) to 1.0 (best)
+/// except for the raw cue count.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandidateAssessment {
+    /// Speech correlation with the video, if the audio was analyzed
+    pub timing: Option<f32>,
+    /// 1.0 for the video's release group, 0.0 for another one, 0.5 unknown
+    pub release_group: f32,
+    /// Quality of the file's text encoding
+    pub encoding: f32,
+    /// Number of cues
+    pub cue_count: usize,
+    /// 1.0 plain, 0.5 SDH, 0.0 forced
+    pub sdh: f32,
+    /// Share of cues without timing or layout problems
+    pub lint: f32,
+}
+
+impl CandidateAssessment {
+    /// Assess `subtitle` as a candidate for `video`, using the video's
+    /// detected `speech` for the timing factor when available. A subtitle
+    /// that cannot be parsed rates 0.0 on every content factor.
+    pub fn assess(video: &MediaFile, subtitle: &MediaFile, speech: Option<&SpeechPattern>) -> Self {
+        let name = subtitle.parsed_name();
+        let release_group = match (&video.parsed_name().release_group, &name.release_group) {
+            (Some(a), Some(b)) if a.eq_ignore_ascii_case(b) => 1.0,
+            (Some(_), Some(_)) => 0.0,
+            _ => 0.5,
+        };
+        let parsed = crate::core::formats::manager::FormatManager::new()
+            .load_subtitle(&subtitle.path)
+            .ok();
+        let sdh = if name.forced {
+            0.0
+        } else if name.hearing_impaired || parsed.as_ref().is_some_and(has_sdh_annotations) {
+            0.5
+        } else {
+            1.0
+        };
+        let (cue_count, lint, timing) = match &parsed {
+            Some(parsed) => {
+                let stats = SubtitleStats::from_subtitle(parsed);
+                let problems = stats.overlap_count + stats.fast_cues + stats.long_lines;
+                let lint = if stats.entry_count == 0 {
+                    0.0
+                } else {
+                    1.0 - (problems as f32 / stats.entry_count as f32).min(1.0)
+                };
+                let timing = speech.map(|speech| {
+                    SpeechPattern::from_subtitle(parsed)
+                        .correlate(speech)
+                        .score
+                        .max(0.0)
+                });
+                (stats.entry_count, lint, timing)
+            }
+            None => (0, 0.0, speech.map(|_| 0.0)),
+        };
+        Self {
+            timing,
+            release_group,
+            encoding: encoding_quality(&subtitle.path),
+            cue_count,
+            sdh,
+            lint,
+        }
+    }
+
+    /// Weighted score from 0.0 to 1.0, with the cue count rated against
+    /// `most_cues`, the largest count among the competing candidates.
+    pub fn score(&self, most_cues: usize) -> f32 {
+        let cues = if most_cues == 0 {
+            0.0
+        } else {
+            self.cue_count as f32 / most_cues as f32
+        };
+        let mut total = self.release_group * RELEASE_GROUP_WEIGHT
+            + self.encoding * ENCODING_WEIGHT
+            + cues * CUE_COUNT_WEIGHT
+            + self.sdh * SDH_WEIGHT
+            + self.lint * LINT_WEIGHT;
+        let mut weights =
+            RELEASE_GROUP_WEIGHT + ENCODING_WEIGHT + CUE_COUNT_WEIGHT + SDH_WEIGHT + LINT_WEIGHT;
+        if let Some(timing) = self.timing {
+            total += timing * TIMING_WEIGHT;
+            weights += TIMING_WEIGHT;
+        }
+        total / weights
+    }
+
+    /// Short description of the factors for match reasoning.
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(timing) = self.timing {
+            parts.push(format!("timing {:.2}", timing));
+        }
+        parts.push(format!("release group {:.1}", self.release_group));
+        parts.push(format!("encoding {:.2}", self.encoding));
+        parts.push(format!("{} cues", self.cue_count));
+        parts.push(match self.sdh {
+            s if s >= 1.0 => "plain".to_string(),
+            s if s > 0.0 => "SDH".to_string(),
+            _ => "forced".to_string(),
+        });
+        parts.push(format!("lint {:.2}", self.lint));
+        parts.join(", ")
+    }
+}
+
+/// Indices of `assessments` ordered from best to worst score. Equal scores
+/// keep their input order.
+pub fn rank_candidates(assessments: &[CandidateAssessment]) -> Vec<usize> {
+    let most_cues = assessments.iter().map(|a| a.cue_count).max().unwrap_or(0);
+    let scores: Vec<f32> = assessments.iter().map(|a| a.score(most_cues)).collect();
+    let mut order: Vec<usize> = (0..assessments.len()).collect();
+    order.sort_by(|&a, &b| scores[b].total_cmp(&scores[a]));
+    order
+}
+
+/// Whether enough cues carry SDH annotations to call the subtitle SDH.
+fn has_sdh_annotations(subtitle: &Subtitle) -> bool {
+    if subtitle.entries.is_empty() {
+        return false;
+    }
+    let mut copy = subtitle.clone();
+    let report = SdhCleaner::new(SdhLanguage::Auto).clean(&mut copy);
+    let mut cues: Vec<usize> = report.removals.iter().map(|r| r.cue_index).collect();
+    cues.dedup();
+    cues.len() as f32 / subtitle.entries.len() as f32 > SDH_CUE_SHARE
+}
+
+/// 1.0 for Unicode, the detection confidence for other encodings, 0.0 for
+/// unreadable files and text showing mojibake.
+fn encoding_quality(path: &Path) -> f32 {
+    let Ok(info) = EncodingDetector::with_defaults().detect_file_encoding(&path.to_string_lossy())
+    else {
+        return 0.0;
+    };
+    if let Ok(text) = std::fs::read_to_string(path) {
+        if MojibakeAnalyzer::new().analyze(&text).is_some() {
+            return 0.0;
+        }
+    }
+    match info.charset {
+        Charset::Utf8
+        | Charset::Utf16Le
+        | Charset::Utf16Be
+        | Charset::Utf32Le
+        | Charset::Utf32Be => 1.0,
+        _ => info.confidence.clamp(0.0, 1.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assessment(cue_count: usize) -> CandidateAssessment {
+        CandidateAssessment {
+            timing: None,
+            release_group: 0.5,
+            encoding: 1.0,
+            cue_count,
+            sdh: 1.0,
+            lint: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_rank_prefers_release_group_and_full_plain_subtitles() {
+        let other_release = CandidateAssessment {
+            release_group: 0.0,
+            ..assessment(500)
+        };
+        let same_release = CandidateAssessment {
+            release_group: 1.0,
+            ..assessment(500)
+        };
+        let partial = assessment(100);
+        let sdh = CandidateAssessment {
+            sdh: 0.5,
+            ..assessment(500)
+        };
+        assert_eq!(
+            rank_candidates(&[partial, other_release, sdh, same_release]),
+            vec![3, 2, 1, 0]
+        );
+    }
+
+    #[test]
+    fn test_timing_outweighs_other_factors_when_available() {
+        let in_sync = CandidateAssessment {
+            timing: Some(0.9),
+            release_group: 0.0,
+            ..assessment(500)
+        };
+        let out_of_sync = CandidateAssessment {
+            timing: Some(0.1),
+            release_group: 1.0,
+            ..assessment(500)
+        };
+        assert_eq!(rank_candidates(&[out_of_sync.clone(), in_sync]), vec![1, 0]);
+        assert!(out_of_sync.score(500) <= 1.0);
+        assert_eq!(rank_candidates(&[assessment(1), assessment(1)]), vec![0, 1]);
+    }
+}
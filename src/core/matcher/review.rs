@@ -0,0 +1,411 @@
+//! Interactive review of proposed matches.
+//!
+//! [`review_operations`] walks through the [`MatchOperation`]s of a match
+//! run and asks a [`ReviewPrompter`] what to do with each: accept it, reject
+//! it, pair the subtitle with another video from a list ranked by the
+//! [`HeuristicMatcher`], or edit the target file name. Accepted operations
+//! whose target already exists, or is taken by an operation accepted
+//! earlier, go through the same prompter's conflict flow, which the engine
+//! also uses for [`ConflictResolution::Prompt`](super::engine::ConflictResolution::Prompt).
+//!
+//! [`TerminalPrompter`] asks on the terminal with `dialoguer`.
+
+use std::path::Path;
+
+use dialoguer::{Input, Select};
+
+use crate::Result;
+use crate::core::matcher::engine::numbered_path;
+use crate::core::matcher::{HeuristicMatcher, MatchEngine, MatchOperation, MediaFile};
+use crate::error::SubXError;
+
+/// A video offered as an alternative match, with its filename-heuristic
+/// score for the subtitle under review.
+#[derive(Debug, Clone)]
+pub struct RankedVideo {
+    /// The video file
+    pub video: MediaFile,
+    /// Heuristic score (0.0-1.0)
+    pub score: f32,
+}
+
+/// What to do with a proposed match.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReviewDecision {
+    /// Keep the operation as shown
+    Accept,
+    /// Drop the operation
+    Reject,
+    /// Pair the subtitle with the candidate at this index instead
+    ChooseVideo(usize),
+    /// Use this file name for the subtitle instead
+    Rename(String),
+}
+
+/// How to resolve a target name that is already taken.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConflictDecision {
+    /// Use the suggested numbered name
+    UseSuggestion,
+    /// Use this file name instead
+    Rename(String),
+    /// Leave the subtitle alone
+    Skip,
+}
+
+/// Source of review and conflict decisions.
+pub trait ReviewPrompter {
+    /// Decide on `op`, given the videos ranked for its subtitle.
+    fn review(&mut self, op: &MatchOperation, candidates: &[RankedVideo])
+    -> Result<ReviewDecision>;
+
+    /// Decide what to do when `target` is taken; `suggestion` is the first
+    /// free numbered name. [`ConflictDecision::Skip`] is only offered when
+    /// `allow_skip` is set.
+    fn resolve_conflict(
+        &mut self,
+        target: &Path,
+        suggestion: &Path,
+        allow_skip: bool,
+    ) -> Result<ConflictDecision>;
+}
+
+/// [`ReviewPrompter`] asking on the terminal.
+#[derive(Debug, Default)]
+pub struct TerminalPrompter;
+
+impl ReviewPrompter for TerminalPrompter {
+    fn review(
+        &mut self,
+        op: &MatchOperation,
+        candidates: &[RankedVideo],
+    ) -> Result<ReviewDecision> {
+        let actions = [
+            "Accept",
+            "Reject",
+            "Choose a different video",
+            "Edit target name",
+        ];
+        let action = Select::new()
+            .with_prompt("Action")
+            .items(&actions)
+            .default(0)
+            .interact()
+            .map_err(prompt_error)?;
+        Ok(match action {
+            0 => ReviewDecision::Accept,
+            1 => ReviewDecision::Reject,
+            2 => {
+                let items: Vec<String> = candidates
+                    .iter()
+                    .map(|c| format!("{} (score: {:.2})", c.video.name, c.score))
+                    .collect();
+                let index = Select::new()
+                    .with_prompt("Video")
+                    .items(&items)
+                    .default(0)
+                    .interact()
+                    .map_err(prompt_error)?;
+                ReviewDecision::ChooseVideo(index)
+            }
+            _ => ReviewDecision::Rename(ask_name(&op.new_subtitle_name)?),
+        })
+    }
+
+    fn resolve_conflict(
+        &mut self,
+        target: &Path,
+        suggestion: &Path,
+        allow_skip: bool,
+    ) -> Result<ConflictDecision> {
+        eprintln!("⚠️  {} already exists", target.display());
+        let suggested = file_name(suggestion);
+        let mut options = vec![
+            format!("Use {}", suggested),
+            "Enter another name".to_string(),
+        ];
+        if allow_skip {
+            options.push("Skip this subtitle".to_string());
+        }
+        let choice = Select::new()
+            .with_prompt("Resolve conflict")
+            .items(&options)
+            .default(0)
+            .interact()
+            .map_err(prompt_error)?;
+        Ok(match choice {
+            0 => ConflictDecision::UseSuggestion,
+            1 => ConflictDecision::Rename(ask_name(&suggested)?),
+            _ => ConflictDecision::Skip,
+        })
+    }
+}
+
+/// Videos ordered by how well their names fit `subtitle`, best first.
+pub fn rank_videos(subtitle: &MediaFile, videos: &[MediaFile]) -> Vec<RankedVideo> {
+    let matcher = HeuristicMatcher::new();
+    let mut ranked: Vec<RankedVideo> = videos
+        .iter()
+        .map(|video| RankedVideo {
+            video: video.clone(),
+            score: matcher.score(video, subtitle).0,
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.score.total_cmp(&a.score));
+    ranked
+}
+
+/// Review `operations` one by one and return those accepted, as edited.
+///
+/// `videos` are the alternatives offered for each subtitle.
+pub fn review_operations(
+    engine: &MatchEngine,
+    operations: Vec<MatchOperation>,
+    videos: &[MediaFile],
+    prompter: &mut dyn ReviewPrompter,
+) -> Result<Vec<MatchOperation>> {
+    let total = operations.len();
+    let mut accepted: Vec<MatchOperation> = Vec::new();
+    let mut rejected = 0;
+    for (index, mut op) in operations.into_iter().enumerate() {
+        let candidates = rank_videos(&op.subtitle_file, videos);
+        let decided = loop {
+            eprintln!(
+                "\n[{}/{}] {} -> {}",
+                index + 1,
+                total,
+                op.subtitle_file.name,
+                op.new_subtitle_name
+            );
+            eprintln!(
+                "   Video: {} (confidence: {:.2})",
+                op.video_file.name, op.confidence
+            );
+            match prompter.review(&op, &candidates)? {
+                ReviewDecision::Accept => {
+                    break resolve_conflicts(engine, op, &accepted, prompter)?;
+                }
+                ReviewDecision::Reject => break None,
+                ReviewDecision::ChooseVideo(i) => match candidates.get(i) {
+                    Some(candidate) => {
+                        op = engine.retarget(&op, &candidate.video, "chosen in interactive review")
+                    }
+                    None => eprintln!("No such video"),
+                },
+                ReviewDecision::Rename(name) => match validate_name(&name) {
+                    Ok(()) => engine.set_target_name(&mut op, &name),
+                    Err(e) => eprintln!("{}", e),
+                },
+            }
+        };
+        match decided {
+            Some(op) => accepted.push(op),
+            None => rejected += 1,
+        }
+    }
+    eprintln!(
+        "\nReviewed {} matches: {} accepted, {} rejected",
+        total,
+        accepted.len(),
+        rejected
+    );
+    Ok(accepted)
+}
+
+/// Ask about `op`'s target until it no longer clashes with an existing file
+/// or an `accepted` operation, or the subtitle is skipped.
+fn resolve_conflicts(
+    engine: &MatchEngine,
+    mut op: MatchOperation,
+    accepted: &[MatchOperation],
+    prompter: &mut dyn ReviewPrompter,
+) -> Result<Option<MatchOperation>> {
+    loop {
+        let target = op.target_path();
+        let taken = |path: &Path| accepted.iter().any(|other| other.target_path() == path);
+        if !(target.exists() && target != op.subtitle_file.path || taken(&target)) {
+            return Ok(Some(op));
+        }
+        let suggestion = numbered_path(&target, taken).ok_or_else(|| {
+            SubXError::FileOperationFailed("Could not resolve filename conflict".to_string())
+        })?;
+        match prompter.resolve_conflict(&target, &suggestion, true)? {
+            ConflictDecision::UseSuggestion => {
+                engine.set_target_name(&mut op, &file_name(&suggestion))
+            }
+            ConflictDecision::Rename(name) => match validate_name(&name) {
+                Ok(()) => engine.set_target_name(&mut op, &name),
+                Err(e) => eprintln!("{}", e),
+            },
+            ConflictDecision::Skip => return Ok(None),
+        }
+    }
+}
+
+fn validate_name(name: &str) -> Result<()> {
+    if name.trim().is_empty() || name.contains(['/', '\\']) {
+        return Err(SubXError::CommandExecution(format!(
+            "Invalid file name: '{}'",
+            name
+        )));
+    }
+    Ok(())
+}
+
+fn ask_name(initial: &str) -> Result<String> {
+    Input::<String>::new()
+        .with_prompt("File name")
+        .with_initial_text(initial)
+        .interact_text()
+        .map_err(prompt_error)
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+fn prompt_error(e: dialoguer::Error) -> SubXError {
+    SubXError::CommandExecution(format!("Prompt failed: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::matcher::engine::{ConflictResolution, FileRelocationMode};
+    use crate::core::matcher::{FileDiscovery, MatchConfig, MediaFileType};
+    use crate::services::ai::{
+        AIProvider, AnalysisRequest, ConfidenceScore, MatchResult, VerificationRequest,
+    };
+    use async_trait::async_trait;
+    use std::collections::VecDeque;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    struct NoAI;
+
+    #[async_trait]
+    impl AIProvider for NoAI {
+        async fn analyze_content(&self, _req: AnalysisRequest) -> crate::Result<MatchResult> {
+            unimplemented!()
+        }
+        async fn verify_match(&self, _req: VerificationRequest) -> crate::Result<ConfidenceScore> {
+            unimplemented!()
+        }
+    }
+
+    #[derive(Default)]
+    struct Scripted {
+        reviews: VecDeque<ReviewDecision>,
+        conflicts: VecDeque<ConflictDecision>,
+    }
+
+    impl ReviewPrompter for Scripted {
+        fn review(&mut self, _: &MatchOperation, _: &[RankedVideo]) -> Result<ReviewDecision> {
+            Ok(self.reviews.pop_front().unwrap())
+        }
+        fn resolve_conflict(&mut self, _: &Path, _: &Path, _: bool) -> Result<ConflictDecision> {
+            Ok(self.conflicts.pop_front().unwrap())
+        }
+    }
+
+    fn engine() -> MatchEngine {
+        MatchEngine::new(
+            Box::new(NoAI),
+            MatchConfig {
+                confidence_threshold: 0.8,
+                max_sample_length: 1024,
+                conflict_resolution: ConflictResolution::Prompt,
+                ..MatchConfig::for_tests()
+            },
+        )
+    }
+
+    /// Two episodes with their subtitles swapped by the proposed matches.
+    fn setup(dir: &TempDir) -> (Vec<MediaFile>, Vec<MatchOperation>) {
+        let paths: Vec<PathBuf> = ["Show.S01E01.mkv", "Show.S01E02.mkv", "ep01.srt", "ep02.srt"]
+            .iter()
+            .map(|name| {
+                let path = dir.path().join(name);
+                std::fs::write(&path, name).unwrap();
+                path
+            })
+            .collect();
+        let files = FileDiscovery::new().scan_file_list(&paths).unwrap();
+        let (videos, subtitles): (Vec<_>, Vec<_>) = files
+            .into_iter()
+            .partition(|f| matches!(f.file_type, MediaFileType::Video));
+        let engine = engine();
+        let ops = subtitles
+            .iter()
+            .zip(videos.iter().rev())
+            .map(|(subtitle, video)| {
+                let placeholder = MatchOperation {
+                    video_file: video.clone(),
+                    subtitle_file: subtitle.clone(),
+                    new_subtitle_name: String::new(),
+                    confidence: 0.9,
+                    reasoning: vec![],
+                    relocation_mode: FileRelocationMode::None,
+                    relocation_target_path: None,
+                    requires_relocation: false,
+                };
+                engine.retarget(&placeholder, video, "proposed")
+            })
+            .collect();
+        (videos, ops)
+    }
+
+    #[test]
+    fn test_review_reassigns_renames_and_rejects() {
+        let dir = TempDir::new().unwrap();
+        let (videos, ops) = setup(&dir);
+        let ranked = rank_videos(&ops[0].subtitle_file, &videos);
+        assert_eq!(ranked[0].video.name, "Show.S01E01.mkv");
+
+        let mut prompter = Scripted {
+            reviews: VecDeque::from([
+                ReviewDecision::ChooseVideo(0),
+                ReviewDecision::Rename("custom.srt".to_string()),
+                ReviewDecision::Accept,
+                ReviewDecision::Reject,
+            ]),
+            ..Default::default()
+        };
+        let accepted = review_operations(&engine(), ops, &videos, &mut prompter).unwrap();
+        assert_eq!(accepted.len(), 1);
+        assert_eq!(accepted[0].video_file.name, "Show.S01E01.mkv");
+        assert_eq!(accepted[0].new_subtitle_name, "custom.srt");
+        assert!(
+            accepted[0]
+                .reasoning
+                .contains(&"chosen in interactive review".to_string())
+        );
+    }
+
+    #[test]
+    fn test_review_resolves_conflicting_targets() {
+        let dir = TempDir::new().unwrap();
+        let (videos, mut ops) = setup(&dir);
+        std::fs::write(dir.path().join("taken.srt"), "existing").unwrap();
+        let engine = engine();
+        for op in &mut ops {
+            engine.set_target_name(op, "same.srt");
+        }
+        let copy = engine.retarget(&ops[0], &ops[0].video_file, "copy");
+        ops.push(copy);
+        engine.set_target_name(&mut ops[2], "taken.srt");
+
+        let mut prompter = Scripted {
+            reviews: VecDeque::from(vec![ReviewDecision::Accept; 3]),
+            conflicts: VecDeque::from([ConflictDecision::UseSuggestion, ConflictDecision::Skip]),
+        };
+        let accepted = review_operations(&engine, ops, &videos, &mut prompter).unwrap();
+        let names: Vec<_> = accepted
+            .iter()
+            .map(|op| op.new_subtitle_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["same.srt", "same.1.srt"]);
+    }
+}
@@ -0,0 +1,195 @@
+//! Speech-pattern comparison of subtitles against video audio.
+//!
+//! A [`SpeechPattern`] is an on/off timeline sampled every
+//! [`BIN_SECONDS`]: for a subtitle, whether a cue is shown; for a video,
+//! whether voice activity detection found speech. A subtitle made for a
+//! video follows its dialogue closely, so the two timelines correlate
+//! strongly at some offset, while the subtitle of another episode of the
+//! same show does not.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use subx_cli::core::matcher::speech_pattern::SpeechPattern;
+//!
+//! let intervals: Vec<(f64, f64)> = (0..40)
+//!     .map(|i| {
+//!         let start = i as f64 * 7.0 + (i * i % 4) as f64;
+//!         (start, start + 1.0 + (i * 7 % 11) as f64 * 0.4)
+//!     })
+//!     .collect();
+//! let speech = SpeechPattern::from_intervals(intervals.iter().copied());
+//! // The same dialogue, shown two seconds late
+//! let cues = SpeechPattern::from_intervals(intervals.iter().map(|(s, e)| (s + 2.0, e + 2.0)));
+//!
+//! let correlation = cues.correlate(&speech);
+//! assert!(correlation.score > 0.9);
+//! assert_eq!(correlation.offset_seconds, 2.0);
+//! ```
+
+use crate::core::formats::Subtitle;
+use crate::services::vad::SpeechSegment;
+
+/// Length of one timeline bin in seconds.
+pub const BIN_SECONDS: f64 = 0.5;
+
+/// Largest offset between cues and speech that is searched, in seconds.
+pub const MAX_OFFSET_SECONDS: f64 = 60.0;
+
+/// Minimum number of overlapping bins for an offset to be scored.
+const MIN_OVERLAP_BINS: usize = 120;
+
+/// On/off activity timeline of cues or detected speech.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpeechPattern {
+    bins: Vec<f32>,
+}
+
+/// Best alignment of a subtitle pattern against a speech pattern.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PatternCorrelation {
+    /// Pearson correlation of the two timelines at the best offset (-1.0 to 1.0)
+    pub score: f32,
+    /// How far the cues run behind the speech at that offset, in seconds
+    pub offset_seconds: f64,
+}
+
+impl SpeechPattern {
+    /// Timeline active during each `(start, end)` interval in seconds.
+    pub fn from_intervals(intervals: impl IntoIterator<Item = (f64, f64)>) -> Self {
+        let mut bins = Vec::new();
+        for (start, end) in intervals {
+            if end <= start || end <= 0.0 {
+                continue;
+            }
+            let first = (start.max(0.0) / BIN_SECONDS).floor() as usize;
+            let last = (end / BIN_SECONDS).ceil() as usize;
+            if bins.len() < last {
+                bins.resize(last, 0.0);
+            }
+            bins[first..last].fill(1.0);
+        }
+        Self { bins }
+    }
+
+    /// Timeline of when the subtitle shows a cue.
+    pub fn from_subtitle(subtitle: &Subtitle) -> Self {
+        Self::from_intervals(
+            subtitle
+                .entries
+                .iter()
+                .map(|e| (e.start_time.as_secs_f64(), e.end_time.as_secs_f64())),
+        )
+    }
+
+    /// Timeline of the speech segments found by voice activity detection.
+    pub fn from_segments(segments: &[SpeechSegment]) -> Self {
+        Self::from_intervals(segments.iter().map(|s| (s.start_time, s.end_time)))
+    }
+
+    /// Correlate this (cue) timeline with a speech timeline at every offset
+    /// up to [`MAX_OFFSET_SECONDS`] and return the best one. Offsets with too
+    /// little overlap are skipped; without any, the score is 0.
+    pub fn correlate(&self, speech: &SpeechPattern) -> PatternCorrelation {
+        let max_lag = (MAX_OFFSET_SECONDS / BIN_SECONDS) as isize;
+        let mut best = PatternCorrelation {
+            score: 0.0,
+            offset_seconds: 0.0,
+        };
+        // Nearest offsets first, so that ties go to the smaller shift
+        let lags = (0..=max_lag).flat_map(|lag| [lag, -lag]).skip(1);
+        for lag in lags {
+            // Cue bin `i` lines up with speech bin `i - lag`
+            let start = lag.max(0) as usize;
+            let end = self
+                .bins
+                .len()
+                .min((speech.bins.len() as isize + lag).max(0) as usize);
+            if end < start + MIN_OVERLAP_BINS {
+                continue;
+            }
+            let cues = &self.bins[start..end];
+            let offset = (start as isize - lag) as usize;
+            let voice = &speech.bins[offset..offset + cues.len()];
+            let score = pearson(cues, voice);
+            if score > best.score {
+                best = PatternCorrelation {
+                    score,
+                    offset_seconds: lag as f64 * BIN_SECONDS,
+                };
+            }
+        }
+        best
+    }
+}
+
+/// Pearson correlation of two equally long series, 0 when either is flat.
+fn pearson(a: &[f32], b: &[f32]) -> f32 {
+    let n = a.len() as f64;
+    let mean_a = a.iter().map(|&x| x as f64).sum::<f64>() / n;
+    let mean_b = b.iter().map(|&x| x as f64).sum::<f64>() / n;
+    let (mut cov, mut var_a, mut var_b) = (0.0, 0.0, 0.0);
+    for (&x, &y) in a.iter().zip(b) {
+        let (dx, dy) = (x as f64 - mean_a, y as f64 - mean_b);
+        cov += dx * dy;
+        var_a += dx * dx;
+        var_b += dy * dy;
+    }
+    if var_a == 0.0 || var_b == 0.0 {
+        return 0.0;
+    }
+    (cov / (var_a * var_b).sqrt()) as f32
+}
+
+/// Irregular dialogue for tests: `lines` (start, end) intervals of varying
+/// length and gaps, different for every `seed`.
+#[cfg(test)]
+pub(crate) fn dialogue(seed: u64, lines: usize) -> Vec<(f64, f64)> {
+    let mut state = seed;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 33) as f64 / (1u64 << 31) as f64
+    };
+    let mut time = 5.0;
+    (0..lines)
+        .map(|_| {
+            let start = time + 0.5 + next() * 6.0;
+            let end = start + 1.0 + next() * 3.0;
+            time = end;
+            (start, end)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matching_dialogue_correlates_at_its_offset() {
+        let lines = dialogue(1, 200);
+        let speech = SpeechPattern::from_intervals(lines.iter().copied());
+        let cues = SpeechPattern::from_intervals(lines.iter().map(|(s, e)| (s - 3.5, e - 3.5)));
+        let correlation = cues.correlate(&speech);
+        assert!(correlation.score > 0.95, "{correlation:?}");
+        assert_eq!(correlation.offset_seconds, -3.5);
+    }
+
+    #[test]
+    fn test_other_episode_correlates_poorly() {
+        let speech = SpeechPattern::from_intervals(dialogue(1, 200));
+        let cues = SpeechPattern::from_intervals(dialogue(2, 200));
+        assert!(cues.correlate(&speech).score < 0.5);
+    }
+
+    #[test]
+    fn test_short_or_empty_patterns_score_zero() {
+        let speech = SpeechPattern::from_intervals(dialogue(1, 200));
+        let empty = SpeechPattern::from_intervals(Vec::new());
+        assert_eq!(empty.correlate(&speech).score, 0.0);
+        let short = SpeechPattern::from_intervals(vec![(1.0, 2.0), (4.0, 5.0)]);
+        assert_eq!(short.correlate(&speech).score, 0.0);
+    }
+}
@@ -0,0 +1,152 @@
+//! Sanity checks of subtitle timing against the video's duration.
+//!
+//! A subtitle whose last cue ends long after the video does belongs to
+//! another video, or was shifted by a wrong offset. The video's duration is
+//! read from its container metadata with [`probe_media_duration`], which
+//! does not decode any audio and is cheap enough to run on every match.
+//! [`DurationFit::check`] compares it with where the cues end.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use subx_cli::core::media_duration::DurationFit;
+//!
+//! let video = Duration::from_secs(42 * 60);
+//! assert_eq!(
+//!     DurationFit::check(Duration::from_secs(41 * 60), video),
+//!     DurationFit::Fits
+//! );
+//! assert!(matches!(
+//!     DurationFit::check(Duration::from_secs(62 * 60), video),
+//!     DurationFit::Overruns { .. }
+//! ));
+//! ```
+
+use std::path::Path;
+use std::time::Duration;
+
+use crate::core::formats::Subtitle;
+use crate::services::vad::audio_loader::DirectAudioLoader;
+
+/// Time cues may run past the end of the video without being flagged,
+/// on top of [`OVERRUN_RATIO`] of the video's duration.
+pub const OVERRUN_TOLERANCE: Duration = Duration::from_secs(60);
+
+/// Share of the video's duration cues may run past its end, on top of
+/// [`OVERRUN_TOLERANCE`].
+pub const OVERRUN_RATIO: f64 = 0.05;
+
+/// Share of the video below which cues ending early count as short.
+pub const MIN_COVERAGE: f64 = 0.5;
+
+/// How a subtitle's timing fits a video's duration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DurationFit {
+    /// The cues end within the video
+    Fits,
+    /// The cues end `overrun` after the video, beyond the tolerance
+    Overruns {
+        /// Time from the end of the video to the end of the last cue
+        overrun: Duration,
+    },
+    /// The cues end early, covering only `coverage` of the video; typical of
+    /// forced or partial subtitles, or of a subtitle for a shorter cut
+    Short {
+        /// Share of the video before the end of the last cue
+        coverage: f64,
+    },
+}
+
+impl DurationFit {
+    /// Compare `cues_end`, the end of the last cue, with `video`, the
+    /// video's duration. A video of unknown (zero) length always fits.
+    pub fn check(cues_end: Duration, video: Duration) -> Self {
+        if video.is_zero() {
+            return Self::Fits;
+        }
+        let tolerance = OVERRUN_TOLERANCE + video.mul_f64(OVERRUN_RATIO);
+        if cues_end > video + tolerance {
+            return Self::Overruns {
+                overrun: cues_end - video,
+            };
+        }
+        let coverage = cues_end.as_secs_f64() / video.as_secs_f64();
+        if coverage < MIN_COVERAGE {
+            return Self::Short { coverage };
+        }
+        Self::Fits
+    }
+}
+
+/// Duration of the video or audio file at `path` from its container
+/// metadata, or `None` if the container cannot be read or does not record
+/// it. Never decodes the stream.
+pub fn probe_media_duration(path: &Path) -> Option<Duration> {
+    match DirectAudioLoader::new().and_then(|loader| loader.probe_container_duration(path)) {
+        Ok(duration) => duration,
+        Err(e) => {
+            log::debug!("No duration for {}: {}", path.display(), e);
+            None
+        }
+    }
+}
+
+/// End of the last cue of `subtitle`, or `None` if it has no cues.
+pub fn cues_end(subtitle: &Subtitle) -> Option<Duration> {
+    subtitle.entries.iter().map(|entry| entry.end_time).max()
+}
+
+/// `HH:MM:SS` rendering of `duration` for messages.
+pub fn format_hms(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        (secs % 3600) / 60,
+        secs % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duration_fit_tolerance_and_coverage() {
+        let video = Duration::from_secs(2400);
+        // Tolerance is one minute plus 5% of the video: 180s here
+        assert_eq!(
+            DurationFit::check(Duration::from_secs(2580), video),
+            DurationFit::Fits
+        );
+        assert_eq!(
+            DurationFit::check(Duration::from_secs(3600), video),
+            DurationFit::Overruns {
+                overrun: Duration::from_secs(1200)
+            }
+        );
+        assert_eq!(
+            DurationFit::check(Duration::from_secs(600), video),
+            DurationFit::Short { coverage: 0.25 }
+        );
+        assert_eq!(
+            DurationFit::check(Duration::from_secs(9999), Duration::ZERO),
+            DurationFit::Fits
+        );
+        assert_eq!(format_hms(Duration::from_secs(3725)), "01:02:05");
+    }
+
+    #[test]
+    fn test_probe_media_duration_reads_container_metadata() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let audio = temp.path().join("tone.wav");
+        crate::services::vad::audio_loader::write_silent_wav(&audio, 3);
+        assert_eq!(probe_media_duration(&audio), Some(Duration::from_secs(3)));
+
+        let video = temp.path().join("fake.mkv");
+        std::fs::write(&video, b"not a video").unwrap();
+        assert_eq!(probe_media_duration(&video), None);
+        assert_eq!(probe_media_duration(&temp.path().join("missing.mkv")), None);
+    }
+}
@@ -7,23 +7,31 @@
 //! Each subsystem is organized into its own submodule:
 //! - `file_manager` for safe file operations with rollback support
 //! - `formats` for parsing and converting subtitle formats
+//! - `journal` for the persistent record of file changes behind `subx undo`
 //! - `language` for language detection and handling
+//! - `chinese` for Traditional/Simplified Chinese text conversion
 //! - `matcher` for AI-powered subtitle matching algorithms
+//! - `media_duration` for checking subtitle timing against video durations
 //! - `parallel` for task scheduling and parallel execution
 //! - `sync` for audio-text synchronization engines
+//! - `watch` for debouncing, state and logging of `subx watch`
 //! - `factory` for component creation with dependency injection
 //! - `services` for service container and dependency management
 //!
 #![allow(dead_code)]
 
+pub mod chinese;
 pub mod factory;
 pub mod file_manager;
 pub mod formats;
 pub mod fs_util;
+pub mod journal;
 pub mod language;
 pub mod matcher;
+pub mod media_duration;
 pub mod parallel;
 pub mod sync;
+pub mod watch;
 
 // Re-export commonly used types
 pub use factory::ComponentFactory;
@@ -1,5 +1,5 @@
 //! Task definition and utilities for parallel processing
-use crate::core::fs_util::copy_file_cifs_safe;
+use crate::core::fs_util::{copy_file_cifs_safe, hard_link_or_copy, symlink_file};
 use async_trait::async_trait;
 use std::fmt;
 use std::path::Path;
@@ -144,6 +144,23 @@ pub enum ProcessingOperation {
         /// Target file path
         target: std::path::PathBuf,
     },
+    /// Create a symbolic link to a file under a new name
+    SymlinkWithRename {
+        /// File the link points to
+        source: std::path::PathBuf,
+        /// Path of the link
+        target: std::path::PathBuf,
+        /// Store the source path relative to the link's directory
+        relative: bool,
+    },
+    /// Create a hard link to a file under a new name, or a copy when the
+    /// target is on another filesystem
+    HardlinkWithRename {
+        /// Linked file
+        source: std::path::PathBuf,
+        /// Path of the new link
+        target: std::path::PathBuf,
+    },
     /// Create a backup of a file
     CreateBackup {
         /// Original file path
@@ -236,6 +253,35 @@ impl Task for FileProcessingTask {
                     Err(e) => TaskResult::Failed(format!("Copy failed: {}", e)),
                 }
             }
+            ProcessingOperation::SymlinkWithRename {
+                source,
+                target,
+                relative,
+            } => match self
+                .execute_symlink_operation(source, target, *relative)
+                .await
+            {
+                Ok(_) => TaskResult::Success(format!(
+                    "Linked: {} -> {}",
+                    target.display(),
+                    source.display()
+                )),
+                Err(e) => TaskResult::Failed(format!("Symlink failed: {}", e)),
+            },
+            ProcessingOperation::HardlinkWithRename { source, target } => {
+                match self.execute_hardlink_operation(source, target).await {
+                    Ok(true) => TaskResult::Success(format!(
+                        "Hard linked: {} -> {}",
+                        source.display(),
+                        target.display()
+                    )),
+                    Ok(false) => TaskResult::PartialSuccess(
+                        format!("Copied: {} -> {}", source.display(), target.display()),
+                        "hard link not possible across filesystems, copied instead".to_string(),
+                    ),
+                    Err(e) => TaskResult::Failed(format!("Hard link failed: {}", e)),
+                }
+            }
             ProcessingOperation::CreateBackup { source, backup } => {
                 match self.execute_create_backup_operation(source, backup).await {
                     Ok(_) => TaskResult::Success(format!(
@@ -268,6 +314,8 @@ impl Task for FileProcessingTask {
             ProcessingOperation::CopyToVideoFolder { .. } => "copy_to_video_folder",
             ProcessingOperation::MoveToVideoFolder { .. } => "move_to_video_folder",
             ProcessingOperation::CopyWithRename { .. } => "copy_with_rename",
+            ProcessingOperation::SymlinkWithRename { .. } => "symlink_with_rename",
+            ProcessingOperation::HardlinkWithRename { .. } => "hardlink_with_rename",
             ProcessingOperation::CreateBackup { .. } => "create_backup",
             ProcessingOperation::RenameFile { .. } => "rename_file",
         }
@@ -293,6 +341,8 @@ impl Task for FileProcessingTask {
                 ProcessingOperation::CopyToVideoFolder { .. } => size_mb * 0.01, // Fast copy
                 ProcessingOperation::MoveToVideoFolder { .. } => size_mb * 0.005, // Even faster move
                 ProcessingOperation::CopyWithRename { .. } => size_mb * 0.01,
+                ProcessingOperation::SymlinkWithRename { .. } => 0.0,
+                ProcessingOperation::HardlinkWithRename { .. } => 0.0,
                 ProcessingOperation::CreateBackup { .. } => size_mb * 0.01,
                 ProcessingOperation::RenameFile { .. } => size_mb * 0.005,
             };
@@ -338,6 +388,19 @@ impl Task for FileProcessingTask {
                     target.display()
                 )
             }
+            ProcessingOperation::SymlinkWithRename {
+                source,
+                target,
+                relative,
+            } => format!(
+                "Symlink {} to {}{}",
+                target.display(),
+                source.display(),
+                if *relative { " (relative)" } else { "" }
+            ),
+            ProcessingOperation::HardlinkWithRename { source, target } => {
+                format!("Hardlink {} to {}", source.display(), target.display())
+            }
             ProcessingOperation::CreateBackup { source, backup } => {
                 format!("CreateBackup {} to {}", source.display(), backup.display())
             }
@@ -447,6 +510,33 @@ impl FileProcessingTask {
         Ok(())
     }
 
+    /// Execute a symlink operation; the target must not exist yet
+    async fn execute_symlink_operation(
+        &self,
+        source: &Path,
+        target: &Path,
+        relative: bool,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        symlink_file(source, target, relative)?;
+        Ok(())
+    }
+
+    /// Execute a hard link operation, copying across filesystems. Returns
+    /// whether a link was created.
+    async fn execute_hardlink_operation(
+        &self,
+        source: &Path,
+        target: &Path,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        Ok(hard_link_or_copy(source, target)?)
+    }
+
     /// Execute a create backup operation using CIFS-safe copy
     async fn execute_create_backup_operation(
         &self,
@@ -534,6 +624,21 @@ impl std::hash::Hash for ProcessingOperation {
                 source.hash(state);
                 target.hash(state);
             }
+            ProcessingOperation::SymlinkWithRename {
+                source,
+                target,
+                relative,
+            } => {
+                "symlink_with_rename".hash(state);
+                source.hash(state);
+                target.hash(state);
+                relative.hash(state);
+            }
+            ProcessingOperation::HardlinkWithRename { source, target } => {
+                "hardlink_with_rename".hash(state);
+                source.hash(state);
+                target.hash(state);
+            }
             ProcessingOperation::CreateBackup { source, backup } => {
                 "create_backup".hash(state);
                 source.hash(state);
@@ -590,6 +695,53 @@ mod tests {
         assert_eq!(data, b"hello");
     }
 
+    #[tokio::test]
+    async fn test_file_processing_task_link_operations() {
+        let tmp = TempDir::new().unwrap();
+        let src = tmp.path().join("archive").join("orig.srt");
+        tokio::fs::create_dir_all(src.parent().unwrap())
+            .await
+            .unwrap();
+        tokio::fs::write(&src, b"linked").await.unwrap();
+
+        let hard = tmp.path().join("media").join("hard.srt");
+        let task = FileProcessingTask::new(
+            src.clone(),
+            Some(hard.clone()),
+            ProcessingOperation::HardlinkWithRename {
+                source: src.clone(),
+                target: hard.clone(),
+            },
+        );
+        assert!(matches!(task.execute().await, TaskResult::Success(_)));
+        assert_eq!(tokio::fs::read(&hard).await.unwrap(), b"linked");
+
+        #[cfg(unix)]
+        {
+            let soft = tmp.path().join("media").join("soft.srt");
+            let task = FileProcessingTask::new(
+                src.clone(),
+                Some(soft.clone()),
+                ProcessingOperation::SymlinkWithRename {
+                    source: src.clone(),
+                    target: soft.clone(),
+                    relative: true,
+                },
+            );
+            assert!(matches!(task.execute().await, TaskResult::Success(_)));
+            assert!(
+                tokio::fs::symlink_metadata(&soft)
+                    .await
+                    .unwrap()
+                    .file_type()
+                    .is_symlink()
+            );
+            assert_eq!(tokio::fs::read(&soft).await.unwrap(), b"linked");
+        }
+        // The source stays in place
+        assert!(src.exists());
+    }
+
     #[tokio::test]
     async fn test_file_processing_task_create_backup() {
         let tmp = TempDir::new().unwrap();
@@ -155,6 +155,32 @@ impl SyncEngine {
             offset_seconds,
             subtitle.entries.len()
         );
+        self.apply_time_adjustment(subtitle, &TimeAdjustment::offset(offset_seconds))
+    }
+
+    /// Shift and/or stretch the cues of a time range.
+    ///
+    /// Cues starting inside the adjustment range are stretched around the
+    /// range start and then shifted by the offset; cues outside the range are
+    /// left untouched. Cues pushed before zero are clamped or dropped
+    /// according to [`TimeAdjustment::underflow`], and every clamped or
+    /// dropped cue is reported in the result warnings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the offset exceeds the configured maximum or the
+    /// stretch factor is not a positive number.
+    pub fn apply_time_adjustment(
+        &self,
+        subtitle: &mut Subtitle,
+        adjustment: &TimeAdjustment,
+    ) -> Result<SyncResult> {
+        let offset_seconds = adjustment.offset_seconds;
+        debug!(
+            "[SyncEngine] apply_time_adjustment called | adjustment: {:?}, entries: {}",
+            adjustment,
+            subtitle.entries.len()
+        );
         // Validate offset against max_offset_seconds configuration
         if offset_seconds.abs() > self.config.max_offset_seconds {
             warn!(
@@ -166,34 +192,73 @@ impl SyncEngine {
                 offset_seconds, self.config.max_offset_seconds
             )));
         }
+        if !(adjustment.stretch.is_finite() && adjustment.stretch > 0.0) {
+            return Err(SubXError::config(format!(
+                "Stretch factor must be a positive number, got {}",
+                adjustment.stretch
+            )));
+        }
 
         let start = Instant::now();
-        for entry in &mut subtitle.entries {
-            let offset_dur = Duration::from_secs_f32(offset_seconds.abs());
-            if offset_seconds >= 0.0 {
-                entry.start_time = entry.start_time.checked_add(offset_dur).ok_or_else(|| {
-                    SubXError::audio_processing("Invalid offset results in negative time")
-                })?;
-                entry.end_time = entry.end_time.checked_add(offset_dur).ok_or_else(|| {
-                    SubXError::audio_processing("Invalid offset results in negative time")
-                })?;
+        let offset_nanos = Duration::from_secs_f32(offset_seconds.abs()).as_nanos() as i128
+            * if offset_seconds < 0.0 { -1 } else { 1 };
+        let anchor = adjustment.range_start.unwrap_or(Duration::ZERO).as_nanos() as i128;
+        let adjust = |time: Duration| {
+            let relative = time.as_nanos() as i128 - anchor;
+            let stretched = if adjustment.stretch == 1.0 {
+                relative
             } else {
-                // For negative offsets, clamp times to zero instead of erroring on underflow
-                entry.start_time = if entry.start_time > offset_dur {
-                    entry.start_time - offset_dur
-                } else {
-                    Duration::ZERO
-                };
-                entry.end_time = if entry.end_time > offset_dur {
-                    entry.end_time - offset_dur
-                } else {
-                    Duration::ZERO
-                };
+                (relative as f64 * adjustment.stretch).round() as i128
+            };
+            anchor + stretched + offset_nanos
+        };
+        let to_duration = |nanos: i128| {
+            Duration::new(
+                (nanos / 1_000_000_000) as u64,
+                (nanos % 1_000_000_000) as u32,
+            )
+        };
+
+        let mut warnings = Vec::new();
+        let mut modified = 0;
+        let mut clamped = 0;
+        let mut dropped = 0;
+        let entries = std::mem::take(&mut subtitle.entries);
+        for mut entry in entries {
+            if !adjustment.contains(entry.start_time) {
+                subtitle.entries.push(entry);
+                continue;
             }
+            modified += 1;
+            let new_start = adjust(entry.start_time);
+            let new_end = adjust(entry.end_time);
+            if new_start < 0 {
+                let keep = adjustment.underflow == UnderflowPolicy::Clamp && new_end > 0;
+                if !keep {
+                    dropped += 1;
+                    warnings.push(format!(
+                        "Dropped cue #{} ({:?}) pushed before zero",
+                        entry.index, entry.text
+                    ));
+                    continue;
+                }
+                clamped += 1;
+                warnings.push(format!(
+                    "Clamped start of cue #{} ({:?}) to zero",
+                    entry.index, entry.text
+                ));
+            }
+            entry.start_time = to_duration(new_start.max(0));
+            entry.end_time = to_duration(new_end.max(0));
+            subtitle.entries.push(entry);
+        }
+        if dropped > 0 || adjustment.range_start.is_some() || adjustment.range_end.is_some() {
+            // Range shifts may move cues past their untouched neighbours
+            subtitle.sort_entries();
         }
         debug!(
-            "[SyncEngine] Manual offset applied to all entries | offset_seconds: {:.3}",
-            offset_seconds
+            "[SyncEngine] Time adjustment applied | modified: {}, clamped: {}, dropped: {}",
+            modified, clamped, dropped
         );
         Ok(SyncResult {
             offset_seconds,
@@ -202,10 +267,15 @@ impl SyncEngine {
             correlation_peak: 1.0,
             additional_info: Some(json!({
                 "applied_offset": offset_seconds,
-                "entries_modified": subtitle.entries.len(),
+                "stretch": adjustment.stretch,
+                "range_start": adjustment.range_start.map(|t| t.as_secs_f64()),
+                "range_end": adjustment.range_end.map(|t| t.as_secs_f64()),
+                "entries_modified": modified,
+                "entries_clamped": clamped,
+                "entries_dropped": dropped,
             })),
             processing_duration: start.elapsed(),
-            warnings: Vec::new(),
+            warnings,
         })
     }
 
@@ -275,6 +345,79 @@ impl SyncEngine {
     }
 }
 
+/// Handling of cues that a negative shift pushes before zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnderflowPolicy {
+    /// Clamp the start time to zero; cues that would end before zero are dropped
+    #[default]
+    Clamp,
+    /// Drop every cue whose start time would fall before zero
+    Drop,
+}
+
+/// Manual timing adjustment, optionally limited to a time range.
+///
+/// Cues whose start time lies in `[range_start, range_end)` are first
+/// stretched by `stretch` around `range_start` (zero when unset) and then
+/// shifted by `offset_seconds`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeAdjustment {
+    /// Offset in seconds (positive delays, negative advances)
+    pub offset_seconds: f32,
+    /// Stretch factor applied before the offset (1.0 leaves durations unchanged)
+    pub stretch: f64,
+    /// Only cues starting at or after this time are adjusted
+    pub range_start: Option<Duration>,
+    /// Only cues starting before this time are adjusted
+    pub range_end: Option<Duration>,
+    /// Handling of cues pushed before zero
+    pub underflow: UnderflowPolicy,
+}
+
+impl Default for TimeAdjustment {
+    fn default() -> Self {
+        Self::offset(0.0)
+    }
+}
+
+impl TimeAdjustment {
+    /// Create an adjustment that shifts every cue by `offset_seconds`.
+    pub fn offset(offset_seconds: f32) -> Self {
+        Self {
+            offset_seconds,
+            stretch: 1.0,
+            range_start: None,
+            range_end: None,
+            underflow: UnderflowPolicy::default(),
+        }
+    }
+
+    /// Limit the adjustment to cues starting in `[start, end)`.
+    pub fn with_range(mut self, start: Option<Duration>, end: Option<Duration>) -> Self {
+        self.range_start = start;
+        self.range_end = end;
+        self
+    }
+
+    /// Stretch cue times by `factor` around the range start.
+    pub fn with_stretch(mut self, factor: f64) -> Self {
+        self.stretch = factor;
+        self
+    }
+
+    /// Set the handling of cues pushed before zero.
+    pub fn with_underflow(mut self, policy: UnderflowPolicy) -> Self {
+        self.underflow = policy;
+        self
+    }
+
+    /// Whether a cue starting at `start_time` falls inside the range.
+    pub fn contains(&self, start_time: Duration) -> bool {
+        self.range_start.is_none_or(|from| start_time >= from)
+            && self.range_end.is_none_or(|to| start_time < to)
+    }
+}
+
 /// Sync method enumeration.
 ///
 /// Defines the available methods for subtitle synchronization,
@@ -404,6 +547,95 @@ mod tests {
         assert!(strategy.allow_fallback);
     }
 
+    fn create_multi_entry_subtitle() -> Subtitle {
+        let entries = [(1, 3), (10, 12), (20, 22)]
+            .iter()
+            .enumerate()
+            .map(|(i, (start, end))| {
+                SubtitleEntry::new(
+                    i + 1,
+                    Duration::from_secs(*start),
+                    Duration::from_secs(*end),
+                    format!("Line {}", i + 1),
+                )
+            })
+            .collect();
+        Subtitle {
+            entries,
+            metadata: SubtitleMetadata::default(),
+            format: SubtitleFormatType::Srt,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_time_adjustment_range_and_stretch() {
+        let config = TestConfigBuilder::new().build_config();
+        let engine = SyncEngine::new(config.sync).unwrap();
+
+        let mut subtitle = create_multi_entry_subtitle();
+        let adjustment =
+            TimeAdjustment::offset(1.5).with_range(Some(Duration::from_secs(10)), None);
+        engine
+            .apply_time_adjustment(&mut subtitle, &adjustment)
+            .unwrap();
+        assert_eq!(subtitle.entries[0].start_time, Duration::from_secs(1));
+        assert_eq!(
+            subtitle.entries[1].start_time,
+            Duration::from_millis(11_500)
+        );
+        assert_eq!(subtitle.entries[2].end_time, Duration::from_millis(23_500));
+
+        let mut subtitle = create_multi_entry_subtitle();
+        let adjustment = TimeAdjustment::offset(0.0)
+            .with_range(Some(Duration::from_secs(10)), Some(Duration::from_secs(15)))
+            .with_stretch(2.0);
+        engine
+            .apply_time_adjustment(&mut subtitle, &adjustment)
+            .unwrap();
+        assert_eq!(subtitle.entries[1].start_time, Duration::from_secs(10));
+        assert_eq!(subtitle.entries[1].end_time, Duration::from_secs(14));
+        assert_eq!(subtitle.entries[2].start_time, Duration::from_secs(20));
+
+        let invalid = TimeAdjustment::offset(0.0).with_stretch(0.0);
+        assert!(
+            engine
+                .apply_time_adjustment(&mut subtitle, &invalid)
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_time_adjustment_underflow_policies() {
+        let config = TestConfigBuilder::new().build_config();
+        let engine = SyncEngine::new(config.sync).unwrap();
+
+        let mut subtitle = create_multi_entry_subtitle();
+        let result = engine
+            .apply_time_adjustment(&mut subtitle, &TimeAdjustment::offset(-2.0))
+            .unwrap();
+        assert_eq!(subtitle.entries.len(), 3);
+        assert_eq!(subtitle.entries[0].start_time, Duration::ZERO);
+        assert_eq!(subtitle.entries[0].end_time, Duration::from_secs(1));
+        assert_eq!(result.warnings.len(), 1);
+
+        let mut subtitle = create_multi_entry_subtitle();
+        let adjustment = TimeAdjustment::offset(-2.0).with_underflow(UnderflowPolicy::Drop);
+        let result = engine
+            .apply_time_adjustment(&mut subtitle, &adjustment)
+            .unwrap();
+        assert_eq!(subtitle.entries.len(), 2);
+        assert_eq!(subtitle.entries[0].index, 1);
+        assert_eq!(subtitle.entries[0].text, "Line 2");
+        assert!(result.warnings[0].contains("Dropped cue #1"));
+
+        // A cue shifted entirely before zero is dropped even when clamping
+        let mut subtitle = create_multi_entry_subtitle();
+        engine
+            .apply_time_adjustment(&mut subtitle, &TimeAdjustment::offset(-5.0))
+            .unwrap();
+        assert_eq!(subtitle.entries.len(), 2);
+    }
+
     fn create_test_subtitle() -> Subtitle {
         Subtitle {
             entries: vec![SubtitleEntry::new(
@@ -30,4 +30,6 @@
 pub mod engine;
 
 // Re-export main types
-pub use engine::{MethodSelectionStrategy, SyncEngine, SyncMethod, SyncResult};
+pub use engine::{
+    MethodSelectionStrategy, SyncEngine, SyncMethod, SyncResult, TimeAdjustment, UnderflowPolicy,
+};
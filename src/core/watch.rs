@@ -0,0 +1,374 @@
+//! Bookkeeping for `subx watch`.
+//!
+//! A download usually shows up as a burst of filesystem events: the file is
+//! created, written in many chunks and often renamed at the end. The
+//! [`Debouncer`] collects the paths of such events and releases them as one
+//! batch once no event arrived for the configured quiet period.
+//! [`WatchState`] remembers the size and modification time of every file
+//! that went through the pipeline, so that restarts and the pipeline's own
+//! renames and conversions are not processed again, and [`WatchLog`] appends
+//! one JSON record per event to a log file.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::path::PathBuf;
+//! use std::time::{Duration, Instant};
+//! use subx_cli::core::watch::Debouncer;
+//!
+//! let mut debouncer = Debouncer::new(Duration::from_secs(2));
+//! let start = Instant::now();
+//! debouncer.push(PathBuf::from("Show.S01E01.mkv"), start);
+//! debouncer.push(PathBuf::from("Show.S01E01.srt"), start + Duration::from_secs(1));
+//! assert!(debouncer.take_ready(start + Duration::from_secs(2)).is_none());
+//! let batch = debouncer.take_ready(start + Duration::from_secs(3)).unwrap();
+//! assert_eq!(batch.len(), 2);
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Result;
+use crate::core::journal::{format_timestamp, subx_config_dir};
+
+/// Collects changed paths until the filesystem has been quiet for a while.
+#[derive(Debug)]
+pub struct Debouncer {
+    delay: Duration,
+    pending: BTreeSet<PathBuf>,
+    last_event: Option<Instant>,
+}
+
+impl Debouncer {
+    /// Debouncer releasing a batch `delay` after the last event.
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            pending: BTreeSet::new(),
+            last_event: None,
+        }
+    }
+
+    /// Record an event for `path` at `now`; the quiet period restarts.
+    pub fn push(&mut self, path: PathBuf, now: Instant) {
+        self.pending.insert(path);
+        self.last_event = Some(now);
+    }
+
+    /// When the pending batch becomes ready, if there is one.
+    pub fn deadline(&self) -> Option<Instant> {
+        if self.pending.is_empty() {
+            return None;
+        }
+        self.last_event.map(|last| last + self.delay)
+    }
+
+    /// The pending paths, if the quiet period has passed at `now`.
+    pub fn take_ready(&mut self, now: Instant) -> Option<Vec<PathBuf>> {
+        match self.deadline() {
+            Some(deadline) if deadline <= now => {
+                self.last_event = None;
+                Some(std::mem::take(&mut self.pending).into_iter().collect())
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Size and modification time of a processed file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileRecord {
+    /// Size in bytes
+    pub size: u64,
+    /// Modification time in milliseconds since the Unix epoch
+    pub modified_ms: u64,
+    /// When the file was processed, in seconds since the Unix epoch
+    pub processed_at: u64,
+}
+
+/// Files already handled by the watch pipeline, stored as JSON.
+#[derive(Debug)]
+pub struct WatchState {
+    path: PathBuf,
+    files: BTreeMap<PathBuf, FileRecord>,
+}
+
+impl WatchState {
+    /// Location of the state file: `watch_state.json` in the SubX config
+    /// directory.
+    pub fn default_path() -> Result<PathBuf> {
+        Ok(subx_config_dir()?.join("watch_state.json"))
+    }
+
+    /// Load the state stored at `path`; a missing file is an empty state.
+    pub fn load(path: PathBuf) -> Result<Self> {
+        let files = if path.exists() {
+            serde_json::from_str(&fs::read_to_string(&path)?)?
+        } else {
+            BTreeMap::new()
+        };
+        Ok(Self { path, files })
+    }
+
+    /// Whether `path` was processed and has not changed since.
+    pub fn is_processed(&self, path: &Path) -> bool {
+        match (self.files.get(&absolute(path)), fingerprint(path)) {
+            (Some(record), Some((size, modified_ms))) => {
+                record.size == size && record.modified_ms == modified_ms
+            }
+            _ => false,
+        }
+    }
+
+    /// Remember `path` as processed in its current state. Missing files are
+    /// ignored.
+    pub fn mark_processed(&mut self, path: &Path) {
+        if let Some((size, modified_ms)) = fingerprint(path) {
+            self.files.insert(
+                absolute(path),
+                FileRecord {
+                    size,
+                    modified_ms,
+                    processed_at: now().as_secs(),
+                },
+            );
+        }
+    }
+
+    /// Number of files remembered.
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Whether no file is remembered.
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Write the state back to its file.
+    pub fn save(&self) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        // Replace the file atomically so that a crash never leaves it torn
+        let tmp = self.path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(&self.files)?)?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+}
+
+/// Severity of a [`WatchEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WatchLevel {
+    /// Normal progress
+    Info,
+    /// A step was skipped or did not apply
+    Warn,
+    /// A step failed
+    Error,
+}
+
+/// One structured log record.
+#[derive(Debug, Clone, Serialize)]
+pub struct WatchEvent {
+    /// Time of the event, `YYYY-MM-DD HH:MM:SS UTC`
+    pub timestamp: String,
+    /// Severity
+    pub level: WatchLevel,
+    /// Kind of event, e.g. `started`, `batch`, `step`, `stopped`
+    pub event: String,
+    /// Pipeline step the event belongs to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub step: Option<String>,
+    /// File the event concerns
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+    /// Human-readable description
+    pub message: String,
+}
+
+impl WatchEvent {
+    /// Event of kind `event` at the current time.
+    pub fn new(level: WatchLevel, event: &str, message: impl Into<String>) -> Self {
+        Self {
+            timestamp: format_timestamp(now().as_secs()),
+            level,
+            event: event.to_string(),
+            step: None,
+            path: None,
+            message: message.into(),
+        }
+    }
+
+    /// Attribute the event to pipeline step `step`.
+    pub fn with_step(mut self, step: &str) -> Self {
+        self.step = Some(step.to_string());
+        self
+    }
+
+    /// Attribute the event to `path`.
+    pub fn with_path(mut self, path: &Path) -> Self {
+        self.path = Some(path.to_path_buf());
+        self
+    }
+}
+
+/// Appends [`WatchEvent`]s as JSON lines and mirrors them to the `log`
+/// facade.
+#[derive(Debug)]
+pub struct WatchLog {
+    path: PathBuf,
+    file: Mutex<fs::File>,
+}
+
+impl WatchLog {
+    /// Location of the log file: `watch.log` in the SubX config directory.
+    pub fn default_path() -> Result<PathBuf> {
+        Ok(subx_config_dir()?.join("watch.log"))
+    }
+
+    /// Open the log at `path` for appending.
+    pub fn open(path: PathBuf) -> Result<Self> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?;
+        Ok(Self {
+            path,
+            file: Mutex::new(file),
+        })
+    }
+
+    /// Path of the log file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append `event`. Failing to write the log never stops the watcher.
+    pub fn record(&self, event: WatchEvent) {
+        let line = match &event.path {
+            Some(path) => format!("{} ({})", event.message, path.display()),
+            None => event.message.clone(),
+        };
+        match event.level {
+            WatchLevel::Info => log::info!("{}", line),
+            WatchLevel::Warn => log::warn!("{}", line),
+            WatchLevel::Error => log::error!("{}", line),
+        }
+        let Ok(json) = serde_json::to_string(&event) else {
+            return;
+        };
+        if let Ok(mut file) = self.file.lock() {
+            if let Err(e) = writeln!(file, "{}", json) {
+                log::warn!("Unable to write watch log {}: {}", self.path.display(), e);
+            }
+        }
+    }
+}
+
+fn fingerprint(path: &Path) -> Option<(u64, u64)> {
+    let metadata = fs::metadata(path).ok()?;
+    let modified = metadata
+        .modified()
+        .ok()?
+        .duration_since(UNIX_EPOCH)
+        .ok()?
+        .as_millis() as u64;
+    Some((metadata.len(), modified))
+}
+
+fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_debouncer_waits_for_quiet_period() {
+        let mut debouncer = Debouncer::new(Duration::from_millis(500));
+        let start = Instant::now();
+        assert!(debouncer.deadline().is_none());
+
+        debouncer.push(PathBuf::from("a.mkv"), start);
+        debouncer.push(PathBuf::from("a.mkv"), start + Duration::from_millis(400));
+        assert_eq!(
+            debouncer.deadline(),
+            Some(start + Duration::from_millis(900))
+        );
+        assert!(
+            debouncer
+                .take_ready(start + Duration::from_millis(800))
+                .is_none()
+        );
+        let batch = debouncer
+            .take_ready(start + Duration::from_millis(900))
+            .unwrap();
+        assert_eq!(batch, vec![PathBuf::from("a.mkv")]);
+        assert!(debouncer.deadline().is_none());
+    }
+
+    #[test]
+    fn test_state_tracks_changes_and_persists() {
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("movie.srt");
+        fs::write(&file, "one").unwrap();
+        let state_path = temp.path().join("state").join("watch_state.json");
+
+        let mut state = WatchState::load(state_path.clone()).unwrap();
+        assert!(!state.is_processed(&file));
+        state.mark_processed(&file);
+        state.mark_processed(&temp.path().join("missing.srt"));
+        assert!(state.is_processed(&file));
+        state.save().unwrap();
+
+        let state = WatchState::load(state_path).unwrap();
+        assert_eq!(state.len(), 1);
+        assert!(state.is_processed(&file));
+        fs::write(&file, "changed").unwrap();
+        assert!(!state.is_processed(&file));
+    }
+
+    #[test]
+    fn test_log_writes_json_lines() {
+        let temp = TempDir::new().unwrap();
+        let log = WatchLog::open(temp.path().join("watch.log")).unwrap();
+        log.record(WatchEvent::new(WatchLevel::Info, "started", "Watching"));
+        log.record(
+            WatchEvent::new(WatchLevel::Error, "step", "Match failed")
+                .with_step("match")
+                .with_path(Path::new("a.srt")),
+        );
+
+        let content = fs::read_to_string(log.path()).unwrap();
+        let records: Vec<serde_json::Value> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0]["event"], "started");
+        assert!(records[0].get("step").is_none());
+        assert_eq!(records[1]["level"], "error");
+        assert_eq!(records[1]["step"], "match");
+        assert_eq!(records[1]["path"], "a.srt");
+    }
+}
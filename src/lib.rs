@@ -281,13 +281,8 @@ impl App {
     ///
     /// let match_args = MatchArgs {
     ///     path: Some("/path/to/files".into()),
-    ///     input_paths: vec![],
     ///     dry_run: true,
-    ///     confidence: 80,
-    ///     recursive: false,
-    ///     backup: false,
-    ///     copy: false,
-    ///     move_files: false,
+    ///     ..Default::default()
     /// };
     ///
     /// app.handle_command(Commands::Match(match_args)).await?;
@@ -339,13 +334,8 @@ impl App {
     pub async fn match_files(&self, input_path: &str, dry_run: bool) -> Result<()> {
         let args = cli::MatchArgs {
             path: Some(input_path.into()),
-            input_paths: vec![],
             dry_run,
-            confidence: 80,
-            recursive: false,
-            backup: false,
-            copy: false,
-            move_files: false,
+            ..Default::default()
         };
         self.handle_command(cli::Commands::Match(args)).await
     }
@@ -403,6 +393,10 @@ impl App {
             output: output_path.map(Into::into),
             keep_original: false,
             encoding: "utf-8".to_string(),
+            bom: false,
+            line_ending: None,
+            on_unmappable: cli::UnmappableArg::Fail,
+            strip_sdh: None,
         };
         self.handle_command(cli::Commands::Convert(args)).await
     }
@@ -457,6 +451,14 @@ impl App {
             input_paths: vec![],
             recursive: false,
             offset: None,
+            from: None,
+            to: None,
+            stretch: None,
+            on_underflow: cli::UnderflowPolicyArg::Clamp,
+            encoding: None,
+            bom: false,
+            line_ending: None,
+            on_unmappable: cli::UnmappableArg::Fail,
             method: sync_method,
             window: 30,
             vad_sensitivity: None,
@@ -503,6 +505,14 @@ impl App {
             input_paths: vec![],
             recursive: false,
             offset: Some(offset),
+            from: None,
+            to: None,
+            stretch: None,
+            on_underflow: cli::UnderflowPolicyArg::Clamp,
+            encoding: None,
+            bom: false,
+            line_ending: None,
+            on_unmappable: cli::UnmappableArg::Fail,
             method: None,
             window: 30,
             vad_sensitivity: None,
@@ -0,0 +1,191 @@
+//! Offline provider that matches files by filename heuristics.
+//!
+//! `LocalProvider` implements [`AIProvider`] on top of
+//! [`HeuristicMatcher`], so the match engine works unchanged on machines
+//! without network access or an API key. It is selected with
+//! `ai.provider = "local"` or `subx match --no-ai`.
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+
+use crate::core::matcher::{HeuristicMatcher, MediaFile, MediaFileType};
+use crate::services::ai::{
+    AIProvider, AnalysisRequest, ConfidenceScore, FileMatch, MatchResult, VerificationRequest,
+};
+
+/// Model name reported for matches made by [`LocalProvider`].
+pub const LOCAL_MODEL_NAME: &str = "local-heuristics";
+
+/// Matching provider that runs entirely on the local machine.
+#[derive(Default)]
+pub struct LocalProvider {
+    matcher: HeuristicMatcher,
+}
+
+impl LocalProvider {
+    /// Create a provider with the default heuristics.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl AIProvider for LocalProvider {
+    async fn analyze_content(&self, request: AnalysisRequest) -> crate::Result<MatchResult> {
+        let videos: Vec<MediaFile> = request
+            .video_files
+            .iter()
+            .map(|entry| parse_entry(entry, MediaFileType::Video))
+            .collect();
+        let subtitles: Vec<MediaFile> = request
+            .subtitle_files
+            .iter()
+            .map(|entry| parse_entry(entry, MediaFileType::Subtitle))
+            .collect();
+        let videos: Vec<&MediaFile> = videos.iter().collect();
+        let subtitles: Vec<&MediaFile> = subtitles.iter().collect();
+
+        let matches: Vec<FileMatch> = self
+            .matcher
+            .match_files(&videos, &subtitles)
+            .into_iter()
+            .map(|m| FileMatch {
+                video_file_id: m.video.id.clone(),
+                subtitle_file_id: m.subtitle.id.clone(),
+                confidence: m.confidence,
+                match_factors: m.reasons,
+            })
+            .collect();
+        let confidence = if matches.is_empty() {
+            0.0
+        } else {
+            matches.iter().map(|m| m.confidence).sum::<f32>() / matches.len() as f32
+        };
+
+        Ok(MatchResult {
+            matches,
+            confidence,
+            reasoning: "Matched offline by filename heuristics".to_string(),
+        })
+    }
+
+    async fn verify_match(
+        &self,
+        verification: VerificationRequest,
+    ) -> crate::Result<ConfidenceScore> {
+        let video = media_file_from_path(
+            verification.video_file.clone(),
+            verification.video_file,
+            MediaFileType::Video,
+        );
+        let subtitle = media_file_from_path(
+            verification.subtitle_file.clone(),
+            verification.subtitle_file,
+            MediaFileType::Subtitle,
+        );
+        let (score, factors) = self.matcher.score(&video, &subtitle);
+        Ok(ConfidenceScore { score, factors })
+    }
+}
+
+/// Parse an `ID:<id> | Name:<name> | Path:<relative path>` request entry,
+/// ignoring a trailing ` | Parsed:<summary>` and treating anything else as
+/// a bare path.
+fn parse_entry(entry: &str, file_type: MediaFileType) -> MediaFile {
+    let parsed = entry.strip_prefix("ID:").and_then(|rest| {
+        let (id, rest) = rest.split_once(" | Name:")?;
+        let rest = rest
+            .rsplit_once(" | Parsed:")
+            .map_or(rest, |(rest, _)| rest);
+        let (name, path) = rest.rsplit_once(" | Path:")?;
+        Some((id, name, path))
+    });
+    match parsed {
+        Some((id, name, path)) => {
+            let mut file = media_file_from_path(id.to_string(), path.to_string(), file_type);
+            file.name = name.to_string();
+            file
+        }
+        None => media_file_from_path(entry.to_string(), entry.to_string(), file_type),
+    }
+}
+
+fn media_file_from_path(id: String, path: String, file_type: MediaFileType) -> MediaFile {
+    let path_buf = PathBuf::from(&path);
+    let name = path_buf
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.clone());
+    let extension = Path::new(&name)
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    MediaFile {
+        id,
+        path: path_buf,
+        file_type,
+        size: 0,
+        name,
+        extension,
+        relative_path: path,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_analyze_content_matches_by_episode() {
+        let request = AnalysisRequest {
+            video_files: vec![
+                "ID:v1 | Name:Show.S01E01.mkv | Path:Show.S01E01.mkv".to_string(),
+                "ID:v2 | Name:Show.S01E02.mkv | Path:Show.S01E02.mkv | Parsed:title=Show; episode=S01E02"
+                    .to_string(),
+            ],
+            subtitle_files: vec![
+                "ID:s2 | Name:Show 1x02.srt | Path:Show 1x02.srt".to_string(),
+                "ID:s1 | Name:Show 1x01.srt | Path:Show 1x01.srt".to_string(),
+            ],
+            content_samples: Vec::new(),
+        };
+        let result = LocalProvider::new().analyze_content(request).await.unwrap();
+        let mut pairs: Vec<_> = result
+            .matches
+            .iter()
+            .map(|m| (m.video_file_id.as_str(), m.subtitle_file_id.as_str()))
+            .collect();
+        pairs.sort();
+        assert_eq!(pairs, vec![("v1", "s1"), ("v2", "s2")]);
+        assert!(result.confidence >= 0.8);
+    }
+
+    #[tokio::test]
+    async fn test_verify_match_scores_pair() {
+        let provider = LocalProvider::new();
+        let score = provider
+            .verify_match(VerificationRequest {
+                video_file: "movies/Movie.2020.mkv".to_string(),
+                subtitle_file: "movies/Movie.2020.en.srt".to_string(),
+                match_factors: Vec::new(),
+                content_sample: None,
+                directory_listing: Vec::new(),
+            })
+            .await
+            .unwrap();
+        assert!(score.score > 0.9);
+
+        let score = provider
+            .verify_match(VerificationRequest {
+                video_file: "Show.S01E01.mkv".to_string(),
+                subtitle_file: "Show.S01E05.srt".to_string(),
+                match_factors: Vec::new(),
+                content_sample: None,
+                directory_listing: Vec::new(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(score.score, 0.0);
+    }
+}
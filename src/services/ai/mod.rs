@@ -72,6 +72,8 @@
 //!             "title_similarity".to_string(),
 //!             "content_correlation".to_string(),
 //!         ],
+//!         content_sample: None,
+//!         directory_listing: Vec::new(),
 //!     };
 //!     
 //!     let confidence = ai_client.verify_match(verification).await?;
@@ -259,6 +261,10 @@ pub struct VerificationRequest {
     pub subtitle_file: String,
     /// Factors to consider when matching subtitles to video content
     pub match_factors: Vec<String>,
+    /// Longer excerpt of the subtitle text than the analysis samples
+    pub content_sample: Option<String>,
+    /// Names of the files next to the video and the subtitle
+    pub directory_listing: Vec<String>,
 }
 
 /// AI usage statistics.
@@ -294,6 +300,8 @@ pub mod openrouter;
 /// Azure OpenAI service provider client implementation
 pub mod azure_openai;
 
+pub mod local;
+
 /// AI prompt templates and management
 pub mod prompts;
 
@@ -301,5 +309,6 @@ pub mod prompts;
 pub mod retry;
 
 pub use cache::AICache;
+pub use local::LocalProvider;
 pub use openai::OpenAIClient;
 pub use retry::{RetryConfig, retry_with_backoff};
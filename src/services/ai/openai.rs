@@ -157,6 +157,8 @@ mod tests {
             retry_delay_ms: 150,
             request_timeout_seconds: 60,
             api_version: None,
+            verification_margin: 0.1,
+            max_prompt_tokens: 32_000,
         };
         let client = OpenAIClient::from_config(&config).unwrap();
         assert_eq!(client.api_key, "test-key");
@@ -179,6 +181,8 @@ mod tests {
             retry_delay_ms: 150,
             request_timeout_seconds: 30,
             api_version: None,
+            verification_margin: 0.1,
+            max_prompt_tokens: 32_000,
         };
         let err = OpenAIClient::from_config(&config).unwrap_err();
         // Non-http/https protocols should return protocol error message
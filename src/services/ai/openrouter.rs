@@ -446,6 +446,8 @@ mod tests {
             retry_delay_ms: 150,
             request_timeout_seconds: 120,
             api_version: None,
+            verification_margin: 0.1,
+            max_prompt_tokens: 32_000,
         };
 
         let client = OpenRouterClient::from_config(&config).unwrap();
@@ -471,6 +473,8 @@ mod tests {
             retry_delay_ms: 100,
             request_timeout_seconds: 30,
             api_version: None,
+            verification_margin: 0.1,
+            max_prompt_tokens: 32_000,
         };
 
         let result = OpenRouterClient::from_config(&config);
@@ -498,6 +502,8 @@ mod tests {
             retry_delay_ms: 100,
             request_timeout_seconds: 30,
             api_version: None,
+            verification_margin: 0.1,
+            max_prompt_tokens: 32_000,
         };
 
         let result = OpenRouterClient::from_config(&config);
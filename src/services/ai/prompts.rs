@@ -43,7 +43,8 @@ pub trait ResponseParser {
 pub fn build_analysis_prompt_base(request: &AnalysisRequest) -> String {
     let mut prompt = String::new();
     prompt.push_str(
-        "Please analyze the matching relationship between the following video and subtitle files. Each file has a unique ID that you must use in your response.\n\n",
+        "Please analyze the matching relationship between the following video and subtitle files. Each file has a unique ID that you must use in your response. \
+Where present, the Parsed field lists release information already extracted from the file name (title, year, season and episode, resolution, source, codec, release group, languages).\n\n",
     );
     prompt.push_str("Video files:\n");
     for video in &request.video_files {
@@ -100,6 +101,15 @@ pub fn build_verification_prompt_base(request: &VerificationRequest) -> String {
     for factor in &request.match_factors {
         prompt.push_str(&format!("- {}\n", factor));
     }
+    if let Some(sample) = &request.content_sample {
+        prompt.push_str(&format!("\nSubtitle content sample:\n{}\n", sample));
+    }
+    if !request.directory_listing.is_empty() {
+        prompt.push_str("\nNeighboring files:\n");
+        for name in &request.directory_listing {
+            prompt.push_str(&format!("- {}\n", name));
+        }
+    }
     prompt.push_str(
         "\nPlease respond in JSON format as follows:\n{\"score\": 0.9,\"factors\": [\"...\"]}",
     );
@@ -211,4 +221,18 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_verification_prompt_includes_context() {
+        let request = super::VerificationRequest {
+            video_file: "Show/S01E02.mkv".into(),
+            subtitle_file: "subs/show_02.srt".into(),
+            match_factors: vec!["episode_number".into()],
+            content_sample: Some("Previously on Show...".into()),
+            directory_listing: vec!["S01E01.mkv".into(), "S01E03.mkv".into()],
+        };
+        let prompt = super::build_verification_prompt_base(&request);
+        assert!(prompt.contains("Subtitle content sample:\nPreviously on Show..."));
+        assert!(prompt.contains("Neighboring files:\n- S01E01.mkv\n- S01E03.mkv"));
+    }
 }
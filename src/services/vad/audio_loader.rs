@@ -21,6 +21,23 @@ pub struct DirectAudioLoader {
     codecs: &'static CodecRegistry,
 }
 
+/// Write `seconds` of 16 kHz mono silence to a WAV file at `path`, a
+/// stand-in for videos in tests.
+#[cfg(test)]
+pub(crate) fn write_silent_wav(path: &Path, seconds: u32) {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: 16000,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut writer = hound::WavWriter::create(path, spec).unwrap();
+    for _ in 0..seconds * spec.sample_rate {
+        writer.write_sample(0i16).unwrap();
+    }
+    writer.finalize().unwrap();
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -39,6 +56,17 @@ mod tests {
             "total_samples should be greater than 0"
         );
     }
+
+    #[test]
+    fn test_probe_duration_of_wav() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("two_seconds.wav");
+        write_silent_wav(&path, 2);
+
+        let loader = DirectAudioLoader::new().unwrap();
+        let duration = loader.probe_duration(&path).unwrap();
+        assert!((duration.as_secs_f64() - 2.0).abs() < 0.01);
+    }
 }
 
 impl DirectAudioLoader {
@@ -222,4 +250,68 @@ impl DirectAudioLoader {
             },
         ))
     }
+
+    /// Returns the media duration of a video or audio file.
+    ///
+    /// The duration is read from container metadata when available, which
+    /// avoids decoding the whole stream. Files without frame count metadata
+    /// fall back to a full decode via [`Self::load_audio_samples`].
+    pub fn probe_duration<P: AsRef<Path>>(&self, path: P) -> Result<std::time::Duration> {
+        let path_ref = path.as_ref();
+        let from_metadata = self
+            .probe_container_duration(path_ref)?
+            .map(|duration| duration.as_secs_f64());
+        let seconds = match from_metadata {
+            Some(seconds) => seconds,
+            None => {
+                debug!(
+                    "[DirectAudioLoader] No duration metadata for {:?}, decoding stream",
+                    path_ref
+                );
+                self.load_audio_samples(path_ref)?.1.duration_seconds
+            }
+        };
+        debug!(
+            "[DirectAudioLoader] Probed duration for {:?}: {:.3}s",
+            path_ref, seconds
+        );
+        Ok(std::time::Duration::from_secs_f64(seconds.max(0.0)))
+    }
+
+    /// Returns the media duration recorded in the container metadata,
+    /// without decoding any audio.
+    ///
+    /// `Ok(None)` means the container was recognized but carries no frame
+    /// count for its audio tracks.
+    pub fn probe_container_duration<P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> Result<Option<std::time::Duration>> {
+        let path_ref = path.as_ref();
+        let file = File::open(path_ref).map_err(|e| {
+            SubXError::audio_processing(format!("Failed to open media file: {}", e))
+        })?;
+        let mss = MediaSourceStream::new(Box::new(file), Default::default());
+        let mut hint = Hint::new();
+        if let Some(ext) = path_ref.extension().and_then(|e| e.to_str()) {
+            hint.with_extension(ext);
+        }
+        let probed = self
+            .probe
+            .format(&hint, mss, &FormatOptions::default(), &Default::default())
+            .map_err(|e| SubXError::audio_processing(format!("Failed to probe format: {}", e)))?;
+        Ok(probed
+            .format
+            .tracks()
+            .iter()
+            .filter(|t| t.codec_params.sample_rate.is_some())
+            .find_map(|t| {
+                let n_frames = t.codec_params.n_frames?;
+                let tb = t.codec_params.time_base?;
+                let time = tb.calc_time(n_frames);
+                Some(std::time::Duration::from_secs_f64(
+                    (time.seconds as f64 + time.frac).max(0.0),
+                ))
+            }))
+    }
 }
@@ -44,6 +44,8 @@ async fn test_build_verification_prompt_base_function() {
             "content_correlation".to_string(),
             "timing_analysis".to_string(),
         ],
+        content_sample: None,
+        directory_listing: Vec::new(),
     };
 
     let prompt = build_verification_prompt_base(&request);
@@ -206,6 +208,8 @@ async fn test_verification_prompt_various_factors() {
         video_file: "movie.mkv".to_string(),
         subtitle_file: "movie.srt".to_string(),
         match_factors: vec!["exact_filename".to_string()],
+        content_sample: None,
+        directory_listing: Vec::new(),
     };
 
     let prompt_single = build_verification_prompt_base(&request_single);
@@ -218,6 +222,8 @@ async fn test_verification_prompt_various_factors() {
         video_file: "test.mkv".to_string(),
         subtitle_file: "test.srt".to_string(),
         match_factors: vec![],
+        content_sample: None,
+        directory_listing: Vec::new(),
     };
 
     let prompt_empty = build_verification_prompt_base(&request_empty);
@@ -246,6 +252,8 @@ async fn test_trait_default_implementations() {
         video_file: "test.mkv".to_string(),
         subtitle_file: "test.srt".to_string(),
         match_factors: vec!["test_factor".to_string()],
+        content_sample: None,
+        directory_listing: Vec::new(),
     };
 
     let trait_verification = client.build_verification_prompt(&verification_request);
@@ -49,6 +49,8 @@ async fn test_openai_client_prompt_builder_trait() {
             "filename_similarity".to_string(),
             "content_correlation".to_string(),
         ],
+        content_sample: None,
+        directory_listing: Vec::new(),
     };
 
     let verification_prompt = client.build_verification_prompt(&verification_request);
@@ -176,6 +178,8 @@ async fn test_openrouter_client_prompt_builder_trait() {
         video_file: "episode1.mkv".to_string(),
         subtitle_file: "episode1.srt".to_string(),
         match_factors: vec!["exact_match".to_string()],
+        content_sample: None,
+        directory_listing: Vec::new(),
     };
 
     let verification_prompt = client.build_verification_prompt(&verification_request);
@@ -300,6 +304,8 @@ async fn test_azure_openai_client_prompt_builder_trait() {
         video_file: "azure_test.mkv".to_string(),
         subtitle_file: "azure_test.srt".to_string(),
         match_factors: vec!["azure_similarity".to_string()],
+        content_sample: None,
+        directory_listing: Vec::new(),
     };
 
     let verification_prompt = client.build_verification_prompt(&verification_request);
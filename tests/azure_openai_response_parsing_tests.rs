@@ -35,6 +35,8 @@ fn create_sample_verification_request() -> VerificationRequest {
             "filename_similarity".to_string(),
             "content_correlation".to_string(),
         ],
+        content_sample: None,
+        directory_listing: Vec::new(),
     }
 }
 
@@ -2,7 +2,7 @@ use std::fs;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tempfile::TempDir;
-use subx_cli::cli::SyncArgs;
+use subx_cli::cli::{SyncArgs, UnderflowPolicyArg, UnmappableArg};
 use subx_cli::commands::sync_command;
 use subx_cli::config::test_service::TestConfigService;
 
@@ -28,6 +28,14 @@ async fn test_manual_sync_without_video_file() {
         input_paths: vec![],
         recursive: false,
         offset: Some(2.5),
+        from: None,
+        to: None,
+        stretch: None,
+        on_underflow: UnderflowPolicyArg::Clamp,
+        encoding: None,
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
         method: None,
         window: 30,
         vad_sensitivity: None,
@@ -59,6 +67,14 @@ async fn test_auto_sync_requires_video_file() {
         input_paths: vec![],
         recursive: false,
         offset: None,
+        from: None,
+        to: None,
+        stretch: None,
+        on_underflow: UnderflowPolicyArg::Clamp,
+        encoding: None,
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
         method: None,
         window: 30,
         vad_sensitivity: None,
@@ -89,6 +105,14 @@ async fn test_backward_compatibility() {
         input_paths: vec![],
         recursive: false,
         offset: Some(1.5),
+        from: None,
+        to: None,
+        stretch: None,
+        on_underflow: UnderflowPolicyArg::Clamp,
+        encoding: None,
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
         method: None,
         window: 30,
         vad_sensitivity: None,
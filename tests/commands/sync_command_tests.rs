@@ -1,6 +1,6 @@
 use tempfile::TempDir;
 use std::fs;
-use subx_cli::cli::SyncArgs;
+use subx_cli::cli::{SyncArgs, UnderflowPolicyArg, UnmappableArg};
 use subx_cli::commands::sync_command;
 use crate::common::command_helpers::create_utf8_subtitle_file;
 
@@ -16,6 +16,14 @@ async fn test_audio_sync_workflow_manual_offset() {
         input_paths: vec![],
         recursive: false,
         offset: Some(1.5),
+        from: None,
+        to: None,
+        stretch: None,
+        on_underflow: UnderflowPolicyArg::Clamp,
+        encoding: None,
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
         method: None,
         window: 30,
         vad_sensitivity: None,
@@ -18,14 +18,9 @@ mod debug_path_tests {
 
         // Test the get_input_handler and get_directories logic
         let args = MatchArgs {
-            input_paths: vec![],
-            recursive: false,
             path: Some(root.to_path_buf()),
-            dry_run: false,
-            confidence: 80,
-            backup: false,
             copy: true,
-            move_files: false,
+            ..Default::default()
         };
 
         let input_handler = args.get_input_handler().unwrap();
@@ -9,12 +9,158 @@ use subx_cli::cli::DetectEncodingArgs;
 use subx_cli::commands::detect_encoding_command::{
     detect_encoding_command, detect_encoding_command_with_config,
 };
-use subx_cli::config::TestConfigService;
+use subx_cli::config::{TestConfigBuilder, TestConfigService};
 use tempfile::TempDir;
 
 mod detect_encoding_command_tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_detect_encoding_command_converts_with_backups() {
+        let temp_dir = TempDir::new().unwrap();
+        let gbk_file = temp_dir.path().join("gbk.srt");
+        let utf8_file = temp_dir.path().join("utf8.srt");
+        let text =
+            "1\n00:00:01,000 --> 00:00:03,000\n你好，我们今天去看电影吧。这是一个很好的问题。\n\n";
+        let (gbk_bytes, _, _) = encoding_rs::GBK.encode(text);
+        fs::write(&gbk_file, &gbk_bytes).unwrap();
+        fs::write(&utf8_file, text).unwrap();
+
+        let args = DetectEncodingArgs {
+            verbose: false,
+            input_paths: vec![temp_dir.path().to_path_buf()],
+            recursive: false,
+            fix_mojibake: false,
+            convert: true,
+            to: None,
+            force: false,
+            file_paths: vec![],
+        };
+        detect_encoding_command(&args).unwrap();
+
+        assert_eq!(fs::read_to_string(&gbk_file).unwrap(), text);
+        assert_eq!(
+            fs::read(temp_dir.path().join("gbk.srt.bak")).unwrap(),
+            gbk_bytes.as_ref()
+        );
+        assert_eq!(fs::read_to_string(&utf8_file).unwrap(), text);
+        assert!(!temp_dir.path().join("utf8.srt.bak").exists());
+    }
+
+    #[tokio::test]
+    async fn test_detect_encoding_command_convert_keeps_existing_backup() {
+        let temp_dir = TempDir::new().unwrap();
+        let gbk_file = temp_dir.path().join("gbk.srt");
+        let old_backup = temp_dir.path().join("gbk.srt.bak");
+        let text =
+            "1\n00:00:01,000 --> 00:00:03,000\n你好，我们今天去看电影吧。这是一个很好的问题。\n\n";
+        let (gbk_bytes, _, _) = encoding_rs::GBK.encode(text);
+        fs::write(&gbk_file, &gbk_bytes).unwrap();
+        fs::write(&old_backup, "earlier backup").unwrap();
+
+        let args = DetectEncodingArgs {
+            verbose: false,
+            input_paths: vec![],
+            recursive: false,
+            fix_mojibake: false,
+            convert: true,
+            to: None,
+            force: false,
+            file_paths: vec![gbk_file.to_string_lossy().to_string()],
+        };
+        detect_encoding_command_with_config(args, &TestConfigService::with_defaults()).unwrap();
+
+        assert_eq!(fs::read_to_string(&gbk_file).unwrap(), text);
+        assert_eq!(fs::read_to_string(&old_backup).unwrap(), "earlier backup");
+        assert_eq!(
+            fs::read(temp_dir.path().join("gbk.srt.bak.1")).unwrap(),
+            gbk_bytes.as_ref()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_detect_encoding_command_convert_uses_configured_confidence() {
+        let temp_dir = TempDir::new().unwrap();
+        let latin_file = temp_dir.path().join("latin.srt");
+        let text = "1\n00:00:01,000 --> 00:00:03,000\nCafé crème\n\n";
+        let (latin_bytes, _, _) = encoding_rs::WINDOWS_1252.encode(text);
+        fs::write(&latin_file, &latin_bytes).unwrap();
+
+        let args = DetectEncodingArgs {
+            verbose: false,
+            input_paths: vec![],
+            recursive: false,
+            fix_mojibake: false,
+            convert: true,
+            to: None,
+            force: false,
+            file_paths: vec![latin_file.to_string_lossy().to_string()],
+        };
+        let config_service = TestConfigBuilder::new()
+            .with_encoding_detection_confidence(0.99)
+            .build_service();
+        detect_encoding_command_with_config(args, &config_service).unwrap();
+
+        assert_eq!(fs::read(&latin_file).unwrap(), latin_bytes.as_ref());
+        assert!(!temp_dir.path().join("latin.srt.bak").exists());
+    }
+
+    #[tokio::test]
+    async fn test_detect_encoding_command_convert_rejects_unknown_target() {
+        let temp_dir = TempDir::new().unwrap();
+        let file = temp_dir.path().join("a.srt");
+        fs::write(&file, "1\n00:00:01,000 --> 00:00:03,000\nHello\n\n").unwrap();
+
+        let args = DetectEncodingArgs {
+            verbose: false,
+            input_paths: vec![],
+            recursive: false,
+            fix_mojibake: false,
+            convert: true,
+            to: Some("klingon".to_string()),
+            force: false,
+            file_paths: vec![file.to_string_lossy().to_string()],
+        };
+        assert!(detect_encoding_command(&args).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_detect_encoding_command_fixes_mojibake() {
+        let temp_dir = TempDir::new().unwrap();
+        let broken = temp_dir.path().join("broken.srt");
+        let intact = temp_dir.path().join("intact.srt");
+        fs::write(
+            &broken,
+            "1\n00:00:01,000 --> 00:00:03,000\nÃ‡a a Ã©tÃ© un trÃ¨s bel Ã©tÃ©.\n\n",
+        )
+        .unwrap();
+        let intact_content = "1\n00:00:01,000 --> 00:00:03,000\nÇa a été un très bel été.\n\n";
+        fs::write(&intact, intact_content).unwrap();
+
+        let mut args = DetectEncodingArgs {
+            verbose: false,
+            input_paths: vec![],
+            recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
+            file_paths: vec![
+                broken.to_string_lossy().to_string(),
+                intact.to_string_lossy().to_string(),
+            ],
+        };
+
+        // Detection alone leaves the files untouched
+        detect_encoding_command(&args).unwrap();
+        assert!(fs::read_to_string(&broken).unwrap().contains("Ã©tÃ©"));
+
+        args.fix_mojibake = true;
+        detect_encoding_command(&args).unwrap();
+        assert_eq!(fs::read_to_string(&broken).unwrap(), intact_content);
+        assert_eq!(fs::read_to_string(&intact).unwrap(), intact_content);
+    }
+
     #[tokio::test]
     async fn test_detect_encoding_command_with_utf8_file() {
         let temp_dir = TempDir::new().unwrap();
@@ -29,6 +175,10 @@ mod detect_encoding_command_tests {
             verbose: false,
             input_paths: vec![utf8_file.clone()],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec![],
         };
 
@@ -53,6 +203,10 @@ mod detect_encoding_command_tests {
             verbose: false,
             input_paths: vec![ascii_file.clone()],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec![],
         };
 
@@ -77,6 +231,10 @@ mod detect_encoding_command_tests {
             verbose: true,
             input_paths: vec![test_file.clone()],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec![],
         };
 
@@ -117,6 +275,10 @@ mod detect_encoding_command_tests {
             verbose: false,
             input_paths: vec![file1.clone(), file2.clone(), file3.clone()],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec![],
         };
 
@@ -144,6 +306,10 @@ mod detect_encoding_command_tests {
             verbose: false,
             input_paths: vec![],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec![
                 "/nonexistent/file.srt".to_string(),
                 real_file.to_string_lossy().to_string(),
@@ -172,6 +338,10 @@ mod detect_encoding_command_tests {
             verbose: false,
             input_paths: vec![empty_file.clone()],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec![],
         };
 
@@ -196,6 +366,10 @@ mod detect_encoding_command_tests {
             verbose: false,
             input_paths: vec![binary_file.clone()],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec![],
         };
 
@@ -221,6 +395,10 @@ mod detect_encoding_command_tests {
             verbose: false,
             input_paths: vec![test_file.clone()],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec![],
         };
 
@@ -247,6 +425,10 @@ mod detect_encoding_command_tests {
             verbose: false,
             input_paths: vec![],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec![test_file.to_string_lossy().to_string()],
         };
 
@@ -284,6 +466,10 @@ mod detect_encoding_command_tests {
             verbose: false,
             input_paths: vec![temp_path.to_path_buf()],
             recursive: true,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec![],
         };
 
@@ -300,6 +486,10 @@ mod detect_encoding_command_tests {
             verbose: false,
             input_paths: vec![],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec![],
         };
 
@@ -335,6 +525,10 @@ mod detect_encoding_command_tests {
             verbose: false,
             input_paths: files,
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec![],
         };
 
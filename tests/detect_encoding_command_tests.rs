@@ -17,6 +17,10 @@ mod detect_encoding_args_tests {
             verbose: false,
             input_paths: vec![],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec!["test.srt".to_string()],
         };
 
@@ -33,6 +37,10 @@ mod detect_encoding_args_tests {
             verbose: true,
             input_paths: vec![PathBuf::from("test.srt")],
             recursive: true,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec![],
         };
 
@@ -49,6 +57,10 @@ mod detect_encoding_args_tests {
             verbose: false,
             input_paths: vec![],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec![
                 "file1.srt".to_string(),
                 "file2.sub".to_string(),
@@ -70,6 +82,10 @@ mod detect_encoding_args_tests {
             verbose: false,
             input_paths: vec![PathBuf::from("*.srt"), PathBuf::from("subtitles/**/*.vtt")],
             recursive: true,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec![],
         };
 
@@ -86,6 +102,10 @@ mod detect_encoding_args_tests {
             verbose: true,
             input_paths: vec![],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec!["test.srt".to_string(), "test.vtt".to_string()],
         };
 
@@ -101,6 +121,10 @@ mod detect_encoding_args_tests {
             verbose: false,
             input_paths: vec![],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec!["file1.srt".to_string(), "file2.vtt".to_string()],
         };
 
@@ -118,6 +142,10 @@ mod detect_encoding_args_tests {
             verbose: true,
             input_paths: vec![],
             recursive: true,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec!["test.srt".to_string()],
         };
 
@@ -135,6 +163,10 @@ mod detect_encoding_args_tests {
             verbose: true,
             input_paths: vec![],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec!["test1.srt".to_string(), "test2.vtt".to_string()],
         };
 
@@ -165,6 +197,10 @@ mod detect_encoding_args_tests {
                 verbose: false,
                 input_paths: vec![],
                 recursive: false,
+                fix_mojibake: false,
+                convert: false,
+                to: None,
+                force: false,
                 file_paths: vec![filename.clone()],
             };
 
@@ -179,6 +215,10 @@ mod detect_encoding_args_tests {
             verbose: false,
             input_paths: vec![],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec![
                 "path with spaces.srt".to_string(),
                 "another path/file.vtt".to_string(),
@@ -198,6 +238,10 @@ mod detect_encoding_args_tests {
             verbose: false,
             input_paths: vec![],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec![
                 "файл.srt".to_string(),
                 "字幕.vtt".to_string(),
@@ -219,6 +263,10 @@ mod detect_encoding_args_tests {
             verbose: false,
             input_paths: vec![],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec!["file1.srt".to_string()],
         };
 
@@ -243,6 +291,10 @@ mod detect_encoding_args_tests {
             verbose: false,
             input_paths: vec![PathBuf::from("test.srt")],
             recursive: true,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec![],
         };
 
@@ -265,6 +317,10 @@ mod detect_encoding_args_tests {
             verbose: false,
             input_paths: vec![],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec![],
         };
 
@@ -284,6 +340,10 @@ mod detect_encoding_command_tests {
             verbose: false,
             input_paths: vec![],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec!["nonexistent.srt".to_string()],
         };
 
@@ -300,6 +360,10 @@ mod detect_encoding_command_tests {
             verbose: false,
             input_paths: vec![],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec![],
         };
 
@@ -314,6 +378,10 @@ mod detect_encoding_command_tests {
             verbose: true,
             input_paths: vec![],
             recursive: false,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec!["nonexistent.srt".to_string()],
         };
 
@@ -328,6 +396,10 @@ mod detect_encoding_command_tests {
             verbose: false,
             input_paths: vec![PathBuf::from("nonexistent_dir")],
             recursive: true,
+            fix_mojibake: false,
+            convert: false,
+            to: None,
+            force: false,
             file_paths: vec![],
         };
 
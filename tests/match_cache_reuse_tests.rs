@@ -93,14 +93,10 @@ async fn test_cache_reuse_preserves_copy_mode() {
     // First execution dry-run to create cache
     debug!("Executing first match command (dry-run) to create cache");
     let args_preview = MatchArgs {
-        input_paths: vec![],
-        recursive: false,
         path: Some(test_root.to_path_buf()),
         dry_run: true,
-        confidence: 80,
-        backup: false,
         copy: true,
-        move_files: false,
+        ..Default::default()
     };
     match_command::execute(args_preview, &config_service)
         .await
@@ -110,14 +106,10 @@ async fn test_cache_reuse_preserves_copy_mode() {
     // Second execution of the same dry-run operation, should use cache (same directory)
     debug!("Executing second match command (should use cache)");
     let args_second = MatchArgs {
-        input_paths: vec![],
-        recursive: false,
         path: Some(test_root.to_path_buf()), // Use the same directory
         dry_run: true,                       // Keep the same mode
-        confidence: 80,
-        backup: false,
         copy: true,
-        move_files: false,
+        ..Default::default()
     };
     match_command::execute(args_second, &config_service)
         .await
@@ -210,14 +202,10 @@ async fn test_cache_reuse_preserves_move_mode() {
     // First execution dry-run to create cache
     debug!("Executing first match command (dry-run) to create cache");
     let args_preview = MatchArgs {
-        input_paths: vec![],
-        recursive: false,
         path: Some(test_root.to_path_buf()),
         dry_run: true,
-        confidence: 80,
-        backup: false,
-        copy: false,
         move_files: true,
+        ..Default::default()
     };
     match_command::execute(args_preview, &config_service)
         .await
@@ -227,14 +215,10 @@ async fn test_cache_reuse_preserves_move_mode() {
     // Second execution of the same dry-run operation, should use cache
     debug!("Executing second match command (should use cache)");
     let args_second = MatchArgs {
-        input_paths: vec![],
-        recursive: false,
         path: Some(test_root.to_path_buf()),
         dry_run: true, // Keep the same mode
-        confidence: 80,
-        backup: false,
-        copy: false,
         move_files: true,
+        ..Default::default()
     };
     match_command::execute(args_second, &config_service)
         .await
@@ -100,14 +100,11 @@ async fn test_match_cache_copy_mode_target_directory_correctness() {
     // First execution: dry-run to create cache
     debug!("Execute first match command (dry-run) to create cache");
     let args_dry_run = MatchArgs {
-        input_paths: vec![],
         recursive: true,
         path: Some(test_root.to_path_buf()),
         dry_run: true,
-        confidence: 80,
-        backup: false,
         copy: true,
-        move_files: false,
+        ..Default::default()
     };
     match_command::execute(args_dry_run, &config_service)
         .await
@@ -121,14 +118,10 @@ async fn test_match_cache_copy_mode_target_directory_correctness() {
     // Second execution: actual copy operation (using cache)
     debug!("Execute second match command (copy mode, should use cache)");
     let args_copy = MatchArgs {
-        input_paths: vec![],
         recursive: true,
         path: Some(test_root.to_path_buf()),
-        dry_run: false, // Actual execution
-        confidence: 80,
-        backup: false,
         copy: true,
-        move_files: false,
+        ..Default::default()
     };
     match_command::execute(args_copy, &config_service)
         .await
@@ -237,14 +230,11 @@ async fn test_match_cache_dry_run_vs_actual_execution_consistency() {
     // First execution: dry-run to create cache
     debug!("Execute dry-run to create cache");
     let args_dry_run = MatchArgs {
-        input_paths: vec![],
         recursive: true,
         path: Some(test_root.to_path_buf()),
         dry_run: true,
-        confidence: 80,
-        backup: false,
         copy: true,
-        move_files: false,
+        ..Default::default()
     };
     match_command::execute(args_dry_run, &config_service)
         .await
@@ -260,14 +250,10 @@ async fn test_match_cache_dry_run_vs_actual_execution_consistency() {
     // Second execution: actual copy operation (using cache)
     debug!("Execute actual copy operation");
     let args_actual = MatchArgs {
-        input_paths: vec![],
         recursive: true,
         path: Some(test_root.to_path_buf()),
-        dry_run: false,
-        confidence: 80,
-        backup: false,
         copy: true,
-        move_files: false,
+        ..Default::default()
     };
     match_command::execute(args_actual, &config_service)
         .await
@@ -355,14 +341,11 @@ async fn test_match_cache_move_mode_target_directory_correctness() {
     // First execution: dry-run to create cache (move mode)
     debug!("Execute move mode dry-run to create cache");
     let args_dry_run = MatchArgs {
-        input_paths: vec![],
         recursive: true,
         path: Some(test_root.to_path_buf()),
         dry_run: true,
-        confidence: 80,
-        backup: false,
-        copy: false,
         move_files: true,
+        ..Default::default()
     };
     match_command::execute(args_dry_run, &config_service)
         .await
@@ -371,14 +354,10 @@ async fn test_match_cache_move_mode_target_directory_correctness() {
     // Second execution: actual move operation (using cache)
     debug!("Execute actual move operation");
     let args_move = MatchArgs {
-        input_paths: vec![],
         recursive: true,
         path: Some(test_root.to_path_buf()),
-        dry_run: false,
-        confidence: 80,
-        backup: false,
-        copy: false,
         move_files: true,
+        ..Default::default()
     };
     match_command::execute(args_move, &config_service)
         .await
@@ -0,0 +1,225 @@
+//! Integration tests for splitting large match batches into several AI
+//! requests.
+
+use async_trait::async_trait;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use subx_cli::config::TestConfigBuilder;
+use subx_cli::core::matcher::engine::{ConflictResolution, FileRelocationMode};
+use subx_cli::core::matcher::{MatchConfig, MatchEngine, NamingTemplate};
+use subx_cli::services::ai::{
+    AIProvider, AnalysisRequest, ConfidenceScore, FileMatch, MatchResult, VerificationRequest,
+};
+use tempfile::TempDir;
+
+// Using async mutex to avoid environment variable race conditions while avoiding clippy::await_holding_lock warning
+static TEST_MUTEX: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+/// AI provider that pairs each subtitle with the video of the same episode
+/// when the request contains it, and otherwise with the first video at a
+/// lower confidence. Requests listing the `failing` file name are rejected.
+struct EpisodeAI {
+    requests: Arc<Mutex<Vec<AnalysisRequest>>>,
+    failing: Option<&'static str>,
+}
+
+#[async_trait]
+impl AIProvider for EpisodeAI {
+    async fn analyze_content(&self, request: AnalysisRequest) -> subx_cli::Result<MatchResult> {
+        if let Some(failing) = self.failing
+            && request.video_files.iter().any(|v| v.contains(failing))
+        {
+            return Err(subx_cli::error::SubXError::AiService(
+                "rate limited".to_string(),
+            ));
+        }
+        let id = |entry: &str| entry[3..entry.find(" |").unwrap()].to_string();
+        let episode = |entry: &str| {
+            let start = entry.find("Name:").unwrap() + 5;
+            entry[start..start + 11].to_string()
+        };
+        let matches = request
+            .subtitle_files
+            .iter()
+            .map(|subtitle| {
+                let same = request
+                    .video_files
+                    .iter()
+                    .find(|v| episode(v) == episode(subtitle));
+                let (video, confidence) = match same {
+                    Some(video) => (video, 0.95),
+                    None => (&request.video_files[0], 0.85),
+                };
+                FileMatch {
+                    video_file_id: id(video),
+                    subtitle_file_id: id(subtitle),
+                    confidence,
+                    match_factors: vec!["episode_number".to_string()],
+                }
+            })
+            .collect();
+        self.requests.lock().unwrap().push(request);
+        Ok(MatchResult {
+            matches,
+            confidence: 0.9,
+            reasoning: String::new(),
+        })
+    }
+
+    async fn verify_match(&self, _req: VerificationRequest) -> subx_cli::Result<ConfidenceScore> {
+        panic!("verification is disabled in these tests")
+    }
+}
+
+fn match_config() -> MatchConfig {
+    MatchConfig {
+        confidence_threshold: 0.8,
+        max_sample_length: 1024,
+        enable_content_analysis: true,
+        backup_enabled: false,
+        relocation_mode: FileRelocationMode::None,
+        conflict_resolution: ConflictResolution::Skip,
+        ai_model: "test-model".to_string(),
+        output_encoding: None,
+        naming: NamingTemplate::default(),
+        verification_margin: 0.0,
+    }
+}
+
+fn write_library(root: &std::path::Path, episodes: u32) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for episode in 1..=episodes {
+        for ext in ["mkv", "srt"] {
+            let path = root.join(format!("Show.S01E{episode:02}.{ext}"));
+            fs::write(
+                &path,
+                format!("1\n00:00:01,000 --> 00:00:02,000\nLine {episode}\n"),
+            )
+            .unwrap();
+            files.push(path);
+        }
+    }
+    files
+}
+
+#[tokio::test]
+async fn test_large_batch_is_split_and_each_subtitle_matched_once() {
+    let _guard = TEST_MUTEX.lock().await;
+    let temp_dir = TempDir::new().unwrap();
+    unsafe {
+        std::env::set_var("XDG_CONFIG_HOME", temp_dir.path());
+    }
+    let files = write_library(temp_dir.path(), 30);
+
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let ai = EpisodeAI {
+        requests: requests.clone(),
+        failing: None,
+    };
+    let engine = MatchEngine::new(Box::new(ai), match_config())
+        .with_chunking(1000)
+        .with_scheduler_config(
+            &TestConfigBuilder::new()
+                .with_max_concurrent_jobs(4)
+                .build_config(),
+        );
+    let operations = engine.match_file_list(&files).await.unwrap();
+
+    let requests = requests.lock().unwrap();
+    assert!(requests.len() > 1, "expected several requests");
+    assert!(requests.iter().all(|r| r.video_files.len() < 30));
+
+    // The wrong low-confidence guesses from other chunks are dropped
+    assert_eq!(operations.len(), 30);
+    for op in &operations {
+        assert_eq!(op.confidence, 0.95);
+        assert_eq!(
+            op.video_file.name.trim_end_matches(".mkv"),
+            op.subtitle_file.name.trim_end_matches(".srt")
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_small_batch_is_sent_in_one_request() {
+    let _guard = TEST_MUTEX.lock().await;
+    let temp_dir = TempDir::new().unwrap();
+    unsafe {
+        std::env::set_var("XDG_CONFIG_HOME", temp_dir.path());
+    }
+    let files = write_library(temp_dir.path(), 3);
+
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let ai = EpisodeAI {
+        requests: requests.clone(),
+        failing: None,
+    };
+    let engine = MatchEngine::new(Box::new(ai), match_config());
+    let operations = engine.match_file_list(&files).await.unwrap();
+
+    assert_eq!(requests.lock().unwrap().len(), 1);
+    assert_eq!(operations.len(), 3);
+}
+
+#[tokio::test]
+async fn test_failed_chunk_keeps_matches_of_other_chunks() {
+    let _guard = TEST_MUTEX.lock().await;
+    let temp_dir = TempDir::new().unwrap();
+    unsafe {
+        std::env::set_var("XDG_CONFIG_HOME", temp_dir.path());
+    }
+    let files = write_library(temp_dir.path(), 30);
+
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let ai = EpisodeAI {
+        requests: requests.clone(),
+        failing: Some("Show.S01E01.mkv"),
+    };
+    let engine = MatchEngine::new(Box::new(ai), match_config())
+        .with_chunking(1000)
+        .with_scheduler_config(
+            &TestConfigBuilder::new()
+                .with_max_concurrent_jobs(4)
+                .build_config(),
+        );
+    let operations = engine.match_file_list(&files).await.unwrap();
+
+    // The requests without Show.S01E01.mkv still pair their episodes
+    let correct = operations
+        .iter()
+        .filter(|op| {
+            op.video_file.name.trim_end_matches(".mkv")
+                == op.subtitle_file.name.trim_end_matches(".srt")
+        })
+        .count();
+    assert!(correct >= 19, "only {correct} episodes matched");
+    assert!(
+        operations
+            .iter()
+            .all(|op| op.video_file.name != "Show.S01E01.mkv")
+    );
+}
+
+#[tokio::test]
+async fn test_all_chunks_failing_is_an_error() {
+    let _guard = TEST_MUTEX.lock().await;
+    let temp_dir = TempDir::new().unwrap();
+    unsafe {
+        std::env::set_var("XDG_CONFIG_HOME", temp_dir.path());
+    }
+    let files = write_library(temp_dir.path(), 30);
+
+    let ai = EpisodeAI {
+        requests: Arc::new(Mutex::new(Vec::new())),
+        failing: Some("Show.S01E"),
+    };
+    let engine = MatchEngine::new(Box::new(ai), match_config())
+        .with_chunking(1000)
+        .with_scheduler_config(
+            &TestConfigBuilder::new()
+                .with_max_concurrent_jobs(4)
+                .build_config(),
+        );
+    assert!(engine.match_file_list(&files).await.is_err());
+}
@@ -83,12 +83,8 @@ async fn test_match_with_combined_path_and_input_paths_simple() {
     let args = MatchArgs {
         path: Some(main_dir.clone()),         // Main directory as path
         input_paths: vec![input_dir.clone()], // Empty input directory
-        recursive: false,
         dry_run: true,
-        confidence: 80,
-        backup: false,
-        copy: false,
-        move_files: false,
+        ..Default::default()
     };
 
     let result = match_command::execute(args, &config_service).await;
@@ -185,14 +181,9 @@ async fn test_match_with_only_input_paths() {
     // Execute match command with only input_paths (no path parameter)
     debug!("Executing match command with only input_paths");
     let args = MatchArgs {
-        path: None,                                                // No main path
         input_paths: vec![input1_dir.clone(), input2_dir.clone()], // Only input paths
-        recursive: false,
         dry_run: true,
-        confidence: 80,
-        backup: false,
-        copy: false,
-        move_files: false,
+        ..Default::default()
     };
 
     let result = match_command::execute(args, &config_service).await;
@@ -292,12 +283,8 @@ async fn test_match_with_file_and_directory_inputs() {
     let args = MatchArgs {
         path: Some(main_dir.clone()),                // Directory as main path
         input_paths: vec![input_video_file.clone()], // Specific file as input path
-        recursive: false,
         dry_run: true,
-        confidence: 80,
-        backup: false,
-        copy: false,
-        move_files: false,
+        ..Default::default()
     };
 
     let result = match_command::execute(args, &config_service).await;
@@ -53,12 +53,8 @@ async fn test_match_command_file_discovery_with_combined_paths() {
     let args = MatchArgs {
         path: Some(main_dir.clone()),                              // Main path
         input_paths: vec![input1_dir.clone(), input2_dir.clone()], // Additional input paths
-        recursive: false,
         dry_run: true,
-        confidence: 80,
-        backup: false,
-        copy: false,
-        move_files: false,
+        ..Default::default()
     };
 
     // Test that get_input_handler combines all paths correctly
@@ -147,13 +143,8 @@ async fn test_match_command_simple_execution_with_combined_paths() {
     // Execute match command with path parameter (traditional way)
     let args = MatchArgs {
         path: Some(main_dir.clone()),
-        input_paths: vec![], // No additional input paths for this simple test
-        recursive: false,
         dry_run: true,
-        confidence: 80,
-        backup: false,
-        copy: false,
-        move_files: false,
+        ..Default::default()
     };
 
     debug!("Executing match command with simple path");
@@ -215,12 +206,8 @@ async fn test_match_command_with_individual_files_and_directories() {
             dir2.clone(),      // Directory
             subtitle1.clone(), // Another individual file (same parent as video1)
         ],
-        recursive: false,
         dry_run: true,
-        confidence: 80,
-        backup: false,
-        copy: false,
-        move_files: false,
+        ..Default::default()
     };
 
     // Test that get_input_handler handles mixed inputs correctly
@@ -55,14 +55,10 @@ async fn test_copy_mode_preserves_original_file() {
 
     // Debug: check what get_input_handler returns
     let args = MatchArgs {
-        input_paths: vec![],
         recursive: true, // Fixed: need recursive to find files in subdirectories
         path: Some(root.to_path_buf()),
-        dry_run: false,
-        confidence: 80,
-        backup: false,
         copy: true,
-        move_files: false,
+        ..Default::default()
     };
     let input_handler = args.get_input_handler().unwrap();
     let directories = input_handler.get_directories();
@@ -136,14 +132,10 @@ async fn test_copy_mode_with_rename() {
         .await;
 
     let args = MatchArgs {
-        input_paths: vec![],
         recursive: true, // Fixed: need recursive to find files in subdirectories
         path: Some(root.to_path_buf()),
-        dry_run: false,
-        confidence: 80,
-        backup: false,
         copy: true,
-        move_files: false,
+        ..Default::default()
     };
     let config_service = TestConfigBuilder::new()
         .with_mock_ai_server(&mock_helper.base_url())
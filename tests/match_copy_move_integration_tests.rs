@@ -5,7 +5,7 @@
 
 use std::fs;
 use std::path::PathBuf;
-use subx_cli::cli::MatchArgs;
+use subx_cli::cli::{ConflictArg, MatchArgs};
 use subx_cli::commands::match_command;
 use subx_cli::config::TestConfigBuilder;
 use tempfile::TempDir;
@@ -42,14 +42,11 @@ async fn test_match_copy_operation() {
 
     // Test match with copy operation
     let args = MatchArgs {
-        input_paths: vec![],
-        recursive: false,
         path: Some(root.to_path_buf()),
-        dry_run: false,
         confidence: 50,
         backup: true,
         copy: true,
-        move_files: false,
+        ..Default::default()
     };
 
     let config_service = TestConfigBuilder::new()
@@ -124,14 +121,11 @@ async fn test_match_move_operation() {
 
     // Test match with move operation
     let args = MatchArgs {
-        input_paths: vec![],
-        recursive: false,
         path: Some(root.to_path_buf()),
-        dry_run: false,
         confidence: 50,
         backup: true,
-        copy: false,
         move_files: true,
+        ..Default::default()
     };
 
     let config_service = TestConfigBuilder::new()
@@ -206,14 +200,12 @@ async fn test_match_copy_dry_run() {
 
     // Test dry run with copy operation
     let args = MatchArgs {
-        input_paths: vec![],
         recursive: true,
         path: Some(root.to_path_buf()),
         dry_run: true,
         confidence: 50,
-        backup: false,
         copy: true,
-        move_files: false,
+        ..Default::default()
     };
 
     let config_service = TestConfigBuilder::new()
@@ -245,14 +237,11 @@ async fn test_match_copy_dry_run() {
 #[test]
 fn test_copy_move_mutual_exclusion() {
     let args = MatchArgs {
-        input_paths: vec![],
-        recursive: false,
         path: Some(PathBuf::from("/tmp")),
         dry_run: true,
-        confidence: 80,
-        backup: false,
         copy: true,
         move_files: true, // Both copy and move set to true
+        ..Default::default()
     };
 
     let validation_result = args.validate();
@@ -271,14 +260,9 @@ fn test_copy_move_mutual_exclusion() {
 #[test]
 fn test_no_operation_mode() {
     let args = MatchArgs {
-        input_paths: vec![],
-        recursive: false,
         path: Some(PathBuf::from("/tmp")),
         dry_run: true,
-        confidence: 80,
-        backup: false,
-        copy: false,
-        move_files: false, // Neither copy nor move
+        ..Default::default()
     };
 
     let validation_result = args.validate();
@@ -287,3 +271,127 @@ fn test_no_operation_mode() {
         "Should allow neither copy nor move (traditional rename only)"
     );
 }
+
+fn link_args(paths: Vec<PathBuf>, symlink: bool, hardlink: bool, relative: bool) -> MatchArgs {
+    MatchArgs {
+        input_paths: paths,
+        confidence: 50,
+        symlink,
+        hardlink,
+        relative,
+        ..Default::default()
+    }
+}
+
+/// Test that symlink and hardlink modes reference the archived subtitle
+#[cfg(unix)]
+#[tokio::test]
+async fn test_match_link_operations_keep_archive() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    let archive = root.join("archive");
+    let media = root.join("media");
+    fs::create_dir_all(&archive).unwrap();
+    fs::create_dir_all(&media).unwrap();
+    let video = media.join("Show.S01E01.mkv");
+    fs::write(&video, "fake video content").unwrap();
+    let original = archive.join("show.s01e01.srt");
+    fs::write(&original, "1\n00:00:01,000 --> 00:00:02,000\nHi\n\n").unwrap();
+    let config_service = TestConfigBuilder::new()
+        .with_ai_provider("local")
+        .build_service();
+
+    let args = link_args(vec![video.clone(), original.clone()], true, false, true);
+    match_command::execute(args, &config_service).await.unwrap();
+    let link = media.join("Show.S01E01.srt");
+    assert_eq!(
+        fs::read_link(&link).unwrap(),
+        PathBuf::from("../archive/show.s01e01.srt")
+    );
+    assert!(original.exists());
+    fs::remove_file(&link).unwrap();
+
+    let args = link_args(vec![video.clone(), original.clone()], false, true, false);
+    match_command::execute(args, &config_service).await.unwrap();
+    assert!(!fs::symlink_metadata(&link).unwrap().is_symlink());
+    fs::write(&original, "1\n00:00:01,000 --> 00:00:02,000\nHello\n\n").unwrap();
+    assert!(fs::read_to_string(&link).unwrap().contains("Hello"));
+}
+
+/// Test that re-matching an organised library replaces identical subtitles
+/// instead of numbering them, and that --dedupe removes leftover copies
+#[tokio::test]
+async fn test_match_overwrite_identical_and_dedupe() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    let archive = root.join("archive");
+    let media = root.join("media");
+    fs::create_dir_all(&archive).unwrap();
+    fs::create_dir_all(&media).unwrap();
+    let video = media.join("Show.S01E01.mkv");
+    fs::write(&video, "fake video content").unwrap();
+    let content = "1\n00:00:01,000 --> 00:00:02,000\nHi\n\n";
+    let original = archive.join("show.s01e01.srt");
+    fs::write(&original, content).unwrap();
+    fs::write(media.join("Show.S01E01.srt"), content).unwrap();
+    fs::write(media.join("Show.S01E01.1.srt"), content).unwrap();
+    let config_service = TestConfigBuilder::new()
+        .with_ai_provider("local")
+        .build_service();
+
+    let args = |dedupe| MatchArgs {
+        copy: true,
+        on_conflict: Some(ConflictArg::OverwriteIdentical),
+        dedupe,
+        ..link_args(vec![video.clone(), original.clone()], false, false, false)
+    };
+    match_command::execute(args(false), &config_service)
+        .await
+        .unwrap();
+    assert_eq!(
+        fs::read_to_string(media.join("Show.S01E01.srt")).unwrap(),
+        content
+    );
+    assert!(media.join("Show.S01E01.1.srt").exists());
+    assert!(!media.join("Show.S01E01.2.srt").exists());
+
+    match_command::execute(args(true), &config_service)
+        .await
+        .unwrap();
+    assert!(media.join("Show.S01E01.srt").exists());
+    assert!(!media.join("Show.S01E01.1.srt").exists());
+    assert!(original.exists());
+}
+
+/// Test that --dedupe does not remove the subtitle a symlink or hard link
+/// written by the run points to
+#[cfg(unix)]
+#[tokio::test]
+async fn test_match_dedupe_keeps_linked_subtitle() {
+    let content = "1\n00:00:01,000 --> 00:00:02,000\nHello there friend\n\n";
+    let config_service = TestConfigBuilder::new()
+        .with_ai_provider("local")
+        .build_service();
+
+    for (symlink, hardlink) in [(true, false), (false, true)] {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("Movie.2020.mkv"), "fake video content").unwrap();
+        let original = root.join("Movie.2020.English.srt");
+        fs::write(&original, content).unwrap();
+
+        let args = MatchArgs {
+            path: Some(root.to_path_buf()),
+            confidence: 0,
+            no_ai: true,
+            dedupe: true,
+            ..link_args(Vec::new(), symlink, hardlink, false)
+        };
+        match_command::execute(args, &config_service).await.unwrap();
+        assert_eq!(fs::read_to_string(&original).unwrap(), content);
+        assert_eq!(
+            fs::read_to_string(root.join("Movie.2020.en.srt")).unwrap(),
+            content
+        );
+    }
+}
@@ -92,14 +92,10 @@ async fn test_multiple_files_rename_to_same_target_with_auto_rename() {
 
     // Execute match command with copy mode to test rename conflicts
     let args = MatchArgs {
-        input_paths: vec![],
         recursive: true,
         path: Some(root.to_path_buf()),
-        dry_run: false,
-        confidence: 80,
-        backup: false,
         copy: true,
-        move_files: false,
+        ..Default::default()
     };
 
     let config_service = TestConfigBuilder::new()
@@ -247,14 +243,10 @@ async fn test_conflict_resolution_with_existing_target_files() {
 
     // Execute match command
     let args = MatchArgs {
-        input_paths: vec![],
         recursive: true,
         path: Some(root.to_path_buf()),
-        dry_run: false,
-        confidence: 80,
-        backup: false,
         copy: true,
-        move_files: false,
+        ..Default::default()
     };
 
     let config_service = TestConfigBuilder::new()
@@ -381,14 +373,10 @@ async fn test_move_mode_with_duplicate_rename_conflicts() {
 
     // Execute match command with move mode
     let args = MatchArgs {
-        input_paths: vec![],
         recursive: true,
         path: Some(root.to_path_buf()),
-        dry_run: false,
-        confidence: 80,
-        backup: false,
-        copy: false,
         move_files: true,
+        ..Default::default()
     };
 
     let config_service = TestConfigBuilder::new()
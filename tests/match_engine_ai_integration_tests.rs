@@ -92,14 +92,12 @@ async fn test_parallel_match_operations_with_mock() {
         .build_service();
 
     let args = MatchArgs {
-        input_paths: vec![],
         recursive: true,
         path: Some(root.to_path_buf()),
-        dry_run: false,
         confidence: 50,
         backup: true,
         copy: true,
-        move_files: false,
+        ..Default::default()
     };
 
     let start = Instant::now();
@@ -138,14 +136,11 @@ async fn test_confidence_threshold_filtering() {
         .build_service();
 
     let args = MatchArgs {
-        input_paths: vec![],
         recursive: true,
         path: Some(root.to_path_buf()),
-        dry_run: false,
-        confidence: 80,
         backup: true,
         copy: true,
-        move_files: false,
+        ..Default::default()
     };
 
     let result = match_command::execute(args, &config_service).await;
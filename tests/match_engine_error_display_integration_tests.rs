@@ -64,6 +64,9 @@ async fn test_rename_operation_success_and_error_messages() {
             relocation_mode: FileRelocationMode::None,
             conflict_resolution: ConflictResolution::Skip,
             ai_model: "test-model".to_string(),
+            output_encoding: None,
+            naming: subx_cli::core::matcher::NamingTemplate::default(),
+            verification_margin: 0.0,
         },
     );
 
@@ -106,6 +109,9 @@ async fn test_file_relocation_operations_with_success_indicators() {
             relocation_mode: FileRelocationMode::None,
             conflict_resolution: ConflictResolution::Skip,
             ai_model: "test-model".to_string(),
+            output_encoding: None,
+            naming: subx_cli::core::matcher::NamingTemplate::default(),
+            verification_margin: 0.0,
         },
     );
 
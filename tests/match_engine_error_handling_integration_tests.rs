@@ -35,14 +35,10 @@ async fn test_unauthorized_error_handling() {
         .build_service();
 
     let args = MatchArgs {
-        input_paths: vec![],
-        recursive: false,
         path: Some(root.to_path_buf()),
-        dry_run: false,
         confidence: 50,
-        backup: false,
         copy: true,
-        move_files: false,
+        ..Default::default()
     };
 
     let result = match_command::execute(args, &config_service).await;
@@ -69,14 +65,10 @@ async fn test_rate_limit_error_handling() {
         .build_service();
 
     let args = MatchArgs {
-        input_paths: vec![],
-        recursive: false,
         path: Some(root.to_path_buf()),
-        dry_run: false,
         confidence: 50,
-        backup: false,
         copy: true,
-        move_files: false,
+        ..Default::default()
     };
 
     let result = match_command::execute(args, &config_service).await;
@@ -103,14 +95,10 @@ async fn test_internal_server_error_handling() {
         .build_service();
 
     let args = MatchArgs {
-        input_paths: vec![],
-        recursive: false,
         path: Some(root.to_path_buf()),
-        dry_run: false,
         confidence: 50,
-        backup: false,
         copy: true,
-        move_files: false,
+        ..Default::default()
     };
 
     let result = match_command::execute(args, &config_service).await;
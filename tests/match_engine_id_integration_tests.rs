@@ -105,6 +105,9 @@ async fn test_file_id_based_matching_integration() {
         relocation_mode: subx_cli::core::matcher::engine::FileRelocationMode::None,
         conflict_resolution: subx_cli::core::matcher::engine::ConflictResolution::Skip,
         ai_model: "test-model".to_string(),
+        output_encoding: None,
+        naming: subx_cli::core::matcher::NamingTemplate::default(),
+        verification_margin: 0.0,
     };
 
     let engine = MatchEngine::new(Box::new(MockAIClientWithIds), config);
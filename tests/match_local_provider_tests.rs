@@ -0,0 +1,187 @@
+//! Integration tests for offline matching with the local heuristics provider
+//! and the local pre-filter in front of an AI provider.
+
+use async_trait::async_trait;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use subx_cli::cli::MatchArgs;
+use subx_cli::commands::match_command;
+use subx_cli::config::TestConfigBuilder;
+use subx_cli::core::matcher::engine::{ConflictResolution, FileRelocationMode};
+use subx_cli::core::matcher::{MatchConfig, MatchEngine};
+use subx_cli::services::ai::{
+    AIProvider, AnalysisRequest, ConfidenceScore, LocalProvider, MatchResult, VerificationRequest,
+};
+use tempfile::TempDir;
+
+// Using async mutex to avoid environment variable race conditions while avoiding clippy::await_holding_lock warning
+static TEST_MUTEX: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+/// AI provider that records the subtitles it is asked about and matches none.
+struct RecordingAI {
+    subtitle_requests: Arc<Mutex<Vec<Vec<String>>>>,
+}
+
+#[async_trait]
+impl AIProvider for RecordingAI {
+    async fn analyze_content(&self, request: AnalysisRequest) -> subx_cli::Result<MatchResult> {
+        self.subtitle_requests
+            .lock()
+            .unwrap()
+            .push(request.subtitle_files);
+        Ok(MatchResult {
+            matches: Vec::new(),
+            confidence: 0.0,
+            reasoning: String::new(),
+        })
+    }
+
+    async fn verify_match(&self, _req: VerificationRequest) -> subx_cli::Result<ConfidenceScore> {
+        unimplemented!()
+    }
+}
+
+fn match_config() -> MatchConfig {
+    MatchConfig {
+        confidence_threshold: 0.8,
+        max_sample_length: 1024,
+        enable_content_analysis: true,
+        backup_enabled: false,
+        relocation_mode: FileRelocationMode::None,
+        conflict_resolution: ConflictResolution::Skip,
+        ai_model: "test-model".to_string(),
+        output_encoding: None,
+        naming: subx_cli::core::matcher::NamingTemplate::default(),
+        verification_margin: 0.0,
+    }
+}
+
+fn write_files(root: &std::path::Path, names: &[&str]) -> Vec<PathBuf> {
+    names
+        .iter()
+        .map(|name| {
+            let path = root.join(name);
+            fs::write(&path, format!("content for {name}")).unwrap();
+            path
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_local_provider_matches_episodes_offline() {
+    let _guard = TEST_MUTEX.lock().await;
+    let temp_dir = TempDir::new().unwrap();
+    unsafe {
+        std::env::set_var("XDG_CONFIG_HOME", temp_dir.path());
+    }
+    let media = temp_dir.path().join("media");
+    fs::create_dir_all(&media).unwrap();
+    let files = write_files(
+        &media,
+        &[
+            "[Noumin Kanren no Skill][01][BDRIP][1080P][H264_FLACx2].mkv",
+            "[Noumin Kanren no Skill][02][BDRIP][1080P][H264_FLACx2].mkv",
+            "Noumin Kanren no Skill S01E02-[1080p][BDRIP][x265.FLAC].cht.srt",
+            "Noumin Kanren no Skill S01E01-[1080p][BDRIP][x265.FLAC].cht.srt",
+        ],
+    );
+
+    let engine = MatchEngine::new(Box::new(LocalProvider::new()), match_config());
+    let mut operations = engine.match_file_list(&files).await.unwrap();
+    operations.sort_by(|a, b| a.video_file.name.cmp(&b.video_file.name));
+
+    assert_eq!(operations.len(), 2);
+    assert!(operations[0].subtitle_file.name.contains("S01E01"));
+    assert!(operations[1].subtitle_file.name.contains("S01E02"));
+    assert_eq!(
+        operations[0].new_subtitle_name,
+        "[Noumin Kanren no Skill][01][BDRIP][1080P][H264_FLACx2].tc.srt"
+    );
+    assert!(operations.iter().all(|op| !op.reasoning.is_empty()));
+}
+
+#[tokio::test]
+async fn test_local_prefilter_sends_only_unmatched_subtitles_to_ai() {
+    let _guard = TEST_MUTEX.lock().await;
+    let temp_dir = TempDir::new().unwrap();
+    unsafe {
+        std::env::set_var("XDG_CONFIG_HOME", temp_dir.path());
+    }
+    let media = temp_dir.path().join("media");
+    fs::create_dir_all(&media).unwrap();
+    let files = write_files(
+        &media,
+        &[
+            "Show.S01E01.mkv",
+            "Show.S01E02.mkv",
+            "Show.S01E01.srt",
+            "mystery.srt",
+        ],
+    );
+
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let ai = RecordingAI {
+        subtitle_requests: requests.clone(),
+    };
+    let engine = MatchEngine::new(Box::new(ai), match_config()).with_local_prefilter(true);
+    let operations = engine.match_file_list(&files).await.unwrap();
+
+    assert_eq!(operations.len(), 1);
+    assert_eq!(operations[0].subtitle_file.name, "Show.S01E01.srt");
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].len(), 1);
+    assert!(requests[0][0].contains("mystery.srt"));
+}
+
+#[tokio::test]
+async fn test_local_prefilter_skips_ai_when_everything_matches() {
+    let _guard = TEST_MUTEX.lock().await;
+    let temp_dir = TempDir::new().unwrap();
+    unsafe {
+        std::env::set_var("XDG_CONFIG_HOME", temp_dir.path());
+    }
+    let media = temp_dir.path().join("media");
+    fs::create_dir_all(&media).unwrap();
+    let files = write_files(&media, &["movie.mkv", "movie.en.srt"]);
+
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let ai = RecordingAI {
+        subtitle_requests: requests.clone(),
+    };
+    let engine = MatchEngine::new(Box::new(ai), match_config()).with_local_prefilter(true);
+    let operations = engine.match_file_list(&files).await.unwrap();
+
+    assert_eq!(operations.len(), 1);
+    assert_eq!(operations[0].new_subtitle_name, "movie.en.srt");
+    assert!(requests.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_match_command_no_ai_renames_without_api_key() {
+    let _guard = TEST_MUTEX.lock().await;
+    let temp_dir = TempDir::new().unwrap();
+    unsafe {
+        std::env::set_var("XDG_CONFIG_HOME", temp_dir.path());
+    }
+    let media = temp_dir.path().join("media");
+    fs::create_dir_all(&media).unwrap();
+    write_files(&media, &["Show.S02E03.mkv", "Show 2x03.srt"]);
+
+    // The configured provider has no API key and would fail to start
+    let config_service = TestConfigBuilder::new()
+        .with_ai_provider("openai")
+        .with_ai_model("gpt-4.1-mini")
+        .build_service();
+    let args = MatchArgs {
+        path: Some(media.clone()),
+        no_ai: true,
+        ..Default::default()
+    };
+
+    match_command::execute(args, &config_service).await.unwrap();
+
+    assert!(media.join("Show.S02E03.srt").exists());
+    assert!(!media.join("Show 2x03.srt").exists());
+}
@@ -0,0 +1,157 @@
+//! Integration tests for the second-pass verification of matches that fall
+//! just below the confidence threshold.
+
+use async_trait::async_trait;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use subx_cli::core::matcher::engine::{ConflictResolution, FileRelocationMode};
+use subx_cli::core::matcher::{MatchConfig, MatchEngine, NamingTemplate};
+use subx_cli::services::ai::{
+    AIProvider, AnalysisRequest, ConfidenceScore, FileMatch, MatchResult, VerificationRequest,
+};
+use tempfile::TempDir;
+
+// Using async mutex to avoid environment variable race conditions while avoiding clippy::await_holding_lock warning
+static TEST_MUTEX: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+/// AI provider that pairs each subtitle with the video of the same episode
+/// at a fixed confidence and verifies only the first episode.
+struct GreyZoneAI {
+    confidence: f32,
+    verifications: Arc<Mutex<Vec<VerificationRequest>>>,
+}
+
+#[async_trait]
+impl AIProvider for GreyZoneAI {
+    async fn analyze_content(&self, request: AnalysisRequest) -> subx_cli::Result<MatchResult> {
+        let id = |entry: &str| entry[3..entry.find(" |").unwrap()].to_string();
+        let matches = request
+            .subtitle_files
+            .iter()
+            .map(|subtitle| {
+                let episode = if subtitle.contains("E01") {
+                    "E01"
+                } else {
+                    "E02"
+                };
+                let video = request
+                    .video_files
+                    .iter()
+                    .find(|v| v.contains(episode))
+                    .unwrap();
+                FileMatch {
+                    video_file_id: id(video),
+                    subtitle_file_id: id(subtitle),
+                    confidence: self.confidence,
+                    match_factors: vec!["episode_number".to_string()],
+                }
+            })
+            .collect();
+        Ok(MatchResult {
+            matches,
+            confidence: self.confidence,
+            reasoning: String::new(),
+        })
+    }
+
+    async fn verify_match(&self, req: VerificationRequest) -> subx_cli::Result<ConfidenceScore> {
+        let score = if req.subtitle_file.contains("E01") {
+            0.92
+        } else {
+            0.4
+        };
+        self.verifications.lock().unwrap().push(req);
+        Ok(ConfidenceScore {
+            score,
+            factors: vec!["verified".to_string()],
+        })
+    }
+}
+
+fn match_config(verification_margin: f32) -> MatchConfig {
+    MatchConfig {
+        confidence_threshold: 0.8,
+        max_sample_length: 1024,
+        enable_content_analysis: true,
+        backup_enabled: false,
+        relocation_mode: FileRelocationMode::None,
+        conflict_resolution: ConflictResolution::Skip,
+        ai_model: "test-model".to_string(),
+        output_encoding: None,
+        naming: NamingTemplate::default(),
+        verification_margin,
+    }
+}
+
+fn write_files(root: &std::path::Path) -> Vec<PathBuf> {
+    [
+        "Show.S01E01.mkv",
+        "Show.S01E02.mkv",
+        "ep.E01.srt",
+        "ep.E02.srt",
+    ]
+    .iter()
+    .map(|name| {
+        let path = root.join(name);
+        fs::write(&path, format!("1\n00:00:01,000 --> 00:00:02,000\n{name}\n")).unwrap();
+        path
+    })
+    .collect()
+}
+
+#[tokio::test]
+async fn test_grey_zone_matches_are_promoted_or_rejected_by_verification() {
+    let _guard = TEST_MUTEX.lock().await;
+    let temp_dir = TempDir::new().unwrap();
+    unsafe {
+        std::env::set_var("XDG_CONFIG_HOME", temp_dir.path());
+    }
+    let files = write_files(temp_dir.path());
+
+    let verifications = Arc::new(Mutex::new(Vec::new()));
+    let ai = GreyZoneAI {
+        confidence: 0.75,
+        verifications: verifications.clone(),
+    };
+    let engine = MatchEngine::new(Box::new(ai), match_config(0.1));
+    let operations = engine.match_file_list(&files).await.unwrap();
+
+    assert_eq!(operations.len(), 1);
+    assert_eq!(operations[0].subtitle_file.name, "ep.E01.srt");
+    assert_eq!(operations[0].confidence, 0.92);
+    assert!(operations[0].reasoning.contains(&"verified".to_string()));
+
+    let verifications = verifications.lock().unwrap();
+    assert_eq!(verifications.len(), 2);
+    let request = &verifications[0];
+    assert!(request.content_sample.as_deref().unwrap().contains("ep.E0"));
+    assert!(
+        request
+            .directory_listing
+            .contains(&"Show.S01E01.mkv".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_matches_below_the_margin_are_not_verified() {
+    let _guard = TEST_MUTEX.lock().await;
+    let temp_dir = TempDir::new().unwrap();
+    unsafe {
+        std::env::set_var("XDG_CONFIG_HOME", temp_dir.path());
+    }
+    let files = write_files(temp_dir.path());
+
+    for (confidence, margin) in [(0.6, 0.1), (0.75, 0.0)] {
+        let verifications = Arc::new(Mutex::new(Vec::new()));
+        let ai = GreyZoneAI {
+            confidence,
+            verifications: verifications.clone(),
+        };
+        let engine = MatchEngine::new(Box::new(ai), match_config(margin));
+        let operations = engine.match_file_list(&files).await.unwrap();
+
+        assert!(operations.is_empty());
+        assert!(verifications.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,74 @@
+//! Integration tests for the bilingual `merge` command.
+
+use std::fs;
+use subx_cli::cli::{MergeArgs, OutputSubtitleFormat};
+use subx_cli::commands::merge_command;
+use subx_cli::config::TestConfigService;
+use tempfile::TempDir;
+
+fn write_tracks(dir: &std::path::Path) -> (std::path::PathBuf, std::path::PathBuf) {
+    let zh = dir.join("movie.tc.srt");
+    let en = dir.join("movie.en.srt");
+    fs::write(
+        &zh,
+        "1\n00:00:01,000 --> 00:00:03,000\n你好\n\n2\n00:00:05,000 --> 00:00:07,000\n再見\n\n",
+    )
+    .unwrap();
+    fs::write(
+        &en,
+        "1\n00:00:01,200 --> 00:00:03,100\nHello\n\n2\n00:00:09,000 --> 00:00:10,000\nThe end\n\n",
+    )
+    .unwrap();
+    (zh, en)
+}
+
+fn merge_args(primary: std::path::PathBuf, secondary: std::path::PathBuf) -> MergeArgs {
+    MergeArgs {
+        primary,
+        secondary,
+        output: None,
+        format: None,
+        min_overlap: 0.5,
+        top_font: None,
+        bottom_font: None,
+        force: false,
+    }
+}
+
+#[tokio::test]
+async fn test_merge_to_ass_with_language_fonts() {
+    let temp = TempDir::new().unwrap();
+    let (zh, en) = write_tracks(temp.path());
+    let mut args = merge_args(zh, en);
+    args.format = Some(OutputSubtitleFormat::Ass);
+    args.bottom_font = Some("Helvetica".to_string());
+
+    let config_service = TestConfigService::with_defaults();
+    merge_command::execute(args, &config_service).await.unwrap();
+
+    let content = fs::read_to_string(temp.path().join("movie.tc-en.ass")).unwrap();
+    assert!(content.contains("Style: Top,Microsoft JhengHei,"));
+    assert!(content.contains("Style: Bottom,Helvetica,"));
+    assert!(content.contains(",Top,,0000,0000,0000,,你好"));
+    assert!(content.contains(",Bottom,,0000,0000,0000,,Hello"));
+    // Unpaired cues from both tracks are preserved
+    assert!(content.contains(",Top,,0000,0000,0000,,再見"));
+    assert!(content.contains(",Bottom,,0000,0000,0000,,The end"));
+}
+
+#[tokio::test]
+async fn test_merge_format_inferred_from_output_extension() {
+    let temp = TempDir::new().unwrap();
+    let (zh, en) = write_tracks(temp.path());
+    let output = temp.path().join("bilingual.vtt");
+    let mut args = merge_args(zh, en);
+    args.output = Some(output.clone());
+
+    let config_service = TestConfigService::with_defaults();
+    merge_command::execute(args, &config_service).await.unwrap();
+
+    let content = fs::read_to_string(&output).unwrap();
+    assert!(content.starts_with("WEBVTT"));
+    assert!(content.contains("你好\nHello"));
+    assert!(content.contains("The end"));
+}
@@ -0,0 +1,75 @@
+//! Integration tests for the `split` and `join` commands.
+
+use std::fs;
+use std::time::Duration;
+use subx_cli::cli::{JoinArgs, SplitArgs};
+use subx_cli::commands::{join_command, split_command};
+use subx_cli::config::TestConfigService;
+use tempfile::TempDir;
+
+#[tokio::test]
+async fn test_split_then_join_round_trip() {
+    let temp = TempDir::new().unwrap();
+    let input = temp.path().join("episode.srt");
+    fs::write(
+        &input,
+        "1\n00:00:01,000 --> 00:00:02,000\nOne\n\n2\n00:00:05,000 --> 00:00:06,000\nTwo\n\n3\n00:00:10,500 --> 00:00:12,000\nThree\n\n",
+    )
+    .unwrap();
+    let config_service = TestConfigService::with_defaults();
+
+    let split = SplitArgs {
+        input: input.clone(),
+        at: None,
+        at_index: Some(2),
+        output: Vec::new(),
+        force: false,
+    };
+    split_command::execute(split, &config_service)
+        .await
+        .unwrap();
+
+    let part1 = temp.path().join("episode.part1.srt");
+    let part2 = temp.path().join("episode.part2.srt");
+    let second = fs::read_to_string(&part2).unwrap();
+    assert!(second.contains("1\n00:00:00,000 --> 00:00:01,000\nTwo"));
+    assert!(second.contains("2\n00:00:05,500 --> 00:00:07,000\nThree"));
+
+    let output = temp.path().join("rejoined.srt");
+    let join = JoinArgs {
+        parts: vec![part1, part2],
+        offset: vec![Duration::from_secs(5)],
+        videos: Vec::new(),
+        output: Some(output.clone()),
+        force: false,
+    };
+    join_command::execute(join, &config_service).await.unwrap();
+
+    let rejoined = fs::read_to_string(&output).unwrap();
+    assert!(rejoined.contains("2\n00:00:05,000 --> 00:00:06,000\nTwo"));
+    assert!(rejoined.contains("3\n00:00:10,500 --> 00:00:12,000\nThree"));
+}
+
+#[tokio::test]
+async fn test_split_refuses_to_overwrite_without_force() {
+    let temp = TempDir::new().unwrap();
+    let input = temp.path().join("episode.srt");
+    fs::write(
+        &input,
+        "1\n00:00:01,000 --> 00:00:02,000\nOne\n\n2\n00:00:05,000 --> 00:00:06,000\nTwo\n\n",
+    )
+    .unwrap();
+    let existing = temp.path().join("episode.part2.srt");
+    fs::write(&existing, "existing").unwrap();
+
+    let args = SplitArgs {
+        input,
+        at: Some(Duration::from_secs(3)),
+        at_index: None,
+        output: Vec::new(),
+        force: false,
+    };
+    let config_service = TestConfigService::with_defaults();
+    assert!(split_command::execute(args, &config_service).await.is_err());
+    assert_eq!(fs::read_to_string(&existing).unwrap(), "existing");
+}
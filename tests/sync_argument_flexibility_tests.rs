@@ -2,7 +2,7 @@
 
 use std::fs;
 use std::sync::Arc;
-use subx_cli::cli::{SyncArgs, SyncMethodArg, SyncMode};
+use subx_cli::cli::{SyncArgs, SyncMethodArg, SyncMode, UnderflowPolicyArg, UnmappableArg};
 use subx_cli::commands::sync_command;
 use subx_cli::config::TestConfigService;
 use tempfile::TempDir;
@@ -21,6 +21,14 @@ async fn test_sync_batch_with_directory_argument() {
         input_paths: Vec::new(),
         recursive: false,
         offset: None,
+        from: None,
+        to: None,
+        stretch: None,
+        on_underflow: UnderflowPolicyArg::Clamp,
+        encoding: None,
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
         method: None,
         window: 30,
         vad_sensitivity: None,
@@ -60,6 +68,14 @@ async fn test_sync_single_video_positional() {
         input_paths: Vec::new(),
         recursive: false,
         offset: None,
+        from: None,
+        to: None,
+        stretch: None,
+        on_underflow: UnderflowPolicyArg::Clamp,
+        encoding: None,
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
         method: None,
         window: 30,
         vad_sensitivity: None,
@@ -103,6 +119,14 @@ async fn test_sync_manual_offset_with_positional() {
         input_paths: Vec::new(),
         recursive: false,
         offset: Some(2.5),
+        from: None,
+        to: None,
+        stretch: None,
+        on_underflow: UnderflowPolicyArg::Clamp,
+        encoding: None,
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
         method: Some(SyncMethodArg::Manual),
         window: 30,
         vad_sensitivity: None,
@@ -133,6 +157,14 @@ async fn test_sync_validation_errors() {
         input_paths: Vec::new(),
         recursive: false,
         offset: None,
+        from: None,
+        to: None,
+        stretch: None,
+        on_underflow: UnderflowPolicyArg::Clamp,
+        encoding: None,
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
         method: None,
         window: 30,
         vad_sensitivity: None,
@@ -6,7 +6,7 @@
 use std::fs;
 use tempfile::TempDir;
 
-use subx_cli::cli::{SyncArgs, SyncMethodArg};
+use subx_cli::cli::{SyncArgs, SyncMethodArg, UnderflowPolicyArg, UnmappableArg};
 
 /// Test basic functionality of CLI parameter parsing
 #[test]
@@ -28,6 +28,14 @@ fn test_sync_args_basic_parsing() {
         input_paths: vec![],
         recursive: false,
         offset: None,
+        from: None,
+        to: None,
+        stretch: None,
+        on_underflow: UnderflowPolicyArg::Clamp,
+        encoding: None,
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
         method: Some(SyncMethodArg::Vad),
         window: 30,
         vad_sensitivity: Some(0.8),
@@ -61,6 +69,14 @@ fn test_sync_args_vad_method() {
         input_paths: vec![],
         recursive: false,
         offset: None,
+        from: None,
+        to: None,
+        stretch: None,
+        on_underflow: UnderflowPolicyArg::Clamp,
+        encoding: None,
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
         method: Some(SyncMethodArg::Vad),
         window: 45,
         vad_sensitivity: Some(0.7),
@@ -93,6 +109,14 @@ fn test_sync_args_manual_method() {
         video: None, // Manual offset doesn't require video file
         subtitle: Some(subtitle_path.clone()),
         offset: Some(2.5),
+        from: None,
+        to: None,
+        stretch: None,
+        on_underflow: UnderflowPolicyArg::Clamp,
+        encoding: None,
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
         method: Some(SyncMethodArg::Manual),
         window: 30,
         vad_sensitivity: None,
@@ -127,6 +151,14 @@ fn test_sync_args_batch_mode() {
         video: Some(video_path.clone()),
         subtitle: Some(subtitle_path.clone()),
         offset: None,
+        from: None,
+        to: None,
+        stretch: None,
+        on_underflow: UnderflowPolicyArg::Clamp,
+        encoding: None,
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
         method: Some(SyncMethodArg::Vad),
         window: 30,
         vad_sensitivity: None,
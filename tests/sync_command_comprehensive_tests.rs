@@ -7,7 +7,7 @@
 use std::fs;
 use std::path::PathBuf;
 use std::sync::Arc;
-use subx_cli::cli::SyncArgs;
+use subx_cli::cli::{SyncArgs, UnderflowPolicyArg, UnmappableArg};
 use subx_cli::commands::sync_command::{execute, execute_with_config};
 use subx_cli::config::{ConfigService, TestConfigService};
 use tempfile::TempDir;
@@ -23,10 +23,18 @@ mod sync_command_tests {
             input_paths: vec![],
             recursive: false,
             offset: None,
+            from: None,
+            to: None,
+            stretch: None,
+            on_underflow: UnderflowPolicyArg::Clamp,
             method: None,
             window: 30,
             vad_sensitivity: None,
             output: None,
+            encoding: None,
+            bom: false,
+            line_ending: None,
+            on_unmappable: UnmappableArg::Fail,
             verbose: false,
             dry_run: false,
             force: false,
@@ -1,7 +1,7 @@
 use std::fs;
 use std::sync::Arc;
 use subx_cli::Result;
-use subx_cli::cli::SyncArgs;
+use subx_cli::cli::{SyncArgs, UnderflowPolicyArg, UnmappableArg};
 use subx_cli::commands::sync_command;
 use subx_cli::config::{Config, TestConfigService};
 use subx_cli::core::sync::SyncEngine;
@@ -26,6 +26,14 @@ async fn test_manual_offset_exceeds_max_limit() -> Result<()> {
         input_paths: vec![],
         recursive: false,
         offset: Some(45.0),
+        from: None,
+        to: None,
+        stretch: None,
+        on_underflow: UnderflowPolicyArg::Clamp,
+        encoding: None,
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
         method: Some(subx_cli::cli::SyncMethodArg::Manual),
         window: 30,
         vad_sensitivity: None,
@@ -67,6 +75,14 @@ async fn test_manual_offset_within_limit() -> Result<()> {
         input_paths: vec![],
         recursive: false,
         offset: Some(25.0),
+        from: None,
+        to: None,
+        stretch: None,
+        on_underflow: UnderflowPolicyArg::Clamp,
+        encoding: None,
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
         method: Some(subx_cli::cli::SyncMethodArg::Manual), // Explicitly specify manual mode
         window: 30,
         vad_sensitivity: None,
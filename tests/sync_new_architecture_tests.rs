@@ -2,7 +2,7 @@ use std::fs;
 use std::path::Path;
 use tempfile::TempDir;
 
-use subx_cli::cli::{SyncArgs, SyncMethodArg};
+use subx_cli::cli::{SyncArgs, SyncMethodArg, UnderflowPolicyArg, UnmappableArg};
 use subx_cli::core::sync::SyncMethod;
 
 /// Test comprehensive integration functionality of the new sync architecture
@@ -31,6 +31,14 @@ fn test_sync_args_with_vad_method() {
         input_paths: vec![],
         recursive: false,
         offset: None,
+        from: None,
+        to: None,
+        stretch: None,
+        on_underflow: UnderflowPolicyArg::Clamp,
+        encoding: None,
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
         method: Some(SyncMethodArg::Vad),
         window: 45,
         vad_sensitivity: Some(0.8),
@@ -63,6 +71,14 @@ fn test_sync_args_with_vad_default_settings() {
         input_paths: vec![],
         recursive: false,
         offset: None,
+        from: None,
+        to: None,
+        stretch: None,
+        on_underflow: UnderflowPolicyArg::Clamp,
+        encoding: None,
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
         method: Some(SyncMethodArg::Vad),
         window: 30,
         vad_sensitivity: None,
@@ -91,6 +107,14 @@ fn test_sync_args_with_manual_offset() {
         input_paths: vec![],
         recursive: false,
         offset: Some(2.5),
+        from: None,
+        to: None,
+        stretch: None,
+        on_underflow: UnderflowPolicyArg::Clamp,
+        encoding: None,
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
         method: Some(SyncMethodArg::Manual),
         window: 30,
         vad_sensitivity: None,
@@ -123,6 +147,14 @@ fn test_sync_args_batch_mode() {
         input_paths: vec![],
         recursive: false,
         offset: None,
+        from: None,
+        to: None,
+        stretch: None,
+        on_underflow: UnderflowPolicyArg::Clamp,
+        encoding: None,
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
         method: Some(SyncMethodArg::Vad), // Use Vad instead of Auto
         window: 30,
         vad_sensitivity: None,
@@ -152,6 +184,14 @@ fn test_sync_args_validation() {
         input_paths: vec![],
         recursive: false,
         offset: None, // Missing offset
+        from: None,
+        to: None,
+        stretch: None,
+        on_underflow: UnderflowPolicyArg::Clamp,
+        encoding: None,
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
         method: Some(SyncMethodArg::Manual),
         window: 30,
         vad_sensitivity: None,
@@ -172,6 +212,14 @@ fn test_sync_args_validation() {
     let valid_args = SyncArgs {
         positional_paths: Vec::new(),
         offset: Some(2.5), // Provide offset
+        from: None,
+        to: None,
+        stretch: None,
+        on_underflow: UnderflowPolicyArg::Clamp,
+        encoding: None,
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
         ..args
     };
 
@@ -0,0 +1,151 @@
+//! Integration tests for the persistent journal and the `undo` command.
+
+use async_trait::async_trait;
+use std::fs;
+use subx_cli::cli::{ConvertArgs, OutputSubtitleFormat, UndoArgs, UnmappableArg};
+use subx_cli::commands::{convert_command, undo_command};
+use subx_cli::config::TestConfigService;
+use subx_cli::core::journal::Journal;
+use subx_cli::core::matcher::engine::{ConflictResolution, FileRelocationMode};
+use subx_cli::core::matcher::{MatchConfig, MatchEngine, NamingTemplate};
+use subx_cli::services::ai::{
+    AIProvider, AnalysisRequest, ConfidenceScore, FileMatch, MatchResult, VerificationRequest,
+};
+use tempfile::TempDir;
+
+// Using async mutex to avoid environment variable race conditions while avoiding clippy::await_holding_lock warning
+static TEST_MUTEX: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+/// AI provider that pairs the only subtitle with the only video.
+struct SingleMatchAI;
+
+#[async_trait]
+impl AIProvider for SingleMatchAI {
+    async fn analyze_content(&self, request: AnalysisRequest) -> subx_cli::Result<MatchResult> {
+        let id = |entry: &str| entry[3..entry.find(" |").unwrap()].to_string();
+        Ok(MatchResult {
+            matches: vec![FileMatch {
+                video_file_id: id(&request.video_files[0]),
+                subtitle_file_id: id(&request.subtitle_files[0]),
+                confidence: 0.95,
+                match_factors: vec!["single_pair".to_string()],
+            }],
+            confidence: 0.95,
+            reasoning: String::new(),
+        })
+    }
+
+    async fn verify_match(&self, _req: VerificationRequest) -> subx_cli::Result<ConfidenceScore> {
+        unreachable!()
+    }
+}
+
+fn undo_args(run_id: Option<String>) -> UndoArgs {
+    UndoArgs {
+        run_id,
+        list: false,
+        dry_run: false,
+    }
+}
+
+#[tokio::test]
+async fn test_undo_restores_converted_original() {
+    let _guard = TEST_MUTEX.lock().await;
+    let temp_dir = TempDir::new().unwrap();
+    unsafe {
+        std::env::set_var("XDG_CONFIG_HOME", temp_dir.path());
+    }
+    let input = temp_dir.path().join("movie.srt");
+    let content = "1\n00:00:01,000 --> 00:00:02,000\nHello\n\n";
+    fs::write(&input, content).unwrap();
+
+    let config_service = TestConfigService::with_defaults();
+    let args = ConvertArgs {
+        input: Some(input.clone()),
+        input_paths: vec![],
+        recursive: false,
+        format: Some(OutputSubtitleFormat::Vtt),
+        output: None,
+        keep_original: false,
+        encoding: "utf-8".to_string(),
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
+        strip_sdh: None,
+    };
+    convert_command::execute(args, &config_service)
+        .await
+        .unwrap();
+    let output = temp_dir.path().join("movie.vtt");
+    assert!(output.exists());
+    assert!(!input.exists());
+
+    undo_command::execute(undo_args(None), &config_service)
+        .await
+        .unwrap();
+    assert_eq!(fs::read_to_string(&input).unwrap(), content);
+    assert!(!output.exists());
+
+    // Nothing is left to undo
+    assert!(
+        undo_command::execute(undo_args(None), &config_service)
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_undo_refuses_when_renamed_subtitle_was_edited() {
+    let _guard = TEST_MUTEX.lock().await;
+    let temp_dir = TempDir::new().unwrap();
+    unsafe {
+        std::env::set_var("XDG_CONFIG_HOME", temp_dir.path());
+    }
+    let video = temp_dir.path().join("Movie.2020.mkv");
+    let subtitle = temp_dir.path().join("subs.srt");
+    fs::write(&video, b"").unwrap();
+    fs::write(&subtitle, "1\n00:00:01,000 --> 00:00:02,000\nHi\n\n").unwrap();
+
+    let config = MatchConfig {
+        confidence_threshold: 0.8,
+        max_sample_length: 1024,
+        enable_content_analysis: false,
+        backup_enabled: false,
+        relocation_mode: FileRelocationMode::None,
+        conflict_resolution: ConflictResolution::Skip,
+        ai_model: "test-model".to_string(),
+        output_encoding: None,
+        naming: NamingTemplate::default(),
+        verification_margin: 0.0,
+    };
+    let journal = Journal::start("match").unwrap();
+    let run_id = journal.run_id().to_string();
+    let engine = MatchEngine::new(Box::new(SingleMatchAI), config).with_journal(journal);
+    let operations = engine
+        .match_file_list(&[video.clone(), subtitle.clone()])
+        .await
+        .unwrap();
+    engine.execute_operations(&operations, false).await.unwrap();
+    let renamed = temp_dir.path().join("Movie.2020.srt");
+    assert!(renamed.exists());
+
+    fs::write(&renamed, "edited").unwrap();
+    let config_service = TestConfigService::with_defaults();
+    let result = undo_command::execute(undo_args(Some(run_id.clone())), &config_service).await;
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("changed since the run")
+    );
+    assert_eq!(fs::read_to_string(&renamed).unwrap(), "edited");
+    assert!(!subtitle.exists());
+
+    // Restoring the content makes the run undoable again
+    fs::write(&renamed, "1\n00:00:01,000 --> 00:00:02,000\nHi\n\n").unwrap();
+    undo_command::execute(undo_args(Some(run_id)), &config_service)
+        .await
+        .unwrap();
+    assert!(subtitle.exists());
+    assert!(!renamed.exists());
+}
@@ -7,7 +7,10 @@
 use std::fs;
 use tempfile::TempDir;
 
-use subx_cli::cli::{ConvertArgs, DetectEncodingArgs, InputPathHandler, MatchArgs, SyncArgs};
+use subx_cli::cli::{
+    ConvertArgs, DetectEncodingArgs, InputPathHandler, MatchArgs, SyncArgs, UnderflowPolicyArg,
+    UnmappableArg,
+};
 use subx_cli::error::SubXError;
 
 /// Test that MatchArgs correctly merges path and input_paths
@@ -26,12 +29,7 @@ fn test_match_args_path_merging() {
     let args = MatchArgs {
         path: Some(dir1.clone()),
         input_paths: vec![dir2.clone(), file1.clone()],
-        dry_run: false,
-        confidence: 80,
-        recursive: false,
-        backup: false,
-        copy: false,
-        move_files: false,
+        ..Default::default()
     };
 
     let handler = args.get_input_handler().unwrap();
@@ -66,6 +64,10 @@ fn test_convert_args_path_merging() {
         output: None,
         keep_original: false,
         encoding: "utf-8".to_string(),
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
+        strip_sdh: None,
     };
 
     let handler = args.get_input_handler().unwrap();
@@ -99,6 +101,14 @@ fn test_sync_args_path_merging() {
         input_paths: vec![dir1.clone()],
         recursive: false,
         offset: None,
+        from: None,
+        to: None,
+        stretch: None,
+        on_underflow: UnderflowPolicyArg::Clamp,
+        encoding: None,
+        bom: false,
+        line_ending: None,
+        on_unmappable: UnmappableArg::Fail,
         method: Some(SyncMethodArg::Vad),
         window: 30,
         vad_sensitivity: None,
@@ -135,6 +145,10 @@ fn test_detect_encoding_args_path_merging() {
         verbose: false,
         input_paths: vec![dir1.clone()],
         recursive: false,
+        fix_mojibake: false,
+        convert: false,
+        to: None,
+        force: false,
         file_paths: vec![
             file1.to_string_lossy().to_string(),
             file2.to_string_lossy().to_string(),
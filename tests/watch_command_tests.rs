@@ -0,0 +1,125 @@
+//! Integration tests for the `watch` command's pipeline, run with `--once`.
+
+use std::fs;
+use std::path::Path;
+use subx_cli::cli::WatchArgs;
+use subx_cli::commands::watch_command;
+use subx_cli::config::TestConfigBuilder;
+use tempfile::TempDir;
+
+// Using async mutex to avoid environment variable race conditions while avoiding clippy::await_holding_lock warning
+static TEST_MUTEX: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+fn watch_once(root: &Path, state: &Path, log: &Path) -> WatchArgs {
+    WatchArgs {
+        paths: vec![root.to_path_buf()],
+        recursive: false,
+        once: true,
+        debounce: None,
+        state_file: Some(state.to_path_buf()),
+        log_file: Some(log.to_path_buf()),
+    }
+}
+
+fn log_events(log: &Path) -> Vec<serde_json::Value> {
+    fs::read_to_string(log)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_watch_converts_and_matches_new_files_once() {
+    let _guard = TEST_MUTEX.lock().await;
+    let temp = TempDir::new().unwrap();
+    unsafe {
+        std::env::set_var("XDG_CONFIG_HOME", temp.path().join("config"));
+    }
+    let library = temp.path().join("library");
+    fs::create_dir(&library).unwrap();
+    fs::write(library.join("Show.S01E01.mkv"), b"video").unwrap();
+    fs::write(
+        library.join("show_s01e01.vtt"),
+        "WEBVTT\n\n00:00:01.000 --> 00:00:02.000\nHello\n",
+    )
+    .unwrap();
+    let state = temp.path().join("state.json");
+    let log = temp.path().join("watch.log");
+
+    let config_service = TestConfigBuilder::new()
+        .with_ai_provider("local")
+        .with_watch_pipeline(&["convert", "match"])
+        .with_watch_relocation("none")
+        .build_service();
+    watch_command::execute(watch_once(&library, &state, &log), &config_service)
+        .await
+        .unwrap();
+
+    let mut names: Vec<String> = fs::read_dir(&library)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+    // The converted original is kept unless `watch.delete_converted` is set
+    assert_eq!(
+        names,
+        vec!["Show.S01E01.mkv", "Show.S01E01.srt", "show_s01e01.vtt"]
+    );
+
+    let events = log_events(&log);
+    assert_eq!(events[0]["event"], "batch");
+    assert!(
+        events
+            .iter()
+            .any(|e| e["step"] == "convert" && e["level"] == "info")
+    );
+    assert!(
+        events
+            .iter()
+            .any(|e| e["step"] == "match" && e["level"] == "info")
+    );
+
+    // Nothing is processed twice
+    watch_command::execute(watch_once(&library, &state, &log), &config_service)
+        .await
+        .unwrap();
+    assert_eq!(log_events(&log).len(), events.len());
+}
+
+#[tokio::test]
+async fn test_watch_retries_unmatched_subtitle_when_video_arrives() {
+    let _guard = TEST_MUTEX.lock().await;
+    let temp = TempDir::new().unwrap();
+    unsafe {
+        std::env::set_var("XDG_CONFIG_HOME", temp.path().join("config"));
+    }
+    let downloads = temp.path().join("downloads");
+    fs::create_dir(&downloads).unwrap();
+    let subtitle = downloads.join("movie.2020.srt");
+    fs::write(&subtitle, "1\n00:00:01,000 --> 00:00:02,000\nHi\n\n").unwrap();
+    let state = temp.path().join("state.json");
+    let log = temp.path().join("watch.log");
+
+    let config_service = TestConfigBuilder::new()
+        .with_ai_provider("local")
+        .with_watch_pipeline(&["match"])
+        .with_watch_relocation("none")
+        .build_service();
+    watch_command::execute(watch_once(&downloads, &state, &log), &config_service)
+        .await
+        .unwrap();
+    assert!(subtitle.exists());
+    assert!(
+        log_events(&log)
+            .iter()
+            .any(|e| e["step"] == "match" && e["level"] == "warn")
+    );
+
+    fs::write(downloads.join("Movie.2020.1080p.mkv"), b"video").unwrap();
+    watch_command::execute(watch_once(&downloads, &state, &log), &config_service)
+        .await
+        .unwrap();
+    assert!(!subtitle.exists());
+    assert!(downloads.join("Movie.2020.1080p.srt").exists());
+}
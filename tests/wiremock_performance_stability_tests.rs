@@ -33,14 +33,13 @@ async fn test_high_load_scenario() {
         .build_service();
 
     let args = MatchArgs {
-        input_paths: vec![],
         recursive: true,
         path: Some(root.to_path_buf()),
         dry_run: true,
         confidence: 50,
         backup: true,
         copy: true,
-        move_files: false,
+        ..Default::default()
     };
 
     let start_time = std::time::Instant::now();
@@ -72,14 +71,12 @@ async fn test_memory_stability() {
             .build_service();
 
         let args = MatchArgs {
-            input_paths: vec![],
             recursive: true,
             path: Some(root.to_path_buf()),
             dry_run: true,
             confidence: 50,
             backup: true,
-            copy: false,
-            move_files: false,
+            ..Default::default()
         };
 
         let result = match_command::execute(args, &config_service).await;